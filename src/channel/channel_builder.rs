@@ -12,9 +12,23 @@
 
 
 use ChannelBuilder;
+use NamespaceExtension;
 use enums::Day;
+use extension::atom::{self, AtomLink};
+use extension::comments;
+use extension::content;
+use extension::georss;
+use extension::googleplay::{self, GooglePlayChannelExtension};
+use extension::itunes::ITunesChannelExtensionBuilder;
+use extension::media::{self, MediaExtension};
+use extension::podcast::{self, PodcastChannelExtension};
+use extension::registry;
+use extension::syndication::{self, SyndicationExtension};
 use rss::{Category, Channel, Cloud, Image, Item, TextInput};
-use rss::extension::itunes::ITunesChannelExtension;
+use rss::extension::{Extension, ExtensionMap};
+use rss::extension::dublincore::DublinCoreExtension;
+use rss::extension::itunes::{self, ITunesChannelExtension};
+use std::collections::HashMap;
 use utils::string_utils;
 
 
@@ -479,12 +493,349 @@ impl ChannelBuilder
     /// channel_builder.itunes_ext(Some(itunes_channel));
     /// ```
     pub fn itunes_ext(&mut self, itunes_ext: Option<ITunesChannelExtension>) -> &mut ChannelBuilder
+    {
+        self.itunes_ext = itunes_ext.map(ITunesChannelExtensionBuilder::from);
+        self
+    }
+
+
+    /// Set the optional itunes channel extension that exists under `Channel`
+    /// from its builder, keeping the show type and the title that
+    /// `ITunesChannelExtension` has no fields for in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.author(Some("Jupiter Broadcasting".to_owned())).show_type(Some("serial".to_owned()));
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .itunes_ext_builder(Some(itunes_builder))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("Jupiter Broadcasting".to_owned()), channel.itunes_ext().unwrap().author);
+    /// assert_eq!(Some("serial".to_owned()), channel.itunes_show_type());
+    /// ```
+    pub fn itunes_ext_builder(&mut self, itunes_ext: Option<ITunesChannelExtensionBuilder>) -> &mut ChannelBuilder
     {
         self.itunes_ext = itunes_ext;
         self
     }
 
 
+    /// Set the optional dublin_core_ext that exists under `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creator(vec!["Jupiter Broadcasting".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.dublin_core_ext(Some(dublin_core));
+    /// ```
+    pub fn dublin_core_ext(&mut self, dublin_core_ext: Option<DublinCoreExtension>) -> &mut ChannelBuilder
+    {
+        self.dublin_core_ext = dublin_core_ext;
+        self
+    }
+
+
+    /// Set the optional media_ext that exists under `Channel`.
+    ///
+    /// The Media RSS elements it covers replace those in the extensions, and
+    /// the other `media` extensions are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media = MediaExtensionBuilder::new()
+    ///     .keywords(vec!["Linux".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.media_ext(Some(media));
+    /// ```
+    pub fn media_ext(&mut self, media_ext: Option<MediaExtension>) -> &mut ChannelBuilder
+    {
+        self.media_ext = media_ext;
+        self
+    }
+
+
+    /// Set the atom links that exist under `Channel`.
+    ///
+    /// They replace the `atom:link` extensions, and the other Atom elements
+    /// are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .rel(Some("self".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.atom_links(vec![link]);
+    /// ```
+    pub fn atom_links(&mut self, atom_links: Vec<AtomLink>) -> &mut ChannelBuilder
+    {
+        self.atom_links = Some(atom_links);
+        self
+    }
+
+
+    /// Set the optional googleplay_ext that exists under `Channel`.
+    ///
+    /// The Google Play tags it covers replace those in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let googleplay = GooglePlayChannelExtensionBuilder::new()
+    ///     .explicit(Some("no".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.googleplay_ext(Some(googleplay));
+    /// ```
+    pub fn googleplay_ext(&mut self, googleplay_ext: Option<GooglePlayChannelExtension>) -> &mut ChannelBuilder
+    {
+        self.googleplay_ext = googleplay_ext;
+        self
+    }
+
+
+    /// Set the optional podcast_ext that exists under `Channel`.
+    ///
+    /// The Podcasting 2.0 tags it covers replace those in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastFundingBuilder};
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value("Support the show!")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .funding(vec![funding])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.podcast_ext(Some(podcast));
+    /// ```
+    pub fn podcast_ext(&mut self, podcast_ext: Option<PodcastChannelExtension>) -> &mut ChannelBuilder
+    {
+        self.podcast_ext = podcast_ext;
+        self
+    }
+
+
+    /// Set the optional syndication_ext that exists under `Channel`.
+    ///
+    /// The syndication tags it covers replace those in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let syndication = SyndicationExtensionBuilder::new()
+    ///     .update_period(Some(SyndicationPeriod::Daily))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.syndication_ext(Some(syndication));
+    /// ```
+    pub fn syndication_ext(&mut self, syndication_ext: Option<SyndicationExtension>) -> &mut ChannelBuilder
+    {
+        self.syndication_ext = syndication_ext;
+        self
+    }
+
+
+    /// Set a `NamespaceExtension` that exists under `Channel`.
+    ///
+    /// Its elements replace those with the same name under its prefix in the
+    /// extensions. The extension must be registered with
+    /// `extension::registry::register` before the builder is finalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelBuilder, NamespaceExtension};
+    /// use feed::extension::registry;
+    /// use rss::extension::{Extension, ExtensionMap};
+    /// use std::collections::HashMap;
+    ///
+    /// struct License(String);
+    ///
+    /// impl NamespaceExtension for License
+    /// {
+    ///     fn namespace() -> &'static str
+    ///     {
+    ///         "http://backend.userland.com/creativeCommonsRssModule"
+    ///     }
+    ///
+    ///     fn prefix() -> &'static str
+    ///     {
+    ///         "creativeCommons"
+    ///     }
+    ///
+    ///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+    ///     {
+    ///         let element = map.get("license").and_then(|elements| elements.first());
+    ///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+    ///     }
+    ///
+    ///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    ///     {
+    ///         let license = Extension {
+    ///             name: "creativeCommons:license".to_owned(),
+    ///             value: Some(self.0.clone()),
+    ///             ..Default::default()
+    ///         };
+    ///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+    ///     }
+    /// }
+    ///
+    /// fn main()
+    /// {
+    ///     registry::register::<License>().unwrap();
+    ///
+    ///     let work = Extension {
+    ///         name: "creativeCommons:work".to_owned(),
+    ///         value: Some("Linux Action Show".to_owned()),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     extensions.insert("creativeCommons".to_owned(),
+    ///                       vec![("work".to_owned(), vec![work])].into_iter().collect());
+    ///
+    ///     let channel = ChannelBuilder::new()
+    ///         .title("The Linux Action Show! OGG")
+    ///         .link("http://www.jupiterbroadcasting.com")
+    ///         .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///         .extensions(extensions)
+    ///         .namespace_ext(&License("http://www.creativecommons.org/licenses/by-nc-sa/1.0".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(2, channel.extensions["creativeCommons"].len());
+    /// }
+    /// ```
+    pub fn namespace_ext<T: NamespaceExtension>(&mut self, namespace_ext: &T) -> &mut ChannelBuilder
+    {
+        self.namespace_exts
+            .entry(T::prefix().to_owned())
+            .or_default()
+            .extend(namespace_ext.to_map());
+        self
+    }
+
+
+    /// Set the extensions that exist under `Channel`.
+    ///
+    /// Elements from namespaces the builder does not model are kept here,
+    /// keyed by prefix and then by name, so they are written back out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::ChannelBuilder;
+    /// use rss::extension::{Extension, ExtensionMap};
+    /// use std::collections::HashMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let info = Extension {
+    ///         name: "feedburner:info".to_owned(),
+    ///         attrs: vec![("uri".to_owned(), "linuxactionshowogg".to_owned())]
+    ///             .into_iter()
+    ///             .collect(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let mut feedburner = HashMap::new();
+    ///     feedburner.insert("info".to_owned(), vec![info]);
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     extensions.insert("feedburner".to_owned(), feedburner);
+    ///
+    ///     let mut channel_builder = ChannelBuilder::new();
+    ///     channel_builder.extensions(extensions);
+    /// }
+    /// ```
+    pub fn extensions(&mut self, extensions: ExtensionMap) -> &mut ChannelBuilder
+    {
+        self.extensions = extensions;
+        self
+    }
+
+
+    /// Set the namespaces that exist under `Channel`, keyed by prefix.
+    ///
+    /// The iTunes and Dublin Core namespaces are always declared, so they do
+    /// not need to be given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut namespaces = HashMap::new();
+    /// namespaces.insert("feedburner".to_owned(),
+    ///                   "http://rssnamespace.org/feedburner/ext/1.0".to_owned());
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.namespaces(namespaces);
+    /// ```
+    pub fn namespaces(&mut self, namespaces: HashMap<String, String>) -> &mut ChannelBuilder
+    {
+        self.namespaces = namespaces;
+        self
+    }
+
+
     /// Validate the contents of `Channel`.
     ///
     /// # Examples
@@ -613,6 +964,153 @@ impl ChannelBuilder
 
         let ttl = string_utils::option_i64_to_option_string(self.ttl)?;
 
+        // `rss::Channel` has no rating field, so it is kept as an element
+        // without a namespace prefix.
+        let mut extensions = self.extensions.clone();
+        if let Some(rating) = self.rating.clone()
+        {
+            extensions.entry(String::new())
+                .or_default()
+                .insert("rating".to_owned(),
+                        vec![Extension {
+                                 name: "rating".to_owned(),
+                                 value: Some(rating),
+                                 ..Default::default()
+                             }]);
+        }
+
+        if let Some(media) = self.media_ext.clone()
+        {
+            media.insert_into(&mut extensions);
+        }
+
+        if let Some(ref atom_links) = self.atom_links
+        {
+            AtomLink::insert_into(atom_links, &mut extensions);
+        }
+
+        if let Some(ref googleplay) = self.googleplay_ext
+        {
+            googleplay.insert_into(&mut extensions);
+        }
+
+        if let Some(ref podcast) = self.podcast_ext
+        {
+            podcast.insert_into(&mut extensions);
+        }
+
+        let itunes_ext = match self.itunes_ext
+        {
+            Some(ref itunes_builder) =>
+            {
+                itunes_builder.insert_into(&mut extensions);
+                Some(itunes_builder.finalize()?)
+            }
+            None => None,
+        };
+
+        if let Some(ref syndication) = self.syndication_ext
+        {
+            syndication.insert_into(&mut extensions);
+        }
+
+        for (prefix, elements) in &self.namespace_exts
+        {
+            if registry::namespace(prefix).is_none()
+            {
+                return Err(format!("Invalid value: {}. The prefix is not registered.", prefix));
+            }
+
+            extensions
+                .entry(prefix.clone())
+                .or_default()
+                .extend(elements.clone());
+        }
+
+        let mut namespaces = self.namespaces.clone();
+        let has_media = extensions.contains_key("media") ||
+                        self.items.iter().any(|item| item.extensions.contains_key("media"));
+        if has_media && !namespaces.contains_key("media")
+        {
+            namespaces.insert("media".to_owned(), media::NAMESPACE.to_owned());
+        }
+
+        let has_atom = extensions.contains_key("atom") ||
+                       self.items.iter().any(|item| item.extensions.contains_key("atom"));
+        if has_atom && !namespaces.contains_key("atom")
+        {
+            namespaces.insert("atom".to_owned(), atom::NAMESPACE.to_owned());
+        }
+
+        let has_googleplay = extensions.contains_key("googleplay") ||
+                             self.items.iter().any(|item| item.extensions.contains_key("googleplay"));
+        if has_googleplay && !namespaces.contains_key("googleplay")
+        {
+            namespaces.insert("googleplay".to_owned(), googleplay::NAMESPACE.to_owned());
+        }
+
+        let has_podcast = extensions.contains_key("podcast") ||
+                          self.items.iter().any(|item| item.extensions.contains_key("podcast"));
+        if has_podcast && !namespaces.contains_key("podcast")
+        {
+            namespaces.insert("podcast".to_owned(), podcast::NAMESPACE.to_owned());
+        }
+
+        if extensions.contains_key("sy") && !namespaces.contains_key("sy")
+        {
+            namespaces.insert("sy".to_owned(), syndication::NAMESPACE.to_owned());
+        }
+
+        let has_georss = self.items.iter().any(|item| item.extensions.contains_key("georss"));
+        if has_georss && !namespaces.contains_key("georss")
+        {
+            namespaces.insert("georss".to_owned(), georss::NAMESPACE.to_owned());
+        }
+
+        let has_gml = self.items
+            .iter()
+            .any(|item| item.extensions.get("georss").map(|map| map.contains_key("where")).unwrap_or_default());
+        if has_gml && !namespaces.contains_key("gml")
+        {
+            namespaces.insert("gml".to_owned(), georss::GML_NAMESPACE.to_owned());
+        }
+
+        for &(prefix, namespace) in &[("wfw", comments::WFW_NAMESPACE),
+                                      ("slash", comments::SLASH_NAMESPACE),
+                                      ("thr", comments::THR_NAMESPACE)]
+        {
+            let has_prefix = self.items.iter().any(|item| item.extensions.contains_key(prefix));
+            if has_prefix && !namespaces.contains_key(prefix)
+            {
+                namespaces.insert(prefix.to_owned(), namespace.to_owned());
+            }
+        }
+
+        for (prefix, namespace) in registry::registered()
+        {
+            let has_prefix = extensions.contains_key(prefix) ||
+                             self.items.iter().any(|item| item.extensions.contains_key(prefix));
+            if has_prefix && !namespaces.contains_key(prefix)
+            {
+                namespaces.insert(prefix.to_owned(), namespace.to_owned());
+            }
+        }
+
+        // The `rss` crate only declares the iTunes namespace for its own
+        // iTunes extensions.
+        let has_itunes = extensions.contains_key("itunes") ||
+                         self.items.iter().any(|item| item.extensions.contains_key("itunes"));
+        let declares_itunes = self.itunes_ext.is_some() || self.items.iter().any(|item| item.itunes_ext.is_some());
+        if has_itunes && !declares_itunes && !namespaces.contains_key("itunes")
+        {
+            namespaces.insert("itunes".to_owned(), itunes::NAMESPACE.to_owned());
+        }
+
+        if self.items.iter().any(|item| item.content.is_some()) && !namespaces.contains_key("content")
+        {
+            namespaces.insert("content".to_owned(), content::NAMESPACE.to_owned());
+        }
+
         Ok(Channel {
                title: self.title.clone(),
                link: self.link.clone(),
@@ -633,8 +1131,10 @@ impl ChannelBuilder
                skip_hours: skip_hours,
                skip_days: self.skip_days.clone(),
                items: self.items.clone(),
-               itunes_ext: self.itunes_ext.clone(),
-               ..Default::default()
+               extensions,
+               itunes_ext,
+               dublin_core_ext: self.dublin_core_ext.clone(),
+               namespaces,
            })
     }
 }
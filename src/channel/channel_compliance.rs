@@ -0,0 +1,462 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! `Compliance` Trait for `Channel`
+
+
+use ChannelGetters;
+use Compliance;
+use ComplianceIssue;
+use ComplianceReport;
+use EnclosureGetters;
+use ImageGetters;
+use ItemGetters;
+use enums::{ComplianceProfile, ITunesExplicit};
+use extension::itunes::{ITunesCategoryGetters, ITunesChannelExtensionGetters, ITunesItemExtensionGetters,
+                        ITunesOwnerBuilder, ITunesOwnerGetters, itunes_category_taxonomy};
+use extension::podcast::PodcastChannelExtensionGetters;
+use rss::{Channel, Item};
+use utils::string_utils;
+
+
+// The longest description or summary the directories show, in characters.
+const SUMMARY_LIMIT: usize = 4000;
+
+// The longest subtitle Apple Podcasts shows, in characters.
+const SUBTITLE_LIMIT: usize = 255;
+
+// The video and document types Apple Podcasts accepts besides audio.
+const APPLE_TYPES: &[&str] = &["video/mp4", "video/quicktime", "video/x-m4v", "application/pdf"];
+
+
+// How a directory treats a check.
+#[derive(Clone, Copy)]
+enum Level
+{
+    Error,
+    Warning,
+    Ignore,
+}
+
+
+impl ComplianceReport
+{
+    /// Whether the directory would accept the `Channel`, that is whether
+    /// there are no errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, Compliance};
+    /// use feed::enums::ComplianceProfile;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(!channel.compliance(ComplianceProfile::Apple).is_ready());
+    /// ```
+    pub fn is_ready(&self) -> bool
+    {
+        self.errors.is_empty()
+    }
+
+
+    fn push(&mut self, level: Level, field: &str, message: &str)
+    {
+        let issue = ComplianceIssue {
+            field: field.to_owned(),
+            message: message.to_owned(),
+        };
+
+        match level
+        {
+            Level::Error => self.errors.push(issue),
+            Level::Warning => self.warnings.push(issue),
+            Level::Ignore => (),
+        }
+    }
+}
+
+
+impl Compliance for Channel
+{
+    /// Check `Channel` and its iTunes extensions against what the directory
+    /// of a `ComplianceProfile` requires and recommends.
+    ///
+    /// The artwork, owner, explicit flag, categories, summaries and the
+    /// enclosure and guid of each item are checked. Apple Podcasts and
+    /// Spotify reject what Podcast Index only warns about, and Podcast Index
+    /// also looks for a `podcast:guid` and `podcast:locked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, Compliance, EnclosureBuilder, GuidBuilder, ItemBuilder};
+    /// use feed::enums::ComplianceProfile;
+    /// use feed::extension::itunes::{ITunesCategoryBuilder, ITunesChannelExtensionBuilder, ITunesOwnerBuilder};
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .email(Some("chris@jupiterbroadcasting.com".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let category = ITunesCategoryBuilder::new()
+    ///     .text("Technology")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .author(Some("Jupiter Broadcasting".to_owned()))
+    ///     .image(Some("http://www.jupiterbroadcasting.com/images/LAS-1400x1400.jpg".to_owned()))
+    ///     .explicit(Some("no".to_owned()))
+    ///     .owner(Some(owner))
+    ///     .categories(vec![category])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let enclosure = EnclosureBuilder::new()
+    ///     .url("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jnite/linuxactionshowep408.ogg")
+    ///     .length(65036288)
+    ///     .mime_type("video/ogg")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let guid = GuidBuilder::new()
+    ///     .value("http://www.jupiterbroadcasting.com/97561/")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .enclosure(Some(enclosure))
+    ///     .guid(Some(guid))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .language(Some("en".to_owned()))
+    ///     .itunes_ext(Some(itunes))
+    ///     .items(vec![item])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let report = channel.compliance(ComplianceProfile::Spotify);
+    /// assert!(!report.is_ready());
+    /// assert_eq!("items[0].enclosure.type", report.errors[0].field);
+    ///
+    /// let report = channel.compliance(ComplianceProfile::PodcastIndex);
+    /// assert!(report.is_ready());
+    /// ```
+    fn compliance(&self, profile: ComplianceProfile) -> ComplianceReport
+    {
+        let mut report = ComplianceReport {
+            profile: profile.clone(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        let apple = profile == ComplianceProfile::Apple;
+        let level = |for_apple, for_spotify, for_podcast_index| {
+            pick(&profile, for_apple, for_spotify, for_podcast_index)
+        };
+        let itunes = self.itunes_ext().unwrap_or_default();
+
+        if self.title().trim().is_empty()
+        {
+            report.push(Level::Error, "title", "The show has no title.");
+        }
+
+        if self.description().trim().is_empty()
+        {
+            report.push(level(Level::Error, Level::Error, Level::Warning),
+                        "description",
+                        "The show has no description.");
+        }
+        check_length(&mut report,
+                     level(Level::Error, Level::Error, Level::Warning),
+                     "description",
+                     Some(self.description()),
+                     SUMMARY_LIMIT);
+
+        if self.language().is_none()
+        {
+            report.push(level(Level::Error, Level::Warning, Level::Warning),
+                        "language",
+                        "The show has no language.");
+        }
+
+        let artwork = match (itunes.image(), self.image())
+        {
+            (Some(image), _) => Some(("itunes:image", image)),
+            (None, Some(image)) if !apple => Some(("image.url", image.url())),
+            _ => None,
+        };
+        match artwork
+        {
+            None =>
+            {
+                report.push(level(Level::Error, Level::Error, Level::Warning),
+                            "itunes:image",
+                            "The show has no artwork.")
+            }
+            Some((field, url)) => check_artwork(&mut report, &profile, field, url.as_str()),
+        }
+
+        let owner = itunes.owner().unwrap_or_default();
+        match owner.email()
+        {
+            None =>
+            {
+                report.push(level(Level::Warning, Level::Error, Level::Ignore),
+                            "itunes:owner.itunes:email",
+                            "The show has no owner email, which directories use to verify ownership.")
+            }
+            Some(email) =>
+            {
+                if ITunesOwnerBuilder::new().email(Some(email)).validate().is_err()
+                {
+                    report.push(level(Level::Error, Level::Error, Level::Warning),
+                                "itunes:owner.itunes:email",
+                                "The owner email is not an email address.");
+                }
+            }
+        }
+
+        check_explicit(&mut report,
+                       level(Level::Error, Level::Warning, Level::Warning),
+                       "itunes:explicit",
+                       itunes.explicit());
+
+        if itunes.author().is_none()
+        {
+            report.push(level(Level::Warning, Level::Warning, Level::Ignore),
+                        "itunes:author",
+                        "The show has no author.");
+        }
+
+        if itunes.categories().is_empty()
+        {
+            report.push(level(Level::Error, Level::Warning, Level::Ignore),
+                        "itunes:category",
+                        "The show has no category.");
+        }
+        for (i, category) in itunes.categories().iter().enumerate()
+        {
+            let subcategory = category.subcategory().map(|subcategory| subcategory.text());
+            if let Err(err) = itunes_category_taxonomy::normalize(category.text().as_str(),
+                                                                  subcategory.as_deref())
+            {
+                report.push(level(Level::Error, Level::Warning, Level::Ignore),
+                            format!("itunes:category[{}]", i).as_str(),
+                            err.as_str());
+            }
+        }
+
+        check_length(&mut report,
+                     level(Level::Error, Level::Error, Level::Warning),
+                     "itunes:summary",
+                     itunes.summary(),
+                     SUMMARY_LIMIT);
+        check_length(&mut report,
+                     level(Level::Warning, Level::Ignore, Level::Ignore),
+                     "itunes:subtitle",
+                     itunes.subtitle(),
+                     SUBTITLE_LIMIT);
+
+        if profile == ComplianceProfile::PodcastIndex
+        {
+            let podcast = self.podcast_ext().unwrap_or_default();
+            if podcast.guid().is_none()
+            {
+                report.push(Level::Warning, "podcast:guid", "The show has no podcast:guid.");
+            }
+            if podcast.locked().is_none()
+            {
+                report.push(Level::Warning, "podcast:locked", "The show has no podcast:locked.");
+            }
+        }
+
+        let items = self.items();
+        if items.is_empty()
+        {
+            report.push(level(Level::Error, Level::Error, Level::Warning),
+                        "items",
+                        "The show has no episodes.");
+        }
+        for (i, item) in items.iter().enumerate()
+        {
+            check_item(&mut report, &profile, i, item);
+        }
+
+        report
+    }
+}
+
+
+// The level of a check for the directory of a profile.
+fn pick(profile: &ComplianceProfile, apple: Level, spotify: Level, podcast_index: Level) -> Level
+{
+    match *profile
+    {
+        ComplianceProfile::Apple => apple,
+        ComplianceProfile::Spotify => spotify,
+        ComplianceProfile::PodcastIndex => podcast_index,
+    }
+}
+
+
+// Check an item, the way `compliance` checks the channel.
+fn check_item(report: &mut ComplianceReport, profile: &ComplianceProfile, i: usize, item: &Item)
+{
+    let strict = pick(profile, Level::Error, Level::Error, Level::Warning);
+    let path = |field: &str| format!("items[{}].{}", i, field);
+
+    if item.title().unwrap_or_default().trim().is_empty()
+    {
+        report.push(Level::Error, path("title").as_str(), "The episode has no title.");
+    }
+
+    match item.enclosure()
+    {
+        None => report.push(strict, path("enclosure").as_str(), "The episode has no enclosure."),
+        Some(enclosure) =>
+        {
+            let mime_type = enclosure.mime_type().trim().to_lowercase();
+            let accepted = match *profile
+            {
+                ComplianceProfile::Apple =>
+                {
+                    mime_type.starts_with("audio/") || APPLE_TYPES.contains(&mime_type.as_str())
+                }
+                ComplianceProfile::Spotify => mime_type.starts_with("audio/"),
+                ComplianceProfile::PodcastIndex => mime_type.starts_with("audio/") || mime_type.starts_with("video/"),
+            };
+            if !accepted
+            {
+                report.push(strict,
+                            path("enclosure.type").as_str(),
+                            format!("The enclosure type {} is not audio.", mime_type).as_str());
+            }
+
+            if string_utils::string_to_i64(enclosure.length().trim()).map(|length| length <= 0).unwrap_or(true)
+            {
+                report.push(Level::Warning,
+                            path("enclosure.length").as_str(),
+                            "The enclosure length is not a number of bytes.");
+            }
+        }
+    }
+
+    if item.guid().map(|guid| guid.value).unwrap_or_default().trim().is_empty()
+    {
+        report.push(strict, path("guid").as_str(), "The episode has no guid.");
+    }
+
+    check_length(report, strict, path("description").as_str(), item.description(), SUMMARY_LIMIT);
+
+    let itunes = item.itunes_ext().unwrap_or_default();
+    check_length(report, strict, path("itunes:summary").as_str(), itunes.summary(), SUMMARY_LIMIT);
+    if itunes.explicit().is_some()
+    {
+        check_explicit(report, strict, path("itunes:explicit").as_str(), itunes.explicit());
+    }
+    if itunes.duration().is_some() && itunes.duration_value().is_none()
+    {
+        report.push(Level::Warning,
+                    path("itunes:duration").as_str(),
+                    "The duration is not HH:MM:SS, MM:SS or a number of seconds.");
+    }
+}
+
+
+// Report a missing explicit flag at the given level, and an unreadable one as
+// an error.
+fn check_explicit(report: &mut ComplianceReport, level: Level, field: &str, explicit: Option<String>)
+{
+    match explicit
+    {
+        None => report.push(level, field, "The explicit flag is missing."),
+        Some(explicit) =>
+        {
+            if ITunesExplicit::value_of(explicit.as_str()).is_err()
+            {
+                report.push(Level::Error,
+                            field,
+                            format!("The explicit flag {} is not yes, no, clean, true or false.", explicit).as_str());
+            }
+        }
+    }
+}
+
+
+fn check_length(report: &mut ComplianceReport, level: Level, field: &str, text: Option<String>, limit: usize)
+{
+    if let Some(text) = text
+    {
+        let length = text.chars().count();
+        if length > limit
+        {
+            report.push(level,
+                        field,
+                        format!("The text is {} characters long, more than {}.", length, limit).as_str());
+        }
+    }
+}
+
+
+// Artwork cannot be measured without fetching it, so the format is read from
+// the extension and the size from a `WIDTHxHEIGHT` in the URL.
+fn check_artwork(report: &mut ComplianceReport, profile: &ComplianceProfile, field: &str, url: &str)
+{
+    let path = url.split(&['?', '#'][..]).next().unwrap_or("").to_lowercase();
+    if !path.ends_with(".jpg") && !path.ends_with(".jpeg") && !path.ends_with(".png")
+    {
+        report.push(Level::Warning, field, "The artwork should be a JPEG or PNG.");
+    }
+
+    let min = if *profile == ComplianceProfile::Spotify { 640 } else { 1400 };
+    if let Some((width, height)) = size_hint(path.as_str())
+    {
+        if width != height || width < min || width > 3000
+        {
+            report.push(Level::Warning,
+                        field,
+                        format!("The artwork is {}x{}, it should be square, from {}x{} to 3000x3000.",
+                                width,
+                                height,
+                                min,
+                                min)
+                            .as_str());
+        }
+    }
+}
+
+
+// The last `WIDTHxHEIGHT` in a URL.
+fn size_hint(url: &str) -> Option<(u32, u32)>
+{
+    let name = url.rsplit('/').next().unwrap_or(url);
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter_map(|part| {
+                        let mut sides = part.splitn(2, 'x');
+                        match (sides.next().map(str::parse), sides.next().map(str::parse))
+                        {
+                            (Some(Ok(width)), Some(Ok(height))) => Some((width, height)),
+                            _ => None,
+                        }
+                    })
+        .next_back()
+}
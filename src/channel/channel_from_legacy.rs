@@ -0,0 +1,319 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! `FromLegacy` Trait for `Channel`
+
+
+use ChannelBuilder;
+use FromLegacy;
+use GuidBuilder;
+use ImageBuilder;
+use ItemBuilder;
+use TextInputBuilder;
+use enums::RssVersion;
+use extension::registry;
+use rss::{Channel, Image, Item, TextInput};
+use rss::extension::ExtensionMap;
+use rss::extension::dublincore::DublinCoreExtension;
+use std::str::FromStr;
+use utils::reader_utils::{self, XmlElement};
+
+
+/// The default namespace of an RSS 0.90 document.
+const RSS_090_NAMESPACE: &str = "http://my.netscape.com/rdf/simple/0.9/";
+
+
+impl FromLegacy for Channel
+{
+    /// Construct a `Channel` from an RSS 0.9x, 1.0 or 2.0 document and
+    /// report the `RssVersion` it was written in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelGetters, FromLegacy, ItemGetters};
+    /// use feed::enums::RssVersion;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<?xml version="1.0"?>
+    ///     <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+    ///              xmlns:dc="http://purl.org/dc/elements/1.1/"
+    ///              xmlns="http://purl.org/rss/1.0/">
+    ///       <channel rdf:about="http://www.xml.com/xml/news.rss">
+    ///         <title>XML.com</title>
+    ///         <link>http://xml.com/pub</link>
+    ///         <description>XML.com features a rich mix of information.</description>
+    ///         <items>
+    ///           <rdf:Seq>
+    ///             <rdf:li resource="http://xml.com/pub/2000/08/09/rdfdb/index.html" />
+    ///             <rdf:li resource="http://xml.com/pub/2000/08/09/xslt/xslt.html" />
+    ///           </rdf:Seq>
+    ///         </items>
+    ///       </channel>
+    ///       <item rdf:about="http://xml.com/pub/2000/08/09/xslt/xslt.html">
+    ///         <title>Processing Inclusions with XSLT</title>
+    ///         <link>http://xml.com/pub/2000/08/09/xslt/xslt.html</link>
+    ///       </item>
+    ///       <item rdf:about="http://xml.com/pub/2000/08/09/rdfdb/index.html">
+    ///         <title>Putting RDF to Work</title>
+    ///         <link>http://xml.com/pub/2000/08/09/rdfdb/index.html</link>
+    ///         <dc:creator>Edd Dumbill</dc:creator>
+    ///       </item>
+    ///     </rdf:RDF>"#;
+    ///
+    ///     let (channel, version) = Channel::from_legacy(xml).unwrap();
+    ///     assert_eq!(RssVersion::Rss10, version);
+    ///     assert_eq!("XML.com", channel.title());
+    ///
+    ///     let items = channel.items();
+    ///     assert_eq!(Some("Putting RDF to Work".to_owned()), items[0].title());
+    ///     assert_eq!(vec!["Edd Dumbill".to_owned()],
+    ///                items[0].dublin_core_ext.clone().unwrap().creator);
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelGetters, FromLegacy};
+    /// use feed::enums::RssVersion;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="0.91">
+    ///       <channel>
+    ///         <title>WriteTheWeb</title>
+    ///         <link>http://writetheweb.com</link>
+    ///         <description>News for web users that write back</description>
+    ///         <language>en-us</language>
+    ///         <rating>(PICS-1.1 "http://www.rsac.org/ratingsv01.html" l r (n 0 s 0 v 0 l 0))</rating>
+    ///         <image>
+    ///           <title>WriteTheWeb</title>
+    ///           <url>http://writetheweb.com/images/mynetscape88.gif</url>
+    ///           <link>http://writetheweb.com</link>
+    ///         </image>
+    ///       </channel>
+    ///     </rss>"#;
+    ///
+    ///     let (channel, version) = Channel::from_legacy(xml).unwrap();
+    ///     assert_eq!(RssVersion::Rss091, version);
+    ///     assert!(channel.image().is_some());
+    ///     assert!(channel.rating().is_some());
+    /// }
+    /// ```
+    fn from_legacy(xml: &str) -> Result<(Channel, RssVersion), String>
+    {
+        let root = reader_utils::read_document(xml)?;
+        let (mut channel, version) = match root.name.as_str()
+        {
+            "rss" => from_rss(xml, &root)?,
+            "rdf:RDF" => from_rdf(&root)?,
+            _ => return Err(format!("Invalid value: {}", root.name)),
+        };
+
+        registry::normalize_prefixes(&mut channel);
+        Ok((channel, version))
+    }
+}
+
+
+// Read an RSS 0.91 to 2.0 document, adding the elements the `rss` crate
+// does not know about or drops.
+fn from_rss(xml: &str, root: &XmlElement) -> Result<(Channel, RssVersion), String>
+{
+    let version = RssVersion::value_of(root.attr("version").unwrap_or_else(|| "2.0".to_owned()).as_str())?;
+
+    let mut channel = match Channel::from_str(xml)
+    {
+        Ok(val) => val,
+        Err(err) => return Err(format!("Error: {}", err)),
+    };
+
+    if let Some(element) = root.child("channel")
+    {
+        reader_utils::restore_itunes_elements(&mut channel, element);
+
+        if let Some(rating) = element.child("rating")
+        {
+            reader_utils::insert_extension(&mut channel.extensions, rating);
+        }
+
+        if channel.text_input.is_none()
+        {
+            if let Some(text_input) = element.child("textinput")
+            {
+                channel.text_input = Some(rdf_text_input(text_input)?);
+            }
+        }
+    }
+
+    Ok((channel, version))
+}
+
+
+// Read an RSS 0.90 or RSS 1.0 document.
+fn from_rdf(root: &XmlElement) -> Result<(Channel, RssVersion), String>
+{
+    let version = match root.attr("xmlns")
+    {
+        Some(ref ns) if ns == RSS_090_NAMESPACE => RssVersion::Rss090,
+        _ => RssVersion::Rss10,
+    };
+
+    let element = match root.child("channel")
+    {
+        Some(val) => val,
+        None => return Err("RDF document must contain a channel.".to_owned()),
+    };
+
+    let image = match root.child("image")
+    {
+        Some(val) => Some(rdf_image(val)?),
+        None => None,
+    };
+
+    let text_input = match root.child("textinput")
+    {
+        Some(val) => Some(rdf_text_input(val)?),
+        None => None,
+    };
+
+    let mut items: Vec<(Option<String>, Item)> = Vec::new();
+    for child in root.children.iter().filter(|child| child.name == "item")
+    {
+        items.push((child.attr("rdf:about"), rdf_item(child)?));
+    }
+
+    // `items/rdf:Seq` gives the order of the items, which may differ from
+    // the order they appear in the document.
+    let sequence: Vec<String> = element
+        .child("items")
+        .and_then(|items| items.child("rdf:Seq"))
+        .map(|seq| {
+                 seq.children
+                     .iter()
+                     .filter_map(|li| li.attr("rdf:resource").or_else(|| li.attr("resource")))
+                     .collect()
+             })
+        .unwrap_or_default();
+
+    if !sequence.is_empty()
+    {
+        items.sort_by_key(|item| {
+                              item.0
+                                  .as_ref()
+                                  .and_then(|about| sequence.iter().position(|li| li == about))
+                                  .unwrap_or(sequence.len())
+                          });
+    }
+
+    let mut channel = ChannelBuilder::new()
+        .title(element.child_text("title").unwrap_or_default().as_str())
+        .link(element.child_text("link").unwrap_or_default().as_str())
+        .description(element.child_text("description").unwrap_or_default().as_str())
+        .image(image)
+        .text_input(text_input)
+        .items(items.into_iter().map(|item| item.1).collect())
+        .finalize()?;
+
+    channel.extensions = rdf_extensions(element);
+    channel.itunes_ext = reader_utils::take_itunes_channel_ext(&mut channel.extensions);
+    channel.dublin_core_ext = channel.extensions.remove("dc").map(DublinCoreExtension::from_map);
+
+    for attr in &root.attrs
+    {
+        if attr.0.starts_with("xmlns:")
+        {
+            let prefix = &attr.0["xmlns:".len()..];
+            if prefix != "rdf" && prefix != "itunes" && prefix != "dc"
+            {
+                channel.namespaces.insert(prefix.to_owned(), attr.1.clone());
+            }
+        }
+    }
+
+    Ok((channel, version))
+}
+
+
+fn rdf_item(element: &XmlElement) -> Result<Item, String>
+{
+    let link = element.child_text("link");
+
+    let guid = match element.attr("rdf:about")
+    {
+        Some(about) =>
+        {
+            let is_permalink = link.as_ref() == Some(&about);
+            Some(GuidBuilder::new()
+                     .value(about.as_str())
+                     .is_permalink(Some(is_permalink))
+                     .finalize()?)
+        }
+        None => None,
+    };
+
+    let mut item = ItemBuilder::new()
+        .title(element.child_text("title"))
+        .link(link)
+        .description(element.child_text("description"))
+        .guid(guid)
+        .finalize()?;
+
+    item.content = element.child_text("content:encoded");
+    item.extensions = rdf_extensions(element);
+    item.itunes_ext = reader_utils::take_itunes_item_ext(&mut item.extensions);
+    item.dublin_core_ext = item.extensions.remove("dc").map(DublinCoreExtension::from_map);
+
+    Ok(item)
+}
+
+
+fn rdf_image(element: &XmlElement) -> Result<Image, String>
+{
+    ImageBuilder::new()
+        .url(element.child_text("url").unwrap_or_default().as_str())
+        .title(element.child_text("title").unwrap_or_default().as_str())
+        .link(element.child_text("link").unwrap_or_default().as_str())
+        .finalize()
+}
+
+
+fn rdf_text_input(element: &XmlElement) -> Result<TextInput, String>
+{
+    TextInputBuilder::new()
+        .title(element.child_text("title").unwrap_or_default().as_str())
+        .description(element.child_text("description").unwrap_or_default().as_str())
+        .name(element.child_text("name").unwrap_or_default().as_str())
+        .link(element.child_text("link").unwrap_or_default().as_str())
+        .finalize()
+}
+
+
+fn rdf_extensions(element: &XmlElement) -> ExtensionMap
+{
+    let mut extensions = ExtensionMap::new();
+    for child in &element.children
+    {
+        match child.prefix()
+        {
+            None | Some("rdf") | Some("content") => (),
+            Some(_) => reader_utils::insert_extension(&mut extensions, child),
+        }
+    }
+    extensions
+}
@@ -0,0 +1,294 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! `FromLenient` Trait for `Channel`
+
+
+use FromLenient;
+use ParseIssue;
+use extension::registry;
+use rss::Channel;
+use rss::extension::dublincore::DublinCoreExtension;
+use std::collections::HashSet;
+use utils::reader_utils::{self, XmlElement};
+use utils::repair_utils::{self, Repair};
+use utils::url_utils;
+
+
+/// The namespaces of the prefixes commonly used without being declared.
+const KNOWN_NAMESPACES: &[(&str, &str)] = &[("atom", "http://www.w3.org/2005/Atom"),
+                                            ("content", "http://purl.org/rss/1.0/modules/content/"),
+                                            ("dc", "http://purl.org/dc/elements/1.1/"),
+                                            ("georss", "http://www.georss.org/georss"),
+                                            ("googleplay", "http://www.google.com/schemas/play-podcasts/1.0"),
+                                            ("itunes", "http://www.itunes.com/dtds/podcast-1.0.dtd"),
+                                            ("media", "http://search.yahoo.com/mrss/"),
+                                            ("podcast", "https://podcastindex.org/namespace/1.0"),
+                                            ("slash", "http://purl.org/rss/1.0/modules/slash/"),
+                                            ("sy", "http://purl.org/rss/1.0/modules/syndication/"),
+                                            ("thr", "http://purl.org/syndication/thread/1.0"),
+                                            ("wfw", "http://wellformedweb.org/CommentAPI/")];
+
+
+impl FromLenient for Channel
+{
+    /// Construct a `Channel` from an RSS document, repairing what can be
+    /// repaired and skipping the items that cannot be read.
+    ///
+    /// Control characters are removed, stray ampersands and HTML entities
+    /// are escaped, undeclared namespace prefixes are declared and the
+    /// elements left open by a truncated document are closed. Every repair
+    /// and skipped item is reported as a `ParseIssue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelGetters, FromLenient, ItemGetters};
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\">\n<channel>\n\
+    ///                <title>Tips & Tricks</title>\n\
+    ///                <item><title>Episode 2</title></item>\n\
+    ///                <item><title>Episode 1</title><link></item>\n\
+    ///                <item><title>Episode 0\u{1}</title></item>\n\
+    ///                <item><title>Epis";
+    ///
+    ///     let (channel, issues) = Channel::from_lenient(xml).unwrap();
+    ///
+    ///     assert_eq!("Tips & Tricks", channel.title());
+    ///     assert_eq!(2, channel.items().len());
+    ///     assert_eq!(Some("Episode 0".to_owned()), channel.items()[1].title());
+    ///
+    ///     assert_eq!(3, issues[0].line);
+    ///     assert_eq!(13, issues[0].column);
+    ///     assert_eq!("Escaped stray ampersand", issues[0].message);
+    ///     assert_eq!(5, issues.len());
+    /// }
+    /// ```
+    fn from_lenient(xml: &str) -> Result<(Channel, Vec<ParseIssue>), String>
+    {
+        let mut repairs = Vec::new();
+        let mut skeleton = String::new();
+        let mut items = Vec::new();
+        let mut prefixes = Vec::new();
+        let mut declared = HashSet::new();
+        let mut last = 0;
+
+        for span in repair_utils::item_spans(xml)
+        {
+            skeleton.push_str(repair_utils::repair_text(&xml[last..span.start], last, &mut repairs).as_str());
+            last = span.end;
+
+            if !span.complete
+            {
+                repairs.push(Repair {
+                                 offset: span.start,
+                                 message: "Skipped truncated item".to_owned(),
+                             });
+                continue;
+            }
+
+            let text = repair_utils::repair_text(&xml[span.start..span.end], span.start, &mut repairs);
+            match reader_utils::read_document(text.as_str())
+            {
+                Ok(element) =>
+                {
+                    collect_prefixes(&element, &mut prefixes, &mut declared);
+                    items.push(reader_utils::element_to_item(&element));
+                }
+                Err(err) =>
+                {
+                    repairs.push(Repair {
+                                     offset: span.start,
+                                     message: format!("Skipped unreadable item: {}", err),
+                                 })
+                }
+            }
+        }
+
+        skeleton.push_str(repair_utils::repair_text(&xml[last..], last, &mut repairs).as_str());
+
+        let (skeleton, closed) = repair_utils::close_open_elements(skeleton.as_str())?;
+        if !closed.is_empty()
+        {
+            repairs.push(Repair {
+                             offset: xml.len(),
+                             message: format!("Closed truncated elements: {}", closed.join(", ")),
+                         });
+        }
+
+        let root = reader_utils::read_document(skeleton.as_str())?;
+        if root.name != "rss"
+        {
+            return Err(format!("Invalid value: {}", root.name));
+        }
+
+        let mut channel = Channel::default();
+        for attr in &root.attrs
+        {
+            if attr.0.starts_with("xmlns:")
+            {
+                let prefix = &attr.0["xmlns:".len()..];
+                declared.insert(prefix.to_owned());
+                if prefix != "itunes" && prefix != "dc"
+                {
+                    channel.namespaces.insert(prefix.to_owned(), attr.1.clone());
+                }
+            }
+        }
+
+        if let Some(element) = root.child("channel")
+        {
+            collect_prefixes(element, &mut prefixes, &mut declared);
+            for child in &element.children
+            {
+                reader_utils::apply_channel_element(&mut channel, child);
+            }
+        }
+
+        for prefix in prefixes
+        {
+            if declared.contains(&prefix) || prefix == "xml" || prefix == "xmlns"
+            {
+                continue;
+            }
+            declared.insert(prefix.clone());
+
+            let offset = xml.find(format!("<{}:", prefix).as_str()).unwrap_or(0);
+            let known = KNOWN_NAMESPACES
+                .iter()
+                .find(|known| known.0 == prefix)
+                .map(|known| known.1)
+                .or_else(|| registry::namespace(prefix.as_str()));
+            match known
+            {
+                Some(namespace) =>
+                {
+                    if prefix != "itunes" && prefix != "dc"
+                    {
+                        channel.namespaces.insert(prefix.clone(), namespace.to_owned());
+                    }
+                    repairs.push(Repair {
+                                     offset,
+                                     message: format!("Declared namespace prefix {} as {}", prefix, namespace),
+                                 });
+                }
+                None =>
+                {
+                    repairs.push(Repair {
+                                     offset,
+                                     message: format!("Namespace prefix {} is not declared", prefix),
+                                 })
+                }
+            }
+        }
+
+        channel.items = items;
+        channel.itunes_ext = reader_utils::take_itunes_channel_ext(&mut channel.extensions);
+        channel.dublin_core_ext = channel.extensions.remove("dc").map(DublinCoreExtension::from_map);
+        registry::normalize_prefixes(&mut channel);
+
+        repairs.sort_by_key(|repair| repair.offset);
+        let issues = repairs
+            .into_iter()
+            .map(|repair| {
+                     let (line, column) = repair_utils::line_column(xml, repair.offset);
+                     ParseIssue { line, column, message: repair.message }
+                 })
+            .collect();
+
+        Ok((channel, issues))
+    }
+
+
+    /// Construct a `Channel` from a `Url` the way `from_lenient` does.
+    ///
+    /// Bytes that are not valid UTF-8 are replaced and reported too.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::FromLenient;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let url = "https://feedpress.me/usererror.xml";
+    ///
+    ///     let (channel, issues) = Channel::from_url_lenient(url).unwrap();
+    ///     for issue in issues
+    ///     {
+    ///         println!("{}:{}: {}", issue.line, issue.column, issue.message);
+    ///     }
+    /// }
+    /// ```
+    fn from_url_lenient(url: &str) -> Result<(Channel, Vec<ParseIssue>), String>
+    {
+        let bytes = url_utils::fetch_xml(url)?;
+        let xml = String::from_utf8_lossy(bytes.as_slice()).into_owned();
+        let (channel, mut issues) = Channel::from_lenient(xml.as_str())?;
+
+        if let Some(offset) = xml.find('\u{FFFD}')
+        {
+            if String::from_utf8(bytes).is_err()
+            {
+                let (line, column) = repair_utils::line_column(xml.as_str(), offset);
+                issues.insert(0,
+                              ParseIssue {
+                                  line,
+                                  column,
+                                  message: "Replaced bytes that are not valid UTF-8".to_owned(),
+                              });
+            }
+        }
+
+        Ok((channel, issues))
+    }
+}
+
+
+// Collect the prefixes used under an element, and those declared on the way.
+fn collect_prefixes(element: &XmlElement, prefixes: &mut Vec<String>, declared: &mut HashSet<String>)
+{
+    for attr in &element.attrs
+    {
+        if attr.0.starts_with("xmlns:")
+        {
+            declared.insert(attr.0["xmlns:".len()..].to_owned());
+        }
+    }
+
+    let names = Some(element.name.as_str()).into_iter().chain(element.attrs.iter().map(|attr| attr.0.as_str()));
+
+    for name in names
+    {
+        if let Some(pos) = name.find(':')
+        {
+            let prefix = name[..pos].to_owned();
+            if !prefixes.contains(&prefix)
+            {
+                prefixes.push(prefix);
+            }
+        }
+    }
+
+    for child in &element.children
+    {
+        collect_prefixes(child, prefixes, declared);
+    }
+}
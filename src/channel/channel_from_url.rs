@@ -12,10 +12,10 @@
 
 
 use FromUrl;
-use curl::easy::Easy;
+use extension::registry;
 use rss::Channel;
 use std::str::FromStr;
-use utils::string_utils;
+use utils::{reader_utils, url_utils};
 
 impl FromUrl for Channel
 {
@@ -39,37 +39,7 @@ impl FromUrl for Channel
     /// ```
     fn from_url(url: &str) -> Result<Channel, String>
     {
-        let feed_url = string_utils::str_to_url(url)?;
-        let mut xml = Vec::new();
-        let mut handle = Easy::new();
-
-        let handle_url = handle.url(feed_url.into_string().as_str());
-        {
-            let mut transfer = handle.transfer();
-            transfer
-                .write_function(|data| {
-                                    xml.extend_from_slice(data);
-                                    Ok(data.len())
-                                })
-                .unwrap();
-            transfer.perform().unwrap();
-        }
-
-        if handle_url.is_err()
-        {
-            return Err(format!("Error: {:?}", handle_url.unwrap_err()));
-        }
-
-        let content_type = match handle.content_type()
-        {
-            Ok(val) => val.unwrap(),
-            Err(err) => return Err(format!("Error: {}", err)),
-        };
-
-        if !content_type.contains("xml")
-        {
-            return Err("Url must end with .xml".to_owned());
-        }
+        let xml = url_utils::fetch_xml(url)?;
 
         match String::from_utf8(xml)
         {
@@ -77,7 +47,19 @@ impl FromUrl for Channel
             {
                 match Channel::from_str(val.as_str())
                 {
-                    Ok(cval) => Ok(cval),
+                    Ok(mut cval) =>
+                    {
+                        // `Channel::from_str` drops the `itunes` elements it
+                        // has no fields for.
+                        let root = reader_utils::read_document(val.as_str())?;
+                        if let Some(element) = root.child("channel")
+                        {
+                            reader_utils::restore_itunes_elements(&mut cval, element);
+                        }
+
+                        registry::normalize_prefixes(&mut cval);
+                        Ok(cval)
+                    }
                     Err(err) => Err(format!("Error: {}", err)),
                 }
             }
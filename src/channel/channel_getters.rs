@@ -13,8 +13,20 @@
 
 
 use ChannelGetters;
+use ItemGetters;
+use NamespaceExtension;
+use extension::atom::AtomLink;
+use extension::georss::{GeoBox, GeoBoxGetters, GeoRssExtensionGetters};
+use extension::googleplay::GooglePlayChannelExtension;
+use extension::podcast::PodcastChannelExtension;
+use extension::syndication::{SyndicationExtension, SyndicationExtensionGetters};
+use extension::media::MediaExtension;
 use rss::{Category, Channel, Cloud, Image, Item, TextInput};
+use rss::extension::{self, ExtensionMap};
+use rss::extension::dublincore::DublinCoreExtension;
 use rss::extension::itunes::ITunesChannelExtension;
+use std::collections::HashMap;
+use std::time::Duration;
 
 
 impl ChannelGetters for Channel
@@ -528,6 +540,58 @@ impl ChannelGetters for Channel
     }
 
 
+    /// Get how often `Channel` should be polled. The ttl, in minutes, is used
+    /// when there is one, and otherwise the interval of the syndication
+    /// extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let syndication = SyndicationExtensionBuilder::new()
+    ///     .update_period(Some(SyndicationPeriod::Hourly))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .syndication_ext(Some(syndication.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(Duration::from_secs(3600)), channel.refresh_interval());
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .ttl(Some(15))
+    ///     .syndication_ext(Some(syndication))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(Duration::from_secs(900)), channel.refresh_interval());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.refresh_interval().is_none());
+    /// ```
+    fn refresh_interval(&self) -> Option<Duration>
+    {
+        match self.ttl.as_ref().and_then(|ttl| ttl.trim().parse::<u64>().ok())
+        {
+            Some(minutes) if minutes > 0 => Some(Duration::from_secs(minutes * 60)),
+            _ => self.syndication_ext().and_then(|syndication| syndication.interval()),
+        }
+    }
+
+
     /// Get the optional image that exists under `Channel`.
     ///
     /// # Examples
@@ -578,6 +642,23 @@ impl ChannelGetters for Channel
     /// ```
     /// use feed::{ChannelBuilder, ChannelGetters};
     ///
+    /// let rating_string = "(PICS-1.1 \"http://www.classify.org/safesurf/\" l r (SS~~000 1))".to_owned();
+    ///
+    /// let channels = ChannelBuilder::new()
+    ///     .rating(Some(rating_string.clone()))
+    ///     .link("http://www.jupiterbroadcasting.com/")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let rating_option = channels.rating();
+    /// assert!(rating_option.is_some());
+    ///
+    /// assert_eq!(rating_string.clone(), rating_option.unwrap());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
     /// let channels = ChannelBuilder::new()
     ///     .rating(None)
     ///     .link("http://www.jupiterbroadcasting.com/")
@@ -588,7 +669,10 @@ impl ChannelGetters for Channel
     /// ```
     fn rating(&self) -> Option<String>
     {
-        None
+        self.extensions
+            .get("")
+            .and_then(|map| extension::get_extension_value(map, "rating"))
+            .map(|val| val.to_owned())
     }
 
 
@@ -796,6 +880,139 @@ impl ChannelGetters for Channel
     }
 
 
+    /// Get the items of `Channel` whose GeoRSS location overlaps a `GeoBox`.
+    /// The smallest box holding the location of an item is compared, and
+    /// items without a location are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters, ItemBuilder, ItemGetters};
+    /// use feed::extension::georss::{GeoBox, GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let harbor = GeoPointBuilder::new()
+    ///     .latitude(43.656)
+    ///     .longitude(-70.248)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let georss = GeoRssExtensionBuilder::new()
+    ///     .point(Some(harbor))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let local = ItemBuilder::new()
+    ///     .title(Some("Harbor closed".to_owned()))
+    ///     .georss_ext(Some(georss))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let national = ItemBuilder::new()
+    ///     .title(Some("Election results".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .link("http://www.example.com/")
+    ///     .items(vec![local, national])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let maine = "42.977 -71.084 47.460 -66.950".parse::<GeoBox>().unwrap();
+    /// let items = channel.items_within(&maine);
+    ///
+    /// assert_eq!(1, items.len());
+    /// assert_eq!(Some("Harbor closed".to_owned()), items[0].title());
+    /// ```
+    fn items_within(&self, bounding_box: &GeoBox) -> Vec<Item>
+    {
+        self.items
+            .iter()
+            .filter(|item| {
+                        item.georss_ext()
+                            .and_then(|georss| georss.bounds())
+                            .map(|bounds| bounding_box.intersects(&bounds))
+                            .unwrap_or_default()
+                    })
+            .cloned()
+            .collect()
+    }
+
+
+    /// Get the optional `NamespaceExtension` of type `T` under `Channel`, read
+    /// from the extensions under its prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelBuilder, ChannelGetters, NamespaceExtension};
+    /// use feed::extension::registry;
+    /// use rss::extension::Extension;
+    /// use std::collections::HashMap;
+    ///
+    /// struct License(String);
+    ///
+    /// impl NamespaceExtension for License
+    /// {
+    ///     fn namespace() -> &'static str
+    ///     {
+    ///         "http://backend.userland.com/creativeCommonsRssModule"
+    ///     }
+    ///
+    ///     fn prefix() -> &'static str
+    ///     {
+    ///         "creativeCommons"
+    ///     }
+    ///
+    ///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+    ///     {
+    ///         let element = map.get("license").and_then(|elements| elements.first());
+    ///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+    ///     }
+    ///
+    ///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    ///     {
+    ///         let license = Extension {
+    ///             name: "creativeCommons:license".to_owned(),
+    ///             value: Some(self.0.clone()),
+    ///             ..Default::default()
+    ///         };
+    ///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+    ///     }
+    /// }
+    ///
+    /// fn main()
+    /// {
+    ///     registry::register::<License>().unwrap();
+    ///
+    ///     let channel = ChannelBuilder::new()
+    ///         .namespace_ext(&License("http://www.creativecommons.org/licenses/by-nc-sa/1.0".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let license = channel.namespace_ext::<License>().unwrap().unwrap();
+    ///     assert_eq!("http://www.creativecommons.org/licenses/by-nc-sa/1.0", license.0);
+    ///
+    ///     let channel = ChannelBuilder::new()
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     assert!(channel.namespace_ext::<License>().unwrap().is_none());
+    /// }
+    /// ```
+    fn namespace_ext<T: NamespaceExtension>(&self) -> Result<Option<T>, String>
+    {
+        match self.extensions.get(T::prefix())
+        {
+            Some(map) => T::from_map(map).map(Some),
+            None => Ok(None),
+        }
+    }
+
+
     /// Get the optional `ITunesChannelExtension` under `Channel`.
     ///
     /// # Examples
@@ -861,4 +1078,384 @@ impl ChannelGetters for Channel
     {
         self.itunes_ext.clone()
     }
+
+
+    /// Get the optional `DublinCoreExtension` under `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creator(vec!["Jupiter Broadcasting".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .dublin_core_ext(Some(dublin_core))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec!["Jupiter Broadcasting".to_owned()], channel.dublin_core_ext().unwrap().creator());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .dublin_core_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.dublin_core_ext().is_none());
+    /// ```
+    fn dublin_core_ext(&self) -> Option<DublinCoreExtension>
+    {
+        self.dublin_core_ext.clone()
+    }
+
+
+    /// Get the optional `MediaExtension` under `Channel`, read from the
+    /// `media` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaExtensionGetters};
+    ///
+    /// let media = MediaExtensionBuilder::new()
+    ///     .keywords(vec!["Linux".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .media_ext(Some(media))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec!["Linux".to_owned()], channel.media_ext().unwrap().keywords());
+    /// assert!(channel.namespaces().contains_key("media"));
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .media_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.media_ext().is_none());
+    /// ```
+    fn media_ext(&self) -> Option<MediaExtension>
+    {
+        self.extensions.get("media").map(MediaExtension::from_map)
+    }
+
+
+    /// Get the atom links that exist under `Channel`, read from the `atom`
+    /// extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::ChannelGetters;
+    /// use feed::extension::atom::AtomLinkGetters;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let links = channel.atom_links();
+    ///     let self_link = links.iter().find(|link| link.rel() == Some("self".to_owned())).unwrap();
+    ///     assert_eq!("http://feeds.feedburner.com/TheLinuxActionShowOGG", self_link.href());
+    /// }
+    /// ```
+    fn atom_links(&self) -> Vec<AtomLink>
+    {
+        AtomLink::from_extensions(&self.extensions)
+    }
+
+
+    /// Get the optional `GooglePlayChannelExtension` under `Channel`, read
+    /// from the `googleplay` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::ChannelGetters;
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionGetters;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/example2.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let googleplay = channel.googleplay_ext().unwrap();
+    ///     assert_eq!(Some("Jupiter Broadcasting".to_owned()), googleplay.author());
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .googleplay_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.googleplay_ext().is_none());
+    /// ```
+    fn googleplay_ext(&self) -> Option<GooglePlayChannelExtension>
+    {
+        self.extensions.get("googleplay").map(GooglePlayChannelExtension::from_map)
+    }
+
+
+    /// Get the optional `PodcastChannelExtension` under `Channel`, read from
+    /// the `podcast` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters,
+    ///                                PodcastFundingBuilder, PodcastFundingGetters};
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value("Support the show!")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .funding(vec![funding])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .podcast_ext(Some(podcast))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!("Support the show!", channel.podcast_ext().unwrap().funding()[0].value());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .podcast_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.podcast_ext().is_none());
+    /// ```
+    fn podcast_ext(&self) -> Option<PodcastChannelExtension>
+    {
+        self.extensions.get("podcast").map(PodcastChannelExtension::from_map)
+    }
+
+
+    /// Get the optional iTunes show type under `Channel`, read from the
+    /// `itunes` extensions.
+    ///
+    /// `Channel::from_str` drops it, so it is only there when the channel is
+    /// built with a `ChannelBuilder` or read with `ChannelReader`, `FromUrl`,
+    /// `FromLegacy` or `FromLenient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.show_type(Some("serial".to_owned()));
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .itunes_ext_builder(Some(itunes_builder))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("serial".to_owned()), channel.itunes_show_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .itunes_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.itunes_show_type().is_none());
+    /// ```
+    fn itunes_show_type(&self) -> Option<String>
+    {
+        self.extensions
+            .get("itunes")
+            .and_then(|map| extension::get_extension_value(map, "type"))
+            .map(|val| val.trim().to_owned())
+    }
+
+
+    /// Get the optional iTunes title under `Channel`, read from the `itunes`
+    /// extensions.
+    ///
+    /// `Channel::from_str` drops it, so it is only there when the channel is
+    /// built with a `ChannelBuilder` or read with `ChannelReader`, `FromUrl`,
+    /// `FromLegacy` or `FromLenient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate feed;
+    /// extern crate rss;
+    ///
+    /// use feed::{ChannelGetters, FromLegacy};
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+    ///     <channel><title>LAS</title>
+    ///     <itunes:title>The Linux Action Show!</itunes:title>
+    ///     </channel></rss>"#;
+    ///
+    ///     let (channel, _) = Channel::from_legacy(xml).unwrap();
+    ///     assert_eq!(Some("The Linux Action Show!".to_owned()), channel.itunes_title());
+    /// }
+    /// ```
+    fn itunes_title(&self) -> Option<String>
+    {
+        self.extensions
+            .get("itunes")
+            .and_then(|map| extension::get_extension_value(map, "title"))
+            .map(|val| val.trim().to_owned())
+    }
+
+
+    /// Get the optional `SyndicationExtension` under `Channel`, read from the
+    /// `sy` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let syndication = SyndicationExtensionBuilder::new()
+    ///     .update_frequency(Some(2))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .syndication_ext(Some(syndication))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(2), channel.syndication_ext().unwrap().update_frequency());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .syndication_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.syndication_ext().is_none());
+    /// ```
+    fn syndication_ext(&self) -> Option<SyndicationExtension>
+    {
+        self.extensions.get("sy").map(SyndicationExtension::from_map)
+    }
+
+
+    /// Get the extensions that exist under `Channel`.
+    ///
+    /// Elements the getters do not model are kept here, keyed by prefix and
+    /// then by name, so they survive being read, modified and written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelBuilder, ChannelGetters, Validate};
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/example2.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap().validate().unwrap();
+    ///
+    ///     let channel = ChannelBuilder::new()
+    ///         .title("New Title")
+    ///         .link(channel.link().as_str())
+    ///         .description(channel.description().as_str())
+    ///         .items(channel.items())
+    ///         .extensions(channel.extensions())
+    ///         .namespaces(channel.namespaces())
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     assert!(channel.extensions()["feedpress"].contains_key("locale"));
+    ///     assert!(channel.to_string().contains("<feedpress:locale>"));
+    /// }
+    /// ```
+    fn extensions(&self) -> ExtensionMap
+    {
+        self.extensions.clone()
+    }
+
+
+    /// Get the namespaces that exist under `Channel`, keyed by prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelGetters};
+    /// use std::collections::HashMap;
+    ///
+    /// let url = "http://rssnamespace.org/feedburner/ext/1.0".to_owned();
+    ///
+    /// let mut namespaces = HashMap::new();
+    /// namespaces.insert("feedburner".to_owned(), url.clone());
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .namespaces(namespaces)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(&url), channel.namespaces().get("feedburner"));
+    /// ```
+    fn namespaces(&self) -> HashMap<String, String>
+    {
+        self.namespaces.clone()
+    }
 }
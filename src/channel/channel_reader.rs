@@ -0,0 +1,250 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading a `Channel` incrementally by using the methods under
+//! `ChannelReader`.
+
+
+use ChannelReader;
+use quick_xml::{Event, XmlReader};
+use rss::{Channel, Item};
+use rss::extension::dublincore::DublinCoreExtension;
+use std::io::{BufReader, Read};
+use utils::reader_utils;
+
+
+impl<R: Read> ChannelReader<R>
+{
+    /// Construct a new `ChannelReader`, reading the `Channel` up to its first
+    /// `Item`.
+    ///
+    /// Only one `Item` is held in memory at a time, however many the
+    /// document contains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelGetters, ChannelReader};
+    /// use std::fs::File;
+    ///
+    /// let file = File::open("rsc/sample_rss.xml").unwrap();
+    /// let mut reader = ChannelReader::new(file).unwrap();
+    ///
+    /// assert_eq!("The Linux Action Show! OGG", reader.channel().title());
+    /// assert_eq!(99, reader.count());
+    /// ```
+    pub fn new(source: R) -> Result<ChannelReader<R>, String>
+    {
+        let mut reader = XmlReader::from_reader(BufReader::new(source)).trim_text(true);
+        let mut channel = Channel::default();
+        let mut in_rss = false;
+
+        loop
+        {
+            match reader.next()
+            {
+                Some(event) =>
+                {
+                    match event.map_err(reader_utils::error_to_string)?
+                    {
+                        Event::Start(ref element) if !in_rss =>
+                        {
+                            let element = reader_utils::start_to_element(element)?;
+                            if element.name != "rss"
+                            {
+                                return Err(format!("Invalid value: {}", element.name));
+                            }
+
+                            for attr in element.attrs
+                            {
+                                if attr.0.starts_with("xmlns:") && attr.0 != "xmlns:itunes" &&
+                                   attr.0 != "xmlns:dc"
+                                {
+                                    channel.namespaces.insert(attr.0["xmlns:".len()..].to_owned(), attr.1);
+                                }
+                            }
+                            in_rss = true;
+                        }
+                        Event::Start(ref element) if element.name() == b"channel" => break,
+                        Event::Start(ref element) =>
+                        {
+                            reader_utils::read_element(&mut reader, element)?;
+                        }
+                        _ => (),
+                    }
+                }
+                None => return Err("Error: Document has no channel".to_owned()),
+            }
+        }
+
+        let mut channel_reader = ChannelReader {
+            reader,
+            channel,
+            next_item: None,
+            finished: false,
+        };
+
+        channel_reader.next_item = channel_reader.read_item()?;
+        Ok(channel_reader)
+    }
+
+
+    /// Get the `Channel` read so far, without its items.
+    ///
+    /// Channel elements that appear after the first item are added as the
+    /// reader reaches them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelGetters, ChannelReader};
+    ///
+    /// let xml = r#"<rss version="2.0"><channel>
+    ///     <title>The Linux Action Show! OGG</title>
+    ///     <item><title>Episode 1</title></item>
+    ///     <ttl>60</ttl>
+    /// </channel></rss>"#;
+    ///
+    /// let mut reader = ChannelReader::new(xml.as_bytes()).unwrap();
+    /// assert!(reader.channel().ttl().is_none());
+    ///
+    /// while let Some(item) = reader.next()
+    /// {
+    ///     item.unwrap();
+    /// }
+    ///
+    /// assert_eq!(Some("60".to_owned()), reader.channel().ttl());
+    /// assert!(reader.channel().items().is_empty());
+    /// ```
+    pub fn channel(&self) -> Channel
+    {
+        let mut channel = self.channel.clone();
+        channel.itunes_ext = reader_utils::take_itunes_channel_ext(&mut channel.extensions);
+        channel.dublin_core_ext = channel.extensions.remove("dc").map(DublinCoreExtension::from_map);
+        channel
+    }
+
+
+    /// Read the `Item`s up to, but not including, the first one with the
+    /// given guid, and stop reading there.
+    ///
+    /// The `Item` with the guid is not consumed, so reading can carry on from
+    /// it. When no `Item` has the guid, every remaining `Item` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelReader, GuidGetters, ItemGetters};
+    /// use std::fs::File;
+    ///
+    /// let file = File::open("rsc/sample_rss.xml").unwrap();
+    /// let mut reader = ChannelReader::new(file).unwrap();
+    ///
+    /// let new_items = reader.read_until_guid("55EE8E2F-138B-431C-8D38-32A971F578BA").unwrap();
+    /// assert_eq!(2, new_items.len());
+    ///
+    /// let known = reader.next().unwrap().unwrap();
+    /// assert_eq!("55EE8E2F-138B-431C-8D38-32A971F578BA", known.guid().unwrap().value());
+    /// ```
+    pub fn read_until_guid(&mut self, guid: &str) -> Result<Vec<Item>, String>
+    {
+        let mut items = Vec::new();
+
+        while let Some(item) = self.next()
+        {
+            let item = item?;
+            if item.guid.as_ref().map(|val| val.value.as_str()) == Some(guid)
+            {
+                self.next_item = Some(item);
+                break;
+            }
+            items.push(item);
+        }
+
+        Ok(items)
+    }
+
+
+    // Read channel elements up to the next item, or the end of the channel.
+    fn read_item(&mut self) -> Result<Option<Item>, String>
+    {
+        while let Some(event) = self.reader.next()
+        {
+            match event.map_err(reader_utils::error_to_string)?
+            {
+                Event::Start(ref element) =>
+                {
+                    let element = reader_utils::read_element(&mut self.reader, element)?;
+                    if element.name == "item"
+                    {
+                        return Ok(Some(reader_utils::element_to_item(&element)));
+                    }
+                    reader_utils::apply_channel_element(&mut self.channel, &element);
+                }
+                Event::End(_) =>
+                {
+                    self.finished = true;
+                    return Ok(None);
+                }
+                _ => (),
+            }
+        }
+
+        Err("Error: Missing end tag for channel".to_owned())
+    }
+}
+
+
+impl<R: Read> Iterator for ChannelReader<R>
+{
+    type Item = Result<Item, String>;
+
+
+    /// Read the next `Item` of the `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelReader, ItemGetters};
+    ///
+    /// let xml = r#"<rss version="2.0"><channel>
+    ///     <title>The Linux Action Show! OGG</title>
+    ///     <item><title>Episode 2</title></item>
+    ///     <item><title>Episode 1</title></item>
+    /// </channel></rss>"#;
+    ///
+    /// let mut reader = ChannelReader::new(xml.as_bytes()).unwrap();
+    /// let item = reader.next().unwrap().unwrap();
+    ///
+    /// assert_eq!(Some("Episode 2".to_owned()), item.title());
+    /// ```
+    fn next(&mut self) -> Option<Result<Item, String>>
+    {
+        if let Some(item) = self.next_item.take()
+        {
+            return Some(Ok(item));
+        }
+
+        if self.finished
+        {
+            return None;
+        }
+
+        match self.read_item()
+        {
+            Ok(item) => item.map(Ok),
+            Err(err) =>
+            {
+                self.finished = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! `ToLegacy` Trait for `Channel`
+
+
+use ChannelGetters;
+use ImageGetters;
+use ItemGetters;
+use TextInputGetters;
+use ToLegacy;
+use rss::Channel;
+use utils::writer_utils;
+
+
+impl ToLegacy for Channel
+{
+    /// Write `Channel` as an RSS 0.91 document.
+    ///
+    /// RSS 0.91 requires a language and an image, allows at most 15 items
+    /// and only carries the title, link and description of each item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ImageBuilder, ItemBuilder, ToLegacy};
+    ///
+    /// let image = ImageBuilder::new()
+    ///     .url("http://jupiterbroadcasting.com/images/LAS-300-Badge.jpg")
+    ///     .title("LAS 300 Logo")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .link(Some("http://www.jupiterbroadcasting.com".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .language(Some("en".to_owned()))
+    ///     .image(Some(image))
+    ///     .items(vec![item])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let xml = channel.to_rss_091().unwrap();
+    /// assert!(xml.contains("<rss version=\"0.91\">"));
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ToLegacy};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .language(Some("en".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.to_rss_091().is_err());
+    /// ```
+    fn to_rss_091(&self) -> Result<String, String>
+    {
+        let language = match self.language()
+        {
+            Some(val) => val,
+            None => return Err("RSS 0.91 Channel must have a language.".to_owned()),
+        };
+
+        let image = match self.image()
+        {
+            Some(val) => val,
+            None => return Err("RSS 0.91 Channel must have an image.".to_owned()),
+        };
+
+        let items = self.items();
+        if items.len() > 15
+        {
+            return Err("RSS 0.91 Channel cannot have more than 15 items.".to_owned());
+        }
+
+        check_length("Channel Title", self.title().as_str(), 100)?;
+        check_length("Channel Description", self.description().as_str(), 500)?;
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<!DOCTYPE rss PUBLIC \"-//Netscape Communications//DTD RSS 0.91//EN\" ");
+        xml.push_str("\"http://my.netscape.com/publish/formats/rss-0.91.dtd\">\n");
+        xml.push_str("<rss version=\"0.91\"><channel>");
+        xml.push_str(writer_utils::text_element("title", self.title().as_str()).as_str());
+        xml.push_str(writer_utils::text_element("link", self.link().as_str()).as_str());
+        xml.push_str(writer_utils::text_element("description", self.description().as_str()).as_str());
+        xml.push_str(writer_utils::text_element("language", language.as_str()).as_str());
+
+        let optional = vec![("copyright", self.copyright()),
+                            ("managingEditor", self.managing_editor()),
+                            ("webMaster", self.webmaster()),
+                            ("rating", self.rating()),
+                            ("pubDate", self.pub_date()),
+                            ("lastBuildDate", self.last_build_date()),
+                            ("docs", self.docs())];
+
+        for (name, value) in optional
+        {
+            if let Some(val) = value
+            {
+                xml.push_str(writer_utils::text_element(name, val.as_str()).as_str());
+            }
+        }
+
+        xml.push_str("<image>");
+        xml.push_str(writer_utils::text_element("title", image.title().as_str()).as_str());
+        xml.push_str(writer_utils::text_element("url", image.url().as_str()).as_str());
+        xml.push_str(writer_utils::text_element("link", image.link().as_str()).as_str());
+        if let Some(width) = image.width()
+        {
+            xml.push_str(writer_utils::text_element("width", width.as_str()).as_str());
+        }
+        if let Some(height) = image.height()
+        {
+            xml.push_str(writer_utils::text_element("height", height.as_str()).as_str());
+        }
+        if let Some(description) = image.description()
+        {
+            xml.push_str(writer_utils::text_element("description", description.as_str()).as_str());
+        }
+        xml.push_str("</image>");
+
+        if let Some(text_input) = self.text_input()
+        {
+            xml.push_str("<textinput>");
+            xml.push_str(writer_utils::text_element("title", text_input.title().as_str()).as_str());
+            xml.push_str(writer_utils::text_element("description", text_input.description().as_str()).as_str());
+            xml.push_str(writer_utils::text_element("name", text_input.name().as_str()).as_str());
+            xml.push_str(writer_utils::text_element("link", text_input.link().as_str()).as_str());
+            xml.push_str("</textinput>");
+        }
+
+        let skip_hours = self.skip_hours();
+        if !skip_hours.is_empty()
+        {
+            xml.push_str("<skipHours>");
+            for hour in skip_hours
+            {
+                xml.push_str(writer_utils::text_element("hour", hour.as_str()).as_str());
+            }
+            xml.push_str("</skipHours>");
+        }
+
+        let skip_days = self.skip_days();
+        if !skip_days.is_empty()
+        {
+            xml.push_str("<skipDays>");
+            for day in skip_days
+            {
+                xml.push_str(writer_utils::text_element("day", day.as_str()).as_str());
+            }
+            xml.push_str("</skipDays>");
+        }
+
+        for item in items
+        {
+            let title = match item.title()
+            {
+                Some(val) => val,
+                None => return Err("RSS 0.91 Item must have a title.".to_owned()),
+            };
+
+            let link = match item.link()
+            {
+                Some(val) => val,
+                None => return Err("RSS 0.91 Item must have a link.".to_owned()),
+            };
+
+            check_length("Item Title", title.as_str(), 100)?;
+            check_length("Item Link", link.as_str(), 500)?;
+
+            xml.push_str("<item>");
+            xml.push_str(writer_utils::text_element("title", title.as_str()).as_str());
+            xml.push_str(writer_utils::text_element("link", link.as_str()).as_str());
+            if let Some(description) = item.description()
+            {
+                check_length("Item Description", description.as_str(), 500)?;
+                xml.push_str(writer_utils::text_element("description", description.as_str()).as_str());
+            }
+            xml.push_str("</item>");
+        }
+
+        xml.push_str("</channel></rss>\n");
+        Ok(xml)
+    }
+}
+
+
+fn check_length(field: &str, value: &str, max: usize) -> Result<(), String>
+{
+    if value.chars().count() > max
+    {
+        return Err(format!("RSS 0.91 {} cannot be longer than {} characters.", field, max));
+    }
+
+    Ok(())
+}
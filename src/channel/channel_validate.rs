@@ -30,10 +30,37 @@ use SourceGetters;
 use TextInputBuilder;
 use TextInputGetters;
 use Validate;
+use extension::atom::{AtomLink, AtomLinkBuilder, AtomLinkGetters};
+use extension::comments::{CommentsExtension, CommentsExtensionBuilder, CommentsExtensionGetters};
+use extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+use extension::georss::{GeoRssExtension, GeoRssExtensionBuilder, GeoRssExtensionGetters};
+use extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters,
+                            GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
 use extension::itunes::{ITunesCategoryBuilder, ITunesCategoryGetters, ITunesChannelExtensionBuilder,
                         ITunesChannelExtensionGetters, ITunesItemExtensionBuilder, ITunesItemExtensionGetters,
-                        ITunesOwnerBuilder, ITunesOwnerGetters};
+                        ITunesOwnerBuilder, ITunesOwnerGetters, itunes_category_taxonomy};
+use extension::media::{MediaCategory, MediaCategoryBuilder, MediaCategoryGetters, MediaContent, MediaContentBuilder,
+                       MediaContentGetters, MediaCredit, MediaCreditBuilder, MediaCreditGetters, MediaExtension,
+                       MediaExtensionBuilder, MediaExtensionGetters, MediaGroupBuilder, MediaGroupGetters,
+                       MediaRating, MediaRatingBuilder, MediaRatingGetters, MediaThumbnail, MediaThumbnailBuilder,
+                       MediaThumbnailGetters};
+use extension::podcast::{PodcastAlternateEnclosure, PodcastAlternateEnclosureBuilder,
+                         PodcastAlternateEnclosureGetters, PodcastChannelExtension, PodcastChannelExtensionBuilder,
+                         PodcastChannelExtensionGetters, PodcastChaptersBuilder, PodcastChaptersGetters,
+                         PodcastEpisodeBuilder, PodcastEpisodeGetters, PodcastFundingBuilder, PodcastFundingGetters,
+                         PodcastIntegrityBuilder, PodcastIntegrityGetters, PodcastItemExtension,
+                         PodcastItemExtensionBuilder, PodcastItemExtensionGetters, PodcastLiveItemBuilder,
+                         PodcastLiveItemGetters, PodcastLocation, PodcastLocationBuilder, PodcastLocationGetters,
+                         PodcastLockedBuilder, PodcastLockedGetters, PodcastPerson, PodcastPersonBuilder,
+                         PodcastPersonGetters, PodcastSeasonBuilder, PodcastSeasonGetters, PodcastSoundbiteBuilder,
+                         PodcastSoundbiteGetters, PodcastSourceBuilder, PodcastSourceGetters,
+                         PodcastTranscriptBuilder, PodcastTranscriptGetters, PodcastValue, PodcastValueBuilder,
+                         PodcastValueGetters, PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+use extension::registry;
+use extension::syndication::{SyndicationExtension, SyndicationExtensionBuilder, SyndicationExtensionGetters};
 use rss::{Category, Channel, Item};
+use rss::extension;
+use rss::extension::dublincore::DublinCoreExtension;
 use rss::extension::itunes::ITunesCategory;
 use utils::string_utils;
 
@@ -57,8 +84,34 @@ impl Validate for Channel
     ///     channel.validate().unwrap();
     /// }
     /// ```
+    ///
+    /// Extension values that cannot be read fail the validation rather than
+    /// being dropped.
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::Validate;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:sy=\"http://purl.org/rss/1.0/modules/syndication/\">\
+    ///                <channel><title>Linux Action Show</title>\
+    ///                <link>http://www.jupiterbroadcasting.com</link>\
+    ///                <description>Linux &amp; Open Source</description>\
+    ///                <sy:updatePeriod>fortnightly</sy:updatePeriod>\
+    ///                </channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     assert!(channel.validate().is_err());
+    /// }
+    /// ```
     fn validate(&self) -> Result<Channel, String>
     {
+        registry::validate(&self.extensions)?;
+
         let cloud = match self.cloud()
         {
             None => None,
@@ -126,91 +179,12 @@ impl Validate for Channel
         let mut items: Vec<Item> = Vec::new();
         for item in self.items()
         {
-            let mut item_cat: Vec<Category> = Vec::new();
-            for cat in item.categories()
-            {
-                item_cat.push(CategoryBuilder::new()
-                                  .name(cat.name().as_str())
-                                  .domain(cat.domain())
-                                  .validate()?
-                                  .finalize()?);
-            }
-
-            let enclosure = match item.enclosure()
-            {
-                None => None,
-                Some(eval) =>
-                {
-                    Some(EnclosureBuilder::new()
-                             .url(eval.url().as_str())
-                             .length(string_utils::string_to_i64(eval.length.as_str())?)
-                             .mime_type(eval.mime_type().as_str())
-                             .validate()?
-                             .finalize()?)
-                }
-            };
-
-            let guid = match item.guid()
-            {
-                None => None,
-                Some(gval) =>
-                {
-                    Some(GuidBuilder::new()
-                             .value(gval.value().as_str())
-                             .is_permalink(Some(gval.is_permalink()))
-                             .finalize()?)
-                }
-            };
-
-            let source = match item.source()
-            {
-                None => None,
-                Some(sval) =>
-                {
-                    Some(SourceBuilder::new()
-                             .url(sval.url().as_str())
-                             .title(sval.title())
-                             .validate()?
-                             .finalize()?)
-                }
-            };
-
-            let itunes_item = match item.itunes_ext()
-            {
-                None => None,
-                Some(ival) =>
-                {
-                    Some(ITunesItemExtensionBuilder::new()
-                             .author(ival.author())
-                             .block(ival.block())
-                             .image(ival.image())
-                             .duration(ival.duration())
-                             .explicit(ival.explicit())
-                             .closed_captioned(ival.closed_captioned())
-                             .order(ival.order())
-                             .subtitle(ival.subtitle())
-                             .summary(ival.summary())
-                             .keywords(ival.keywords())
-                             .finalize()?)
-                }
-            };
-
-            items.push(ItemBuilder::new()
-                           .title(item.title())
-                           .link(item.link())
-                           .description(item.description())
-                           .author(item.author())
-                           .pub_date(item.pub_date())
-                           .comments(item.comments())
-                           .categories(item_cat)
-                           .enclosure(enclosure)
-                           .guid(guid)
-                           .source(source)
-                           .itunes_ext(itunes_item)
-                           .validate()?
-                           .finalize()?);
+            items.push(validate_item(&item)?);
         }
 
+        let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+        itunes_builder.show_type(self.itunes_show_type()).title(self.itunes_title()).validate()?;
+
         let itunes_channel = match self.itunes_ext()
         {
             None => None,
@@ -224,6 +198,7 @@ impl Validate for Channel
                         Some(ITunesOwnerBuilder::new()
                                  .name(oval.name())
                                  .email(oval.email())
+                                 .validate()?
                                  .finalize()?)
                     }
                 };
@@ -234,11 +209,11 @@ impl Validate for Channel
                     itunes_cat.push(ITunesCategoryBuilder::new()
                                         .text(cat.text().as_str())
                                         .subcategory(cat.subcategory())
+                                        .validate()?
                                         .finalize()?);
                 }
 
-                Some(ITunesChannelExtensionBuilder::new()
-                         .author(cval.author())
+                Some(itunes_builder.author(cval.author())
                          .block(cval.block())
                          .image(cval.image())
                          .explicit(cval.explicit())
@@ -249,6 +224,59 @@ impl Validate for Channel
                          .keywords(cval.keywords())
                          .categories(itunes_cat)
                          .owner(itunes_owner)
+                         .validate()?
+                         .clone())
+            }
+        };
+
+        let dublin_core_channel = match self.dublin_core_ext()
+        {
+            None => None,
+            Some(dval) => Some(validate_dublin_core(&dval)?),
+        };
+
+        let media_channel = match self.extensions.get("media")
+        {
+            None => None,
+            Some(map) => Some(validate_media(&MediaExtension::try_from_map(map)?)?),
+        };
+
+        let googleplay_channel = match self.googleplay_ext()
+        {
+            None => None,
+            Some(gval) =>
+            {
+                Some(GooglePlayChannelExtensionBuilder::new()
+                         .author(gval.author())
+                         .block(gval.block())
+                         .categories(gval.categories())
+                         .description(gval.description())
+                         .email(gval.email())
+                         .explicit(gval.explicit())
+                         .image(gval.image())
+                         .validate()?
+                         .finalize()?)
+            }
+        };
+
+        let podcast_channel = match self.extensions.get("podcast")
+        {
+            None => None,
+            Some(map) => Some(validate_podcast_channel(&PodcastChannelExtension::try_from_map(map)?)?),
+        };
+
+        let syndication = match self.extensions.get("sy")
+        {
+            None => None,
+            Some(map) =>
+            {
+                let sval = SyndicationExtension::try_from_map(map)?;
+
+                Some(SyndicationExtensionBuilder::new()
+                         .update_period(sval.update_period())
+                         .update_frequency(sval.update_frequency())
+                         .update_base(sval.update_base())
+                         .validate()?
                          .finalize()?)
             }
         };
@@ -265,7 +293,7 @@ impl Validate for Channel
             .last_build_date(self.last_build_date())
             .generator(self.generator())
             .docs(self.docs())
-            .rating(None)
+            .rating(self.rating())
             .ttl(string_utils::option_string_to_option_i64(self.ttl())?)
             .cloud(cloud)
             .categories(channel_cat)
@@ -274,8 +302,780 @@ impl Validate for Channel
             .skip_hours(skip_hours)
             .skip_days(self.skip_days())
             .items(items)
-            .itunes_ext(itunes_channel)
+            .itunes_ext_builder(itunes_channel)
+            .dublin_core_ext(dublin_core_channel)
+            .media_ext(media_channel)
+            .atom_links(validate_atom_links(AtomLink::try_from_extensions(&self.extensions)?)?)
+            .googleplay_ext(googleplay_channel)
+            .podcast_ext(podcast_channel)
+            .syndication_ext(syndication)
+            .extensions(self.extensions())
+            .namespaces(self.namespaces())
             .validate()?
             .finalize()
     }
+
+
+    /// Get the warnings about `Channel`, for what is valid but goes against
+    /// best practice.
+    ///
+    /// A `Channel` without an `atom:link` with `rel="self"` is warned about, as
+    /// are retired iTunes categories, with their replacement, and `dc:date`s
+    /// that are not W3C dates.
+    ///
+    /// ## Examples
+    /// ```
+    /// use feed::{ChannelBuilder, Validate};
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, channel.warnings().len());
+    ///
+    /// let link = AtomLinkBuilder::new()
+    ///     .href("http://feeds.feedburner.com/TheLinuxActionShowOGG")
+    ///     .rel(Some("self".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .atom_links(vec![link])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.warnings().is_empty());
+    /// ```
+    ///
+    /// ```
+    /// extern crate feed;
+    /// extern crate rss;
+    ///
+    /// use feed::Validate;
+    /// use feed::extension::dublincore::DublinCoreExtensionGetters;
+    /// use rss::Channel;
+    /// use std::str::FromStr;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><channel>\
+    ///                <title>The Linux Action Show! OGG</title>\
+    ///                <link>http://www.jupiterbroadcasting.com</link>\
+    ///                <description>Ogg Vorbis audio versions of The Linux Action Show!</description>\
+    ///                <dc:date>Sun, 20 Mar 2016 19:10:54 -0700</dc:date>\
+    ///                </channel></rss>";
+    ///     let channel = Channel::from_str(xml).unwrap();
+    ///
+    ///     let validated = channel.validate().unwrap();
+    ///     assert_eq!(vec!["Sun, 20 Mar 2016 19:10:54 -0700".to_owned()],
+    ///                validated.dublin_core_ext.unwrap().date());
+    ///     assert_eq!(2, channel.warnings().len());
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, Validate};
+    /// use feed::extension::itunes::{ITunesCategoryBuilder, ITunesChannelExtensionBuilder};
+    ///
+    /// let subcategory = ITunesCategoryBuilder::new()
+    ///     .text("Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let category = ITunesCategoryBuilder::new()
+    ///     .text("Technology")
+    ///     .subcategory(Some(Box::new(subcategory)))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .categories(vec![category])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .itunes_ext(Some(itunes))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(channel.validate().is_ok());
+    /// let warning = "Deprecated value: Technology > Tech News. Use News > Tech News instead.".to_owned();
+    /// assert!(channel.warnings().contains(&warning));
+    /// ```
+    fn warnings(&self) -> Vec<String>
+    {
+        let mut warnings = Vec::new();
+
+        if !self.atom_links().iter().any(|link| link.rel() == Some("self".to_owned()))
+        {
+            warnings.push("Channel should have an atom:link with rel=\"self\".".to_owned());
+        }
+
+        if let Some(itunes) = self.itunes_ext()
+        {
+            for category in itunes.categories()
+            {
+                let text = category.text();
+                let subcategory = category.subcategory().map(|subcategory| subcategory.text());
+                if let Err(err) = itunes_category_taxonomy::normalize(text.as_str(), subcategory.as_deref())
+                {
+                    if itunes_category_taxonomy::replacement(text.as_str(), subcategory.as_deref()).is_some()
+                    {
+                        warnings.push(err);
+                    }
+                }
+            }
+        }
+
+        if let Some(dublin_core) = self.dublin_core_ext()
+        {
+            warnings.extend(dublin_core_warnings(&dublin_core, "Channel"));
+        }
+
+        for (index, item) in self.items().iter().enumerate()
+        {
+            if let Some(dublin_core) = item.dublin_core_ext()
+            {
+                warnings.extend(dublin_core_warnings(&dublin_core, format!("Item {}", index).as_str()));
+            }
+        }
+
+        warnings
+    }
+}
+
+
+// Rebuild an `Item`, validating each of its elements.
+pub(crate) fn validate_item(item: &Item) -> Result<Item, String>
+{
+    registry::validate(&item.extensions)?;
+
+    let mut item_cat: Vec<Category> = Vec::new();
+    for cat in item.categories()
+    {
+        item_cat.push(CategoryBuilder::new()
+                          .name(cat.name().as_str())
+                          .domain(cat.domain())
+                          .validate()?
+                          .finalize()?);
+    }
+
+    let enclosure = match item.enclosure()
+    {
+        None => None,
+        Some(eval) =>
+        {
+            Some(EnclosureBuilder::new()
+                     .url(eval.url().as_str())
+                     .length(string_utils::string_to_i64(eval.length.as_str())?)
+                     .mime_type(eval.mime_type().as_str())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    let guid = match item.guid()
+    {
+        None => None,
+        Some(gval) =>
+        {
+            Some(GuidBuilder::new()
+                     .value(gval.value().as_str())
+                     .is_permalink(Some(gval.is_permalink()))
+                     .finalize()?)
+        }
+    };
+
+    let source = match item.source()
+    {
+        None => None,
+        Some(sval) =>
+        {
+            Some(SourceBuilder::new()
+                     .url(sval.url().as_str())
+                     .title(sval.title())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    itunes_builder.title(item.itunes_title())
+        .episode(itunes_number(item, "episode")?)
+        .season(itunes_number(item, "season")?)
+        .episode_type(item.itunes_episode_type())
+        .validate()?;
+
+    let itunes_item = match item.itunes_ext()
+    {
+        None => None,
+        Some(ival) =>
+        {
+            Some(itunes_builder.author(ival.author())
+                     .block(ival.block())
+                     .image(ival.image())
+                     .duration(ival.duration())
+                     .explicit(ival.explicit())
+                     .closed_captioned(ival.closed_captioned())
+                     .order(ival.order())
+                     .subtitle(ival.subtitle())
+                     .summary(ival.summary())
+                     .keywords(ival.keywords())
+                     .validate()?
+                     .clone())
+        }
+    };
+
+    let dublin_core_item = match item.dublin_core_ext()
+    {
+        None => None,
+        Some(dval) => Some(validate_dublin_core(&dval)?),
+    };
+
+    let media_item = match item.extensions.get("media")
+    {
+        None => None,
+        Some(map) => Some(validate_media(&MediaExtension::try_from_map(map)?)?),
+    };
+
+    let googleplay_item = match item.googleplay_ext()
+    {
+        None => None,
+        Some(gval) =>
+        {
+            Some(GooglePlayItemExtensionBuilder::new()
+                     .author(gval.author())
+                     .block(gval.block())
+                     .description(gval.description())
+                     .explicit(gval.explicit())
+                     .image(gval.image())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    let podcast_item = match item.extensions.get("podcast")
+    {
+        None => None,
+        Some(map) => Some(validate_podcast_item(&PodcastItemExtension::try_from_map(map)?)?),
+    };
+
+    let cval = CommentsExtension::try_from_extensions(&item.extensions)?;
+    let comments = if cval == CommentsExtension::default()
+    {
+        None
+    }
+    else
+    {
+        Some(CommentsExtensionBuilder::new()
+                 .comment_rss(cval.comment_rss())
+                 .comments(cval.comments())
+                 .total(cval.total())
+                 .in_reply_to(cval.in_reply_to())
+                 .validate()?
+                 .finalize()?)
+    };
+
+    let georss = match item.extensions.get("georss")
+    {
+        None => None,
+        Some(map) =>
+        {
+            let gval = GeoRssExtension::try_from_map(map)?;
+
+            Some(GeoRssExtensionBuilder::new()
+                     .point(gval.point())
+                     .line(gval.line())
+                     .polygon(gval.polygon())
+                     .bounding_box(gval.bounding_box())
+                     .feature_name(gval.feature_name())
+                     .elevation(gval.elevation())
+                     .radius(gval.radius())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    ItemBuilder::new()
+        .title(item.title())
+        .link(item.link())
+        .description(item.description())
+        .author(item.author())
+        .pub_date(item.pub_date())
+        .comments(item.comments())
+        .categories(item_cat)
+        .enclosure(enclosure)
+        .guid(guid)
+        .source(source)
+        .content(item.content())
+        .itunes_ext_builder(itunes_item)
+        .dublin_core_ext(dublin_core_item)
+        .media_ext(media_item)
+        .atom_links(validate_atom_links(AtomLink::try_from_extensions(&item.extensions)?)?)
+        .googleplay_ext(googleplay_item)
+        .podcast_ext(podcast_item)
+        .comments_ext(comments)
+        .georss_ext(georss)
+        .extensions(item.extensions())
+        .validate()?
+        .finalize()
+}
+
+
+// Read an iTunes episode or season number of an `Item`, which must be a
+// number when it is there.
+fn itunes_number(item: &Item, name: &str) -> Result<Option<i64>, String>
+{
+    let number = item.extensions
+        .get("itunes")
+        .and_then(|map| extension::get_extension_value(map, name))
+        .map(|val| val.trim().to_owned());
+    string_utils::option_string_to_option_i64(number)
+}
+
+
+// Rebuild a `DublinCoreExtension`, keeping its dates as they were written.
+// Dates that are not W3C dates are left to `warnings`.
+fn validate_dublin_core(dublin_core: &DublinCoreExtension) -> Result<DublinCoreExtension, String>
+{
+    let mut validated = DublinCoreExtensionBuilder::new()
+        .contributor(dublin_core.contributor())
+        .coverage(dublin_core.coverage())
+        .creator(dublin_core.creator())
+        .description(dublin_core.description())
+        .format(dublin_core.format())
+        .identifier(dublin_core.identifier())
+        .language(dublin_core.language())
+        .publisher(dublin_core.publisher())
+        .relation(dublin_core.relation())
+        .rights(dublin_core.rights())
+        .source(dublin_core.source())
+        .subject(dublin_core.subject())
+        .title(dublin_core.title())
+        .resource_type(dublin_core.resource_type())
+        .finalize()?;
+
+    validated.date = dublin_core.date();
+    Ok(validated)
+}
+
+
+// The warnings about the dates of a `DublinCoreExtension` that are not W3C
+// dates.
+fn dublin_core_warnings(dublin_core: &DublinCoreExtension, owner: &str) -> Vec<String>
+{
+    dublin_core.date()
+        .iter()
+        .filter(|date| string_utils::w3cdtf_to_date(date).is_err())
+        .map(|date| format!("{} dc:date should be a W3C date: {}", owner, date))
+        .collect()
+}
+
+
+// Rebuild the `AtomLink`s, validating each of them.
+fn validate_atom_links(links: Vec<AtomLink>) -> Result<Vec<AtomLink>, String>
+{
+    let mut validated = Vec::new();
+    for link in links
+    {
+        validated.push(AtomLinkBuilder::new()
+                           .href(link.href().as_str())
+                           .rel(link.rel())
+                           .mime_type(link.mime_type())
+                           .hreflang(link.hreflang())
+                           .title(link.title())
+                           .length(link.length())
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
+}
+
+
+// Rebuild a `PodcastChannelExtension`, validating each of its elements.
+fn validate_podcast_channel(podcast: &PodcastChannelExtension) -> Result<PodcastChannelExtension, String>
+{
+    let mut funding = Vec::new();
+    for fval in podcast.funding()
+    {
+        funding.push(PodcastFundingBuilder::new()
+                         .url(fval.url().as_str())
+                         .value(fval.value().as_str())
+                         .validate()?
+                         .finalize()?);
+    }
+
+    let value = match podcast.value()
+    {
+        None => None,
+        Some(vval) => Some(validate_podcast_value(&vval)?),
+    };
+
+    let location = match podcast.location()
+    {
+        None => None,
+        Some(lval) => Some(validate_podcast_location(&lval)?),
+    };
+
+    let locked = match podcast.locked()
+    {
+        None => None,
+        Some(lval) =>
+        {
+            Some(PodcastLockedBuilder::new()
+                     .value(lval.value().as_str())
+                     .owner(lval.owner())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    let mut live_items = Vec::new();
+    for live_item in podcast.live_items()
+    {
+        live_items.push(PodcastLiveItemBuilder::new()
+                            .status(live_item.status().as_str())
+                            .start(live_item.start().as_str())
+                            .end(live_item.end())
+                            .item(validate_item(&live_item.item())?)
+                            .validate()?
+                            .finalize()?);
+    }
+
+    PodcastChannelExtensionBuilder::new()
+        .funding(funding)
+        .value(value)
+        .persons(validate_podcast_persons(podcast.persons())?)
+        .location(location)
+        .guid(podcast.guid())
+        .locked(locked)
+        .live_items(live_items)
+        .validate()?
+        .finalize()
+}
+
+
+// Rebuild a `PodcastItemExtension`, validating each of its elements.
+fn validate_podcast_item(podcast: &PodcastItemExtension) -> Result<PodcastItemExtension, String>
+{
+    let mut transcripts = Vec::new();
+    for transcript in podcast.transcripts()
+    {
+        transcripts.push(PodcastTranscriptBuilder::new()
+                             .url(transcript.url().as_str())
+                             .mime_type(transcript.mime_type().as_str())
+                             .language(transcript.language())
+                             .rel(transcript.rel())
+                             .validate()?
+                             .finalize()?);
+    }
+
+    let chapters = match podcast.chapters()
+    {
+        None => None,
+        Some(cval) =>
+        {
+            Some(PodcastChaptersBuilder::new()
+                     .url(cval.url().as_str())
+                     .mime_type(cval.mime_type().as_str())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    let value = match podcast.value()
+    {
+        None => None,
+        Some(vval) => Some(validate_podcast_value(&vval)?),
+    };
+
+    let location = match podcast.location()
+    {
+        None => None,
+        Some(lval) => Some(validate_podcast_location(&lval)?),
+    };
+
+    let season = match podcast.season()
+    {
+        None => None,
+        Some(sval) =>
+        {
+            Some(PodcastSeasonBuilder::new()
+                     .number(sval.number())
+                     .name(sval.name())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    let episode = match podcast.episode()
+    {
+        None => None,
+        Some(eval) =>
+        {
+            Some(PodcastEpisodeBuilder::new()
+                     .number(eval.number())
+                     .display(eval.display())
+                     .validate()?
+                     .finalize()?)
+        }
+    };
+
+    let mut soundbites = Vec::new();
+    for soundbite in podcast.soundbites()
+    {
+        soundbites.push(PodcastSoundbiteBuilder::new()
+                            .start_time(soundbite.start_time())
+                            .duration(soundbite.duration())
+                            .title(soundbite.title())
+                            .validate()?
+                            .finalize()?);
+    }
+
+    PodcastItemExtensionBuilder::new()
+        .transcripts(transcripts)
+        .chapters(chapters)
+        .value(value)
+        .persons(validate_podcast_persons(podcast.persons())?)
+        .location(location)
+        .season(season)
+        .episode(episode)
+        .soundbites(soundbites)
+        .alternate_enclosures(validate_podcast_alternate_enclosures(podcast.alternate_enclosures())?)
+        .finalize()
+}
+
+
+fn validate_podcast_persons(persons: Vec<PodcastPerson>) -> Result<Vec<PodcastPerson>, String>
+{
+    let mut validated = Vec::new();
+    for person in persons
+    {
+        validated.push(PodcastPersonBuilder::new()
+                           .name(person.name().as_str())
+                           .role(person.role())
+                           .group(person.group())
+                           .img(person.img())
+                           .href(person.href())
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
+}
+
+
+fn validate_podcast_location(location: &PodcastLocation) -> Result<PodcastLocation, String>
+{
+    PodcastLocationBuilder::new()
+        .name(location.name().as_str())
+        .geo(location.geo())
+        .osm(location.osm())
+        .validate()?
+        .finalize()
+}
+
+
+// Rebuild the `PodcastAlternateEnclosure`s, validating their sources and
+// integrity.
+fn validate_podcast_alternate_enclosures(alternates: Vec<PodcastAlternateEnclosure>)
+    -> Result<Vec<PodcastAlternateEnclosure>, String>
+{
+    let mut validated = Vec::new();
+    for alternate in alternates
+    {
+        let mut sources = Vec::new();
+        for source in alternate.sources()
+        {
+            sources.push(PodcastSourceBuilder::new()
+                             .uri(source.uri().as_str())
+                             .content_type(source.content_type())
+                             .validate()?
+                             .finalize()?);
+        }
+
+        let integrity = match alternate.integrity()
+        {
+            None => None,
+            Some(ival) =>
+            {
+                Some(PodcastIntegrityBuilder::new()
+                         .integrity_type(ival.integrity_type().as_str())
+                         .value(ival.value().as_str())
+                         .validate()?
+                         .finalize()?)
+            }
+        };
+
+        validated.push(PodcastAlternateEnclosureBuilder::new()
+                           .mime_type(alternate.mime_type().as_str())
+                           .length(alternate.length())
+                           .bitrate(alternate.bitrate())
+                           .height(alternate.height())
+                           .lang(alternate.lang())
+                           .title(alternate.title())
+                           .rel(alternate.rel())
+                           .codecs(alternate.codecs())
+                           .is_default(alternate.is_default())
+                           .sources(sources)
+                           .integrity(integrity)
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
+}
+
+
+// Rebuild a `PodcastValue`, validating its recipients and their splits.
+fn validate_podcast_value(value: &PodcastValue) -> Result<PodcastValue, String>
+{
+    let mut recipients = Vec::new();
+    for recipient in value.recipients()
+    {
+        recipients.push(PodcastValueRecipientBuilder::new()
+                            .name(recipient.name())
+                            .custom_key(recipient.custom_key())
+                            .custom_value(recipient.custom_value())
+                            .recipient_type(recipient.recipient_type().as_str())
+                            .address(recipient.address().as_str())
+                            .split(recipient.split())
+                            .fee(recipient.fee())
+                            .validate()?
+                            .finalize()?);
+    }
+
+    PodcastValueBuilder::new()
+        .value_type(value.value_type().as_str())
+        .method(value.method().as_str())
+        .suggested(value.suggested())
+        .recipients(recipients)
+        .validate()?
+        .finalize()
+}
+
+
+// Rebuild a `MediaExtension`, validating each of its elements.
+fn validate_media(media: &MediaExtension) -> Result<MediaExtension, String>
+{
+    let mut groups = Vec::new();
+    for group in media.groups()
+    {
+        groups.push(MediaGroupBuilder::new()
+                        .contents(validate_media_contents(group.contents())?)
+                        .thumbnails(validate_media_thumbnails(group.thumbnails())?)
+                        .credits(validate_media_credits(group.credits())?)
+                        .ratings(validate_media_ratings(group.ratings())?)
+                        .keywords(group.keywords())
+                        .categories(validate_media_categories(group.categories())?)
+                        .validate()?
+                        .finalize()?);
+    }
+
+    MediaExtensionBuilder::new()
+        .groups(groups)
+        .contents(validate_media_contents(media.contents())?)
+        .thumbnails(validate_media_thumbnails(media.thumbnails())?)
+        .credits(validate_media_credits(media.credits())?)
+        .ratings(validate_media_ratings(media.ratings())?)
+        .keywords(media.keywords())
+        .categories(validate_media_categories(media.categories())?)
+        .finalize()
+}
+
+
+fn validate_media_contents(contents: Vec<MediaContent>) -> Result<Vec<MediaContent>, String>
+{
+    let mut validated = Vec::new();
+    for content in contents
+    {
+        validated.push(MediaContentBuilder::new()
+                           .url(content.url())
+                           .file_size(content.file_size())
+                           .mime_type(content.mime_type())
+                           .medium(content.medium())
+                           .is_default(content.is_default())
+                           .expression(content.expression())
+                           .bitrate(content.bitrate())
+                           .duration(content.duration())
+                           .height(content.height())
+                           .width(content.width())
+                           .lang(content.lang())
+                           .thumbnails(validate_media_thumbnails(content.thumbnails())?)
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
+}
+
+
+fn validate_media_thumbnails(thumbnails: Vec<MediaThumbnail>) -> Result<Vec<MediaThumbnail>, String>
+{
+    let mut validated = Vec::new();
+    for thumbnail in thumbnails
+    {
+        validated.push(MediaThumbnailBuilder::new()
+                           .url(thumbnail.url().as_str())
+                           .width(thumbnail.width())
+                           .height(thumbnail.height())
+                           .time(thumbnail.time())
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
+}
+
+
+fn validate_media_credits(credits: Vec<MediaCredit>) -> Result<Vec<MediaCredit>, String>
+{
+    let mut validated = Vec::new();
+    for credit in credits
+    {
+        validated.push(MediaCreditBuilder::new()
+                           .value(credit.value().as_str())
+                           .role(credit.role())
+                           .scheme(credit.scheme())
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
+}
+
+
+fn validate_media_ratings(ratings: Vec<MediaRating>) -> Result<Vec<MediaRating>, String>
+{
+    let mut validated = Vec::new();
+    for rating in ratings
+    {
+        validated.push(MediaRatingBuilder::new()
+                           .value(rating.value().as_str())
+                           .scheme(rating.scheme())
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
+}
+
+
+fn validate_media_categories(categories: Vec<MediaCategory>) -> Result<Vec<MediaCategory>, String>
+{
+    let mut validated = Vec::new();
+    for category in categories
+    {
+        validated.push(MediaCategoryBuilder::new()
+                           .value(category.value().as_str())
+                           .scheme(category.scheme())
+                           .label(category.label())
+                           .validate()?
+                           .finalize()?);
+    }
+    Ok(validated)
 }
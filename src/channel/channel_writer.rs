@@ -0,0 +1,589 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Writing a `Channel` as canonical XML by using the methods under
+//! `ChannelWriter`.
+
+
+use ChannelWriter;
+use channel::channel_validate;
+use rss::{Channel, Item};
+use std::io::Write;
+use utils::reader_utils::XmlElement;
+use utils::writer_utils;
+
+
+impl Default for ChannelWriter
+{
+    fn default() -> ChannelWriter
+    {
+        ChannelWriter {
+            indent: Some(2),
+            cdata: false,
+            declaration: true,
+            encoding: "UTF-8".to_owned(),
+            stylesheets: Vec::new(),
+        }
+    }
+}
+
+
+impl ChannelWriter
+{
+    /// Construct a new `ChannelWriter` that indents by two spaces, escapes
+    /// text and writes a UTF-8 XML declaration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelWriter;
+    ///
+    /// let writer = ChannelWriter::new();
+    /// ```
+    pub fn new() -> ChannelWriter
+    {
+        ChannelWriter::default()
+    }
+
+
+    /// Set the number of spaces each level of elements is indented by, or
+    /// `None` to write the document on a single line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let xml = ChannelWriter::new()
+    ///     .indent(None)
+    ///     .declaration(false)
+    ///     .write_string(&channel)
+    ///     .unwrap();
+    ///
+    /// assert_eq!("<rss version=\"2.0\"><channel><title>The Linux Action Show! OGG</title>\
+    ///             <link/><description/></channel></rss>",
+    ///            xml);
+    /// ```
+    pub fn indent(&mut self, indent: Option<usize>) -> &mut ChannelWriter
+    {
+        self.indent = indent;
+        self
+    }
+
+
+    /// Set whether text holding markup is written as a CDATA section rather
+    /// than escaped.
+    ///
+    /// `content:encoded` is written as CDATA either way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .description("<p>Linux & Open Source</p>")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let xml = ChannelWriter::new()
+    ///     .cdata(true)
+    ///     .write_string(&channel)
+    ///     .unwrap();
+    ///
+    /// assert!(xml.contains("<description><![CDATA[<p>Linux & Open Source</p>]]></description>"));
+    /// ```
+    pub fn cdata(&mut self, cdata: bool) -> &mut ChannelWriter
+    {
+        self.cdata = cdata;
+        self
+    }
+
+
+    /// Set whether the document starts with an XML declaration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter};
+    ///
+    /// let channel = ChannelBuilder::new().finalize().unwrap();
+    ///
+    /// let xml = ChannelWriter::new()
+    ///     .declaration(false)
+    ///     .write_string(&channel)
+    ///     .unwrap();
+    ///
+    /// assert!(xml.starts_with("<rss"));
+    /// ```
+    pub fn declaration(&mut self, declaration: bool) -> &mut ChannelWriter
+    {
+        self.declaration = declaration;
+        self
+    }
+
+
+    /// Set the encoding named by the XML declaration.
+    ///
+    /// The document is always written as UTF-8 bytes. For any other
+    /// encoding the characters outside ASCII are written as character
+    /// references, so the bytes are valid in every ASCII based encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Café")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let xml = ChannelWriter::new()
+    ///     .encoding("ISO-8859-1")
+    ///     .write_string(&channel)
+    ///     .unwrap();
+    ///
+    /// assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>"));
+    /// assert!(xml.contains("<title>Caf&#233;</title>"));
+    /// ```
+    pub fn encoding(&mut self, encoding: &str) -> &mut ChannelWriter
+    {
+        self.encoding = encoding.to_owned();
+        self
+    }
+
+
+    /// Set the `xml-stylesheet` processing instructions written before the
+    /// `rss` element, each given as its pseudo-attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter};
+    ///
+    /// let channel = ChannelBuilder::new().finalize().unwrap();
+    ///
+    /// let xml = ChannelWriter::new()
+    ///     .stylesheets(vec!["type=\"text/xsl\" href=\"/feed.xsl\"".to_owned()])
+    ///     .write_string(&channel)
+    ///     .unwrap();
+    ///
+    /// assert!(xml.contains("<?xml-stylesheet type=\"text/xsl\" href=\"/feed.xsl\"?>\n<rss"));
+    /// ```
+    pub fn stylesheets(&mut self, stylesheets: Vec<String>) -> &mut ChannelWriter
+    {
+        self.stylesheets = stylesheets;
+        self
+    }
+
+
+    /// Set the `xml-stylesheet` processing instructions to those found
+    /// before the root element of an existing document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::ChannelWriter;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let output = ChannelWriter::new()
+    ///         .stylesheets_from(xml.as_str())
+    ///         .write_string(&channel)
+    ///         .unwrap();
+    ///
+    ///     assert!(output.contains("<?xml-stylesheet type=\"text/xsl\" media=\"screen\" \
+    ///                              href=\"/~d/styles/rss2enclosuresfull.xsl\"?>"));
+    /// }
+    /// ```
+    pub fn stylesheets_from(&mut self, xml: &str) -> &mut ChannelWriter
+    {
+        let mut stylesheets = Vec::new();
+        let mut rest = xml;
+
+        while let Some(start) = rest.find('<')
+        {
+            rest = &rest[start..];
+            if !rest.starts_with("<?") && !rest.starts_with("<!")
+            {
+                break;
+            }
+
+            let end = match rest.find('>')
+            {
+                Some(end) => end,
+                None => break,
+            };
+
+            if let Some(pseudo_attrs) = rest[..end].strip_prefix("<?xml-stylesheet")
+            {
+                stylesheets.push(pseudo_attrs.trim_end_matches('?').trim().to_owned());
+            }
+            rest = &rest[end + 1..];
+        }
+
+        self.stylesheets = stylesheets;
+        self
+    }
+
+
+    /// Write the `Channel` as an RSS 2.0 document.
+    ///
+    /// Channel and item elements follow the order of the RSS 2.0
+    /// specification, then come the extensions in order of prefix and name.
+    /// Namespaces and the attributes of extensions are sorted by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter, ItemBuilder};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Linux & Open Source")
+    ///     .items(vec![item])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let xml = ChannelWriter::new().write_string(&channel).unwrap();
+    ///
+    /// assert_eq!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+    /// <rss version=\"2.0\">
+    ///   <channel>
+    ///     <title>The Linux Action Show! OGG</title>
+    ///     <link>http://www.jupiterbroadcasting.com</link>
+    ///     <description>Linux &amp; Open Source</description>
+    ///     <item>
+    ///       <title>Making Music with Linux | LAS 408</title>
+    ///     </item>
+    ///   </channel>
+    /// </rss>
+    /// ",
+    ///            xml);
+    /// ```
+    pub fn write_string(&self, channel: &Channel) -> Result<String, String>
+    {
+        let mut xml = self.header(channel, &writer_utils::rss_element(channel, false))?;
+        for item in &channel.items
+        {
+            xml.push_str(self.item(item).as_str());
+        }
+        xml.push_str(self.footer().as_str());
+
+        Ok(xml)
+    }
+
+
+    /// Write the `Channel` as an RSS 2.0 document to an `io::Write`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut output = Vec::new();
+    /// ChannelWriter::new().write(&channel, &mut output).unwrap();
+    ///
+    /// assert!(String::from_utf8(output).unwrap().ends_with("</rss>\n"));
+    /// ```
+    ///
+    /// Raw `dc` extensions are written when there is no `DublinCoreExtension`.
+    ///
+    /// ```
+    /// extern crate feed;
+    /// extern crate rss;
+    ///
+    /// use feed::{ChannelBuilder, ChannelWriter};
+    /// use rss::extension::Extension;
+    /// use std::collections::HashMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut channel = ChannelBuilder::new()
+    ///         .title("The Linux Action Show! OGG")
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let creator = Extension { name: "dc:creator".to_owned(),
+    ///                               value: Some("Jupiter Broadcasting".to_owned()),
+    ///                               attrs: HashMap::new(),
+    ///                               children: HashMap::new() };
+    ///     let mut map = HashMap::new();
+    ///     map.insert("creator".to_owned(), vec![creator]);
+    ///     channel.extensions.insert("dc".to_owned(), map);
+    ///
+    ///     let mut output = Vec::new();
+    ///     ChannelWriter::new().write(&channel, &mut output).unwrap();
+    ///     let xml = String::from_utf8(output).unwrap();
+    ///
+    ///     assert!(xml.contains("xmlns:dc=\"http://purl.org/dc/elements/1.1/\""));
+    ///     assert!(xml.contains("<dc:creator>Jupiter Broadcasting</dc:creator>"));
+    /// }
+    /// ```
+    pub fn write<W: Write>(&self, channel: &Channel, mut writer: W) -> Result<(), String>
+    {
+        let xml = self.write_string(channel)?;
+        write_all(&mut writer, xml.as_str())
+    }
+
+
+    /// Write the `Channel` to an `io::Write`, followed by the `Item`s pulled
+    /// from an iterator one at a time, and return the number of `Item`s
+    /// written.
+    ///
+    /// Each `Item` is rebuilt into an `ItemBuilder` and checked by
+    /// `ItemBuilder::validate` before it is written, the way `Validate` checks
+    /// the items of a `Channel`, so its extensions are checked as well. The
+    /// items already under `Channel` are written first.
+    ///
+    /// As the items are not known when the `rss` element is written, the
+    /// namespaces of every extension this crate handles and of every
+    /// registered extension are declared, along with those of `Channel`. An
+    /// `Item` with extensions under any other prefix is an error. When an
+    /// `Item` is invalid the error is returned and the document is left
+    /// unfinished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter, ItemBuilder};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let items = (1..10001).map(|number| {
+    ///     ItemBuilder::new()
+    ///         .title(Some(format!("Episode {}", number)))
+    ///         .finalize()
+    ///         .unwrap()
+    /// });
+    ///
+    /// let mut output = Vec::new();
+    /// let count = ChannelWriter::new()
+    ///     .write_stream(&channel, items, &mut output)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(10000, count);
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ChannelBuilder, ChannelWriter, ItemBuilder};
+    ///
+    /// let channel = ChannelBuilder::new().finalize().unwrap();
+    /// let item = ItemBuilder::new().finalize().unwrap();
+    ///
+    /// let mut output = Vec::new();
+    /// let result = ChannelWriter::new().write_stream(&channel, vec![item], &mut output);
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelBuilder, ChannelWriter, ItemBuilder};
+    /// use feed::extension::media::MediaExtensionBuilder;
+    /// use rss::extension::{Extension, ExtensionMap};
+    ///
+    /// fn main()
+    /// {
+    ///     let channel = ChannelBuilder::new()
+    ///         .title("The Linux Action Show! OGG")
+    ///         .link("http://www.jupiterbroadcasting.com")
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let media = MediaExtensionBuilder::new()
+    ///         .keywords(vec!["Linux".to_owned()])
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let item = ItemBuilder::new()
+    ///         .title(Some("LAS 409".to_owned()))
+    ///         .media_ext(Some(media))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut output = Vec::new();
+    ///     ChannelWriter::new().write_stream(&channel, vec![item], &mut output).unwrap();
+    ///
+    ///     let xml = String::from_utf8(output).unwrap();
+    ///     assert!(xml.contains("xmlns:media=\"http://search.yahoo.com/mrss/\""));
+    ///     assert!(xml.contains("<media:keywords>Linux</media:keywords>"));
+    ///
+    ///     let info = Extension {
+    ///         name: "feedburner:info".to_owned(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     extensions.insert("feedburner".to_owned(),
+    ///                       vec![("info".to_owned(), vec![info])].into_iter().collect());
+    ///
+    ///     let item = ItemBuilder::new()
+    ///         .title(Some("LAS 410".to_owned()))
+    ///         .extensions(extensions)
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut output = Vec::new();
+    ///     assert!(ChannelWriter::new().write_stream(&channel, vec![item], &mut output).is_err());
+    /// }
+    /// ```
+    pub fn write_stream<W, I>(&self, channel: &Channel, items: I, mut writer: W) -> Result<usize, String>
+        where W: Write,
+              I: IntoIterator<Item = Item>
+    {
+        let rss = writer_utils::rss_element(channel, true);
+        let declared: Vec<&str> = rss.attrs
+            .iter()
+            .filter_map(|attr| attr.0.strip_prefix("xmlns:"))
+            .collect();
+
+        let header = self.header(channel, &rss)?;
+        write_all(&mut writer, header.as_str())?;
+
+        let mut count = 0;
+        for item in channel.items.iter().cloned().chain(items)
+        {
+            // `validate_item` rebuilds the item and ends in `ItemBuilder::validate`.
+            channel_validate::validate_item(&item).map_err(|err| format!("Item {}: {}", count, err))?;
+
+            if let Some(prefix) = item.extensions.keys().find(|prefix| !declared.contains(&prefix.as_str()))
+            {
+                return Err(format!("Item {}: Invalid value: {}. The prefix is not declared.", count, prefix));
+            }
+
+            write_all(&mut writer, self.item(&item).as_str())?;
+            count += 1;
+        }
+
+        write_all(&mut writer, self.footer().as_str())?;
+        Ok(count)
+    }
+
+
+    // Write everything that comes before the first item.
+    fn header(&self, channel: &Channel, rss: &XmlElement) -> Result<String, String>
+    {
+        let mut xml = self.prolog()?;
+        let ascii = self.ascii();
+
+        writer_utils::write_start(&mut xml, rss, 0, self.indent, ascii);
+        writer_utils::write_start(&mut xml, &writer_utils::text_node("channel", ""), 1, self.indent, ascii);
+        for element in writer_utils::channel_elements(channel)
+        {
+            writer_utils::write_element(&mut xml, &element, 2, self.indent, self.cdata, ascii);
+        }
+
+        Ok(xml)
+    }
+
+
+    // Write an item of the channel.
+    fn item(&self, item: &Item) -> String
+    {
+        let mut xml = String::new();
+        writer_utils::write_element(&mut xml,
+                                    &writer_utils::item_element(item),
+                                    2,
+                                    self.indent,
+                                    self.cdata,
+                                    self.ascii());
+        xml
+    }
+
+
+    // Write everything that comes after the last item.
+    fn footer(&self) -> String
+    {
+        let mut xml = String::new();
+        writer_utils::write_end(&mut xml, "channel", 1, self.indent);
+        writer_utils::write_end(&mut xml, "rss", 0, self.indent);
+        xml
+    }
+
+
+    // Write the XML declaration and stylesheets that come before the root.
+    fn prolog(&self) -> Result<String, String>
+    {
+        let mut xml = String::new();
+        let newline = if self.indent.is_some() { "\n" } else { "" };
+
+        if self.declaration
+        {
+            let valid = self.encoding.starts_with(|c: char| c.is_ascii_alphabetic()) &&
+                        self.encoding.chars().all(|c| c.is_ascii_alphanumeric() || "._-".contains(c));
+            if !valid
+            {
+                return Err(format!("Invalid value: {}", self.encoding));
+            }
+
+            xml.push_str(format!("<?xml version=\"1.0\" encoding=\"{}\"?>{}", self.encoding, newline).as_str());
+        }
+
+        for stylesheet in &self.stylesheets
+        {
+            if stylesheet.contains("?>")
+            {
+                return Err(format!("Invalid value: {}", stylesheet));
+            }
+
+            xml.push_str(format!("<?xml-stylesheet {}?>{}", stylesheet, newline).as_str());
+        }
+
+        Ok(xml)
+    }
+
+
+    // Whether characters outside ASCII must be written as references.
+    fn ascii(&self) -> bool
+    {
+        let encoding = self.encoding.to_lowercase();
+        encoding != "utf-8" && encoding != "utf8"
+    }
+}
+
+
+fn write_all<W: Write>(writer: &mut W, xml: &str) -> Result<(), String>
+{
+    writer.write_all(xml.as_bytes()).map_err(|err| format!("Error: {}", err))
+}
@@ -12,7 +12,18 @@
 
 
 use ItemBuilder;
+use NamespaceExtension;
+use extension::atom::AtomLink;
+use extension::comments::CommentsExtension;
+use extension::georss::GeoRssExtension;
+use extension::googleplay::GooglePlayItemExtension;
+use extension::itunes::ITunesItemExtensionBuilder;
+use extension::podcast::PodcastItemExtension;
+use extension::media::MediaExtension;
+use extension::registry;
 use rss::{Category, Enclosure, Guid, Item, Source};
+use rss::extension::ExtensionMap;
+use rss::extension::dublincore::DublinCoreExtension;
 use rss::extension::itunes::ITunesItemExtension;
 use utils::string_utils;
 
@@ -232,6 +243,24 @@ impl ItemBuilder
     }
 
 
+    /// Set the optional content that exists under `Item`, written as
+    /// `content:encoded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.content(Some("<p>Making Music with Linux</p>".to_owned()));
+    /// ```
+    pub fn content(&mut self, content: Option<String>) -> &mut ItemBuilder
+    {
+        self.content = content;
+        self
+    }
+
+
     /// Set the optional itunes_ext that exists under `Item`.
     ///
     /// # Examples
@@ -260,12 +289,341 @@ impl ItemBuilder
     /// item_builder.itunes_ext(Some(itunes_item));
     /// ```
     pub fn itunes_ext(&mut self, itunes_ext: Option<ITunesItemExtension>) -> &mut ItemBuilder
+    {
+        self.itunes_ext = itunes_ext.map(ITunesItemExtensionBuilder::from);
+        self
+    }
+
+
+    /// Set the optional itunes_ext that exists under `Item` from its builder,
+    /// keeping the title, the episode and season numbers and the episode type
+    /// that `ITunesItemExtension` has no fields for in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.duration(Some("55:33".to_owned())).episode(Some(408)).season(Some(2));
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .itunes_ext_builder(Some(itunes_builder))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("55:33".to_owned()), item.itunes_ext().unwrap().duration);
+    /// assert_eq!(Some(408), item.itunes_episode());
+    /// assert_eq!(Some(2), item.itunes_season());
+    /// ```
+    pub fn itunes_ext_builder(&mut self, itunes_ext: Option<ITunesItemExtensionBuilder>) -> &mut ItemBuilder
     {
         self.itunes_ext = itunes_ext;
         self
     }
 
 
+    /// Set the optional dublin_core_ext that exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creator(vec!["Jupiter Broadcasting".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.dublin_core_ext(Some(dublin_core));
+    /// ```
+    pub fn dublin_core_ext(&mut self, dublin_core_ext: Option<DublinCoreExtension>) -> &mut ItemBuilder
+    {
+        self.dublin_core_ext = dublin_core_ext;
+        self
+    }
+
+
+    /// Set the optional media_ext that exists under `Item`.
+    ///
+    /// The Media RSS elements it covers replace those in the extensions, and
+    /// the other `media` extensions are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media = MediaExtensionBuilder::new()
+    ///     .keywords(vec!["Linux".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.media_ext(Some(media));
+    /// ```
+    pub fn media_ext(&mut self, media_ext: Option<MediaExtension>) -> &mut ItemBuilder
+    {
+        self.media_ext = media_ext;
+        self
+    }
+
+
+    /// Set the atom links that exist under `Item`.
+    ///
+    /// They replace the `atom:link` extensions, and the other Atom elements
+    /// are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .rel(Some("self".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.atom_links(vec![link]);
+    /// ```
+    pub fn atom_links(&mut self, atom_links: Vec<AtomLink>) -> &mut ItemBuilder
+    {
+        self.atom_links = Some(atom_links);
+        self
+    }
+
+
+    /// Set the optional googleplay_ext that exists under `Item`.
+    ///
+    /// The Google Play tags it covers replace those in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let googleplay = GooglePlayItemExtensionBuilder::new()
+    ///     .explicit(Some("no".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.googleplay_ext(Some(googleplay));
+    /// ```
+    pub fn googleplay_ext(&mut self, googleplay_ext: Option<GooglePlayItemExtension>) -> &mut ItemBuilder
+    {
+        self.googleplay_ext = googleplay_ext;
+        self
+    }
+
+
+    /// Set the optional podcast_ext that exists under `Item`.
+    ///
+    /// The Podcasting 2.0 tags it covers replace those in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastTranscriptBuilder};
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .transcripts(vec![transcript])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.podcast_ext(Some(podcast));
+    /// ```
+    pub fn podcast_ext(&mut self, podcast_ext: Option<PodcastItemExtension>) -> &mut ItemBuilder
+    {
+        self.podcast_ext = podcast_ext;
+        self
+    }
+
+
+    /// Set the optional comments_ext that exists under `Item`.
+    ///
+    /// The `wfw`, `slash` and `thr` tags it covers replace those in the
+    /// extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::comments::CommentsExtensionBuilder;
+    ///
+    /// let comments = CommentsExtensionBuilder::new()
+    ///     .comment_rss(Some("http://www.jupiterbroadcasting.com/97561/feed/".to_owned()))
+    ///     .comments(Some(12))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.comments_ext(Some(comments));
+    /// ```
+    pub fn comments_ext(&mut self, comments_ext: Option<CommentsExtension>) -> &mut ItemBuilder
+    {
+        self.comments_ext = comments_ext;
+        self
+    }
+
+
+    /// Set the optional georss_ext that exists under `Item`.
+    ///
+    /// The GeoRSS tags it covers replace those in the extensions, and are
+    /// written as GeoRSS Simple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let point = GeoPointBuilder::new()
+    ///     .latitude(44.27)
+    ///     .longitude(-71.303)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let georss = GeoRssExtensionBuilder::new()
+    ///     .point(Some(point))
+    ///     .feature_name(Some("Mount Washington".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.georss_ext(Some(georss));
+    /// ```
+    pub fn georss_ext(&mut self, georss_ext: Option<GeoRssExtension>) -> &mut ItemBuilder
+    {
+        self.georss_ext = georss_ext;
+        self
+    }
+
+
+    /// Set a `NamespaceExtension` that exists under `Item`.
+    ///
+    /// Its elements replace those with the same name under its prefix in the
+    /// extensions. The extension must be registered with
+    /// `extension::registry::register` before the builder is finalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ItemBuilder, NamespaceExtension};
+    /// use feed::extension::registry;
+    /// use rss::extension::Extension;
+    /// use std::collections::HashMap;
+    ///
+    /// struct License(String);
+    ///
+    /// impl NamespaceExtension for License
+    /// {
+    ///     fn namespace() -> &'static str
+    ///     {
+    ///         "http://backend.userland.com/creativeCommonsRssModule"
+    ///     }
+    ///
+    ///     fn prefix() -> &'static str
+    ///     {
+    ///         "creativeCommons"
+    ///     }
+    ///
+    ///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+    ///     {
+    ///         let element = map.get("license").and_then(|elements| elements.first());
+    ///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+    ///     }
+    ///
+    ///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    ///     {
+    ///         let license = Extension {
+    ///             name: "creativeCommons:license".to_owned(),
+    ///             value: Some(self.0.clone()),
+    ///             ..Default::default()
+    ///         };
+    ///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+    ///     }
+    /// }
+    ///
+    /// fn main()
+    /// {
+    ///     registry::register::<License>().unwrap();
+    ///
+    ///     let mut item_builder = ItemBuilder::new();
+    ///     item_builder.namespace_ext(&License("http://www.creativecommons.org/licenses/by-nc-sa/1.0".to_owned()));
+    /// }
+    /// ```
+    pub fn namespace_ext<T: NamespaceExtension>(&mut self, namespace_ext: &T) -> &mut ItemBuilder
+    {
+        self.namespace_exts
+            .entry(T::prefix().to_owned())
+            .or_default()
+            .extend(namespace_ext.to_map());
+        self
+    }
+
+
+    /// Set the extensions that exist under `Item`.
+    ///
+    /// Elements from namespaces the builder does not model are kept here,
+    /// keyed by prefix and then by name, so they are written back out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::ItemBuilder;
+    /// use rss::extension::{Extension, ExtensionMap};
+    /// use std::collections::HashMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let thumbnail = Extension {
+    ///         name: "media:thumbnail".to_owned(),
+    ///         attrs: vec![("url".to_owned(), "http://www.example.com/thumb.jpg".to_owned())]
+    ///             .into_iter()
+    ///             .collect(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let mut media = HashMap::new();
+    ///     media.insert("thumbnail".to_owned(), vec![thumbnail]);
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     extensions.insert("media".to_owned(), media);
+    ///
+    ///     let mut item_builder = ItemBuilder::new();
+    ///     item_builder.extensions(extensions);
+    /// }
+    /// ```
+    pub fn extensions(&mut self, extensions: ExtensionMap) -> &mut ItemBuilder
+    {
+        self.extensions = extensions;
+        self
+    }
+
+
     /// Validate the contents of `Item`.
     ///
     /// # Examples
@@ -335,6 +693,60 @@ impl ItemBuilder
     /// ```
     pub fn finalize(&self) -> Result<Item, String>
     {
+        let mut extensions = self.extensions.clone();
+        if let Some(media) = self.media_ext.clone()
+        {
+            media.insert_into(&mut extensions);
+        }
+
+        if let Some(ref atom_links) = self.atom_links
+        {
+            AtomLink::insert_into(atom_links, &mut extensions);
+        }
+
+        if let Some(ref googleplay) = self.googleplay_ext
+        {
+            googleplay.insert_into(&mut extensions);
+        }
+
+        if let Some(ref podcast) = self.podcast_ext
+        {
+            podcast.insert_into(&mut extensions);
+        }
+
+        let itunes_ext = match self.itunes_ext
+        {
+            Some(ref itunes_builder) =>
+            {
+                itunes_builder.insert_into(&mut extensions);
+                Some(itunes_builder.finalize()?)
+            }
+            None => None,
+        };
+
+        if let Some(ref comments) = self.comments_ext
+        {
+            comments.insert_into(&mut extensions);
+        }
+
+        if let Some(ref georss) = self.georss_ext
+        {
+            georss.insert_into(&mut extensions);
+        }
+
+        for (prefix, elements) in &self.namespace_exts
+        {
+            if registry::namespace(prefix).is_none()
+            {
+                return Err(format!("Invalid value: {}. The prefix is not registered.", prefix));
+            }
+
+            extensions
+                .entry(prefix.clone())
+                .or_default()
+                .extend(elements.clone());
+        }
+
         Ok(Item {
                title: self.title.clone(),
                link: self.link.clone(),
@@ -346,8 +758,10 @@ impl ItemBuilder
                guid: self.guid.clone(),
                pub_date: self.pub_date.clone(),
                source: self.source.clone(),
-               itunes_ext: self.itunes_ext.clone(),
-               ..Default::default()
+               content: self.content.clone(),
+               itunes_ext,
+               dublin_core_ext: self.dublin_core_ext.clone(),
+               extensions,
            })
     }
 }
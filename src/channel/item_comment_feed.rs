@@ -0,0 +1,48 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! `CommentFeed` Trait for `Item`
+
+
+use CommentFeed;
+use FromUrl;
+use ItemGetters;
+use rss::{Channel, Item};
+
+
+impl CommentFeed for Item
+{
+    /// Fetch the comment feed of `Item`, from its `wfw:commentRss`, as a
+    /// `Channel`, so its comments can be shown with the `Item`.
+    ///
+    /// There is no comment feed to fetch when `Item` has no
+    /// `wfw:commentRss`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{CommentFeed, ItemBuilder};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.comment_feed().unwrap().is_none());
+    /// ```
+    fn comment_feed(&self) -> Result<Option<Channel>, String>
+    {
+        match self.comments_ext().and_then(|comments| comments.comment_rss)
+        {
+            None => Ok(None),
+            Some(url) => Channel::from_url(url.as_str()).map(Some),
+        }
+    }
+}
@@ -11,7 +11,16 @@
 //! The fields under item can be retrieved by using the methods under `Item`.
 
 use ItemGetters;
+use NamespaceExtension;
+use extension::atom::AtomLink;
+use extension::comments::CommentsExtension;
+use extension::georss::GeoRssExtension;
+use extension::googleplay::GooglePlayItemExtension;
+use extension::podcast::PodcastItemExtension;
+use extension::media::MediaExtension;
 use rss::{Category, Enclosure, Guid, Item, Source};
+use rss::extension::{self, ExtensionMap};
+use rss::extension::dublincore::DublinCoreExtension;
 use rss::extension::itunes::ITunesItemExtension;
 
 
@@ -398,6 +407,86 @@ impl ItemGetters for Item
     }
 
 
+    /// Get the optional content that exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let content = Some("<p>Making Music with Linux</p>".to_owned());
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .content(content.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(content, item.content());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .content(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.content().is_none());
+    /// ```
+    fn content(&self) -> Option<String>
+    {
+        self.content.clone()
+    }
+
+
+    /// Get the richest body of `Item`: the content if there is any, otherwise
+    /// the description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelGetters, ItemGetters};
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/example2.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let item = xml.parse::<Channel>().unwrap().items()[0].clone();
+    ///
+    ///     assert_eq!(item.content(), item.body());
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .description(Some("Making Music with Linux".to_owned()))
+    ///     .content(Some("   ".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("Making Music with Linux".to_owned()), item.body());
+    /// ```
+    fn body(&self) -> Option<String>
+    {
+        match self.content
+        {
+            Some(ref content) if !content.trim().is_empty() => Some(content.clone()),
+            _ => self.description.clone(),
+        }
+    }
+
+
     /// Get the optional `ITunesItemExtension` under `Item`.
     /// # Examples
     ///
@@ -443,4 +532,550 @@ impl ItemGetters for Item
     {
         self.itunes_ext.clone()
     }
+
+
+    /// Get the optional `DublinCoreExtension` under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creator(vec!["Jupiter Broadcasting".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .dublin_core_ext(Some(dublin_core))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec!["Jupiter Broadcasting".to_owned()], item.dublin_core_ext().unwrap().creator());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .dublin_core_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.dublin_core_ext().is_none());
+    /// ```
+    fn dublin_core_ext(&self) -> Option<DublinCoreExtension>
+    {
+        self.dublin_core_ext.clone()
+    }
+
+
+    /// Get the optional `MediaExtension` under `Item`, read from the `media`
+    /// extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelGetters, ItemGetters};
+    /// use feed::extension::media::MediaContentGetters;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let item = xml.parse::<Channel>().unwrap().items()[0].clone();
+    ///
+    ///     let media = item.media_ext().unwrap();
+    ///     assert_eq!(Some(69796480), media.contents[0].file_size());
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .media_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.media_ext().is_none());
+    /// ```
+    fn media_ext(&self) -> Option<MediaExtension>
+    {
+        self.extensions.get("media").map(MediaExtension::from_map)
+    }
+
+
+    /// Get the atom links that exist under `Item`, read from the `atom`
+    /// extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let link = AtomLinkBuilder::new()
+    ///     .href("http://www.jupiterbroadcasting.com/97561/making-music-with-linux-las-408/")
+    ///     .rel(Some("alternate".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .atom_links(vec![link.clone()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec![link], item.atom_links());
+    /// ```
+    fn atom_links(&self) -> Vec<AtomLink>
+    {
+        AtomLink::from_extensions(&self.extensions)
+    }
+
+
+    /// Get the optional `GooglePlayItemExtension` under `Item`, read
+    /// from the `googleplay` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let googleplay = GooglePlayItemExtensionBuilder::new()
+    ///     .explicit(Some("no".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .googleplay_ext(Some(googleplay))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("no".to_owned()), item.googleplay_ext().unwrap().explicit());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .googleplay_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.googleplay_ext().is_none());
+    /// ```
+    fn googleplay_ext(&self) -> Option<GooglePlayItemExtension>
+    {
+        self.extensions.get("googleplay").map(GooglePlayItemExtension::from_map)
+    }
+
+
+    /// Get the optional `PodcastItemExtension` under `Item`, read from the
+    /// `podcast` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastChaptersGetters, PodcastItemExtensionBuilder,
+    ///                                PodcastItemExtensionGetters};
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .url("https://example.com/episode1/chapters.json")
+    ///     .mime_type("application/json+chapters")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .chapters(Some(chapters))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .podcast_ext(Some(podcast))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!("https://example.com/episode1/chapters.json",
+    ///            item.podcast_ext().unwrap().chapters().unwrap().url());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .podcast_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.podcast_ext().is_none());
+    /// ```
+    fn podcast_ext(&self) -> Option<PodcastItemExtension>
+    {
+        self.extensions.get("podcast").map(PodcastItemExtension::from_map)
+    }
+
+
+    /// Get the optional iTunes title under `Item`, read from the `itunes`
+    /// extensions.
+    ///
+    /// `Channel::from_str` drops it, so it is only there when the item is
+    /// built with an `ItemBuilder` or read with `ChannelReader`, `FromUrl`,
+    /// `FromLegacy` or `FromLenient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.title(Some("Making Music with Linux".to_owned()));
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .itunes_ext_builder(Some(itunes_builder))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("Making Music with Linux".to_owned()), item.itunes_title());
+    /// ```
+    fn itunes_title(&self) -> Option<String>
+    {
+        self.extensions
+            .get("itunes")
+            .and_then(|map| extension::get_extension_value(map, "title"))
+            .map(|val| val.trim().to_owned())
+    }
+
+
+    /// Get the optional iTunes episode number under `Item`, read from the
+    /// `itunes` extensions. It is `None` when it is not a number.
+    ///
+    /// `Channel::from_str` drops it, so it is only there when the item is
+    /// built with an `ItemBuilder` or read with `ChannelReader`, `FromUrl`,
+    /// `FromLegacy` or `FromLenient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.episode(Some(408));
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .itunes_ext_builder(Some(itunes_builder))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(408), item.itunes_episode());
+    /// ```
+    ///
+    /// ```
+    /// extern crate feed;
+    /// extern crate rss;
+    ///
+    /// use feed::{FromLegacy, ItemGetters};
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+    ///     <channel><title>LAS</title>
+    ///     <item><title>Making Music with Linux | LAS 408</title>
+    ///     <itunes:episode>408</itunes:episode>
+    ///     <itunes:duration>55:33</itunes:duration>
+    ///     </item></channel></rss>"#;
+    ///
+    ///     let (channel, _) = Channel::from_legacy(xml).unwrap();
+    ///     assert_eq!(Some(408), channel.items[0].itunes_episode());
+    ///     assert_eq!(Some("55:33".to_owned()), channel.items[0].itunes_ext().unwrap().duration);
+    /// }
+    /// ```
+    fn itunes_episode(&self) -> Option<i64>
+    {
+        self.extensions
+            .get("itunes")
+            .and_then(|map| extension::get_extension_value(map, "episode"))
+            .and_then(|val| val.trim().parse().ok())
+    }
+
+
+    /// Get the optional iTunes season number under `Item`, read from the
+    /// `itunes` extensions. It is `None` when it is not a number.
+    ///
+    /// `Channel::from_str` drops it, so it is only there when the item is
+    /// built with an `ItemBuilder` or read with `ChannelReader`, `FromUrl`,
+    /// `FromLegacy` or `FromLenient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.season(Some(2));
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .itunes_ext_builder(Some(itunes_builder))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(2), item.itunes_season());
+    /// ```
+    fn itunes_season(&self) -> Option<i64>
+    {
+        self.extensions
+            .get("itunes")
+            .and_then(|map| extension::get_extension_value(map, "season"))
+            .and_then(|val| val.trim().parse().ok())
+    }
+
+
+    /// Get the optional iTunes episode type under `Item`, read from the `itunes`
+    /// extensions.
+    ///
+    /// `Channel::from_str` drops it, so it is only there when the item is
+    /// built with an `ItemBuilder` or read with `ChannelReader`, `FromUrl`,
+    /// `FromLegacy` or `FromLenient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.episode_type(Some("full".to_owned()));
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .itunes_ext_builder(Some(itunes_builder))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("full".to_owned()), item.itunes_episode_type());
+    /// ```
+    fn itunes_episode_type(&self) -> Option<String>
+    {
+        self.extensions
+            .get("itunes")
+            .and_then(|map| extension::get_extension_value(map, "episodeType"))
+            .map(|val| val.trim().to_owned())
+    }
+
+
+    /// Get the optional `CommentsExtension` under `Item`, read from the
+    /// `wfw`, `slash` and `thr` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters};
+    ///
+    /// let comments = CommentsExtensionBuilder::new()
+    ///     .comment_rss(Some("http://www.jupiterbroadcasting.com/97561/feed/".to_owned()))
+    ///     .comments(Some(12))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .comments_ext(Some(comments))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(12), item.comments_ext().unwrap().comments());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .comments_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.comments_ext().is_none());
+    /// ```
+    fn comments_ext(&self) -> Option<CommentsExtension>
+    {
+        let comments = CommentsExtension::from_extensions(&self.extensions);
+        if comments == CommentsExtension::default()
+        {
+            None
+        }
+        else
+        {
+            Some(comments)
+        }
+    }
+
+
+    /// Get the optional `GeoRssExtension` under `Item`, read from the
+    /// `georss` extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let georss = GeoRssExtensionBuilder::new()
+    ///     .feature_name(Some("Mount Washington".to_owned()))
+    ///     .elevation(Some(1917.0))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Storm on the summit".to_owned()))
+    ///     .georss_ext(Some(georss))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(1917.0), item.georss_ext().unwrap().elevation());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{ItemBuilder, ItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Storm on the summit".to_owned()))
+    ///     .georss_ext(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(item.georss_ext().is_none());
+    /// ```
+    fn georss_ext(&self) -> Option<GeoRssExtension>
+    {
+        self.extensions.get("georss").map(GeoRssExtension::from_map)
+    }
+
+
+    /// Get the optional `NamespaceExtension` of type `T` under `Item`, read
+    /// from the extensions under its prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ItemBuilder, ItemGetters, NamespaceExtension};
+    /// use feed::extension::registry;
+    /// use rss::extension::Extension;
+    /// use std::collections::HashMap;
+    ///
+    /// struct License(String);
+    ///
+    /// impl NamespaceExtension for License
+    /// {
+    ///     fn namespace() -> &'static str
+    ///     {
+    ///         "http://backend.userland.com/creativeCommonsRssModule"
+    ///     }
+    ///
+    ///     fn prefix() -> &'static str
+    ///     {
+    ///         "creativeCommons"
+    ///     }
+    ///
+    ///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+    ///     {
+    ///         let element = map.get("license").and_then(|elements| elements.first());
+    ///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+    ///     }
+    ///
+    ///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    ///     {
+    ///         let license = Extension {
+    ///             name: "creativeCommons:license".to_owned(),
+    ///             value: Some(self.0.clone()),
+    ///             ..Default::default()
+    ///         };
+    ///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+    ///     }
+    /// }
+    ///
+    /// fn main()
+    /// {
+    ///     registry::register::<License>().unwrap();
+    ///
+    ///     let item = ItemBuilder::new()
+    ///         .namespace_ext(&License("http://www.creativecommons.org/licenses/by-nc-sa/1.0".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let license = item.namespace_ext::<License>().unwrap().unwrap();
+    ///     assert_eq!("http://www.creativecommons.org/licenses/by-nc-sa/1.0", license.0);
+    ///
+    ///     let item = ItemBuilder::new()
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     assert!(item.namespace_ext::<License>().unwrap().is_none());
+    /// }
+    /// ```
+    fn namespace_ext<T: NamespaceExtension>(&self) -> Result<Option<T>, String>
+    {
+        match self.extensions.get(T::prefix())
+        {
+            Some(map) => T::from_map(map).map(Some),
+            None => Ok(None),
+        }
+    }
+
+
+    /// Get the extensions that exist under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelGetters, ItemBuilder, ItemGetters};
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let item = xml.parse::<Channel>().unwrap().items()[0].clone();
+    ///
+    ///     let item = ItemBuilder::new()
+    ///         .title(item.title())
+    ///         .extensions(item.extensions())
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     assert!(item.extensions()["media"].contains_key("thumbnail"));
+    /// }
+    /// ```
+    fn extensions(&self) -> ExtensionMap
+    {
+        self.extensions.clone()
+    }
 }
@@ -13,10 +13,16 @@
 
 pub mod category_getters;
 pub mod category_builder;
+pub mod channel_from_legacy;
+pub mod channel_from_lenient;
 pub mod channel_from_url;
 pub mod channel_getters;
+pub mod channel_reader;
 pub mod channel_builder;
+pub mod channel_compliance;
+pub mod channel_to_legacy;
 pub mod channel_validate;
+pub mod channel_writer;
 pub mod cloud_getters;
 pub mod cloud_builder;
 pub mod enclosure_getters;
@@ -25,6 +31,7 @@ pub mod guid_getters;
 pub mod guid_builder;
 pub mod image_getters;
 pub mod image_builder;
+pub mod item_comment_feed;
 pub mod item_getters;
 pub mod item_builder;
 pub mod source_getters;
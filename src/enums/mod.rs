@@ -11,6 +11,9 @@
 //! All of the enum.
 
 
+use std::time::Duration;
+
+
 /// Enumerations of protocols for `Cloud`.
 #[derive(Clone, Debug)]
 pub enum CloudProtocol
@@ -28,7 +31,7 @@ pub enum CloudProtocol
 
 impl CloudProtocol
 {
-    // Convert `&str` to `CloudProtocol`.
+    /// Convert `&str` to `CloudProtocol`.
     pub fn value_of(s: &str) -> Result<CloudProtocol, String>
     {
         match s
@@ -86,3 +89,454 @@ impl Day
         }
     }
 }
+
+
+/// Enumerations of versions for `Channel`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RssVersion
+{
+    /// RSS 0.90
+    Rss090,
+
+    /// RSS 0.91
+    Rss091,
+
+    /// RSS 0.92
+    Rss092,
+
+    /// RSS 0.93
+    Rss093,
+
+    /// RSS 0.94
+    Rss094,
+
+    /// RSS 1.0
+    Rss10,
+
+    /// RSS 2.0
+    Rss20,
+}
+
+
+impl RssVersion
+{
+    /// Convert `&str` to `RssVersion`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::RssVersion;
+    ///
+    /// assert_eq!(RssVersion::Rss091, RssVersion::value_of("0.91").unwrap());
+    /// assert!(RssVersion::value_of("3.0").is_err());
+    /// ```
+    pub fn value_of(s: &str) -> Result<RssVersion, String>
+    {
+        match s
+        {
+            "0.9" | "0.90" => Ok(RssVersion::Rss090),
+            "0.91" => Ok(RssVersion::Rss091),
+            "0.92" => Ok(RssVersion::Rss092),
+            "0.93" => Ok(RssVersion::Rss093),
+            "0.94" => Ok(RssVersion::Rss094),
+            "1.0" => Ok(RssVersion::Rss10),
+            "2.0" | "2.0.1" => Ok(RssVersion::Rss20),
+            _ => Err(format!("Invalid value: {}", s)),
+        }
+    }
+
+
+    /// Get the version number of `RssVersion`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::RssVersion;
+    ///
+    /// assert_eq!("1.0", RssVersion::Rss10.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str
+    {
+        match *self
+        {
+            RssVersion::Rss090 => "0.90",
+            RssVersion::Rss091 => "0.91",
+            RssVersion::Rss092 => "0.92",
+            RssVersion::Rss093 => "0.93",
+            RssVersion::Rss094 => "0.94",
+            RssVersion::Rss10 => "1.0",
+            RssVersion::Rss20 => "2.0",
+        }
+    }
+}
+
+
+/// Enumerations of formats for `Feed`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedFormat
+{
+    /// RSS 2.0
+    Rss,
+
+    /// Atom 1.0
+    Atom,
+
+    /// JSON Feed 1.1
+    JsonFeed,
+
+    /// RSS 1.0 (RDF)
+    Rdf,
+}
+
+
+impl FeedFormat
+{
+    /// Convert `&str` to `FeedFormat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::FeedFormat;
+    ///
+    /// assert_eq!(FeedFormat::JsonFeed, FeedFormat::value_of("json").unwrap());
+    /// assert!(FeedFormat::value_of("opml").is_err());
+    /// ```
+    pub fn value_of(s: &str) -> Result<FeedFormat, String>
+    {
+        match s
+        {
+            "rss" => Ok(FeedFormat::Rss),
+            "atom" => Ok(FeedFormat::Atom),
+            "json" => Ok(FeedFormat::JsonFeed),
+            "rdf" => Ok(FeedFormat::Rdf),
+            _ => Err(format!("Invalid value: {}", s)),
+        }
+    }
+}
+
+
+/// Enumerations of explicit values for the iTunes extensions.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ITunesExplicit
+{
+    /// yes or true
+    Yes,
+
+    /// no or false
+    No,
+
+    /// clean
+    Clean,
+}
+
+
+impl ITunesExplicit
+{
+    /// Convert `&str` to `ITunesExplicit`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesExplicit;
+    ///
+    /// assert_eq!(ITunesExplicit::Yes, ITunesExplicit::value_of("true").unwrap());
+    /// assert_eq!(ITunesExplicit::Clean, ITunesExplicit::value_of("Clean").unwrap());
+    /// assert!(ITunesExplicit::value_of("explicit").is_err());
+    /// assert!(ITunesExplicit::value_of("maybe").is_err());
+    /// ```
+    pub fn value_of(s: &str) -> Result<ITunesExplicit, String>
+    {
+        match s.trim().to_lowercase().as_str()
+        {
+            "yes" | "true" => Ok(ITunesExplicit::Yes),
+            "no" | "false" => Ok(ITunesExplicit::No),
+            "clean" => Ok(ITunesExplicit::Clean),
+            _ => Err(format!("Invalid value: {}", s)),
+        }
+    }
+
+
+    /// Get the value of `ITunesExplicit` as it is written to a feed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesExplicit;
+    ///
+    /// assert_eq!("no", ITunesExplicit::No.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str
+    {
+        match *self
+        {
+            ITunesExplicit::Yes => "yes",
+            ITunesExplicit::No => "no",
+            ITunesExplicit::Clean => "clean",
+        }
+    }
+}
+
+
+/// Enumerations of block values for the iTunes extensions.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ITunesBlock
+{
+    /// yes
+    Yes,
+
+    /// no
+    No,
+}
+
+
+impl ITunesBlock
+{
+    /// Convert `&str` to `ITunesBlock`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesBlock;
+    ///
+    /// assert_eq!(ITunesBlock::Yes, ITunesBlock::value_of("Yes").unwrap());
+    /// assert!(ITunesBlock::value_of("true").is_err());
+    /// ```
+    pub fn value_of(s: &str) -> Result<ITunesBlock, String>
+    {
+        match s.trim().to_lowercase().as_str()
+        {
+            "yes" => Ok(ITunesBlock::Yes),
+            "no" => Ok(ITunesBlock::No),
+            _ => Err(format!("Invalid value: {}", s)),
+        }
+    }
+
+
+    /// Get the value of `ITunesBlock` as it is written to a feed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesBlock;
+    ///
+    /// assert_eq!("yes", ITunesBlock::Yes.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str
+    {
+        match *self
+        {
+            ITunesBlock::Yes => "yes",
+            ITunesBlock::No => "no",
+        }
+    }
+}
+
+
+/// Enumerations of complete values for the iTunes extensions.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ITunesComplete
+{
+    /// yes
+    Yes,
+
+    /// no
+    No,
+}
+
+
+impl ITunesComplete
+{
+    /// Convert `&str` to `ITunesComplete`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesComplete;
+    ///
+    /// assert_eq!(ITunesComplete::No, ITunesComplete::value_of("no").unwrap());
+    /// assert!(ITunesComplete::value_of("done").is_err());
+    /// ```
+    pub fn value_of(s: &str) -> Result<ITunesComplete, String>
+    {
+        match s.trim().to_lowercase().as_str()
+        {
+            "yes" => Ok(ITunesComplete::Yes),
+            "no" => Ok(ITunesComplete::No),
+            _ => Err(format!("Invalid value: {}", s)),
+        }
+    }
+
+
+    /// Get the value of `ITunesComplete` as it is written to a feed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesComplete;
+    ///
+    /// assert_eq!("yes", ITunesComplete::Yes.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str
+    {
+        match *self
+        {
+            ITunesComplete::Yes => "yes",
+            ITunesComplete::No => "no",
+        }
+    }
+}
+
+
+/// Enumerations of podcast directories for `ComplianceReport`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ComplianceProfile
+{
+    /// Apple Podcasts
+    Apple,
+
+    /// Spotify
+    Spotify,
+
+    /// Podcast Index
+    PodcastIndex,
+}
+
+
+impl ComplianceProfile
+{
+    /// Convert `&str` to `ComplianceProfile`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ComplianceProfile;
+    ///
+    /// assert_eq!(ComplianceProfile::PodcastIndex, ComplianceProfile::value_of("podcastindex").unwrap());
+    /// assert!(ComplianceProfile::value_of("stitcher").is_err());
+    /// ```
+    pub fn value_of(s: &str) -> Result<ComplianceProfile, String>
+    {
+        match s
+        {
+            "apple" => Ok(ComplianceProfile::Apple),
+            "spotify" => Ok(ComplianceProfile::Spotify),
+            "podcastindex" => Ok(ComplianceProfile::PodcastIndex),
+            _ => Err(format!("Invalid value: {}", s)),
+        }
+    }
+
+
+    /// Get the name of `ComplianceProfile`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ComplianceProfile;
+    ///
+    /// assert_eq!("spotify", ComplianceProfile::Spotify.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str
+    {
+        match *self
+        {
+            ComplianceProfile::Apple => "apple",
+            ComplianceProfile::Spotify => "spotify",
+            ComplianceProfile::PodcastIndex => "podcastindex",
+        }
+    }
+}
+
+
+/// Enumerations of update periods for the syndication extension.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyndicationPeriod
+{
+    /// hourly
+    Hourly,
+
+    /// daily
+    Daily,
+
+    /// weekly
+    Weekly,
+
+    /// monthly
+    Monthly,
+
+    /// yearly
+    Yearly,
+}
+
+
+impl SyndicationPeriod
+{
+    /// Convert `&str` to `SyndicationPeriod`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    ///
+    /// assert_eq!(SyndicationPeriod::Hourly, SyndicationPeriod::value_of("hourly").unwrap());
+    /// assert!(SyndicationPeriod::value_of("fortnightly").is_err());
+    /// ```
+    pub fn value_of(s: &str) -> Result<SyndicationPeriod, String>
+    {
+        match s.trim().to_lowercase().as_str()
+        {
+            "hourly" => Ok(SyndicationPeriod::Hourly),
+            "daily" => Ok(SyndicationPeriod::Daily),
+            "weekly" => Ok(SyndicationPeriod::Weekly),
+            "monthly" => Ok(SyndicationPeriod::Monthly),
+            "yearly" => Ok(SyndicationPeriod::Yearly),
+            _ => Err(format!("Invalid value: {}", s)),
+        }
+    }
+
+
+    /// Get the value of `SyndicationPeriod` as it is written to a feed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    ///
+    /// assert_eq!("weekly", SyndicationPeriod::Weekly.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str
+    {
+        match *self
+        {
+            SyndicationPeriod::Hourly => "hourly",
+            SyndicationPeriod::Daily => "daily",
+            SyndicationPeriod::Weekly => "weekly",
+            SyndicationPeriod::Monthly => "monthly",
+            SyndicationPeriod::Yearly => "yearly",
+        }
+    }
+
+
+    /// Get the length of `SyndicationPeriod`, counting a month as 30 days and
+    /// a year as 365.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(86400), SyndicationPeriod::Daily.duration());
+    /// ```
+    pub fn duration(&self) -> Duration
+    {
+        let hours = match *self
+        {
+            SyndicationPeriod::Hourly => 1,
+            SyndicationPeriod::Daily => 24,
+            SyndicationPeriod::Weekly => 24 * 7,
+            SyndicationPeriod::Monthly => 24 * 30,
+            SyndicationPeriod::Yearly => 24 * 365,
+        };
+        Duration::from_secs(hours * 3600)
+    }
+}
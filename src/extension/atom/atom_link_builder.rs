@@ -0,0 +1,205 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for atom link by using the methods under
+//! `AtomLinkBuilder`.
+
+
+use extension::atom::{AtomLink, AtomLinkBuilder};
+use mime::Mime;
+use utils::string_utils;
+
+
+impl AtomLinkBuilder
+{
+    /// Construct a new `AtomLinkBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let atom_link_builder = AtomLinkBuilder::new();
+    /// ```
+    pub fn new() -> AtomLinkBuilder
+    {
+        AtomLinkBuilder::default()
+    }
+
+
+    /// Set the href that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let mut atom_link_builder = AtomLinkBuilder::new();
+    /// atom_link_builder.href("http://feedpress.me/usererror");
+    /// ```
+    pub fn href(&mut self, href: &str) -> &mut AtomLinkBuilder
+    {
+        self.href = href.to_owned();
+        self
+    }
+
+
+    /// Set the optional relation that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let mut atom_link_builder = AtomLinkBuilder::new();
+    /// atom_link_builder.rel(Some("self".to_owned()));
+    /// ```
+    pub fn rel(&mut self, rel: Option<String>) -> &mut AtomLinkBuilder
+    {
+        self.rel = rel;
+        self
+    }
+
+
+    /// Set the optional mime type that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let mut atom_link_builder = AtomLinkBuilder::new();
+    /// atom_link_builder.mime_type(Some("application/rss+xml".to_owned()));
+    /// ```
+    pub fn mime_type(&mut self, mime_type: Option<String>) -> &mut AtomLinkBuilder
+    {
+        self.mime_type = mime_type;
+        self
+    }
+
+
+    /// Set the optional language that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let mut atom_link_builder = AtomLinkBuilder::new();
+    /// atom_link_builder.hreflang(Some("en".to_owned()));
+    /// ```
+    pub fn hreflang(&mut self, hreflang: Option<String>) -> &mut AtomLinkBuilder
+    {
+        self.hreflang = hreflang;
+        self
+    }
+
+
+    /// Set the optional title that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let mut atom_link_builder = AtomLinkBuilder::new();
+    /// atom_link_builder.title(Some("User Error".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut AtomLinkBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional length in bytes that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let mut atom_link_builder = AtomLinkBuilder::new();
+    /// atom_link_builder.length(Some(4096));
+    /// ```
+    pub fn length(&mut self, length: Option<i64>) -> &mut AtomLinkBuilder
+    {
+        self.length = length;
+        self
+    }
+
+
+    /// Validate the contents of `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .rel(Some("self".to_owned()))
+    ///     .mime_type(Some("application/rss+xml".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut AtomLinkBuilder, String>
+    {
+        string_utils::str_to_url(self.href.as_str())?;
+
+        if let Some(ref rel) = self.rel
+        {
+            if rel.contains(':')
+            {
+                string_utils::str_to_url(rel.as_str())?;
+            }
+        }
+
+        if let Some(ref mime_type) = self.mime_type
+        {
+            if let Err(err) = mime_type.parse::<Mime>()
+            {
+                return Err(format!("Error: {:?}", err));
+            }
+        }
+
+        if self.length.is_some_and(|length| length < 0)
+        {
+            return Err("Atom link length cannot be a negative value.".to_owned());
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `AtomLink` from the `AtomLinkBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<AtomLink, String>
+    {
+        Ok(AtomLink {
+               href: self.href.clone(),
+               rel: self.rel.clone(),
+               mime_type: self.mime_type.clone(),
+               hreflang: self.hreflang.clone(),
+               title: self.title.clone(),
+               length: self.length,
+           })
+    }
+}
@@ -0,0 +1,215 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under atom link can be retrieved by using the methods
+//! under `AtomLink`.
+
+
+use extension::atom::{AtomLink, AtomLinkGetters};
+
+
+impl AtomLinkGetters for AtomLink
+{
+    /// Get the href that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let href = "http://feedpress.me/usererror";
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href(href)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(href.to_owned(), atom_link.href());
+    /// ```
+    fn href(&self) -> String
+    {
+        self.href.clone()
+    }
+
+
+    /// Get the optional relation that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let rel = Some("self".to_owned());
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .rel(rel.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rel, atom_link.rel());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .rel(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(atom_link.rel().is_none());
+    /// ```
+    fn rel(&self) -> Option<String>
+    {
+        self.rel.clone()
+    }
+
+
+    /// Get the optional mime type that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let mime_type = Some("application/rss+xml".to_owned());
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .mime_type(mime_type.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mime_type, atom_link.mime_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .mime_type(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(atom_link.mime_type().is_none());
+    /// ```
+    fn mime_type(&self) -> Option<String>
+    {
+        self.mime_type.clone()
+    }
+
+
+    /// Get the optional language that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let hreflang = Some("en".to_owned());
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .hreflang(hreflang.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(hreflang, atom_link.hreflang());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .hreflang(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(atom_link.hreflang().is_none());
+    /// ```
+    fn hreflang(&self) -> Option<String>
+    {
+        self.hreflang.clone()
+    }
+
+
+    /// Get the optional title that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let title = Some("User Error".to_owned());
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .title(title.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(title, atom_link.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .title(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(atom_link.title().is_none());
+    /// ```
+    fn title(&self) -> Option<String>
+    {
+        self.title.clone()
+    }
+
+
+    /// Get the optional length in bytes that exists under `AtomLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let length = Some(4096);
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .length(length)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(length, atom_link.length());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::atom::{AtomLinkBuilder, AtomLinkGetters};
+    ///
+    /// let atom_link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .length(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(atom_link.length().is_none());
+    /// ```
+    fn length(&self) -> Option<i64>
+    {
+        self.length
+    }
+}
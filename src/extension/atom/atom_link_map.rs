@@ -0,0 +1,234 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing the `atom:link` extensions of an `Item` or a `Channel`
+//! as `AtomLink`s.
+
+
+use extension::atom::{AtomLink, NAMESPACE};
+use rss::extension::{Extension, ExtensionMap};
+
+
+impl AtomLink
+{
+    /// Construct the `AtomLink`s from the extensions of an `Item` or a
+    /// `Channel`.
+    ///
+    /// Lengths that cannot be read are left out, where `try_from_extensions`
+    /// fails on them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::atom::AtomLink;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let links = AtomLink::from_extensions(&channel.extensions);
+    ///
+    ///     let hub = links.iter().find(|link| link.rel == Some("hub".to_owned())).unwrap();
+    ///     assert_eq!("http://pubsubhubbub.appspot.com/", hub.href);
+    /// }
+    /// ```
+    pub fn from_extensions(extensions: &ExtensionMap) -> Vec<AtomLink>
+    {
+        link_elements(extensions).into_iter().map(link_from_extension).collect()
+    }
+
+
+    /// Construct the `AtomLink`s from the extensions of an `Item` or a
+    /// `Channel`, failing on lengths that cannot be read instead of leaving
+    /// them out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::atom::AtomLink;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\
+    ///                <channel><title>User Error</title>\
+    ///                <atom:link href=\"http://feedpress.me/usererror\" rel=\"self\" length=\"large\" />\
+    ///                </channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let result = AtomLink::try_from_extensions(&channel.extensions);
+    ///     assert_eq!(Err("Invalid length: large".to_owned()), result.map(|_| ()));
+    /// }
+    /// ```
+    pub fn try_from_extensions(extensions: &ExtensionMap) -> Result<Vec<AtomLink>, String>
+    {
+        let elements = link_elements(extensions);
+        for element in &elements
+        {
+            if let Some(length) = element.attrs.get("length")
+            {
+                if length.trim().parse::<i64>().is_err()
+                {
+                    return Err(format!("Invalid length: {}", length));
+                }
+            }
+        }
+
+        Ok(elements.into_iter().map(link_from_extension).collect())
+    }
+
+
+    /// Convert the `AtomLink` to an `atom:link` extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::atom::AtomLinkBuilder;
+    ///
+    /// let link = AtomLinkBuilder::new()
+    ///     .href("http://feedpress.me/usererror")
+    ///     .rel(Some("self".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let extension = link.to_extension();
+    /// assert_eq!("atom:link", extension.name);
+    /// assert_eq!("self", extension.attrs["rel"]);
+    /// ```
+    pub fn to_extension(&self) -> Extension
+    {
+        let attrs = vec![("href", Some(self.href.clone())),
+                         ("rel", self.rel.clone()),
+                         ("type", self.mime_type.clone()),
+                         ("hreflang", self.hreflang.clone()),
+                         ("title", self.title.clone()),
+                         ("length", self.length.map(|length| length.to_string()))];
+
+        Extension {
+            name: "atom:link".to_owned(),
+            attrs: attrs
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|val| (name.to_owned(), val)))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+
+    /// Replace the `atom:link` extensions of an `ExtensionMap` with `links`,
+    /// keeping the other Atom elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::atom::{AtomLink, AtomLinkBuilder};
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let mut channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let link = AtomLinkBuilder::new()
+    ///         .href("http://feeds.feedburner.com/TheLinuxActionShowOGG")
+    ///         .rel(Some("self".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///     AtomLink::insert_into(&[link.clone()], &mut channel.extensions);
+    ///
+    ///     assert_eq!(vec![link], AtomLink::from_extensions(&channel.extensions));
+    /// }
+    /// ```
+    pub fn insert_into(links: &[AtomLink], extensions: &mut ExtensionMap)
+    {
+        for (prefix, map) in extensions.iter_mut()
+        {
+            let mut empty = false;
+            if let Some(elements) = map.get_mut("link")
+            {
+                elements.retain(|element| !is_atom(prefix, element));
+                empty = elements.is_empty();
+            }
+            if empty
+            {
+                map.remove("link");
+            }
+        }
+        extensions.retain(|_, map| !map.is_empty());
+
+        if !links.is_empty()
+        {
+            extensions
+                .entry("atom".to_owned())
+                .or_default()
+                .insert("link".to_owned(), links.iter().map(AtomLink::to_extension).collect());
+        }
+    }
+}
+
+
+// Whether a `link` element under `prefix` is an Atom link, the prefix being
+// `atom` or bound to the Atom namespace on the element.
+fn is_atom(prefix: &str, element: &Extension) -> bool
+{
+    prefix == "atom" ||
+    element
+        .attrs
+        .get(format!("xmlns:{}", prefix).as_str())
+        .is_some_and(|namespace| namespace == NAMESPACE)
+}
+
+
+// The Atom `link` elements, those under the `atom` prefix first.
+fn link_elements(extensions: &ExtensionMap) -> Vec<&Extension>
+{
+    let mut prefixes: Vec<&String> = extensions.keys().collect();
+    prefixes.sort_by_key(|prefix| (prefix.as_str() != "atom", prefix.as_str()));
+
+    let mut elements = Vec::new();
+    for prefix in prefixes
+    {
+        if let Some(links) = extensions[prefix].get("link")
+        {
+            elements.extend(links.iter().filter(|element| is_atom(prefix, element)));
+        }
+    }
+    elements
+}
+
+
+fn link_from_extension(extension: &Extension) -> AtomLink
+{
+    AtomLink {
+        href: extension.attrs.get("href").cloned().unwrap_or_default(),
+        rel: extension.attrs.get("rel").cloned(),
+        mime_type: extension.attrs.get("type").cloned(),
+        hreflang: extension.attrs.get("hreflang").cloned(),
+        title: extension.attrs.get("title").cloned(),
+        length: extension.attrs.get("length").and_then(|length| length.trim().parse().ok()),
+    }
+}
@@ -0,0 +1,80 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for `atom:link` elements in RSS.
+//!
+//! The links are read from and written to the `atom` extensions of an `Item`
+//! or a `Channel`. Links under another prefix bound to the Atom namespace on
+//! the element itself, as `atom10:link` often is, are read too.
+
+
+pub mod atom_link_builder;
+pub mod atom_link_getters;
+pub mod atom_link_map;
+
+
+/// The namespace of Atom.
+pub const NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+
+
+/// A link to a related resource, read from `atom:link`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AtomLink
+{
+    /// The URL the link points to.
+    pub href: String,
+    /// The relation of the link, such as `self`, `hub`, `next` or
+    /// `prev-archive`; `alternate` when absent.
+    pub rel: Option<String>,
+    /// The MIME type of the resource the link points to.
+    pub mime_type: Option<String>,
+    /// The language of the resource the link points to.
+    pub hreflang: Option<String>,
+    /// The human readable title of the link.
+    pub title: Option<String>,
+    /// The size of the resource the link points to in bytes.
+    pub length: Option<i64>,
+}
+
+
+/// The Getter functions for `AtomLink`
+pub trait AtomLinkGetters
+{
+    /// Get the href that exists under `AtomLink`.
+    fn href(&self) -> String;
+
+    /// Get the optional relation that exists under `AtomLink`.
+    fn rel(&self) -> Option<String>;
+
+    /// Get the optional mime type that exists under `AtomLink`.
+    fn mime_type(&self) -> Option<String>;
+
+    /// Get the optional language that exists under `AtomLink`.
+    fn hreflang(&self) -> Option<String>;
+
+    /// Get the optional title that exists under `AtomLink`.
+    fn title(&self) -> Option<String>;
+
+    /// Get the optional length in bytes that exists under `AtomLink`.
+    fn length(&self) -> Option<i64>;
+}
+
+
+/// This `AtomLinkBuilder` struct creates the `AtomLink`.
+#[derive(Clone, Default)]
+pub struct AtomLinkBuilder
+{
+    href: String,
+    rel: Option<String>,
+    mime_type: Option<String>,
+    hreflang: Option<String>,
+    title: Option<String>,
+    length: Option<i64>,
+}
@@ -0,0 +1,174 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for comments extension by using the methods
+//! under `CommentsExtensionBuilder`.
+
+
+use extension::comments::{CommentsExtension, CommentsExtensionBuilder, ThreadInReplyTo, ThreadInReplyToBuilder};
+use utils::string_utils;
+
+
+impl CommentsExtensionBuilder
+{
+    /// Construct a new `CommentsExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::CommentsExtensionBuilder;
+    ///
+    /// let comments_extension_builder = CommentsExtensionBuilder::new();
+    /// ```
+    pub fn new() -> CommentsExtensionBuilder
+    {
+        CommentsExtensionBuilder::default()
+    }
+
+
+    /// Set the optional comment feed URL that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::CommentsExtensionBuilder;
+    ///
+    /// let mut comments_extension_builder = CommentsExtensionBuilder::new();
+    /// comments_extension_builder.comment_rss(Some("http://www.jupiterbroadcasting.com/97561/feed/".to_owned()));
+    /// ```
+    pub fn comment_rss(&mut self, comment_rss: Option<String>) -> &mut CommentsExtensionBuilder
+    {
+        self.comment_rss = comment_rss;
+        self
+    }
+
+
+    /// Set the optional comment count that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::CommentsExtensionBuilder;
+    ///
+    /// let mut comments_extension_builder = CommentsExtensionBuilder::new();
+    /// comments_extension_builder.comments(Some(12));
+    /// ```
+    pub fn comments(&mut self, comments: Option<i64>) -> &mut CommentsExtensionBuilder
+    {
+        self.comments = comments;
+        self
+    }
+
+
+    /// Set the optional reply total that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::CommentsExtensionBuilder;
+    ///
+    /// let mut comments_extension_builder = CommentsExtensionBuilder::new();
+    /// comments_extension_builder.total(Some(12));
+    /// ```
+    pub fn total(&mut self, total: Option<i64>) -> &mut CommentsExtensionBuilder
+    {
+        self.total = total;
+        self
+    }
+
+
+    /// Set the in reply to that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, ThreadInReplyToBuilder};
+    ///
+    /// let in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut comments_extension_builder = CommentsExtensionBuilder::new();
+    /// comments_extension_builder.in_reply_to(vec![in_reply_to]);
+    /// ```
+    pub fn in_reply_to(&mut self, in_reply_to: Vec<ThreadInReplyTo>) -> &mut CommentsExtensionBuilder
+    {
+        self.in_reply_to = in_reply_to;
+        self
+    }
+
+
+    /// Validate the contents of `CommentsExtension`.
+    ///
+    /// The comment feed must be a URL, the counts cannot be negative and each
+    /// `ThreadInReplyTo` must be valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::CommentsExtensionBuilder;
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .comment_rss(Some("http://www.jupiterbroadcasting.com/97561/feed/".to_owned()))
+    ///     .comments(Some(12))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut CommentsExtensionBuilder, String>
+    {
+        if let Some(ref comment_rss) = self.comment_rss
+        {
+            string_utils::str_to_url(comment_rss.as_str())?;
+        }
+
+        for count in self.comments.iter().chain(self.total.iter())
+        {
+            if *count < 0
+            {
+                return Err(format!("Invalid value: {}", count));
+            }
+        }
+
+        for in_reply_to in &self.in_reply_to
+        {
+            ThreadInReplyToBuilder::new()
+                .reference(in_reply_to.reference.as_str())
+                .href(in_reply_to.href.clone())
+                .mime_type(in_reply_to.mime_type.clone())
+                .source(in_reply_to.source.clone())
+                .validate()?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `CommentsExtension` from the `CommentsExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::CommentsExtensionBuilder;
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<CommentsExtension, String>
+    {
+        Ok(CommentsExtension {
+               comment_rss: self.comment_rss.clone(),
+               comments: self.comments,
+               total: self.total,
+               in_reply_to: self.in_reply_to.clone(),
+           })
+    }
+}
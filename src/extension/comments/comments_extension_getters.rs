@@ -0,0 +1,143 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under comments extension can be retrieved by using the
+//! methods under `CommentsExtension`.
+
+
+use extension::comments::{CommentsExtension, CommentsExtensionGetters, ThreadInReplyTo};
+
+
+impl CommentsExtensionGetters for CommentsExtension
+{
+    /// Get the optional comment feed URL that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters};
+    ///
+    /// let comment_rss = Some("http://www.jupiterbroadcasting.com/97561/feed/".to_owned());
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .comment_rss(comment_rss.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(comment_rss, comments_extension.comment_rss());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters};
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .comment_rss(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(comments_extension.comment_rss().is_none());
+    /// ```
+    fn comment_rss(&self) -> Option<String>
+    {
+        self.comment_rss.clone()
+    }
+
+
+    /// Get the optional comment count that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters};
+    ///
+    /// let comments = Some(12);
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .comments(comments)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(comments, comments_extension.comments());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters};
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .comments(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(comments_extension.comments().is_none());
+    /// ```
+    fn comments(&self) -> Option<i64>
+    {
+        self.comments
+    }
+
+
+    /// Get the optional reply total that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters};
+    ///
+    /// let total = Some(12);
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .total(total)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(total, comments_extension.total());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters};
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .total(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(comments_extension.total().is_none());
+    /// ```
+    fn total(&self) -> Option<i64>
+    {
+        self.total
+    }
+
+
+    /// Get the in reply to that exists under `CommentsExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{CommentsExtensionBuilder, CommentsExtensionGetters, ThreadInReplyToBuilder};
+    ///
+    /// let in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let in_reply_to = vec![in_reply_to];
+    ///
+    /// let comments_extension = CommentsExtensionBuilder::new()
+    ///     .in_reply_to(in_reply_to.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, comments_extension.in_reply_to().len());
+    /// ```
+    fn in_reply_to(&self) -> Vec<ThreadInReplyTo>
+    {
+        self.in_reply_to.clone()
+    }
+}
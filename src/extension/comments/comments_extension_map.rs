@@ -0,0 +1,255 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing the `wfw`, `slash` and `thr` extensions of an `Item`.
+
+
+use extension::comments::{CommentsExtension, ThreadInReplyTo};
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+use utils::string_utils;
+
+
+// The elements covered by `CommentsExtension`, by prefix and local name.
+const ELEMENTS: &[(&str, &str)] = &[("wfw", "commentRss"),
+                                    ("slash", "comments"),
+                                    ("thr", "total"),
+                                    ("thr", "in-reply-to")];
+
+
+impl CommentsExtension
+{
+    /// Construct a `CommentsExtension` from the extensions of an `Item`.
+    ///
+    /// Counts that cannot be read and `thr:in-reply-to` elements without a
+    /// reference are left out, where `try_from_extensions` fails on them
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::comments::CommentsExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:wfw=\"http://wellformedweb.org/CommentAPI/\" \
+    ///                xmlns:slash=\"http://purl.org/rss/1.0/modules/slash/\">\
+    ///                <channel><title>Linux Action Show</title><item><title>LAS 413</title>\
+    ///                <wfw:commentRss>http://www.jupiterbroadcasting.com/97561/feed/</wfw:commentRss>\
+    ///                <slash:comments>12</slash:comments>\
+    ///                </item></channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let comments = CommentsExtension::from_extensions(&channel.items[0].extensions);
+    ///
+    ///     assert_eq!(Some("http://www.jupiterbroadcasting.com/97561/feed/".to_owned()),
+    ///                comments.comment_rss);
+    ///     assert_eq!(Some(12), comments.comments);
+    /// }
+    /// ```
+    pub fn from_extensions(extensions: &ExtensionMap) -> CommentsExtension
+    {
+        let in_reply_to = extensions
+            .get("thr")
+            .and_then(|map| map.get("in-reply-to"))
+            .map(|elements| elements.iter().filter_map(in_reply_to_from_extension).collect())
+            .unwrap_or_default();
+
+        CommentsExtension {
+            comment_rss: text(extensions, "wfw", "commentRss"),
+            comments: text(extensions, "slash", "comments").and_then(|comments| comments.parse().ok()),
+            total: text(extensions, "thr", "total").and_then(|total| total.parse().ok()),
+            in_reply_to,
+        }
+    }
+
+
+    /// Construct a `CommentsExtension` from the extensions of an `Item`,
+    /// failing on counts that cannot be read and `thr:in-reply-to` elements
+    /// without a reference instead of leaving them out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::comments::CommentsExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:slash=\"http://purl.org/rss/1.0/modules/slash/\">\
+    ///                <channel><title>Linux Action Show</title><item><title>LAS 413</title>\
+    ///                <slash:comments>a dozen</slash:comments>\
+    ///                </item></channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     assert!(CommentsExtension::try_from_extensions(&channel.items[0].extensions).is_err());
+    /// }
+    /// ```
+    pub fn try_from_extensions(extensions: &ExtensionMap) -> Result<CommentsExtension, String>
+    {
+        let mut in_reply_to = Vec::new();
+        for element in extensions.get("thr").and_then(|map| map.get("in-reply-to")).into_iter().flatten()
+        {
+            match in_reply_to_from_extension(element)
+            {
+                Some(val) => in_reply_to.push(val),
+                None => return Err("Reference must not be empty.".to_owned()),
+            }
+        }
+
+        Ok(CommentsExtension {
+               comment_rss: text(extensions, "wfw", "commentRss"),
+               comments: string_utils::option_string_to_option_i64(text(extensions, "slash", "comments"))?,
+               total: string_utils::option_string_to_option_i64(text(extensions, "thr", "total"))?,
+               in_reply_to,
+           })
+    }
+
+
+    /// Replace the `wfw`, `slash` and `thr` extensions of an `ExtensionMap`
+    /// with those of the `CommentsExtension`, keeping the elements it does
+    /// not cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::comments::{CommentsExtension, CommentsExtensionBuilder, ThreadInReplyToBuilder};
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let in_reply_to = ThreadInReplyToBuilder::new()
+    ///         .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///         .href(Some("http://www.jupiterbroadcasting.com/97561/".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let comments = CommentsExtensionBuilder::new()
+    ///         .total(Some(3))
+    ///         .in_reply_to(vec![in_reply_to])
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     comments.insert_into(&mut extensions);
+    ///
+    ///     assert!(!extensions.contains_key("wfw"));
+    ///     assert_eq!(comments, CommentsExtension::from_extensions(&extensions));
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        for &(prefix, name) in ELEMENTS
+        {
+            if let Some(map) = extensions.get_mut(prefix)
+            {
+                map.remove(name);
+            }
+        }
+
+        insert_text(extensions, "wfw", "commentRss", &self.comment_rss);
+        insert_text(extensions, "slash", "comments", &self.comments.map(|comments| comments.to_string()));
+        insert_text(extensions, "thr", "total", &self.total.map(|total| total.to_string()));
+        if !self.in_reply_to.is_empty()
+        {
+            extensions
+                .entry("thr".to_owned())
+                .or_default()
+                .insert("in-reply-to".to_owned(),
+                        self.in_reply_to.iter().map(in_reply_to_to_extension).collect());
+        }
+
+        for &(prefix, _) in ELEMENTS
+        {
+            if extensions.get(prefix).map(HashMap::is_empty).unwrap_or_default()
+            {
+                extensions.remove(prefix);
+            }
+        }
+    }
+}
+
+
+fn text(extensions: &ExtensionMap, prefix: &str, name: &str) -> Option<String>
+{
+    extensions
+        .get(prefix)
+        .and_then(|map| map.get(name))
+        .and_then(|elements| elements.first())
+        .and_then(|element| element.value.clone())
+        .map(|value| value.trim().to_owned())
+}
+
+
+fn insert_text(extensions: &mut ExtensionMap, prefix: &str, name: &str, value: &Option<String>)
+{
+    if let Some(ref value) = *value
+    {
+        extensions
+            .entry(prefix.to_owned())
+            .or_default()
+            .insert(name.to_owned(),
+                    vec![Extension {
+                             name: format!("{}:{}", prefix, name),
+                             value: Some(value.clone()),
+                             attrs: HashMap::new(),
+                             children: HashMap::new(),
+                         }]);
+    }
+}
+
+
+fn in_reply_to_from_extension(element: &Extension) -> Option<ThreadInReplyTo>
+{
+    element
+        .attrs
+        .get("ref")
+        .map(|reference| {
+                 ThreadInReplyTo {
+                     reference: reference.clone(),
+                     href: element.attrs.get("href").cloned(),
+                     mime_type: element.attrs.get("type").cloned(),
+                     source: element.attrs.get("source").cloned(),
+                 }
+             })
+}
+
+
+fn in_reply_to_to_extension(in_reply_to: &ThreadInReplyTo) -> Extension
+{
+    let mut attrs = HashMap::new();
+    attrs.insert("ref".to_owned(), in_reply_to.reference.clone());
+    for &(name, value) in &[("href", &in_reply_to.href),
+                            ("type", &in_reply_to.mime_type),
+                            ("source", &in_reply_to.source)]
+    {
+        if let Some(ref value) = *value
+        {
+            attrs.insert(name.to_owned(), value.clone());
+        }
+    }
+
+    Extension {
+        name: "thr:in-reply-to".to_owned(),
+        value: None,
+        attrs,
+        children: HashMap::new(),
+    }
+}
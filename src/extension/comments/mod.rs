@@ -0,0 +1,120 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for the comment modules of an `Item`.
+//!
+//! The elements are read from and written to the `wfw`, `slash` and `thr`
+//! extensions of an `Item`.
+
+
+pub mod comments_extension_builder;
+pub mod comments_extension_getters;
+pub mod comments_extension_map;
+pub mod thread_in_reply_to_builder;
+pub mod thread_in_reply_to_getters;
+
+
+/// The namespace of the Well-Formed Web comment API.
+pub const WFW_NAMESPACE: &str = "http://wellformedweb.org/CommentAPI/";
+
+
+/// The namespace of the Slash module.
+pub const SLASH_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/slash/";
+
+
+/// The namespace of the Atom threading extensions.
+pub const THR_NAMESPACE: &str = "http://purl.org/syndication/thread/1.0";
+
+
+/// The comment tags of an `Item`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CommentsExtension
+{
+    /// The URL of the comment feed of the item, from `wfw:commentRss`.
+    pub comment_rss: Option<String>,
+    /// The number of comments on the item, from `slash:comments`.
+    pub comments: Option<i64>,
+    /// The number of replies to the item, from `thr:total`.
+    pub total: Option<i64>,
+    /// The entries the item replies to, from `thr:in-reply-to`.
+    pub in_reply_to: Vec<ThreadInReplyTo>,
+}
+
+
+/// The Getter functions for `CommentsExtension`
+pub trait CommentsExtensionGetters
+{
+    /// Get the optional comment feed URL that exists under
+    /// `CommentsExtension`.
+    fn comment_rss(&self) -> Option<String>;
+
+    /// Get the optional comment count that exists under `CommentsExtension`.
+    fn comments(&self) -> Option<i64>;
+
+    /// Get the optional reply total that exists under `CommentsExtension`.
+    fn total(&self) -> Option<i64>;
+
+    /// Get the in reply to that exists under `CommentsExtension`.
+    fn in_reply_to(&self) -> Vec<ThreadInReplyTo>;
+}
+
+
+/// This `CommentsExtensionBuilder` struct creates the `CommentsExtension`.
+#[derive(Clone, Default)]
+pub struct CommentsExtensionBuilder
+{
+    comment_rss: Option<String>,
+    comments: Option<i64>,
+    total: Option<i64>,
+    in_reply_to: Vec<ThreadInReplyTo>,
+}
+
+
+/// An entry an `Item` replies to, from `thr:in-reply-to`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThreadInReplyTo
+{
+    /// The identifier of the entry replied to.
+    pub reference: String,
+    /// The URL of the entry replied to.
+    pub href: Option<String>,
+    /// The mime type of the resource at the href.
+    pub mime_type: Option<String>,
+    /// The URL of the feed the entry replied to is in.
+    pub source: Option<String>,
+}
+
+
+/// The Getter functions for `ThreadInReplyTo`
+pub trait ThreadInReplyToGetters
+{
+    /// Get the reference that exists under `ThreadInReplyTo`.
+    fn reference(&self) -> String;
+
+    /// Get the optional href that exists under `ThreadInReplyTo`.
+    fn href(&self) -> Option<String>;
+
+    /// Get the optional mime type that exists under `ThreadInReplyTo`.
+    fn mime_type(&self) -> Option<String>;
+
+    /// Get the optional source that exists under `ThreadInReplyTo`.
+    fn source(&self) -> Option<String>;
+}
+
+
+/// This `ThreadInReplyToBuilder` struct creates the `ThreadInReplyTo`.
+#[derive(Clone, Default)]
+pub struct ThreadInReplyToBuilder
+{
+    reference: String,
+    href: Option<String>,
+    mime_type: Option<String>,
+    source: Option<String>,
+}
@@ -0,0 +1,155 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for thread in reply to by using the methods
+//! under `ThreadInReplyToBuilder`.
+
+
+use extension::comments::{ThreadInReplyTo, ThreadInReplyToBuilder};
+use utils::string_utils;
+
+
+impl ThreadInReplyToBuilder
+{
+    /// Construct a new `ThreadInReplyToBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::ThreadInReplyToBuilder;
+    ///
+    /// let thread_in_reply_to_builder = ThreadInReplyToBuilder::new();
+    /// ```
+    pub fn new() -> ThreadInReplyToBuilder
+    {
+        ThreadInReplyToBuilder::default()
+    }
+
+
+    /// Set the reference that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::ThreadInReplyToBuilder;
+    ///
+    /// let mut thread_in_reply_to_builder = ThreadInReplyToBuilder::new();
+    /// thread_in_reply_to_builder.reference("tag:jupiterbroadcasting.com,2016:97561");
+    /// ```
+    pub fn reference(&mut self, reference: &str) -> &mut ThreadInReplyToBuilder
+    {
+        self.reference = reference.to_owned();
+        self
+    }
+
+
+    /// Set the optional href that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::ThreadInReplyToBuilder;
+    ///
+    /// let mut thread_in_reply_to_builder = ThreadInReplyToBuilder::new();
+    /// thread_in_reply_to_builder.href(Some("http://www.jupiterbroadcasting.com/97561/".to_owned()));
+    /// ```
+    pub fn href(&mut self, href: Option<String>) -> &mut ThreadInReplyToBuilder
+    {
+        self.href = href;
+        self
+    }
+
+
+    /// Set the optional mime type that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::ThreadInReplyToBuilder;
+    ///
+    /// let mut thread_in_reply_to_builder = ThreadInReplyToBuilder::new();
+    /// thread_in_reply_to_builder.mime_type(Some("text/html".to_owned()));
+    /// ```
+    pub fn mime_type(&mut self, mime_type: Option<String>) -> &mut ThreadInReplyToBuilder
+    {
+        self.mime_type = mime_type;
+        self
+    }
+
+
+    /// Set the optional source that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::ThreadInReplyToBuilder;
+    ///
+    /// let mut thread_in_reply_to_builder = ThreadInReplyToBuilder::new();
+    /// thread_in_reply_to_builder.source(Some("http://www.jupiterbroadcasting.com/feed/".to_owned()));
+    /// ```
+    pub fn source(&mut self, source: Option<String>) -> &mut ThreadInReplyToBuilder
+    {
+        self.source = source;
+        self
+    }
+
+
+    /// Validate the contents of `ThreadInReplyTo`.
+    ///
+    /// The reference cannot be empty, and the href and the source must be URLs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::ThreadInReplyToBuilder;
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut ThreadInReplyToBuilder, String>
+    {
+        if self.reference.trim().is_empty()
+        {
+            return Err("Reference must not be empty.".to_owned());
+        }
+
+        for url in self.href.iter().chain(self.source.iter())
+        {
+            string_utils::str_to_url(url.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `ThreadInReplyTo` from the `ThreadInReplyToBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::ThreadInReplyToBuilder;
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<ThreadInReplyTo, String>
+    {
+        Ok(ThreadInReplyTo {
+               reference: self.reference.clone(),
+               href: self.href.clone(),
+               mime_type: self.mime_type.clone(),
+               source: self.source.clone(),
+           })
+    }
+}
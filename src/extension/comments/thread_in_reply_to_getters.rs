@@ -0,0 +1,145 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under thread in reply to can be retrieved by using the
+//! methods under `ThreadInReplyTo`.
+
+
+use extension::comments::{ThreadInReplyTo, ThreadInReplyToGetters};
+
+
+impl ThreadInReplyToGetters for ThreadInReplyTo
+{
+    /// Get the reference that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{ThreadInReplyToBuilder, ThreadInReplyToGetters};
+    ///
+    /// let reference = "tag:jupiterbroadcasting.com,2016:97561";
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference(reference)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(reference.to_owned(), thread_in_reply_to.reference());
+    /// ```
+    fn reference(&self) -> String
+    {
+        self.reference.clone()
+    }
+
+
+    /// Get the optional href that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{ThreadInReplyToBuilder, ThreadInReplyToGetters};
+    ///
+    /// let href = Some("http://www.jupiterbroadcasting.com/97561/".to_owned());
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .href(href.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(href, thread_in_reply_to.href());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::comments::{ThreadInReplyToBuilder, ThreadInReplyToGetters};
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .href(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(thread_in_reply_to.href().is_none());
+    /// ```
+    fn href(&self) -> Option<String>
+    {
+        self.href.clone()
+    }
+
+
+    /// Get the optional mime type that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{ThreadInReplyToBuilder, ThreadInReplyToGetters};
+    ///
+    /// let mime_type = Some("text/html".to_owned());
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .mime_type(mime_type.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mime_type, thread_in_reply_to.mime_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::comments::{ThreadInReplyToBuilder, ThreadInReplyToGetters};
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .mime_type(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(thread_in_reply_to.mime_type().is_none());
+    /// ```
+    fn mime_type(&self) -> Option<String>
+    {
+        self.mime_type.clone()
+    }
+
+
+    /// Get the optional source that exists under `ThreadInReplyTo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::comments::{ThreadInReplyToBuilder, ThreadInReplyToGetters};
+    ///
+    /// let source = Some("http://www.jupiterbroadcasting.com/feed/".to_owned());
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .source(source.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(source, thread_in_reply_to.source());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::comments::{ThreadInReplyToBuilder, ThreadInReplyToGetters};
+    ///
+    /// let thread_in_reply_to = ThreadInReplyToBuilder::new()
+    ///     .reference("tag:jupiterbroadcasting.com,2016:97561")
+    ///     .source(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(thread_in_reply_to.source().is_none());
+    /// ```
+    fn source(&self) -> Option<String>
+    {
+        self.source.clone()
+    }
+}
@@ -0,0 +1,19 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The content module, which carries the full body of an `Item` in
+//! `content:encoded`.
+//!
+//! `rss::Item` reads the element into its `content` field, so it is set with
+//! `ItemBuilder::content` and read with `ItemGetters::content`.
+
+
+/// The namespace of the content module.
+pub const NAMESPACE: &str = "http://purl.org/rss/1.0/modules/content/";
@@ -0,0 +1,338 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for dublin core extension by using the methods
+//! under `DublinCoreExtensionBuilder`.
+
+
+use chrono::{DateTime, FixedOffset};
+use extension::dublincore::DublinCoreExtensionBuilder;
+use rss::extension::dublincore::DublinCoreExtension;
+
+
+impl DublinCoreExtensionBuilder
+{
+    /// Construct a new `DublinCoreExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// ```
+    pub fn new() -> DublinCoreExtensionBuilder
+    {
+        DublinCoreExtensionBuilder::default()
+    }
+
+
+    /// Set the contributors that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.contributor(vec!["Bryan Lunduke".to_owned()]);
+    /// ```
+    pub fn contributor(&mut self, contributor: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.contributor = contributor;
+        self
+    }
+
+
+    /// Set the coverages that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.coverage(vec!["Worldwide".to_owned()]);
+    /// ```
+    pub fn coverage(&mut self, coverage: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.coverage = coverage;
+        self
+    }
+
+
+    /// Set the creators that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.creator(vec!["Jupiter Broadcasting".to_owned()]);
+    /// ```
+    pub fn creator(&mut self, creator: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.creator = creator;
+        self
+    }
+
+
+    /// Set the dates that exist under `DublinCoreExtension`.
+    ///
+    /// The dates are written in the W3C date and time format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate feed;
+    ///
+    /// use chrono::DateTime;
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// fn main()
+    /// {
+    ///     let date = DateTime::parse_from_rfc3339("2016-03-20T19:10:54-07:00").unwrap();
+    ///
+    ///     let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    ///     dublin_core_builder.date(vec![date]);
+    /// }
+    /// ```
+    pub fn date(&mut self, date: Vec<DateTime<FixedOffset>>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.date = date;
+        self
+    }
+
+
+    /// Set the descriptions that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.description(vec!["A show about Linux".to_owned()]);
+    /// ```
+    pub fn description(&mut self, description: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the formats that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.format(vec!["audio/ogg".to_owned()]);
+    /// ```
+    pub fn format(&mut self, format: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.format = format;
+        self
+    }
+
+
+    /// Set the identifiers that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.identifier(vec!["http://www.jupiterbroadcasting.com/?p=97206".to_owned()]);
+    /// ```
+    pub fn identifier(&mut self, identifier: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.identifier = identifier;
+        self
+    }
+
+
+    /// Set the languages that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.language(vec!["en".to_owned()]);
+    /// ```
+    pub fn language(&mut self, language: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.language = language;
+        self
+    }
+
+
+    /// Set the publishers that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.publisher(vec!["Jupiter Broadcasting".to_owned()]);
+    /// ```
+    pub fn publisher(&mut self, publisher: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.publisher = publisher;
+        self
+    }
+
+
+    /// Set the relations that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.relation(vec!["http://www.jupiterbroadcasting.com/show/linuxactionshow/".to_owned()]);
+    /// ```
+    pub fn relation(&mut self, relation: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.relation = relation;
+        self
+    }
+
+
+    /// Set the rights that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.rights(vec!["Copyright Jupiter Broadcasting".to_owned()]);
+    /// ```
+    pub fn rights(&mut self, rights: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.rights = rights;
+        self
+    }
+
+
+    /// Set the sources that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.source(vec!["http://www.jupiterbroadcasting.com".to_owned()]);
+    /// ```
+    pub fn source(&mut self, source: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.source = source;
+        self
+    }
+
+
+    /// Set the subjects that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.subject(vec!["Technology".to_owned()]);
+    /// ```
+    pub fn subject(&mut self, subject: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.subject = subject;
+        self
+    }
+
+
+    /// Set the titles that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.title(vec!["The Linux Action Show! OGG".to_owned()]);
+    /// ```
+    pub fn title(&mut self, title: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the resource types that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.resource_type(vec!["Sound".to_owned()]);
+    /// ```
+    pub fn resource_type(&mut self, resource_type: Vec<String>) -> &mut DublinCoreExtensionBuilder
+    {
+        self.resource_type = resource_type;
+        self
+    }
+
+
+    /// Construct the `DublinCoreExtension` from the `DublinCoreExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creator(vec!["Jupiter Broadcasting".to_owned()])
+    ///     .subject(vec!["Technology".to_owned()])
+    ///     .rights(vec!["Copyright Jupiter Broadcasting".to_owned()])
+    ///     .language(vec!["en".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<DublinCoreExtension, String>
+    {
+        Ok(DublinCoreExtension {
+               contributor: self.contributor.clone(),
+               coverage: self.coverage.clone(),
+               creator: self.creator.clone(),
+               date: self.date.iter().map(|date| date.to_rfc3339()).collect(),
+               description: self.description.clone(),
+               format: self.format.clone(),
+               identifier: self.identifier.clone(),
+               language: self.language.clone(),
+               publisher: self.publisher.clone(),
+               relation: self.relation.clone(),
+               rights: self.rights.clone(),
+               source: self.source.clone(),
+               subject: self.subject.clone(),
+               title: self.title.clone(),
+               resource_type: self.resource_type.clone(),
+           })
+    }
+}
@@ -0,0 +1,387 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under dublin core extension can be retrieved by using the
+//! methods under `DublinCoreExtension`.
+
+
+use chrono::{DateTime, FixedOffset};
+use extension::dublincore::DublinCoreExtensionGetters;
+use rss::extension::dublincore::DublinCoreExtension;
+use utils::string_utils;
+
+
+impl DublinCoreExtensionGetters for DublinCoreExtension
+{
+    /// Get the contributors that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let contributor = vec!["Bryan Lunduke".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .contributor(contributor.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(contributor, dublin_core.contributor());
+    /// ```
+    fn contributor(&self) -> Vec<String>
+    {
+        self.contributor.clone()
+    }
+
+
+    /// Get the coverages that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let coverage = vec!["Worldwide".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .coverage(coverage.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(coverage, dublin_core.coverage());
+    /// ```
+    fn coverage(&self) -> Vec<String>
+    {
+        self.coverage.clone()
+    }
+
+
+    /// Get the creators that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let creator = vec!["Jupiter Broadcasting".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creator(creator.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(creator, dublin_core.creator());
+    /// ```
+    fn creator(&self) -> Vec<String>
+    {
+        self.creator.clone()
+    }
+
+
+    /// Get the dates that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate feed;
+    ///
+    /// use chrono::DateTime;
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// fn main()
+    /// {
+    ///     let date = DateTime::parse_from_rfc3339("2016-03-20T19:10:54-07:00").unwrap();
+    ///
+    ///     let dublin_core = DublinCoreExtensionBuilder::new()
+    ///         .date(vec![date])
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(vec!["2016-03-20T19:10:54-07:00".to_owned()], dublin_core.date());
+    /// }
+    /// ```
+    fn date(&self) -> Vec<String>
+    {
+        self.date.clone()
+    }
+
+
+    /// Get the dates that exist under `DublinCoreExtension` as
+    /// `DateTime<FixedOffset>`, leaving out those that are not W3C dates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate feed;
+    /// extern crate rss;
+    ///
+    /// use chrono::DateTime;
+    /// use feed::extension::dublincore::DublinCoreExtensionGetters;
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut dublin_core = DublinCoreExtension::default();
+    ///     dublin_core.date = vec!["2016-03-20".to_owned(), "Sun, 20 Mar 2016 19:10:54 -0700".to_owned()];
+    ///
+    ///     let date = DateTime::parse_from_rfc3339("2016-03-20T00:00:00+00:00").unwrap();
+    ///     assert_eq!(vec![date], dublin_core.date_value());
+    /// }
+    /// ```
+    fn date_value(&self) -> Vec<DateTime<FixedOffset>>
+    {
+        self.date.iter().filter_map(|date| string_utils::w3cdtf_to_date(date).ok()).collect()
+    }
+
+
+    /// Get the descriptions that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let description = vec!["A show about Linux".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .description(description.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(description, dublin_core.description());
+    /// ```
+    fn description(&self) -> Vec<String>
+    {
+        self.description.clone()
+    }
+
+
+    /// Get the formats that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let format = vec!["audio/ogg".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .format(format.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(format, dublin_core.format());
+    /// ```
+    fn format(&self) -> Vec<String>
+    {
+        self.format.clone()
+    }
+
+
+    /// Get the identifiers that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let identifier = vec!["http://www.jupiterbroadcasting.com/?p=97206".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .identifier(identifier.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(identifier, dublin_core.identifier());
+    /// ```
+    fn identifier(&self) -> Vec<String>
+    {
+        self.identifier.clone()
+    }
+
+
+    /// Get the languages that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let language = vec!["en".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .language(language.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(language, dublin_core.language());
+    /// ```
+    fn language(&self) -> Vec<String>
+    {
+        self.language.clone()
+    }
+
+
+    /// Get the publishers that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let publisher = vec!["Jupiter Broadcasting".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .publisher(publisher.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(publisher, dublin_core.publisher());
+    /// ```
+    fn publisher(&self) -> Vec<String>
+    {
+        self.publisher.clone()
+    }
+
+
+    /// Get the relations that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let relation = vec!["http://www.jupiterbroadcasting.com/show/linuxactionshow/".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .relation(relation.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(relation, dublin_core.relation());
+    /// ```
+    fn relation(&self) -> Vec<String>
+    {
+        self.relation.clone()
+    }
+
+
+    /// Get the rights that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let rights = vec!["Copyright Jupiter Broadcasting".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .rights(rights.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rights, dublin_core.rights());
+    /// ```
+    fn rights(&self) -> Vec<String>
+    {
+        self.rights.clone()
+    }
+
+
+    /// Get the sources that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let source = vec!["http://www.jupiterbroadcasting.com".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .source(source.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(source, dublin_core.source());
+    /// ```
+    fn source(&self) -> Vec<String>
+    {
+        self.source.clone()
+    }
+
+
+    /// Get the subjects that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let subject = vec!["Technology".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .subject(subject.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(subject, dublin_core.subject());
+    /// ```
+    fn subject(&self) -> Vec<String>
+    {
+        self.subject.clone()
+    }
+
+
+    /// Get the titles that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let title = vec!["The Linux Action Show! OGG".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .title(title.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(title, dublin_core.title());
+    /// ```
+    fn title(&self) -> Vec<String>
+    {
+        self.title.clone()
+    }
+
+
+    /// Get the resource types that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublincore::{DublinCoreExtensionBuilder, DublinCoreExtensionGetters};
+    ///
+    /// let resource_type = vec!["Sound".to_owned()];
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .resource_type(resource_type.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(resource_type, dublin_core.resource_type());
+    /// ```
+    fn resource_type(&self) -> Vec<String>
+    {
+        self.resource_type.clone()
+    }
+}
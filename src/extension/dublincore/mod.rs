@@ -0,0 +1,95 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for dublin core.
+
+
+pub mod dublin_core_extension_getters;
+pub mod dublin_core_extension_builder;
+
+
+use chrono::{DateTime, FixedOffset};
+
+
+/// The Getter functions for `DublinCoreExtension`
+pub trait DublinCoreExtensionGetters
+{
+    /// Get the contributors that exist under `DublinCoreExtension`.
+    fn contributor(&self) -> Vec<String>;
+
+    /// Get the coverages that exist under `DublinCoreExtension`.
+    fn coverage(&self) -> Vec<String>;
+
+    /// Get the creators that exist under `DublinCoreExtension`.
+    fn creator(&self) -> Vec<String>;
+
+    /// Get the dates that exist under `DublinCoreExtension`.
+    fn date(&self) -> Vec<String>;
+
+    /// Get the dates that exist under `DublinCoreExtension` as
+    /// `DateTime<FixedOffset>`, leaving out those that are not W3C dates.
+    fn date_value(&self) -> Vec<DateTime<FixedOffset>>;
+
+    /// Get the descriptions that exist under `DublinCoreExtension`.
+    fn description(&self) -> Vec<String>;
+
+    /// Get the formats that exist under `DublinCoreExtension`.
+    fn format(&self) -> Vec<String>;
+
+    /// Get the identifiers that exist under `DublinCoreExtension`.
+    fn identifier(&self) -> Vec<String>;
+
+    /// Get the languages that exist under `DublinCoreExtension`.
+    fn language(&self) -> Vec<String>;
+
+    /// Get the publishers that exist under `DublinCoreExtension`.
+    fn publisher(&self) -> Vec<String>;
+
+    /// Get the relations that exist under `DublinCoreExtension`.
+    fn relation(&self) -> Vec<String>;
+
+    /// Get the rights that exist under `DublinCoreExtension`.
+    fn rights(&self) -> Vec<String>;
+
+    /// Get the sources that exist under `DublinCoreExtension`.
+    fn source(&self) -> Vec<String>;
+
+    /// Get the subjects that exist under `DublinCoreExtension`.
+    fn subject(&self) -> Vec<String>;
+
+    /// Get the titles that exist under `DublinCoreExtension`.
+    fn title(&self) -> Vec<String>;
+
+    /// Get the resource types that exist under `DublinCoreExtension`.
+    fn resource_type(&self) -> Vec<String>;
+}
+
+
+/// This `DublinCoreExtensionBuilder` struct creates the
+/// `DublinCoreExtension`.
+#[derive(Clone, Default)]
+pub struct DublinCoreExtensionBuilder
+{
+    contributor: Vec<String>,
+    coverage: Vec<String>,
+    creator: Vec<String>,
+    date: Vec<DateTime<FixedOffset>>,
+    description: Vec<String>,
+    format: Vec<String>,
+    identifier: Vec<String>,
+    language: Vec<String>,
+    publisher: Vec<String>,
+    relation: Vec<String>,
+    rights: Vec<String>,
+    source: Vec<String>,
+    subject: Vec<String>,
+    title: Vec<String>,
+    resource_type: Vec<String>,
+}
@@ -0,0 +1,146 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for geo box by using the methods
+//! under `GeoBoxBuilder`.
+
+
+use extension::georss::{GeoBox, GeoBoxBuilder, GeoPoint, GeoPointBuilder};
+
+
+impl GeoBoxBuilder
+{
+    /// Construct a new `GeoBoxBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoBoxBuilder;
+    ///
+    /// let geo_box_builder = GeoBoxBuilder::new();
+    /// ```
+    pub fn new() -> GeoBoxBuilder
+    {
+        GeoBoxBuilder::default()
+    }
+
+
+    /// Set the lower corner that exists under `GeoBox`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxBuilder, GeoPointBuilder};
+    ///
+    /// let lower_corner = GeoPointBuilder::new()
+    ///     .latitude(42.943)
+    ///     .longitude(-71.032)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut geo_box_builder = GeoBoxBuilder::new();
+    /// geo_box_builder.lower_corner(lower_corner);
+    /// ```
+    pub fn lower_corner(&mut self, lower_corner: GeoPoint) -> &mut GeoBoxBuilder
+    {
+        self.lower_corner = lower_corner;
+        self
+    }
+
+
+    /// Set the upper corner that exists under `GeoBox`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxBuilder, GeoPointBuilder};
+    ///
+    /// let upper_corner = GeoPointBuilder::new()
+    ///     .latitude(43.039)
+    ///     .longitude(-69.856)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut geo_box_builder = GeoBoxBuilder::new();
+    /// geo_box_builder.upper_corner(upper_corner);
+    /// ```
+    pub fn upper_corner(&mut self, upper_corner: GeoPoint) -> &mut GeoBoxBuilder
+    {
+        self.upper_corner = upper_corner;
+        self
+    }
+
+
+    /// Validate the contents of `GeoBox`.
+    ///
+    /// Both corners must be valid `GeoPoint`s, and the lower corner cannot be
+    /// north of the upper corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxBuilder, GeoPointBuilder};
+    ///
+    /// let lower_corner = GeoPointBuilder::new()
+    ///     .latitude(42.943)
+    ///     .longitude(-71.032)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let upper_corner = GeoPointBuilder::new()
+    ///     .latitude(43.039)
+    ///     .longitude(-69.856)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let geo_box = GeoBoxBuilder::new()
+    ///     .lower_corner(lower_corner)
+    ///     .upper_corner(upper_corner)
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut GeoBoxBuilder, String>
+    {
+        for corner in &[&self.lower_corner, &self.upper_corner]
+        {
+            GeoPointBuilder::new()
+                .latitude(corner.latitude)
+                .longitude(corner.longitude)
+                .validate()?;
+        }
+
+        if self.lower_corner.latitude > self.upper_corner.latitude
+        {
+            return Err(format!("Invalid value: {}", self.lower_corner.latitude));
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `GeoBox` from the `GeoBoxBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoBoxBuilder;
+    ///
+    /// let geo_box = GeoBoxBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<GeoBox, String>
+    {
+        Ok(GeoBox {
+               lower_corner: self.lower_corner.clone(),
+               upper_corner: self.upper_corner.clone(),
+           })
+    }
+}
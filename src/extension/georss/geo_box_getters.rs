@@ -0,0 +1,144 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under geo box can be retrieved by using the
+//! methods under `GeoBox`.
+
+
+use extension::georss::{GeoBox, GeoBoxGetters, GeoPoint};
+
+
+impl GeoBoxGetters for GeoBox
+{
+    /// Get the lower corner that exists under `GeoBox`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxBuilder, GeoBoxGetters, GeoPointBuilder};
+    ///
+    /// let lower_corner = GeoPointBuilder::new()
+    ///     .latitude(42.943)
+    ///     .longitude(-71.032)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let geo_box = GeoBoxBuilder::new()
+    ///     .lower_corner(lower_corner.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(lower_corner, geo_box.lower_corner());
+    /// ```
+    fn lower_corner(&self) -> GeoPoint
+    {
+        self.lower_corner.clone()
+    }
+
+
+    /// Get the upper corner that exists under `GeoBox`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxBuilder, GeoBoxGetters, GeoPointBuilder};
+    ///
+    /// let upper_corner = GeoPointBuilder::new()
+    ///     .latitude(43.039)
+    ///     .longitude(-69.856)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let geo_box = GeoBoxBuilder::new()
+    ///     .upper_corner(upper_corner.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(upper_corner, geo_box.upper_corner());
+    /// ```
+    fn upper_corner(&self) -> GeoPoint
+    {
+        self.upper_corner.clone()
+    }
+
+
+    /// Check whether a `GeoPoint` lies within `GeoBox`. A box whose lower
+    /// corner is east of its upper corner crosses the antimeridian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBox, GeoBoxGetters, GeoPointBuilder};
+    ///
+    /// let bounds = "42.943 -71.032 43.039 -69.856".parse::<GeoBox>().unwrap();
+    ///
+    /// let portland = GeoPointBuilder::new()
+    ///     .latitude(43.0)
+    ///     .longitude(-70.2)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(bounds.contains(&portland));
+    /// ```
+    fn contains(&self, point: &GeoPoint) -> bool
+    {
+        self.intersects(&GeoBox {
+                            lower_corner: point.clone(),
+                            upper_corner: point.clone(),
+                        })
+    }
+
+
+    /// Check whether `GeoBox` overlaps another `GeoBox`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBox, GeoBoxGetters};
+    ///
+    /// let maine = "42.977 -71.084 47.460 -66.950".parse::<GeoBox>().unwrap();
+    /// let casco_bay = "43.567 -70.260 43.867 -69.850".parse::<GeoBox>().unwrap();
+    /// let cape_cod = "41.529 -70.700 42.084 -69.925".parse::<GeoBox>().unwrap();
+    ///
+    /// assert!(maine.intersects(&casco_bay));
+    /// assert!(!maine.intersects(&cape_cod));
+    /// ```
+    fn intersects(&self, other: &GeoBox) -> bool
+    {
+        let latitudes = self.lower_corner.latitude <= other.upper_corner.latitude &&
+                        other.lower_corner.latitude <= self.upper_corner.latitude;
+
+        latitudes &&
+        longitudes(self)
+            .iter()
+            .any(|&(west, east)| {
+                     longitudes(other)
+                         .iter()
+                         .any(|&(other_west, other_east)| west <= other_east && other_west <= east)
+                 })
+    }
+}
+
+
+// The longitude ranges covered by a `GeoBox`, split in two when it crosses
+// the antimeridian.
+fn longitudes(bounding_box: &GeoBox) -> Vec<(f64, f64)>
+{
+    let west = bounding_box.lower_corner.longitude;
+    let east = bounding_box.upper_corner.longitude;
+    if west <= east
+    {
+        vec![(west, east)]
+    }
+    else
+    {
+        vec![(west, 180.0), (-180.0, east)]
+    }
+}
@@ -0,0 +1,121 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for geo point by using the methods
+//! under `GeoPointBuilder`.
+
+
+use extension::georss::{GeoPoint, GeoPointBuilder};
+
+
+impl GeoPointBuilder
+{
+    /// Construct a new `GeoPointBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoPointBuilder;
+    ///
+    /// let geo_point_builder = GeoPointBuilder::new();
+    /// ```
+    pub fn new() -> GeoPointBuilder
+    {
+        GeoPointBuilder::default()
+    }
+
+
+    /// Set the latitude that exists under `GeoPoint`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoPointBuilder;
+    ///
+    /// let mut geo_point_builder = GeoPointBuilder::new();
+    /// geo_point_builder.latitude(45.256);
+    /// ```
+    pub fn latitude(&mut self, latitude: f64) -> &mut GeoPointBuilder
+    {
+        self.latitude = latitude;
+        self
+    }
+
+
+    /// Set the longitude that exists under `GeoPoint`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoPointBuilder;
+    ///
+    /// let mut geo_point_builder = GeoPointBuilder::new();
+    /// geo_point_builder.longitude(-71.92);
+    /// ```
+    pub fn longitude(&mut self, longitude: f64) -> &mut GeoPointBuilder
+    {
+        self.longitude = longitude;
+        self
+    }
+
+
+    /// Validate the contents of `GeoPoint`.
+    ///
+    /// The latitude must be within -90 and 90 degrees and the longitude within
+    /// -180 and 180 degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoPointBuilder;
+    ///
+    /// let geo_point = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-71.92)
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut GeoPointBuilder, String>
+    {
+        if !(-90.0..=90.0).contains(&self.latitude)
+        {
+            return Err(format!("Invalid value: {}", self.latitude));
+        }
+
+        if !(-180.0..=180.0).contains(&self.longitude)
+        {
+            return Err(format!("Invalid value: {}", self.longitude));
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `GeoPoint` from the `GeoPointBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoPointBuilder;
+    ///
+    /// let geo_point = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-71.92)
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<GeoPoint, String>
+    {
+        Ok(GeoPoint {
+               latitude: self.latitude,
+               longitude: self.longitude,
+           })
+    }
+}
@@ -0,0 +1,64 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under geo point can be retrieved by using the
+//! methods under `GeoPoint`.
+
+
+use extension::georss::{GeoPoint, GeoPointGetters};
+
+
+impl GeoPointGetters for GeoPoint
+{
+    /// Get the latitude that exists under `GeoPoint`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoPointGetters};
+    ///
+    /// let latitude = 45.256;
+    ///
+    /// let geo_point = GeoPointBuilder::new()
+    ///     .longitude(-71.92)
+    ///     .latitude(latitude)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(latitude, geo_point.latitude());
+    /// ```
+    fn latitude(&self) -> f64
+    {
+        self.latitude
+    }
+
+
+    /// Get the longitude that exists under `GeoPoint`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoPointGetters};
+    ///
+    /// let longitude = -71.92;
+    ///
+    /// let geo_point = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(longitude)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(longitude, geo_point.longitude());
+    /// ```
+    fn longitude(&self) -> f64
+    {
+        self.longitude
+    }
+}
@@ -0,0 +1,310 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for GeoRSS extension by using the methods
+//! under `GeoRssExtensionBuilder`.
+
+
+use extension::georss::{GeoBox, GeoBoxBuilder, GeoPoint, GeoPointBuilder, GeoRssExtension, GeoRssExtensionBuilder};
+
+
+impl GeoRssExtensionBuilder
+{
+    /// Construct a new `GeoRssExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoRssExtensionBuilder;
+    ///
+    /// let geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// ```
+    pub fn new() -> GeoRssExtensionBuilder
+    {
+        GeoRssExtensionBuilder::default()
+    }
+
+
+    /// Set the optional point that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let location = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-71.92)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// geo_rss_extension_builder.point(Some(location));
+    /// ```
+    pub fn point(&mut self, point: Option<GeoPoint>) -> &mut GeoRssExtensionBuilder
+    {
+        self.point = point;
+        self
+    }
+
+
+    /// Set the line that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let start = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-110.45)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let end = GeoPointBuilder::new()
+    ///     .latitude(46.46)
+    ///     .longitude(-109.48)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// geo_rss_extension_builder.line(vec![start, end]);
+    /// ```
+    pub fn line(&mut self, line: Vec<GeoPoint>) -> &mut GeoRssExtensionBuilder
+    {
+        self.line = line;
+        self
+    }
+
+
+    /// Set the polygon that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let first = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-110.45)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let second = GeoPointBuilder::new()
+    ///     .latitude(46.46)
+    ///     .longitude(-109.48)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let third = GeoPointBuilder::new()
+    ///     .latitude(43.84)
+    ///     .longitude(-109.86)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// geo_rss_extension_builder.polygon(vec![first.clone(), second, third, first]);
+    /// ```
+    pub fn polygon(&mut self, polygon: Vec<GeoPoint>) -> &mut GeoRssExtensionBuilder
+    {
+        self.polygon = polygon;
+        self
+    }
+
+
+    /// Set the optional bounding box that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxBuilder, GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let lower_corner = GeoPointBuilder::new()
+    ///     .latitude(42.943)
+    ///     .longitude(-71.032)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let upper_corner = GeoPointBuilder::new()
+    ///     .latitude(43.039)
+    ///     .longitude(-69.856)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let bounds = GeoBoxBuilder::new()
+    ///     .lower_corner(lower_corner)
+    ///     .upper_corner(upper_corner)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// geo_rss_extension_builder.bounding_box(Some(bounds));
+    /// ```
+    pub fn bounding_box(&mut self, bounding_box: Option<GeoBox>) -> &mut GeoRssExtensionBuilder
+    {
+        self.bounding_box = bounding_box;
+        self
+    }
+
+
+    /// Set the optional feature name that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoRssExtensionBuilder;
+    ///
+    /// let mut geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// geo_rss_extension_builder.feature_name(Some("Mount Washington".to_owned()));
+    /// ```
+    pub fn feature_name(&mut self, feature_name: Option<String>) -> &mut GeoRssExtensionBuilder
+    {
+        self.feature_name = feature_name;
+        self
+    }
+
+
+    /// Set the optional elevation that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoRssExtensionBuilder;
+    ///
+    /// let mut geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// geo_rss_extension_builder.elevation(Some(1917.0));
+    /// ```
+    pub fn elevation(&mut self, elevation: Option<f64>) -> &mut GeoRssExtensionBuilder
+    {
+        self.elevation = elevation;
+        self
+    }
+
+
+    /// Set the optional radius that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoRssExtensionBuilder;
+    ///
+    /// let mut geo_rss_extension_builder = GeoRssExtensionBuilder::new();
+    /// geo_rss_extension_builder.radius(Some(500.0));
+    /// ```
+    pub fn radius(&mut self, radius: Option<f64>) -> &mut GeoRssExtensionBuilder
+    {
+        self.radius = radius;
+        self
+    }
+
+
+    /// Validate the contents of `GeoRssExtension`.
+    ///
+    /// Every `GeoPoint` and the `GeoBox` must be valid, a line needs 2 points, a
+    /// polygon needs 4 points and must be closed, and the radius cannot be
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let location = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-71.92)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .point(Some(location))
+    ///     .feature_name(Some("Mount Washington".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut GeoRssExtensionBuilder, String>
+    {
+        for point in self.point.iter().chain(self.line.iter()).chain(self.polygon.iter())
+        {
+            GeoPointBuilder::new()
+                .latitude(point.latitude)
+                .longitude(point.longitude)
+                .validate()?;
+        }
+
+        if self.line.len() == 1
+        {
+            return Err("Line must have at least 2 points.".to_owned());
+        }
+
+        if !self.polygon.is_empty()
+        {
+            if self.polygon.len() < 4
+            {
+                return Err("Polygon must have at least 4 points.".to_owned());
+            }
+
+            if self.polygon.first() != self.polygon.last()
+            {
+                return Err("Polygon must end on the point it starts on.".to_owned());
+            }
+        }
+
+        if let Some(ref bounding_box) = self.bounding_box
+        {
+            GeoBoxBuilder::new()
+                .lower_corner(bounding_box.lower_corner.clone())
+                .upper_corner(bounding_box.upper_corner.clone())
+                .validate()?;
+        }
+
+        if let Some(elevation) = self.elevation
+        {
+            if !elevation.is_finite()
+            {
+                return Err(format!("Invalid value: {}", elevation));
+            }
+        }
+
+        if let Some(radius) = self.radius
+        {
+            if !radius.is_finite() || radius < 0.0
+            {
+                return Err(format!("Invalid value: {}", radius));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `GeoRssExtension` from the `GeoRssExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::GeoRssExtensionBuilder;
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<GeoRssExtension, String>
+    {
+        Ok(GeoRssExtension {
+               point: self.point.clone(),
+               line: self.line.clone(),
+               polygon: self.polygon.clone(),
+               bounding_box: self.bounding_box.clone(),
+               feature_name: self.feature_name.clone(),
+               elevation: self.elevation,
+               radius: self.radius,
+           })
+    }
+}
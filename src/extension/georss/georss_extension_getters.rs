@@ -0,0 +1,344 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under GeoRSS extension can be retrieved by using the
+//! methods under `GeoRssExtension`.
+
+
+use extension::georss::{GeoBox, GeoPoint, GeoRssExtension, GeoRssExtensionGetters};
+
+
+impl GeoRssExtensionGetters for GeoRssExtension
+{
+    /// Get the optional point that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let location = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-71.92)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let point = Some(location);
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .point(point.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(point, geo_rss_extension.point());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .point(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(geo_rss_extension.point().is_none());
+    /// ```
+    fn point(&self) -> Option<GeoPoint>
+    {
+        self.point.clone()
+    }
+
+
+    /// Get the line that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let start = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-110.45)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let end = GeoPointBuilder::new()
+    ///     .latitude(46.46)
+    ///     .longitude(-109.48)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let line = vec![start, end];
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .line(line.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, geo_rss_extension.line().len());
+    /// ```
+    fn line(&self) -> Vec<GeoPoint>
+    {
+        self.line.clone()
+    }
+
+
+    /// Get the polygon that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let first = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-110.45)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let second = GeoPointBuilder::new()
+    ///     .latitude(46.46)
+    ///     .longitude(-109.48)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let third = GeoPointBuilder::new()
+    ///     .latitude(43.84)
+    ///     .longitude(-109.86)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let polygon = vec![first.clone(), second, third, first];
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .polygon(polygon.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(4, geo_rss_extension.polygon().len());
+    /// ```
+    fn polygon(&self) -> Vec<GeoPoint>
+    {
+        self.polygon.clone()
+    }
+
+
+    /// Get the optional bounding box that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxBuilder, GeoPointBuilder, GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let lower_corner = GeoPointBuilder::new()
+    ///     .latitude(42.943)
+    ///     .longitude(-71.032)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let upper_corner = GeoPointBuilder::new()
+    ///     .latitude(43.039)
+    ///     .longitude(-69.856)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let bounds = GeoBoxBuilder::new()
+    ///     .lower_corner(lower_corner)
+    ///     .upper_corner(upper_corner)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let bounding_box = Some(bounds);
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .bounding_box(bounding_box.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(bounding_box, geo_rss_extension.bounding_box());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .bounding_box(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(geo_rss_extension.bounding_box().is_none());
+    /// ```
+    fn bounding_box(&self) -> Option<GeoBox>
+    {
+        self.bounding_box.clone()
+    }
+
+
+    /// Get the optional feature name that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let feature_name = Some("Mount Washington".to_owned());
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .feature_name(feature_name.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(feature_name, geo_rss_extension.feature_name());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .feature_name(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(geo_rss_extension.feature_name().is_none());
+    /// ```
+    fn feature_name(&self) -> Option<String>
+    {
+        self.feature_name.clone()
+    }
+
+
+    /// Get the optional elevation that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let elevation = Some(1917.0);
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .elevation(elevation)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(elevation, geo_rss_extension.elevation());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .elevation(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(geo_rss_extension.elevation().is_none());
+    /// ```
+    fn elevation(&self) -> Option<f64>
+    {
+        self.elevation
+    }
+
+
+    /// Get the optional radius that exists under `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let radius = Some(500.0);
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .radius(radius)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(radius, geo_rss_extension.radius());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let geo_rss_extension = GeoRssExtensionBuilder::new()
+    ///     .radius(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(geo_rss_extension.radius().is_none());
+    /// ```
+    fn radius(&self) -> Option<f64>
+    {
+        self.radius
+    }
+
+
+    /// Get the smallest `GeoBox` that holds the point, line, polygon and box
+    /// of `GeoRssExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBoxGetters, GeoPointBuilder, GeoPointGetters, GeoRssExtensionBuilder,
+    ///                               GeoRssExtensionGetters};
+    ///
+    /// let start = GeoPointBuilder::new()
+    ///     .latitude(45.256)
+    ///     .longitude(-110.45)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let end = GeoPointBuilder::new()
+    ///     .latitude(46.46)
+    ///     .longitude(-109.48)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let georss = GeoRssExtensionBuilder::new()
+    ///     .line(vec![start, end])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let bounds = georss.bounds().unwrap();
+    /// assert_eq!(45.256, bounds.lower_corner().latitude());
+    /// assert_eq!(-109.48, bounds.upper_corner().longitude());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoRssExtensionBuilder, GeoRssExtensionGetters};
+    ///
+    /// let georss = GeoRssExtensionBuilder::new()
+    ///     .feature_name(Some("Mount Washington".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(georss.bounds().is_none());
+    /// ```
+    fn bounds(&self) -> Option<GeoBox>
+    {
+        let mut points: Vec<&GeoPoint> = self.point.iter().chain(self.line.iter()).chain(self.polygon.iter()).collect();
+        if let Some(ref bounding_box) = self.bounding_box
+        {
+            points.push(&bounding_box.lower_corner);
+            points.push(&bounding_box.upper_corner);
+        }
+
+        let first = points.first()?;
+        let mut bounds = GeoBox {
+            lower_corner: (*first).clone(),
+            upper_corner: (*first).clone(),
+        };
+        for point in points
+        {
+            bounds.lower_corner.latitude = bounds.lower_corner.latitude.min(point.latitude);
+            bounds.lower_corner.longitude = bounds.lower_corner.longitude.min(point.longitude);
+            bounds.upper_corner.latitude = bounds.upper_corner.latitude.max(point.latitude);
+            bounds.upper_corner.longitude = bounds.upper_corner.longitude.max(point.longitude);
+        }
+
+        Some(bounds)
+    }
+}
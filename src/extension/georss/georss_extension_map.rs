@@ -0,0 +1,369 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing the `georss` extensions of an `Item`.
+
+
+use extension::georss::{GeoBox, GeoBoxBuilder, GeoPoint, GeoRssExtension};
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+
+// The elements covered by `GeoRssExtension`, by local name.
+const ELEMENTS: &[&str] = &["point", "line", "polygon", "box", "where", "featureName", "elev", "radius"];
+
+
+impl GeoRssExtension
+{
+    /// Construct a `GeoRssExtension` from the `georss` extensions of an
+    /// `Item`.
+    ///
+    /// The GeoRSS Simple elements are read first, and the GeoRSS GML
+    /// `georss:where` element is read when there are none. Coordinates and
+    /// numbers that cannot be read are left out, where `try_from_map` fails
+    /// on them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::georss::GeoRssExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:georss=\"http://www.georss.org/georss\" \
+    ///                xmlns:gml=\"http://www.opengis.net/gml\">\
+    ///                <channel><title>Local News</title>\
+    ///                <item><title>Storm on the summit</title>\
+    ///                <georss:point>44.270 -71.303</georss:point>\
+    ///                <georss:featureName>Mount Washington</georss:featureName></item>\
+    ///                <item><title>Harbor closed</title><georss:where><gml:Point>\
+    ///                <gml:pos>43.656 -70.248</gml:pos></gml:Point></georss:where></item>\
+    ///                </channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let summit = GeoRssExtension::from_map(&channel.items[0].extensions["georss"]);
+    ///     assert_eq!(44.270, summit.point.unwrap().latitude);
+    ///     assert_eq!(Some("Mount Washington".to_owned()), summit.feature_name);
+    ///
+    ///     let harbor = GeoRssExtension::from_map(&channel.items[1].extensions["georss"]);
+    ///     assert_eq!(-70.248, harbor.point.unwrap().longitude);
+    /// }
+    /// ```
+    pub fn from_map(map: &HashMap<String, Vec<Extension>>) -> GeoRssExtension
+    {
+        let mut georss = GeoRssExtension {
+            point: text(map, "point").and_then(|point| parse_points(&point)).and_then(single),
+            line: text(map, "line").and_then(|line| parse_points(&line)).unwrap_or_default(),
+            polygon: text(map, "polygon").and_then(|polygon| parse_points(&polygon)).unwrap_or_default(),
+            bounding_box: text(map, "box").and_then(|bounding_box| bounding_box.parse().ok()),
+            feature_name: text(map, "featureName"),
+            elevation: text(map, "elev").and_then(|elevation| elevation.parse().ok()),
+            radius: text(map, "radius").and_then(|radius| radius.parse().ok()),
+        };
+
+        let has_simple = georss.point.is_some() || !georss.line.is_empty() || !georss.polygon.is_empty() ||
+                         georss.bounding_box.is_some();
+        if let Some(element) = map.get("where").and_then(|elements| elements.first())
+        {
+            if !has_simple
+            {
+                read_gml(element, &mut georss);
+            }
+        }
+
+        georss
+    }
+
+
+    /// Construct a `GeoRssExtension` from the `georss` extensions of an
+    /// `Item`, failing on coordinates and numbers that cannot be read instead
+    /// of leaving them out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::georss::GeoRssExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:georss=\"http://www.georss.org/georss\">\
+    ///                <channel><title>Local News</title>\
+    ///                <item><title>Bridge reopens</title>\
+    ///                <georss:point>45.5,-122.6</georss:point></item>\
+    ///                </channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let result = GeoRssExtension::try_from_map(&channel.items[0].extensions["georss"]);
+    ///     assert_eq!(Err("Invalid value: 45.5,-122.6".to_owned()), result.map(|_| ()));
+    /// }
+    /// ```
+    pub fn try_from_map(map: &HashMap<String, Vec<Extension>>) -> Result<GeoRssExtension, String>
+    {
+        let georss = GeoRssExtension::from_map(map);
+
+        let read = [("point", georss.point.is_some()),
+                    ("line", !georss.line.is_empty()),
+                    ("polygon", !georss.polygon.is_empty()),
+                    ("box", georss.bounding_box.is_some()),
+                    ("elev", georss.elevation.is_some()),
+                    ("radius", georss.radius.is_some())];
+        for &(name, is_read) in &read
+        {
+            if let Some(value) = text(map, name)
+            {
+                if !is_read
+                {
+                    return Err(format!("Invalid value: {}", value));
+                }
+            }
+        }
+
+        let has_geometry = read[..4].iter().any(|&(_, is_read)| is_read);
+        if map.contains_key("where") && !has_geometry
+        {
+            return Err("Invalid value: georss:where".to_owned());
+        }
+
+        Ok(georss)
+    }
+
+
+    /// Convert the `GeoRssExtension` to GeoRSS Simple `georss` extensions,
+    /// keyed by local name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoPointBuilder, GeoRssExtensionBuilder};
+    ///
+    /// let point = GeoPointBuilder::new()
+    ///     .latitude(44.27)
+    ///     .longitude(-71.303)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let georss = GeoRssExtensionBuilder::new()
+    ///     .point(Some(point))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let map = georss.to_map();
+    /// assert_eq!(Some("44.27 -71.303".to_owned()), map["point"][0].value);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    {
+        let mut map = HashMap::new();
+        insert_text(&mut map, "point", &self.point.as_ref().map(|point| format_points(&[point])));
+        insert_text(&mut map, "line", &Some(format_points(&self.line.iter().collect::<Vec<_>>())));
+        insert_text(&mut map, "polygon", &Some(format_points(&self.polygon.iter().collect::<Vec<_>>())));
+        insert_text(&mut map,
+                    "box",
+                    &self.bounding_box
+                         .as_ref()
+                         .map(|bounding_box| {
+                                  format_points(&[&bounding_box.lower_corner, &bounding_box.upper_corner])
+                              }));
+        insert_text(&mut map, "featureName", &self.feature_name);
+        insert_text(&mut map, "elev", &self.elevation.map(|elevation| elevation.to_string()));
+        insert_text(&mut map, "radius", &self.radius.map(|radius| radius.to_string()));
+        map
+    }
+
+
+    /// Replace the `georss` extensions of an `ExtensionMap` with those of the
+    /// `GeoRssExtension`, keeping the elements it does not cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::georss::{GeoRssExtension, GeoRssExtensionBuilder};
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let georss = GeoRssExtensionBuilder::new()
+    ///         .bounding_box(Some("42.943 -71.032 43.039 -69.856".parse().unwrap()))
+    ///         .radius(Some(500.0))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     georss.insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(georss, GeoRssExtension::from_map(&extensions["georss"]));
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        let map = extensions.entry("georss".to_owned()).or_default();
+        for name in ELEMENTS
+        {
+            map.remove(*name);
+        }
+        map.extend(self.to_map());
+
+        if map.is_empty()
+        {
+            extensions.remove("georss");
+        }
+    }
+}
+
+
+impl FromStr for GeoBox
+{
+    type Err = String;
+
+
+    /// Construct a `GeoBox` from the latitude and longitude of its lower
+    /// corner and then of its upper corner, separated by whitespace, as in
+    /// `georss:box`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::georss::{GeoBox, GeoBoxGetters, GeoPointGetters};
+    ///
+    /// let bounds = "42.943 -71.032 43.039 -69.856".parse::<GeoBox>().unwrap();
+    /// assert_eq!(-69.856, bounds.upper_corner().longitude());
+    ///
+    /// assert!("42.943 -71.032".parse::<GeoBox>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<GeoBox, String>
+    {
+        let mut points = match parse_points(s)
+        {
+            Some(ref points) if points.len() == 2 => points.clone(),
+            _ => return Err(format!("Invalid value: {}", s)),
+        };
+
+        let upper_corner = points.remove(1);
+        GeoBoxBuilder::new()
+            .lower_corner(points.remove(0))
+            .upper_corner(upper_corner)
+            .validate()?
+            .finalize()
+    }
+}
+
+
+// Read the GeoRSS GML geometry of `georss:where` into a `GeoRssExtension`.
+fn read_gml(element: &Extension, georss: &mut GeoRssExtension)
+{
+    georss.point = child_text(element, &["Point", "pos"]).and_then(|point| parse_points(&point)).and_then(single);
+    georss.line = child_text(element, &["LineString", "posList"])
+        .and_then(|line| parse_points(&line))
+        .unwrap_or_default();
+    georss.polygon = child_text(element, &["Polygon", "exterior", "LinearRing", "posList"])
+        .and_then(|polygon| parse_points(&polygon))
+        .unwrap_or_default();
+
+    let lower_corner = child_text(element, &["Envelope", "lowerCorner"]);
+    let upper_corner = child_text(element, &["Envelope", "upperCorner"]);
+    if let (Some(lower_corner), Some(upper_corner)) = (lower_corner, upper_corner)
+    {
+        georss.bounding_box = format!("{} {}", lower_corner, upper_corner).parse().ok();
+    }
+}
+
+
+fn child_text(element: &Extension, path: &[&str]) -> Option<String>
+{
+    let mut element = element;
+    for name in path
+    {
+        element = element.children.get(*name).and_then(|children| children.first())?;
+    }
+
+    element.value.as_ref().map(|value| value.trim().to_owned())
+}
+
+
+// Parse whitespace separated pairs of latitude and longitude.
+fn parse_points(s: &str) -> Option<Vec<GeoPoint>>
+{
+    let mut numbers = Vec::new();
+    for number in s.split_whitespace()
+    {
+        numbers.push(number.parse::<f64>().ok()?);
+    }
+
+    if numbers.is_empty() || numbers.len() % 2 != 0
+    {
+        return None;
+    }
+
+    Some(numbers
+             .chunks(2)
+             .map(|pair| {
+                      GeoPoint {
+                          latitude: pair[0],
+                          longitude: pair[1],
+                      }
+                  })
+             .collect())
+}
+
+
+fn single(mut points: Vec<GeoPoint>) -> Option<GeoPoint>
+{
+    if points.len() == 1 { points.pop() } else { None }
+}
+
+
+fn format_points(points: &[&GeoPoint]) -> String
+{
+    points
+        .iter()
+        .map(|point| format!("{} {}", point.latitude, point.longitude))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+
+fn text(map: &HashMap<String, Vec<Extension>>, name: &str) -> Option<String>
+{
+    map.get(name)
+        .and_then(|elements| elements.first())
+        .and_then(|element| element.value.clone())
+        .map(|value| value.trim().to_owned())
+}
+
+
+fn insert_text(map: &mut HashMap<String, Vec<Extension>>, name: &str, value: &Option<String>)
+{
+    if let Some(ref value) = *value
+    {
+        if value.is_empty()
+        {
+            return;
+        }
+
+        map.insert(name.to_owned(),
+                   vec![Extension {
+                            name: format!("georss:{}", name),
+                            value: Some(value.clone()),
+                            attrs: HashMap::new(),
+                            children: HashMap::new(),
+                        }]);
+    }
+}
@@ -0,0 +1,165 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for the GeoRSS extension.
+//!
+//! The elements are read from and written to the `georss` extensions of an
+//! `Item`. Both GeoRSS Simple and GeoRSS GML, under `georss:where`, are read,
+//! and GeoRSS Simple is written.
+
+
+pub mod geo_box_builder;
+pub mod geo_box_getters;
+pub mod geo_point_builder;
+pub mod geo_point_getters;
+pub mod georss_extension_builder;
+pub mod georss_extension_getters;
+pub mod georss_extension_map;
+
+
+/// The namespace of GeoRSS.
+pub const NAMESPACE: &str = "http://www.georss.org/georss";
+
+
+/// The namespace of GML, used by GeoRSS GML.
+pub const GML_NAMESPACE: &str = "http://www.opengis.net/gml";
+
+
+/// A position on the earth, in WGS84 degrees.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GeoPoint
+{
+    /// The latitude, from -90 to 90.
+    pub latitude: f64,
+    /// The longitude, from -180 to 180.
+    pub longitude: f64,
+}
+
+
+/// The Getter functions for `GeoPoint`
+pub trait GeoPointGetters
+{
+    /// Get the latitude that exists under `GeoPoint`.
+    fn latitude(&self) -> f64;
+
+    /// Get the longitude that exists under `GeoPoint`.
+    fn longitude(&self) -> f64;
+}
+
+
+/// This `GeoPointBuilder` struct creates the `GeoPoint`.
+#[derive(Clone, Default)]
+pub struct GeoPointBuilder
+{
+    latitude: f64,
+    longitude: f64,
+}
+
+
+/// A bounding box on the earth, from its south west corner to its north east
+/// corner.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GeoBox
+{
+    /// The south west corner.
+    pub lower_corner: GeoPoint,
+    /// The north east corner.
+    pub upper_corner: GeoPoint,
+}
+
+
+/// The Getter functions for `GeoBox`
+pub trait GeoBoxGetters
+{
+    /// Get the lower corner that exists under `GeoBox`.
+    fn lower_corner(&self) -> GeoPoint;
+
+    /// Get the upper corner that exists under `GeoBox`.
+    fn upper_corner(&self) -> GeoPoint;
+
+    /// Check whether a `GeoPoint` lies within `GeoBox`.
+    fn contains(&self, point: &GeoPoint) -> bool;
+
+    /// Check whether `GeoBox` overlaps another `GeoBox`.
+    fn intersects(&self, other: &GeoBox) -> bool;
+}
+
+
+/// This `GeoBoxBuilder` struct creates the `GeoBox`.
+#[derive(Clone, Default)]
+pub struct GeoBoxBuilder
+{
+    lower_corner: GeoPoint,
+    upper_corner: GeoPoint,
+}
+
+
+/// The GeoRSS tags of an `Item`, telling where it is about.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GeoRssExtension
+{
+    /// The point, from `georss:point`.
+    pub point: Option<GeoPoint>,
+    /// The points of the line, from `georss:line`.
+    pub line: Vec<GeoPoint>,
+    /// The points of the closed polygon, from `georss:polygon`.
+    pub polygon: Vec<GeoPoint>,
+    /// The bounding box, from `georss:box`.
+    pub bounding_box: Option<GeoBox>,
+    /// The name of the place, from `georss:featureName`.
+    pub feature_name: Option<String>,
+    /// The elevation in meters, from `georss:elev`.
+    pub elevation: Option<f64>,
+    /// The radius in meters around the location, from `georss:radius`.
+    pub radius: Option<f64>,
+}
+
+
+/// The Getter functions for `GeoRssExtension`
+pub trait GeoRssExtensionGetters
+{
+    /// Get the optional point that exists under `GeoRssExtension`.
+    fn point(&self) -> Option<GeoPoint>;
+
+    /// Get the line that exists under `GeoRssExtension`.
+    fn line(&self) -> Vec<GeoPoint>;
+
+    /// Get the polygon that exists under `GeoRssExtension`.
+    fn polygon(&self) -> Vec<GeoPoint>;
+
+    /// Get the optional bounding box that exists under `GeoRssExtension`.
+    fn bounding_box(&self) -> Option<GeoBox>;
+
+    /// Get the optional feature name that exists under `GeoRssExtension`.
+    fn feature_name(&self) -> Option<String>;
+
+    /// Get the optional elevation that exists under `GeoRssExtension`.
+    fn elevation(&self) -> Option<f64>;
+
+    /// Get the optional radius that exists under `GeoRssExtension`.
+    fn radius(&self) -> Option<f64>;
+
+    /// Get the smallest `GeoBox` that holds the location.
+    fn bounds(&self) -> Option<GeoBox>;
+}
+
+
+/// This `GeoRssExtensionBuilder` struct creates the `GeoRssExtension`.
+#[derive(Clone, Default)]
+pub struct GeoRssExtensionBuilder
+{
+    point: Option<GeoPoint>,
+    line: Vec<GeoPoint>,
+    polygon: Vec<GeoPoint>,
+    bounding_box: Option<GeoBox>,
+    feature_name: Option<String>,
+    elevation: Option<f64>,
+    radius: Option<f64>,
+}
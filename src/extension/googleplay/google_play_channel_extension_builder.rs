@@ -0,0 +1,240 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for google play channel extension by using the methods
+//! under `GooglePlayChannelExtensionBuilder`.
+
+
+use extension::googleplay::{CATEGORIES, GooglePlayChannelExtension, GooglePlayChannelExtensionBuilder};
+use utils::string_utils;
+
+
+impl GooglePlayChannelExtensionBuilder
+{
+    /// Construct a new `GooglePlayChannelExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// ```
+    pub fn new() -> GooglePlayChannelExtensionBuilder
+    {
+        GooglePlayChannelExtensionBuilder::default()
+    }
+
+
+    /// Set the optional author that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let mut google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// google_play_channel_extension_builder.author(Some("Jupiter Broadcasting".to_owned()));
+    /// ```
+    pub fn author(&mut self, author: Option<String>) -> &mut GooglePlayChannelExtensionBuilder
+    {
+        self.author = author;
+        self
+    }
+
+
+    /// Set the optional block that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let mut google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// google_play_channel_extension_builder.block(Some("no".to_owned()));
+    /// ```
+    pub fn block(&mut self, block: Option<String>) -> &mut GooglePlayChannelExtensionBuilder
+    {
+        self.block = block;
+        self
+    }
+
+
+    /// Set the categories that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let mut google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// google_play_channel_extension_builder.categories(vec!["Technology".to_owned()]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<String>) -> &mut GooglePlayChannelExtensionBuilder
+    {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the optional description that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let mut google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// google_play_channel_extension_builder.description(Some("Linux news and reviews".to_owned()));
+    /// ```
+    pub fn description(&mut self, description: Option<String>) -> &mut GooglePlayChannelExtensionBuilder
+    {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the optional email that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let mut google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// google_play_channel_extension_builder.email(Some("chris@jupiterbroadcasting.com".to_owned()));
+    /// ```
+    pub fn email(&mut self, email: Option<String>) -> &mut GooglePlayChannelExtensionBuilder
+    {
+        self.email = email;
+        self
+    }
+
+
+    /// Set the optional explicit that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let mut google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// google_play_channel_extension_builder.explicit(Some("no".to_owned()));
+    /// ```
+    pub fn explicit(&mut self, explicit: Option<String>) -> &mut GooglePlayChannelExtensionBuilder
+    {
+        self.explicit = explicit;
+        self
+    }
+
+
+    /// Set the optional image that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let mut google_play_channel_extension_builder = GooglePlayChannelExtensionBuilder::new();
+    /// google_play_channel_extension_builder.image(Some("http://www.jupiterbroadcasting.com/images/LASUncut-Large.jpg".to_owned()));
+    /// ```
+    pub fn image(&mut self, image: Option<String>) -> &mut GooglePlayChannelExtensionBuilder
+    {
+        self.image = image;
+        self
+    }
+
+
+    /// Validate the contents of `GooglePlayChannelExtension`.
+    ///
+    /// Block must be `yes` or `no`, explicit `yes`, `no` or `clean`, and the
+    /// categories must be Google Play categories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .author(Some("Jupiter Broadcasting".to_owned()))
+    ///     .categories(vec!["Technology".to_owned()])
+    ///     .email(Some("chris@jupiterbroadcasting.com".to_owned()))
+    ///     .explicit(Some("no".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut GooglePlayChannelExtensionBuilder, String>
+    {
+        if let Some(ref block) = self.block
+        {
+            if block != "yes" && block != "no"
+            {
+                return Err(format!("Invalid value: {}", block));
+            }
+        }
+
+        if let Some(ref explicit) = self.explicit
+        {
+            if explicit != "yes" && explicit != "no" && explicit != "clean"
+            {
+                return Err(format!("Invalid value: {}", explicit));
+            }
+        }
+
+        if let Some(ref image) = self.image
+        {
+            string_utils::str_to_url(image.as_str())?;
+        }
+
+        for category in &self.categories
+        {
+            if !CATEGORIES.contains(&category.as_str())
+            {
+                return Err(format!("Invalid value: {}", category));
+            }
+        }
+
+        if let Some(ref email) = self.email
+        {
+            let parts: Vec<&str> = email.split('@').collect();
+            if parts.len() != 2 || parts[0].is_empty() || !parts[1].contains('.')
+            {
+                return Err(format!("Invalid value: {}", email));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `GooglePlayChannelExtension` from the `GooglePlayChannelExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<GooglePlayChannelExtension, String>
+    {
+        Ok(GooglePlayChannelExtension {
+               author: self.author.clone(),
+               block: self.block.clone(),
+               categories: self.categories.clone(),
+               description: self.description.clone(),
+               email: self.email.clone(),
+               explicit: self.explicit.clone(),
+               image: self.image.clone(),
+           })
+    }
+}
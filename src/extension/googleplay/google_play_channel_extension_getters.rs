@@ -0,0 +1,238 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under google play channel extension can be retrieved by using the
+//! methods under `GooglePlayChannelExtension`.
+
+
+use extension::googleplay::{GooglePlayChannelExtension, GooglePlayChannelExtensionGetters};
+
+
+impl GooglePlayChannelExtensionGetters for GooglePlayChannelExtension
+{
+    /// Get the optional author that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let author = Some("Jupiter Broadcasting".to_owned());
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .author(author.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(author, google_play_channel_extension.author());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .author(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_channel_extension.author().is_none());
+    /// ```
+    fn author(&self) -> Option<String>
+    {
+        self.author.clone()
+    }
+
+
+    /// Get the optional block that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let block = Some("no".to_owned());
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .block(block.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(block, google_play_channel_extension.block());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .block(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_channel_extension.block().is_none());
+    /// ```
+    fn block(&self) -> Option<String>
+    {
+        self.block.clone()
+    }
+
+
+    /// Get the categories that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let categories = vec!["Technology".to_owned()];
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .categories(categories.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, google_play_channel_extension.categories().len());
+    /// ```
+    fn categories(&self) -> Vec<String>
+    {
+        self.categories.clone()
+    }
+
+
+    /// Get the optional description that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let description = Some("Linux news and reviews".to_owned());
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .description(description.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(description, google_play_channel_extension.description());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .description(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_channel_extension.description().is_none());
+    /// ```
+    fn description(&self) -> Option<String>
+    {
+        self.description.clone()
+    }
+
+
+    /// Get the optional email that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let email = Some("chris@jupiterbroadcasting.com".to_owned());
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .email(email.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(email, google_play_channel_extension.email());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .email(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_channel_extension.email().is_none());
+    /// ```
+    fn email(&self) -> Option<String>
+    {
+        self.email.clone()
+    }
+
+
+    /// Get the optional explicit that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let explicit = Some("no".to_owned());
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .explicit(explicit.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(explicit, google_play_channel_extension.explicit());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .explicit(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_channel_extension.explicit().is_none());
+    /// ```
+    fn explicit(&self) -> Option<String>
+    {
+        self.explicit.clone()
+    }
+
+
+    /// Get the optional image that exists under `GooglePlayChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let image = Some("http://www.jupiterbroadcasting.com/images/LASUncut-Large.jpg".to_owned());
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .image(image.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(image, google_play_channel_extension.image());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayChannelExtensionBuilder, GooglePlayChannelExtensionGetters};
+    ///
+    /// let google_play_channel_extension = GooglePlayChannelExtensionBuilder::new()
+    ///     .image(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_channel_extension.image().is_none());
+    /// ```
+    fn image(&self) -> Option<String>
+    {
+        self.image.clone()
+    }
+}
@@ -0,0 +1,299 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing the `googleplay` extensions of an `Item` or a
+//! `Channel`.
+
+
+use extension::googleplay::{GooglePlayChannelExtension, GooglePlayItemExtension};
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+
+
+// The elements covered by the Google Play extensions, by local name.
+const ELEMENTS: &[&str] = &["author", "block", "category", "description", "email", "explicit", "image"];
+
+
+impl GooglePlayChannelExtension
+{
+    /// Construct a `GooglePlayChannelExtension` from the `googleplay`
+    /// extensions of a `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::googleplay::GooglePlayChannelExtension;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/example2.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let googleplay = GooglePlayChannelExtension::from_map(&channel.extensions["googleplay"]);
+    ///
+    ///     assert_eq!(Some("error@jupiterbroadcasting.com".to_owned()), googleplay.email);
+    ///     assert_eq!(Some("no".to_owned()), googleplay.explicit);
+    /// }
+    /// ```
+    pub fn from_map(map: &HashMap<String, Vec<Extension>>) -> GooglePlayChannelExtension
+    {
+        GooglePlayChannelExtension {
+            author: text(map, "author"),
+            block: text(map, "block"),
+            categories: map.get("category")
+                .map(|categories| categories.iter().filter_map(|category| category.attrs.get("text").cloned()).collect())
+                .unwrap_or_default(),
+            description: text(map, "description"),
+            email: text(map, "email"),
+            explicit: text(map, "explicit"),
+            image: image(map),
+        }
+    }
+
+
+    /// Convert the `GooglePlayChannelExtension` to `googleplay` extensions,
+    /// keyed by local name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayChannelExtensionBuilder;
+    ///
+    /// let googleplay = GooglePlayChannelExtensionBuilder::new()
+    ///     .categories(vec!["Technology".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let map = googleplay.to_map();
+    /// assert_eq!("Technology", map["category"][0].attrs["text"]);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    {
+        let mut map = HashMap::new();
+        insert_text(&mut map, "author", &self.author);
+        insert_text(&mut map, "block", &self.block);
+        if !self.categories.is_empty()
+        {
+            map.insert("category".to_owned(),
+                       self.categories
+                           .iter()
+                           .map(|category| extension("category", None, Some(("text", category.clone()))))
+                           .collect());
+        }
+        insert_text(&mut map, "description", &self.description);
+        insert_text(&mut map, "email", &self.email);
+        insert_text(&mut map, "explicit", &self.explicit);
+        insert_image(&mut map, &self.image);
+        map
+    }
+
+
+    /// Replace the `googleplay` extensions of an `ExtensionMap` with those of
+    /// the `GooglePlayChannelExtension`, keeping the elements it does not
+    /// cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::googleplay::{GooglePlayChannelExtension, GooglePlayChannelExtensionBuilder};
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let googleplay = GooglePlayChannelExtensionBuilder::new()
+    ///         .author(Some("Jupiter Broadcasting".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     googleplay.insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(googleplay, GooglePlayChannelExtension::from_map(&extensions["googleplay"]));
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        replace(extensions, self.to_map());
+    }
+}
+
+
+impl GooglePlayItemExtension
+{
+    /// Construct a `GooglePlayItemExtension` from the `googleplay` extensions
+    /// of an `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::googleplay::GooglePlayItemExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" \
+    ///                xmlns:googleplay=\"http://www.google.com/schemas/play-podcasts/1.0\">\
+    ///                <channel><item><title>Episode 1</title>\
+    ///                <googleplay:explicit>yes</googleplay:explicit>\
+    ///                </item></channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let googleplay = GooglePlayItemExtension::from_map(&channel.items[0].extensions["googleplay"]);
+    ///
+    ///     assert_eq!(Some("yes".to_owned()), googleplay.explicit);
+    /// }
+    /// ```
+    pub fn from_map(map: &HashMap<String, Vec<Extension>>) -> GooglePlayItemExtension
+    {
+        GooglePlayItemExtension {
+            author: text(map, "author"),
+            block: text(map, "block"),
+            description: text(map, "description"),
+            explicit: text(map, "explicit"),
+            image: image(map),
+        }
+    }
+
+
+    /// Convert the `GooglePlayItemExtension` to `googleplay` extensions,
+    /// keyed by local name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let googleplay = GooglePlayItemExtensionBuilder::new()
+    ///     .explicit(Some("no".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let map = googleplay.to_map();
+    /// assert_eq!(Some("no".to_owned()), map["explicit"][0].value);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    {
+        let mut map = HashMap::new();
+        insert_text(&mut map, "author", &self.author);
+        insert_text(&mut map, "block", &self.block);
+        insert_text(&mut map, "description", &self.description);
+        insert_text(&mut map, "explicit", &self.explicit);
+        insert_image(&mut map, &self.image);
+        map
+    }
+
+
+    /// Replace the `googleplay` extensions of an `ExtensionMap` with those of
+    /// the `GooglePlayItemExtension`, keeping the elements it does not cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::googleplay::{GooglePlayItemExtension, GooglePlayItemExtensionBuilder};
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let googleplay = GooglePlayItemExtensionBuilder::new()
+    ///         .explicit(Some("no".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     googleplay.insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(googleplay, GooglePlayItemExtension::from_map(&extensions["googleplay"]));
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        replace(extensions, self.to_map());
+    }
+}
+
+
+fn text(map: &HashMap<String, Vec<Extension>>, name: &str) -> Option<String>
+{
+    map.get(name)
+        .and_then(|elements| elements.first())
+        .and_then(|element| element.value.clone())
+        .map(|value| value.trim().to_owned())
+}
+
+
+fn image(map: &HashMap<String, Vec<Extension>>) -> Option<String>
+{
+    map.get("image")
+        .and_then(|elements| elements.first())
+        .and_then(|element| element.attrs.get("href").cloned())
+}
+
+
+fn insert_text(map: &mut HashMap<String, Vec<Extension>>, name: &str, value: &Option<String>)
+{
+    if let Some(ref value) = *value
+    {
+        map.insert(name.to_owned(), vec![extension(name, Some(value.clone()), None)]);
+    }
+}
+
+
+fn insert_image(map: &mut HashMap<String, Vec<Extension>>, image: &Option<String>)
+{
+    if let Some(ref image) = *image
+    {
+        map.insert("image".to_owned(), vec![extension("image", None, Some(("href", image.clone())))]);
+    }
+}
+
+
+fn extension(name: &str, value: Option<String>, attr: Option<(&str, String)>) -> Extension
+{
+    Extension {
+        name: format!("googleplay:{}", name),
+        value,
+        attrs: attr.into_iter().map(|(key, val)| (key.to_owned(), val)).collect(),
+        children: HashMap::new(),
+    }
+}
+
+
+// Replace the elements covered by the Google Play extensions under the
+// `googleplay` prefix.
+fn replace(extensions: &mut ExtensionMap, elements: HashMap<String, Vec<Extension>>)
+{
+    let map = extensions.entry("googleplay".to_owned()).or_default();
+    for name in ELEMENTS
+    {
+        map.remove(*name);
+    }
+    map.extend(elements);
+
+    if map.is_empty()
+    {
+        extensions.remove("googleplay");
+    }
+}
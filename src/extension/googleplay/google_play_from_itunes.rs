@@ -0,0 +1,138 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Filling the Google Play extensions from the iTunes extensions.
+
+
+use enums::ITunesExplicit;
+use extension::googleplay::{CATEGORIES, GooglePlayChannelExtension, GooglePlayItemExtension};
+use rss::extension::itunes::{ITunesChannelExtension, ITunesItemExtension};
+
+
+impl GooglePlayChannelExtension
+{
+    /// Fill the values missing from the `GooglePlayChannelExtension` with
+    /// those of an `ITunesChannelExtension`.
+    ///
+    /// The summary, or else the subtitle, stands in for the description, the
+    /// email of the owner for the email, and only the iTunes categories that
+    /// are Google Play categories are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::googleplay::GooglePlayChannelExtension;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let mut googleplay = GooglePlayChannelExtension::default();
+    ///     googleplay.fill_from_itunes(&channel.itunes_ext.unwrap());
+    ///
+    ///     assert_eq!(Some("chris@linuxactionshow.com".to_owned()), googleplay.email);
+    ///     assert_eq!(vec!["Technology".to_owned()], googleplay.categories);
+    /// }
+    /// ```
+    pub fn fill_from_itunes(&mut self, itunes: &ITunesChannelExtension)
+    {
+        fill(&mut self.author, &itunes.author);
+        fill(&mut self.block, &itunes.block.as_ref().map(|block| yes_no(block)));
+        fill(&mut self.description, &itunes.summary.clone().or_else(|| itunes.subtitle.clone()));
+        fill(&mut self.email, &itunes.owner.as_ref().and_then(|owner| owner.email.clone()));
+        fill(&mut self.explicit, &itunes.explicit.as_ref().and_then(|explicit| explicit_value(explicit)));
+        fill(&mut self.image, &itunes.image);
+
+        if self.categories.is_empty()
+        {
+            for category in &itunes.categories
+            {
+                if CATEGORIES.contains(&category.text.as_str()) && !self.categories.contains(&category.text)
+                {
+                    self.categories.push(category.text.clone());
+                }
+            }
+        }
+    }
+}
+
+
+impl GooglePlayItemExtension
+{
+    /// Fill the values missing from the `GooglePlayItemExtension` with those
+    /// of an `ITunesItemExtension`.
+    ///
+    /// The summary, or else the subtitle, stands in for the description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::googleplay::GooglePlayItemExtension;
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// fn main()
+    /// {
+    ///     let itunes = ITunesItemExtensionBuilder::new()
+    ///         .author(Some("Jupiter Broadcasting".to_owned()))
+    ///         .explicit(Some("Clean".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut googleplay = GooglePlayItemExtension::default();
+    ///     googleplay.author = Some("Chris Fisher".to_owned());
+    ///     googleplay.fill_from_itunes(&itunes);
+    ///
+    ///     assert_eq!(Some("Chris Fisher".to_owned()), googleplay.author);
+    ///     assert_eq!(Some("clean".to_owned()), googleplay.explicit);
+    /// }
+    /// ```
+    pub fn fill_from_itunes(&mut self, itunes: &ITunesItemExtension)
+    {
+        fill(&mut self.author, &itunes.author);
+        fill(&mut self.block, &itunes.block.as_ref().map(|block| yes_no(block)));
+        fill(&mut self.description, &itunes.summary.clone().or_else(|| itunes.subtitle.clone()));
+        fill(&mut self.explicit, &itunes.explicit.as_ref().and_then(|explicit| explicit_value(explicit)));
+        fill(&mut self.image, &itunes.image);
+    }
+}
+
+
+fn fill(value: &mut Option<String>, other: &Option<String>)
+{
+    if value.is_none()
+    {
+        *value = other.clone();
+    }
+}
+
+
+// iTunes only blocks on `Yes`, whatever the case.
+fn yes_no(value: &str) -> String
+{
+    if value.trim().eq_ignore_ascii_case("yes") { "yes".to_owned() } else { "no".to_owned() }
+}
+
+
+// iTunes also writes explicit as `true`, `false` and `explicit`.
+fn explicit_value(value: &str) -> Option<String>
+{
+    ITunesExplicit::value_of(value).ok().map(|explicit| explicit.as_str().to_owned())
+}
@@ -0,0 +1,184 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for google play item extension by using the methods
+//! under `GooglePlayItemExtensionBuilder`.
+
+
+use extension::googleplay::{GooglePlayItemExtension, GooglePlayItemExtensionBuilder};
+use utils::string_utils;
+
+
+impl GooglePlayItemExtensionBuilder
+{
+    /// Construct a new `GooglePlayItemExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let google_play_item_extension_builder = GooglePlayItemExtensionBuilder::new();
+    /// ```
+    pub fn new() -> GooglePlayItemExtensionBuilder
+    {
+        GooglePlayItemExtensionBuilder::default()
+    }
+
+
+    /// Set the optional author that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let mut google_play_item_extension_builder = GooglePlayItemExtensionBuilder::new();
+    /// google_play_item_extension_builder.author(Some("Jupiter Broadcasting".to_owned()));
+    /// ```
+    pub fn author(&mut self, author: Option<String>) -> &mut GooglePlayItemExtensionBuilder
+    {
+        self.author = author;
+        self
+    }
+
+
+    /// Set the optional block that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let mut google_play_item_extension_builder = GooglePlayItemExtensionBuilder::new();
+    /// google_play_item_extension_builder.block(Some("no".to_owned()));
+    /// ```
+    pub fn block(&mut self, block: Option<String>) -> &mut GooglePlayItemExtensionBuilder
+    {
+        self.block = block;
+        self
+    }
+
+
+    /// Set the optional description that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let mut google_play_item_extension_builder = GooglePlayItemExtensionBuilder::new();
+    /// google_play_item_extension_builder.description(Some("Linux news and reviews".to_owned()));
+    /// ```
+    pub fn description(&mut self, description: Option<String>) -> &mut GooglePlayItemExtensionBuilder
+    {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the optional explicit that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let mut google_play_item_extension_builder = GooglePlayItemExtensionBuilder::new();
+    /// google_play_item_extension_builder.explicit(Some("no".to_owned()));
+    /// ```
+    pub fn explicit(&mut self, explicit: Option<String>) -> &mut GooglePlayItemExtensionBuilder
+    {
+        self.explicit = explicit;
+        self
+    }
+
+
+    /// Set the optional image that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let mut google_play_item_extension_builder = GooglePlayItemExtensionBuilder::new();
+    /// google_play_item_extension_builder.image(Some("http://www.jupiterbroadcasting.com/images/LASUncut-Large.jpg".to_owned()));
+    /// ```
+    pub fn image(&mut self, image: Option<String>) -> &mut GooglePlayItemExtensionBuilder
+    {
+        self.image = image;
+        self
+    }
+
+
+    /// Validate the contents of `GooglePlayItemExtension`.
+    ///
+    /// Block must be `yes` or `no`, and explicit `yes`, `no` or `clean`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .author(Some("Jupiter Broadcasting".to_owned()))
+    ///     .explicit(Some("no".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut GooglePlayItemExtensionBuilder, String>
+    {
+        if let Some(ref block) = self.block
+        {
+            if block != "yes" && block != "no"
+            {
+                return Err(format!("Invalid value: {}", block));
+            }
+        }
+
+        if let Some(ref explicit) = self.explicit
+        {
+            if explicit != "yes" && explicit != "no" && explicit != "clean"
+            {
+                return Err(format!("Invalid value: {}", explicit));
+            }
+        }
+
+        if let Some(ref image) = self.image
+        {
+            string_utils::str_to_url(image.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `GooglePlayItemExtension` from the `GooglePlayItemExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::GooglePlayItemExtensionBuilder;
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<GooglePlayItemExtension, String>
+    {
+        Ok(GooglePlayItemExtension {
+               author: self.author.clone(),
+               block: self.block.clone(),
+               description: self.description.clone(),
+               explicit: self.explicit.clone(),
+               image: self.image.clone(),
+           })
+    }
+}
@@ -0,0 +1,183 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under google play item extension can be retrieved by using the
+//! methods under `GooglePlayItemExtension`.
+
+
+use extension::googleplay::{GooglePlayItemExtension, GooglePlayItemExtensionGetters};
+
+
+impl GooglePlayItemExtensionGetters for GooglePlayItemExtension
+{
+    /// Get the optional author that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let author = Some("Jupiter Broadcasting".to_owned());
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .author(author.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(author, google_play_item_extension.author());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .author(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_item_extension.author().is_none());
+    /// ```
+    fn author(&self) -> Option<String>
+    {
+        self.author.clone()
+    }
+
+
+    /// Get the optional block that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let block = Some("no".to_owned());
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .block(block.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(block, google_play_item_extension.block());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .block(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_item_extension.block().is_none());
+    /// ```
+    fn block(&self) -> Option<String>
+    {
+        self.block.clone()
+    }
+
+
+    /// Get the optional description that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let description = Some("Linux news and reviews".to_owned());
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .description(description.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(description, google_play_item_extension.description());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .description(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_item_extension.description().is_none());
+    /// ```
+    fn description(&self) -> Option<String>
+    {
+        self.description.clone()
+    }
+
+
+    /// Get the optional explicit that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let explicit = Some("no".to_owned());
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .explicit(explicit.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(explicit, google_play_item_extension.explicit());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .explicit(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_item_extension.explicit().is_none());
+    /// ```
+    fn explicit(&self) -> Option<String>
+    {
+        self.explicit.clone()
+    }
+
+
+    /// Get the optional image that exists under `GooglePlayItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let image = Some("http://www.jupiterbroadcasting.com/images/LASUncut-Large.jpg".to_owned());
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .image(image.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(image, google_play_item_extension.image());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::googleplay::{GooglePlayItemExtensionBuilder, GooglePlayItemExtensionGetters};
+    ///
+    /// let google_play_item_extension = GooglePlayItemExtensionBuilder::new()
+    ///     .image(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(google_play_item_extension.image().is_none());
+    /// ```
+    fn image(&self) -> Option<String>
+    {
+        self.image.clone()
+    }
+}
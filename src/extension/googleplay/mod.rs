@@ -0,0 +1,155 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for the Google Play podcast namespace.
+//!
+//! The elements are read from and written to the `googleplay` extensions of
+//! an `Item` or a `Channel`.
+
+
+pub mod google_play_channel_extension_builder;
+pub mod google_play_channel_extension_getters;
+pub mod google_play_extension_map;
+pub mod google_play_from_itunes;
+pub mod google_play_item_extension_builder;
+pub mod google_play_item_extension_getters;
+
+
+/// The namespace of the Google Play podcast tags.
+pub const NAMESPACE: &str = "http://www.google.com/schemas/play-podcasts/1.0";
+
+
+// The categories Google Play accepts in `googleplay:category`.
+const CATEGORIES: &[&str] = &["Arts",
+                              "Business",
+                              "Comedy",
+                              "Education",
+                              "Games & Hobbies",
+                              "Government & Organizations",
+                              "Health",
+                              "Kids & Family",
+                              "Music",
+                              "News & Politics",
+                              "Religion & Spirituality",
+                              "Science & Medicine",
+                              "Society & Culture",
+                              "Sports & Recreation",
+                              "Technology",
+                              "TV & Film"];
+
+
+/// The Google Play tags of a `Channel`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GooglePlayChannelExtension
+{
+    /// The author of the podcast.
+    pub author: Option<String>,
+    /// Whether the podcast is hidden from Google Play, `yes` or `no`.
+    pub block: Option<String>,
+    /// The Google Play categories of the podcast.
+    pub categories: Vec<String>,
+    /// The description of the podcast.
+    pub description: Option<String>,
+    /// The email address of the owner of the podcast.
+    pub email: Option<String>,
+    /// Whether the podcast is explicit, `yes`, `no` or `clean`.
+    pub explicit: Option<String>,
+    /// The URL of the artwork of the podcast.
+    pub image: Option<String>,
+}
+
+
+/// The Getter functions for `GooglePlayChannelExtension`
+pub trait GooglePlayChannelExtensionGetters
+{
+    /// Get the optional author that exists under `GooglePlayChannelExtension`.
+    fn author(&self) -> Option<String>;
+
+    /// Get the optional block that exists under `GooglePlayChannelExtension`.
+    fn block(&self) -> Option<String>;
+
+    /// Get the categories that exists under `GooglePlayChannelExtension`.
+    fn categories(&self) -> Vec<String>;
+
+    /// Get the optional description that exists under `GooglePlayChannelExtension`.
+    fn description(&self) -> Option<String>;
+
+    /// Get the optional email that exists under `GooglePlayChannelExtension`.
+    fn email(&self) -> Option<String>;
+
+    /// Get the optional explicit that exists under `GooglePlayChannelExtension`.
+    fn explicit(&self) -> Option<String>;
+
+    /// Get the optional image that exists under `GooglePlayChannelExtension`.
+    fn image(&self) -> Option<String>;
+}
+
+
+/// This `GooglePlayChannelExtensionBuilder` struct creates the `GooglePlayChannelExtension`.
+#[derive(Clone, Default)]
+pub struct GooglePlayChannelExtensionBuilder
+{
+    author: Option<String>,
+    block: Option<String>,
+    categories: Vec<String>,
+    description: Option<String>,
+    email: Option<String>,
+    explicit: Option<String>,
+    image: Option<String>,
+}
+
+
+/// The Google Play tags of an `Item`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GooglePlayItemExtension
+{
+    /// The author of the episode.
+    pub author: Option<String>,
+    /// Whether the episode is hidden from Google Play, `yes` or `no`.
+    pub block: Option<String>,
+    /// The description of the episode.
+    pub description: Option<String>,
+    /// Whether the episode is explicit, `yes`, `no` or `clean`.
+    pub explicit: Option<String>,
+    /// The URL of the artwork of the episode.
+    pub image: Option<String>,
+}
+
+
+/// The Getter functions for `GooglePlayItemExtension`
+pub trait GooglePlayItemExtensionGetters
+{
+    /// Get the optional author that exists under `GooglePlayItemExtension`.
+    fn author(&self) -> Option<String>;
+
+    /// Get the optional block that exists under `GooglePlayItemExtension`.
+    fn block(&self) -> Option<String>;
+
+    /// Get the optional description that exists under `GooglePlayItemExtension`.
+    fn description(&self) -> Option<String>;
+
+    /// Get the optional explicit that exists under `GooglePlayItemExtension`.
+    fn explicit(&self) -> Option<String>;
+
+    /// Get the optional image that exists under `GooglePlayItemExtension`.
+    fn image(&self) -> Option<String>;
+}
+
+
+/// This `GooglePlayItemExtensionBuilder` struct creates the `GooglePlayItemExtension`.
+#[derive(Clone, Default)]
+pub struct GooglePlayItemExtensionBuilder
+{
+    author: Option<String>,
+    block: Option<String>,
+    description: Option<String>,
+    explicit: Option<String>,
+    image: Option<String>,
+}
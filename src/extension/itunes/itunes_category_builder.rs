@@ -12,7 +12,7 @@
 //! `ITunesCategoryBuilder`.
 
 
-use extension::itunes::ITunesCategoryBuilder;
+use extension::itunes::{ITunesCategoryBuilder, itunes_category_taxonomy};
 use rss::extension::itunes::ITunesCategory;
 
 
@@ -72,6 +72,90 @@ impl ITunesCategoryBuilder
     }
 
 
+    /// Validate the contents of `ITunesCategory` against the Apple Podcasts
+    /// categories, ignoring case.
+    ///
+    /// The subcategory must be one of the category, and must not have a
+    /// subcategory of its own. Misspelled categories are rejected with the
+    /// closest category. Retired categories are accepted, and
+    /// `Validate::warnings` names their replacement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let subcategory = ITunesCategoryBuilder::new()
+    ///     .text("Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let category = ITunesCategoryBuilder::new()
+    ///     .text("news")
+    ///     .subcategory(Some(Box::new(subcategory)))
+    ///     .validate()
+    ///     .unwrap()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let err = ITunesCategoryBuilder::new()
+    ///     .text("Technolgy")
+    ///     .validate()
+    ///     .map(|_| ())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!("Invalid value: Technolgy. Did you mean Technology?", err);
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let subcategory = ITunesCategoryBuilder::new()
+    ///     .text("Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let result = ITunesCategoryBuilder::new()
+    ///     .text("Technology")
+    ///     .subcategory(Some(Box::new(subcategory)))
+    ///     .validate()
+    ///     .map(|_| ());
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut ITunesCategoryBuilder, String>
+    {
+        let subcategory = match self.subcategory
+        {
+            None => None,
+            Some(ref subcategory) =>
+            {
+                if subcategory.subcategory.is_some()
+                {
+                    return Err(format!("Invalid value: {} > {}. Subcategories cannot have a subcategory.",
+                                       self.text,
+                                       subcategory.text));
+                }
+                Some(subcategory.text.as_str())
+            }
+        };
+
+        if let Err(err) = itunes_category_taxonomy::normalize(self.text.as_str(), subcategory)
+        {
+            if itunes_category_taxonomy::replacement(self.text.as_str(), subcategory).is_none()
+            {
+                return Err(err);
+            }
+        }
+
+        Ok(self)
+    }
+
+
     /// Construct the `ITunesCategory` from the `ITunesCategoryBuilder`.
     ///
     /// # Examples
@@ -0,0 +1,384 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The Apple Podcasts categories and subcategories, for checking and picking
+//! the `itunes:category` of a `Channel`.
+//!
+//! Names are matched ignoring case, and the categories Apple has retired are
+//! mapped to the ones that replaced them.
+
+
+use utils::string_utils;
+
+
+/// The Apple Podcasts categories, each with its subcategories.
+pub const CATEGORIES: &[(&str, &[&str])] = &[("Arts",
+                                              &["Books",
+                                                "Design",
+                                                "Fashion & Beauty",
+                                                "Food",
+                                                "Performing Arts",
+                                                "Visual Arts"]),
+                                             ("Business",
+                                              &["Careers",
+                                                "Entrepreneurship",
+                                                "Investing",
+                                                "Management",
+                                                "Marketing",
+                                                "Non-Profit"]),
+                                             ("Comedy", &["Comedy Interviews", "Improv", "Stand-Up"]),
+                                             ("Education",
+                                              &["Courses", "How To", "Language Learning", "Self-Improvement"]),
+                                             ("Fiction", &["Comedy Fiction", "Drama", "Science Fiction"]),
+                                             ("Government", &[]),
+                                             ("Health & Fitness",
+                                              &["Alternative Health",
+                                                "Fitness",
+                                                "Medicine",
+                                                "Mental Health",
+                                                "Nutrition",
+                                                "Sexuality"]),
+                                             ("History", &[]),
+                                             ("Kids & Family",
+                                              &["Education for Kids",
+                                                "Parenting",
+                                                "Pets & Animals",
+                                                "Stories for Kids"]),
+                                             ("Leisure",
+                                              &["Animation & Manga",
+                                                "Automotive",
+                                                "Aviation",
+                                                "Crafts",
+                                                "Games",
+                                                "Hobbies",
+                                                "Home & Garden",
+                                                "Video Games"]),
+                                             ("Music", &["Music Commentary", "Music History", "Music Interviews"]),
+                                             ("News",
+                                              &["Business News",
+                                                "Daily News",
+                                                "Entertainment News",
+                                                "News Commentary",
+                                                "Politics",
+                                                "Sports News",
+                                                "Tech News"]),
+                                             ("Religion & Spirituality",
+                                              &["Buddhism",
+                                                "Christianity",
+                                                "Hinduism",
+                                                "Islam",
+                                                "Judaism",
+                                                "Religion",
+                                                "Spirituality"]),
+                                             ("Science",
+                                              &["Astronomy",
+                                                "Chemistry",
+                                                "Earth Sciences",
+                                                "Life Sciences",
+                                                "Mathematics",
+                                                "Natural Sciences",
+                                                "Nature",
+                                                "Physics",
+                                                "Social Sciences"]),
+                                             ("Society & Culture",
+                                              &["Documentary",
+                                                "Personal Journals",
+                                                "Philosophy",
+                                                "Places & Travel",
+                                                "Relationships"]),
+                                             ("Sports",
+                                              &["Baseball",
+                                                "Basketball",
+                                                "Cricket",
+                                                "Fantasy Sports",
+                                                "Football",
+                                                "Golf",
+                                                "Hockey",
+                                                "Rugby",
+                                                "Running",
+                                                "Soccer",
+                                                "Swimming",
+                                                "Tennis",
+                                                "Volleyball",
+                                                "Wilderness",
+                                                "Wrestling"]),
+                                             ("Technology", &[]),
+                                             ("True Crime", &[]),
+                                             ("TV & Film",
+                                              &["After Shows",
+                                                "Film History",
+                                                "Film Interviews",
+                                                "Film Reviews",
+                                                "TV Reviews"])];
+
+
+// A category and its optional subcategory.
+type CategoryPath = (&'static str, Option<&'static str>);
+
+
+// The retired categories, with and without a subcategory, and the ones that
+// replaced them.
+const DEPRECATED: &[(CategoryPath, CategoryPath)] =
+    &[(("Arts", Some("Literature")), ("Arts", Some("Books"))),
+      (("Business", Some("Business News")), ("News", Some("Business News"))),
+      (("Business", Some("Management & Marketing")), ("Business", Some("Marketing"))),
+      (("Business", Some("Shopping")), ("Business", None)),
+      (("Education", Some("Educational Technology")), ("Technology", None)),
+      (("Education", Some("Higher Education")), ("Education", Some("Courses"))),
+      (("Education", Some("K-12")), ("Kids & Family", Some("Education for Kids"))),
+      (("Education", Some("Language Courses")), ("Education", Some("Language Learning"))),
+      (("Education", Some("Training")), ("Education", Some("How To"))),
+      (("Games & Hobbies", Some("Automotive")), ("Leisure", Some("Automotive"))),
+      (("Games & Hobbies", Some("Aviation")), ("Leisure", Some("Aviation"))),
+      (("Games & Hobbies", Some("Hobbies")), ("Leisure", Some("Hobbies"))),
+      (("Games & Hobbies", Some("Other Games")), ("Leisure", Some("Games"))),
+      (("Games & Hobbies", Some("Video Games")), ("Leisure", Some("Video Games"))),
+      (("Games & Hobbies", None), ("Leisure", None)),
+      (("Government & Organizations", Some("Non-Profit")), ("Business", Some("Non-Profit"))),
+      (("Government & Organizations", None), ("Government", None)),
+      (("Health", Some("Alternative Health")), ("Health & Fitness", Some("Alternative Health"))),
+      (("Health", Some("Fitness & Nutrition")), ("Health & Fitness", Some("Fitness"))),
+      (("Health", Some("Self-Help")), ("Education", Some("Self-Improvement"))),
+      (("Health", Some("Sexuality")), ("Health & Fitness", Some("Sexuality"))),
+      (("Health", None), ("Health & Fitness", None)),
+      (("News & Politics", None), ("News", None)),
+      (("Religion & Spirituality", Some("Other")), ("Religion & Spirituality", Some("Religion"))),
+      (("Science & Medicine", Some("Medicine")), ("Health & Fitness", Some("Medicine"))),
+      (("Science & Medicine", Some("Natural Sciences")), ("Science", Some("Natural Sciences"))),
+      (("Science & Medicine", Some("Social Sciences")), ("Science", Some("Social Sciences"))),
+      (("Science & Medicine", None), ("Science", None)),
+      (("Society & Culture", Some("History")), ("History", None)),
+      (("Sports & Recreation", Some("Outdoor")), ("Sports", Some("Wilderness"))),
+      (("Sports & Recreation", None), ("Sports", None)),
+      (("Technology", Some("Gadgets")), ("Technology", None)),
+      (("Technology", Some("Podcasting")), ("Technology", None)),
+      (("Technology", Some("Software How-To")), ("Technology", None)),
+      (("Technology", Some("Tech News")), ("News", Some("Tech News")))];
+
+
+/// Get the names of the Apple Podcasts categories, for a category picker.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// let categories = itunes_category_taxonomy::categories();
+/// assert_eq!(19, categories.len());
+/// assert_eq!("Arts", categories[0]);
+/// ```
+pub fn categories() -> Vec<&'static str>
+{
+    CATEGORIES.iter().map(|&(category, _)| category).collect()
+}
+
+
+/// Get the names of the subcategories of a category, ignoring case. The list
+/// is empty when the category has none or is not an Apple Podcasts category.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// let subcategories = itunes_category_taxonomy::subcategories("comedy");
+/// assert_eq!(vec!["Comedy Interviews", "Improv", "Stand-Up"], subcategories);
+///
+/// assert!(itunes_category_taxonomy::subcategories("Technology").is_empty());
+/// ```
+pub fn subcategories(category: &str) -> Vec<&'static str>
+{
+    CATEGORIES.iter()
+        .find(|&&(name, _)| name.eq_ignore_ascii_case(category.trim()))
+        .map(|&(_, subcategories)| subcategories.to_vec())
+        .unwrap_or_default()
+}
+
+
+/// Get the name of a category as Apple Podcasts writes it, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// assert_eq!(Some("TV & Film"), itunes_category_taxonomy::find_category("tv & film"));
+/// assert!(itunes_category_taxonomy::find_category("Games & Hobbies").is_none());
+/// ```
+pub fn find_category(category: &str) -> Option<&'static str>
+{
+    categories().into_iter().find(|name| name.eq_ignore_ascii_case(category.trim()))
+}
+
+
+/// Get the name of a subcategory of a category as Apple Podcasts writes it,
+/// ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// assert_eq!(Some("Tech News"), itunes_category_taxonomy::find_subcategory("News", "tech news"));
+/// assert!(itunes_category_taxonomy::find_subcategory("Technology", "Tech News").is_none());
+/// ```
+pub fn find_subcategory(category: &str, subcategory: &str) -> Option<&'static str>
+{
+    subcategories(category).into_iter().find(|name| name.eq_ignore_ascii_case(subcategory.trim()))
+}
+
+
+/// Get the closest category to a misspelled one, when one is close enough.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// assert_eq!(Some("Technology"), itunes_category_taxonomy::suggest_category("Technolgy"));
+/// assert!(itunes_category_taxonomy::suggest_category("Cooking").is_none());
+/// ```
+pub fn suggest_category(category: &str) -> Option<&'static str>
+{
+    closest(category, categories())
+}
+
+
+/// Get the closest subcategory of a category to a misspelled one, when one is
+/// close enough.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// assert_eq!(Some("Stand-Up"), itunes_category_taxonomy::suggest_subcategory("Comedy", "Standup"));
+/// ```
+pub fn suggest_subcategory(category: &str, subcategory: &str) -> Option<&'static str>
+{
+    closest(subcategory, subcategories(category))
+}
+
+
+/// Get the category, and subcategory, that replaced a retired Apple Podcasts
+/// category, ignoring case.
+///
+/// A retired category with a subcategory that has no replacement of its own
+/// maps to the replacement of the category.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// assert_eq!(Some(("News", Some("Tech News"))),
+///            itunes_category_taxonomy::replacement("Technology", Some("Tech News")));
+/// assert_eq!(Some(("Leisure", None)),
+///            itunes_category_taxonomy::replacement("Games & Hobbies", Some("Board Games")));
+/// assert!(itunes_category_taxonomy::replacement("Comedy", None).is_none());
+/// ```
+pub fn replacement(category: &str, subcategory: Option<&str>) -> Option<(&'static str, Option<&'static str>)>
+{
+    let matches = |name: &str, other: Option<&str>| {
+        name.eq_ignore_ascii_case(category.trim()) &&
+        match (other, subcategory)
+        {
+            (Some(other), Some(subcategory)) => other.eq_ignore_ascii_case(subcategory.trim()),
+            (None, _) => true,
+            (Some(_), None) => false,
+        }
+    };
+
+    DEPRECATED.iter()
+        .find(|&&((name, other), _)| other.is_some() && matches(name, other))
+        .or_else(|| DEPRECATED.iter().find(|&&((name, other), _)| other.is_none() && matches(name, other)))
+        .map(|&(_, replacement)| replacement)
+}
+
+
+/// Check a category, and subcategory, against the Apple Podcasts categories,
+/// ignoring case, and get their names as Apple Podcasts writes them.
+///
+/// The error names the replacement of a retired category, or the closest
+/// category to a misspelled one.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::itunes::itunes_category_taxonomy;
+///
+/// assert_eq!(Ok(("Science", Some("Physics"))),
+///            itunes_category_taxonomy::normalize("science", Some("PHYSICS")));
+///
+/// let err = itunes_category_taxonomy::normalize("Sports & Recreation", None).unwrap_err();
+/// assert_eq!("Deprecated value: Sports & Recreation. Use Sports instead.", err);
+///
+/// let err = itunes_category_taxonomy::normalize("Comdy", None).unwrap_err();
+/// assert_eq!("Invalid value: Comdy. Did you mean Comedy?", err);
+/// ```
+pub fn normalize(category: &str, subcategory: Option<&str>) -> Result<(&'static str, Option<&'static str>), String>
+{
+    let name = match find_category(category)
+    {
+        Some(name) => name,
+        None => return Err(category_error(category, subcategory, suggest_category(category))),
+    };
+
+    match subcategory
+    {
+        None => Ok((name, None)),
+        Some(subcategory) =>
+        {
+            match find_subcategory(name, subcategory)
+            {
+                Some(subname) => Ok((name, Some(subname))),
+                None => Err(category_error(category, Some(subcategory), suggest_subcategory(name, subcategory))),
+            }
+        }
+    }
+}
+
+
+fn category_error(category: &str, subcategory: Option<&str>, suggestion: Option<&str>) -> String
+{
+    let path = match subcategory
+    {
+        Some(subcategory) => format!("{} > {}", category.trim(), subcategory.trim()),
+        None => category.trim().to_owned(),
+    };
+
+    if let Some((replacement, subreplacement)) = replacement(category, subcategory)
+    {
+        let replacement = match subreplacement
+        {
+            Some(subreplacement) => format!("{} > {}", replacement, subreplacement),
+            None => replacement.to_owned(),
+        };
+        return format!("Deprecated value: {}. Use {} instead.", path, replacement);
+    }
+
+    match suggestion
+    {
+        Some(suggestion) => format!("Invalid value: {}. Did you mean {}?", path, suggestion),
+        None => format!("Invalid value: {}", path),
+    }
+}
+
+
+// The closest name, when it is at most a third of the name away.
+fn closest(name: &str, names: Vec<&'static str>) -> Option<&'static str>
+{
+    let name = name.trim().to_lowercase();
+    names.into_iter()
+        .map(|candidate| (string_utils::edit_distance(name.as_str(), candidate.to_lowercase().as_str()), candidate))
+        .filter(|&(distance, candidate)| distance > 0 && distance * 3 <= candidate.len())
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
@@ -12,8 +12,10 @@
 //! `ITunesChannelExtensionBuilder`.
 
 
-use extension::itunes::ITunesChannelExtensionBuilder;
+use enums::{ITunesBlock, ITunesComplete, ITunesExplicit};
+use extension::itunes::{ITunesCategoryBuilder, ITunesChannelExtensionBuilder, ITunesOwnerBuilder};
 use rss::extension::itunes::{ITunesCategory, ITunesChannelExtension, ITunesOwner};
+use utils::string_utils;
 
 
 impl ITunesChannelExtensionBuilder
@@ -240,6 +242,216 @@ impl ITunesChannelExtensionBuilder
     }
 
 
+    /// Set the optional explicit that exists under `ITunesChannelExtension`
+    /// from an `ITunesExplicit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesExplicit;
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut channel_builder = ITunesChannelExtensionBuilder::new();
+    /// channel_builder.explicit_value(Some(ITunesExplicit::Clean));
+    /// ```
+    pub fn explicit_value(&mut self, explicit: Option<ITunesExplicit>) -> &mut ITunesChannelExtensionBuilder
+    {
+        self.explicit = explicit.map(|explicit| explicit.as_str().to_owned());
+        self
+    }
+
+
+    /// Set the optional block that exists under `ITunesChannelExtension` from
+    /// an `ITunesBlock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesBlock;
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut channel_builder = ITunesChannelExtensionBuilder::new();
+    /// channel_builder.block_value(Some(ITunesBlock::No));
+    /// ```
+    pub fn block_value(&mut self, block: Option<ITunesBlock>) -> &mut ITunesChannelExtensionBuilder
+    {
+        self.block = block.map(|block| block.as_str().to_owned());
+        self
+    }
+
+
+    /// Set the optional complete that exists under `ITunesChannelExtension`
+    /// from an `ITunesComplete`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesComplete;
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut channel_builder = ITunesChannelExtensionBuilder::new();
+    /// channel_builder.complete_value(Some(ITunesComplete::Yes));
+    /// ```
+    pub fn complete_value(&mut self, complete: Option<ITunesComplete>) -> &mut ITunesChannelExtensionBuilder
+    {
+        self.complete = complete.map(|complete| complete.as_str().to_owned());
+        self
+    }
+
+
+    /// Set the keywords that exists under `ITunesChannelExtension` from a
+    /// list, joined with commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut channel_builder = ITunesChannelExtensionBuilder::new();
+    /// channel_builder.keyword_list(vec!["linux".to_owned(), "podcast".to_owned()]);
+    /// ```
+    pub fn keyword_list(&mut self, keywords: Vec<String>) -> &mut ITunesChannelExtensionBuilder
+    {
+        self.keywords = if keywords.is_empty() { None } else { Some(keywords.join(",")) };
+        self
+    }
+
+
+    /// Set the optional show type, `episodic` or `serial`.
+    ///
+    /// `ITunesChannelExtension` has no field for it, so it is kept in the
+    /// `itunes` extensions of the `Channel` built with `ChannelBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut channel_builder = ITunesChannelExtensionBuilder::new();
+    /// channel_builder.show_type(Some("serial".to_owned()));
+    /// ```
+    pub fn show_type(&mut self, show_type: Option<String>) -> &mut ITunesChannelExtensionBuilder
+    {
+        self.show_type = show_type;
+        self
+    }
+
+
+    /// Set the optional title of the show.
+    ///
+    /// `ITunesChannelExtension` has no field for it, so it is kept in the
+    /// `itunes` extensions of the `Channel` built with `ChannelBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut channel_builder = ITunesChannelExtensionBuilder::new();
+    /// channel_builder.title(Some("The Linux Action Show!".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut ITunesChannelExtensionBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Validate the contents of `ITunesChannelExtension`.
+    ///
+    /// Explicit must be `yes`, `no`, `clean`, `true` or `false`, block and
+    /// complete `yes` or `no`, the image and the new feed URL must be URLs,
+    /// the owner and the categories must be valid, the show type `episodic`
+    /// or `serial`, and the title cannot be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let channel = ITunesChannelExtensionBuilder::new()
+    ///     .explicit(Some("no".to_owned()))
+    ///     .block(Some("No".to_owned()))
+    ///     .image(Some("http://www.jupiterbroadcasting.com/images/LAS-300-Badge.jpg".to_owned()))
+    ///     .validate()
+    ///     .unwrap()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let result = ITunesChannelExtensionBuilder::new()
+    ///     .explicit(Some("maybe".to_owned()))
+    ///     .validate()
+    ///     .map(|_| ());
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut ITunesChannelExtensionBuilder, String>
+    {
+        if let Some(ref block) = self.block
+        {
+            ITunesBlock::value_of(block.as_str())?;
+        }
+
+        if let Some(ref explicit) = self.explicit
+        {
+            ITunesExplicit::value_of(explicit.as_str())?;
+        }
+
+        if let Some(ref complete) = self.complete
+        {
+            ITunesComplete::value_of(complete.as_str())?;
+        }
+
+        if let Some(ref image) = self.image
+        {
+            string_utils::str_to_url(image.as_str())?;
+        }
+
+        if let Some(ref new_feed_url) = self.new_feed_url
+        {
+            string_utils::str_to_url(new_feed_url.as_str())?;
+        }
+
+        if let Some(ref owner) = self.owner
+        {
+            ITunesOwnerBuilder::new()
+                .name(owner.name.clone())
+                .email(owner.email.clone())
+                .validate()?;
+        }
+
+        for category in &self.categories
+        {
+            ITunesCategoryBuilder::new()
+                .text(category.text.as_str())
+                .subcategory(category.subcategory.clone())
+                .validate()?;
+        }
+
+        if let Some(ref show_type) = self.show_type
+        {
+            if show_type != "episodic" && show_type != "serial"
+            {
+                return Err(format!("Invalid value: {}", show_type));
+            }
+        }
+
+        if let Some(ref title) = self.title
+        {
+            if title.trim().is_empty()
+            {
+                return Err("Title must not be empty.".to_owned());
+            }
+        }
+
+        Ok(self)
+    }
+
+
     /// Construct the `ITunesChannelExtension` from the
     /// `ITunesChannelExtensionBuilder`.
     ///
@@ -300,3 +512,42 @@ impl ITunesChannelExtensionBuilder
            })
     }
 }
+
+
+impl From<ITunesChannelExtension> for ITunesChannelExtensionBuilder
+{
+    /// Construct an `ITunesChannelExtensionBuilder` from the fields of an
+    /// `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let channel = ITunesChannelExtensionBuilder::new()
+    ///     .author(Some("author".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel_builder = ITunesChannelExtensionBuilder::from(channel.clone());
+    /// assert_eq!(channel, channel_builder.finalize().unwrap());
+    /// ```
+    fn from(channel: ITunesChannelExtension) -> ITunesChannelExtensionBuilder
+    {
+        ITunesChannelExtensionBuilder {
+            author: channel.author,
+            block: channel.block,
+            categories: channel.categories,
+            image: channel.image,
+            explicit: channel.explicit,
+            complete: channel.complete,
+            new_feed_url: channel.new_feed_url,
+            owner: channel.owner,
+            subtitle: channel.subtitle,
+            summary: channel.summary,
+            keywords: channel.keywords,
+            show_type: None,
+            title: None,
+        }
+    }
+}
@@ -12,8 +12,10 @@
 //! `ITunesChannelExtension`.
 
 
+use enums::{ITunesBlock, ITunesComplete, ITunesExplicit};
 use extension::itunes::ITunesChannelExtensionGetters;
 use rss::extension::itunes::{ITunesCategory, ITunesChannelExtension, ITunesOwner};
+use utils::string_utils;
 
 
 impl ITunesChannelExtensionGetters for ITunesChannelExtension
@@ -444,4 +446,90 @@ impl ITunesChannelExtensionGetters for ITunesChannelExtension
     {
         self.keywords.clone()
     }
+
+
+    /// Get the optional explicit that exists under `ITunesChannelExtension` as
+    /// an `ITunesExplicit`, or `None` when it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesExplicit;
+    /// use feed::extension::itunes::{ITunesChannelExtensionBuilder, ITunesChannelExtensionGetters};
+    ///
+    /// let channel = ITunesChannelExtensionBuilder::new()
+    ///     .explicit(Some("true".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(ITunesExplicit::Yes), channel.explicit_value());
+    /// ```
+    fn explicit_value(&self) -> Option<ITunesExplicit>
+    {
+        self.explicit.as_ref().and_then(|explicit| ITunesExplicit::value_of(explicit).ok())
+    }
+
+
+    /// Get the optional block that exists under `ITunesChannelExtension` as an
+    /// `ITunesBlock`, or `None` when it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesBlock;
+    /// use feed::extension::itunes::{ITunesChannelExtensionBuilder, ITunesChannelExtensionGetters};
+    ///
+    /// let channel = ITunesChannelExtensionBuilder::new()
+    ///     .block_value(Some(ITunesBlock::Yes))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(ITunesBlock::Yes), channel.block_value());
+    /// ```
+    fn block_value(&self) -> Option<ITunesBlock>
+    {
+        self.block.as_ref().and_then(|block| ITunesBlock::value_of(block).ok())
+    }
+
+
+    /// Get the optional complete that exists under `ITunesChannelExtension` as
+    /// an `ITunesComplete`, or `None` when it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesComplete;
+    /// use feed::extension::itunes::{ITunesChannelExtensionBuilder, ITunesChannelExtensionGetters};
+    ///
+    /// let channel = ITunesChannelExtensionBuilder::new()
+    ///     .complete(Some("Yes".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(ITunesComplete::Yes), channel.complete_value());
+    /// ```
+    fn complete_value(&self) -> Option<ITunesComplete>
+    {
+        self.complete.as_ref().and_then(|complete| ITunesComplete::value_of(complete).ok())
+    }
+
+
+    /// Get the keywords that exists under `ITunesChannelExtension` as a list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesChannelExtensionBuilder, ITunesChannelExtensionGetters};
+    ///
+    /// let channel = ITunesChannelExtensionBuilder::new()
+    ///     .keywords(Some("linux, podcast,".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec!["linux".to_owned(), "podcast".to_owned()], channel.keyword_list());
+    /// ```
+    fn keyword_list(&self) -> Vec<String>
+    {
+        self.keywords.as_ref().map(|keywords| string_utils::keywords_to_vec(keywords)).unwrap_or_default()
+    }
 }
@@ -0,0 +1,110 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Writing the `itunes` tags of an `ITunesChannelExtensionBuilder` or an
+//! `ITunesItemExtensionBuilder` that the `rss` crate does not cover.
+
+
+use extension::itunes::{ITunesChannelExtensionBuilder, ITunesItemExtensionBuilder};
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+
+
+impl ITunesChannelExtensionBuilder
+{
+    /// Add the show type and the title to the `itunes` extensions of an
+    /// `ExtensionMap`, as `ITunesChannelExtension` has no fields for them.
+    ///
+    /// Only the tags that are set are replaced, so those already in the
+    /// extensions are kept when they are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut extensions = ExtensionMap::new();
+    ///     ITunesChannelExtensionBuilder::new()
+    ///         .show_type(Some("serial".to_owned()))
+    ///         .insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(Some("serial".to_owned()), extensions["itunes"]["type"][0].value);
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        insert_text(extensions, "type", &self.show_type);
+        insert_text(extensions, "title", &self.title);
+    }
+}
+
+
+impl ITunesItemExtensionBuilder
+{
+    /// Add the title, the episode and season numbers and the episode type to
+    /// the `itunes` extensions of an `ExtensionMap`, as `ITunesItemExtension`
+    /// has no fields for them.
+    ///
+    /// Only the tags that are set are replaced, so those already in the
+    /// extensions are kept when they are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut extensions = ExtensionMap::new();
+    ///     ITunesItemExtensionBuilder::new()
+    ///         .episode(Some(408))
+    ///         .episode_type(Some("bonus".to_owned()))
+    ///         .insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(Some("408".to_owned()), extensions["itunes"]["episode"][0].value);
+    ///     assert_eq!(Some("bonus".to_owned()), extensions["itunes"]["episodeType"][0].value);
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        insert_text(extensions, "title", &self.title);
+        insert_text(extensions, "episode", &self.episode.map(|episode| episode.to_string()));
+        insert_text(extensions, "season", &self.season.map(|season| season.to_string()));
+        insert_text(extensions, "episodeType", &self.episode_type);
+    }
+}
+
+
+// Replace the `name` element under the `itunes` prefix when there is a value.
+fn insert_text(extensions: &mut ExtensionMap, name: &str, value: &Option<String>)
+{
+    if let Some(ref value) = *value
+    {
+        extensions.entry("itunes".to_owned())
+            .or_default()
+            .insert(name.to_owned(),
+                    vec![Extension {
+                             name: format!("itunes:{}", name),
+                             value: Some(value.clone()),
+                             attrs: HashMap::new(),
+                             children: HashMap::new(),
+                         }]);
+    }
+}
@@ -12,8 +12,11 @@
 //! `ITunesItemExtensionBuilder`.
 
 
+use enums::{ITunesBlock, ITunesExplicit};
 use extension::itunes::ITunesItemExtensionBuilder;
 use rss::extension::itunes::ITunesItemExtension;
+use std::time::Duration;
+use utils::string_utils;
 
 
 impl ITunesItemExtensionBuilder
@@ -204,6 +207,260 @@ impl ITunesItemExtensionBuilder
     }
 
 
+    /// Set the optional explicit that exists under `ITunesItemExtension` from
+    /// an `ITunesExplicit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesExplicit;
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.explicit_value(Some(ITunesExplicit::Yes));
+    /// ```
+    pub fn explicit_value(&mut self, explicit: Option<ITunesExplicit>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.explicit = explicit.map(|explicit| explicit.as_str().to_owned());
+        self
+    }
+
+
+    /// Set the optional block that exists under `ITunesItemExtension` from an
+    /// `ITunesBlock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesBlock;
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.block_value(Some(ITunesBlock::Yes));
+    /// ```
+    pub fn block_value(&mut self, block: Option<ITunesBlock>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.block = block.map(|block| block.as_str().to_owned());
+        self
+    }
+
+
+    /// Set the optional duration that exists under `ITunesItemExtension` from
+    /// a `Duration`, written as `HH:MM:SS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.duration_value(Some(Duration::from_secs(3333)));
+    /// ```
+    pub fn duration_value(&mut self, duration: Option<Duration>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.duration = duration.map(string_utils::duration_to_itunes_duration);
+        self
+    }
+
+
+    /// Set the keywords that exists under `ITunesItemExtension` from a list,
+    /// joined with commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.keyword_list(vec!["linux".to_owned(), "ubuntu".to_owned()]);
+    /// ```
+    pub fn keyword_list(&mut self, keywords: Vec<String>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.keywords = if keywords.is_empty() { None } else { Some(keywords.join(",")) };
+        self
+    }
+
+
+    /// Set the optional title of the episode, without the show or episode
+    /// number.
+    ///
+    /// `ITunesItemExtension` has no field for it, so it is kept in the
+    /// `itunes` extensions of the `Item` built with `ItemBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.title(Some("Making Music with Linux".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional number of the episode.
+    ///
+    /// `ITunesItemExtension` has no field for it, so it is kept in the
+    /// `itunes` extensions of the `Item` built with `ItemBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.episode(Some(408));
+    /// ```
+    pub fn episode(&mut self, episode: Option<i64>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.episode = episode;
+        self
+    }
+
+
+    /// Set the optional number of the season the episode is in.
+    ///
+    /// `ITunesItemExtension` has no field for it, so it is kept in the
+    /// `itunes` extensions of the `Item` built with `ItemBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.season(Some(2));
+    /// ```
+    pub fn season(&mut self, season: Option<i64>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.season = season;
+        self
+    }
+
+
+    /// Set the optional episode type, `full`, `trailer` or `bonus`.
+    ///
+    /// `ITunesItemExtension` has no field for it, so it is kept in the
+    /// `itunes` extensions of the `Item` built with `ItemBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut item_builder = ITunesItemExtensionBuilder::new();
+    /// item_builder.episode_type(Some("full".to_owned()));
+    /// ```
+    pub fn episode_type(&mut self, episode_type: Option<String>) -> &mut ITunesItemExtensionBuilder
+    {
+        self.episode_type = episode_type;
+        self
+    }
+
+
+    /// Validate the contents of `ITunesItemExtension`.
+    ///
+    /// Explicit must be `yes`, `no`, `clean`, `true` or `false`, block and
+    /// closed captioned `yes` or `no`, the duration `HH:MM:SS`, `MM:SS` or a
+    /// number of seconds, where the seconds can have a fraction, the order a
+    /// number and the image a URL. The title
+    /// cannot be empty, the episode and season numbers must be positive and
+    /// the episode type `full`, `trailer` or `bonus`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let item = ITunesItemExtensionBuilder::new()
+    ///     .duration(Some("1:02:03".to_owned()))
+    ///     .explicit(Some("false".to_owned()))
+    ///     .order(Some("2".to_owned()))
+    ///     .validate()
+    ///     .unwrap()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let result = ITunesItemExtensionBuilder::new()
+    ///     .duration(Some("1:75".to_owned()))
+    ///     .validate()
+    ///     .map(|_| ());
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut ITunesItemExtensionBuilder, String>
+    {
+        if let Some(ref block) = self.block
+        {
+            ITunesBlock::value_of(block.as_str())?;
+        }
+
+        if let Some(ref explicit) = self.explicit
+        {
+            ITunesExplicit::value_of(explicit.as_str())?;
+        }
+
+        if let Some(ref duration) = self.duration
+        {
+            string_utils::itunes_duration_to_duration(duration.as_str())?;
+        }
+
+        if let Some(ref closed_captioned) = self.closed_captioned
+        {
+            let closed_captioned = closed_captioned.trim().to_lowercase();
+            if closed_captioned != "yes" && closed_captioned != "no"
+            {
+                return Err(format!("Invalid value: {}", closed_captioned));
+            }
+        }
+
+        if let Some(ref order) = self.order
+        {
+            string_utils::string_to_i64(order.trim())?;
+        }
+
+        if let Some(ref image) = self.image
+        {
+            string_utils::str_to_url(image.as_str())?;
+        }
+
+        if let Some(ref title) = self.title
+        {
+            if title.trim().is_empty()
+            {
+                return Err("Title must not be empty.".to_owned());
+            }
+        }
+
+        for number in self.episode.iter().chain(self.season.iter())
+        {
+            if *number < 1
+            {
+                return Err(format!("Invalid value: {}", number));
+            }
+        }
+
+        if let Some(ref episode_type) = self.episode_type
+        {
+            if episode_type != "full" && episode_type != "trailer" && episode_type != "bonus"
+            {
+                return Err(format!("Invalid value: {}", episode_type));
+            }
+        }
+
+        Ok(self)
+    }
+
+
     /// Construct the `ITunesItemExtension` from the
     /// `ITunesItemExtensionBuilder`.
     ///
@@ -242,3 +499,43 @@ impl ITunesItemExtensionBuilder
            })
     }
 }
+
+
+impl From<ITunesItemExtension> for ITunesItemExtensionBuilder
+{
+    /// Construct an `ITunesItemExtensionBuilder` from the fields of an
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let item = ITunesItemExtensionBuilder::new()
+    ///     .duration(Some("55:33".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item_builder = ITunesItemExtensionBuilder::from(item.clone());
+    /// assert_eq!(item, item_builder.finalize().unwrap());
+    /// ```
+    fn from(item: ITunesItemExtension) -> ITunesItemExtensionBuilder
+    {
+        ITunesItemExtensionBuilder {
+            author: item.author,
+            block: item.block,
+            image: item.image,
+            duration: item.duration,
+            explicit: item.explicit,
+            closed_captioned: item.closed_captioned,
+            order: item.order,
+            subtitle: item.subtitle,
+            summary: item.summary,
+            keywords: item.keywords,
+            title: None,
+            episode: None,
+            season: None,
+            episode_type: None,
+        }
+    }
+}
@@ -12,8 +12,11 @@
 //! `ITunesItemExtension`.
 
 
+use enums::{ITunesBlock, ITunesExplicit};
 use extension::itunes::ITunesItemExtensionGetters;
 use rss::extension::itunes::ITunesItemExtension;
+use std::time::Duration;
+use utils::string_utils;
 
 
 impl ITunesItemExtensionGetters for ITunesItemExtension
@@ -388,4 +391,102 @@ impl ITunesItemExtensionGetters for ITunesItemExtension
     {
         self.keywords.clone()
     }
+
+
+    /// Get the optional explicit that exists under `ITunesItemExtension` as an
+    /// `ITunesExplicit`, or `None` when it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesExplicit;
+    /// use feed::extension::itunes::{ITunesItemExtensionBuilder, ITunesItemExtensionGetters};
+    ///
+    /// let item = ITunesItemExtensionBuilder::new()
+    ///     .explicit(Some("clean".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(ITunesExplicit::Clean), item.explicit_value());
+    /// ```
+    fn explicit_value(&self) -> Option<ITunesExplicit>
+    {
+        self.explicit.as_ref().and_then(|explicit| ITunesExplicit::value_of(explicit).ok())
+    }
+
+
+    /// Get the optional block that exists under `ITunesItemExtension` as an
+    /// `ITunesBlock`, or `None` when it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::ITunesBlock;
+    /// use feed::extension::itunes::{ITunesItemExtensionBuilder, ITunesItemExtensionGetters};
+    ///
+    /// let item = ITunesItemExtensionBuilder::new()
+    ///     .block(Some("no".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(ITunesBlock::No), item.block_value());
+    /// ```
+    fn block_value(&self) -> Option<ITunesBlock>
+    {
+        self.block.as_ref().and_then(|block| ITunesBlock::value_of(block).ok())
+    }
+
+
+    /// Get the optional duration that exists under `ITunesItemExtension` as a
+    /// `Duration`, or `None` when it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesItemExtensionBuilder, ITunesItemExtensionGetters};
+    /// use std::time::Duration;
+    ///
+    /// let item = ITunesItemExtensionBuilder::new()
+    ///     .duration(Some("55:33".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(Duration::from_secs(3333)), item.duration_value());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesItemExtensionBuilder, ITunesItemExtensionGetters};
+    /// use std::time::Duration;
+    ///
+    /// let item = ITunesItemExtensionBuilder::new()
+    ///     .duration(Some("3600.5".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(Duration::from_millis(3600500)), item.duration_value());
+    /// ```
+    fn duration_value(&self) -> Option<Duration>
+    {
+        self.duration.as_ref().and_then(|duration| string_utils::itunes_duration_to_duration(duration).ok())
+    }
+
+
+    /// Get the keywords that exists under `ITunesItemExtension` as a list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesItemExtensionBuilder, ITunesItemExtensionGetters};
+    ///
+    /// let item = ITunesItemExtensionBuilder::new()
+    ///     .keyword_list(vec!["linux".to_owned(), "ubuntu".to_owned()])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(vec!["linux".to_owned(), "ubuntu".to_owned()], item.keyword_list());
+    /// ```
+    fn keyword_list(&self) -> Vec<String>
+    {
+        self.keywords.as_ref().map(|keywords| string_utils::keywords_to_vec(keywords)).unwrap_or_default()
+    }
 }
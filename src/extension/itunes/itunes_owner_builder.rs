@@ -67,6 +67,37 @@ impl ITunesOwnerBuilder
     }
 
 
+    /// Validate the contents of `ITunesOwner`.
+    ///
+    /// The email, when there is one, must be an email address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .email(Some("email@example.com".to_owned()))
+    ///     .validate()
+    ///     .unwrap()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut ITunesOwnerBuilder, String>
+    {
+        if let Some(ref email) = self.email
+        {
+            let parts: Vec<&str> = email.trim().split('@').collect();
+            if parts.len() != 2 || parts[0].is_empty() || !parts[1].contains('.')
+            {
+                return Err(format!("Invalid value: {}", email));
+            }
+        }
+
+        Ok(self)
+    }
+
+
     /// Construct the `ITunesOwner` from the `ITunesOwnerBuilder`.
     ///
     /// # Examples
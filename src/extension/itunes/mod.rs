@@ -13,15 +13,19 @@
 
 pub mod itunes_category_getters;
 pub mod itunes_category_builder;
+pub mod itunes_category_taxonomy;
 pub mod itunes_owner_getters;
 pub mod itunes_owner_builder;
 pub mod itunes_item_extension_getters;
 pub mod itunes_item_extension_builder;
 pub mod itunes_channel_extension_getters;
 pub mod itunes_channel_extension_builder;
+pub mod itunes_extension_map;
 
 
+use enums::{ITunesBlock, ITunesComplete, ITunesExplicit};
 use rss::extension::itunes::{ITunesCategory, ITunesOwner};
+use std::time::Duration;
 
 
 /// The Getter functions for `ITunesCategory`
@@ -100,6 +104,21 @@ pub trait ITunesChannelExtensionGetters
 
     /// Get the optional keywords that exists under `ITunesChannelExtension`.
     fn keywords(&self) -> Option<String>;
+
+    /// Get the optional explicit that exists under `ITunesChannelExtension` as
+    /// an `ITunesExplicit`.
+    fn explicit_value(&self) -> Option<ITunesExplicit>;
+
+    /// Get the optional block that exists under `ITunesChannelExtension` as an
+    /// `ITunesBlock`.
+    fn block_value(&self) -> Option<ITunesBlock>;
+
+    /// Get the optional complete that exists under `ITunesChannelExtension` as
+    /// an `ITunesComplete`.
+    fn complete_value(&self) -> Option<ITunesComplete>;
+
+    /// Get the keywords that exists under `ITunesChannelExtension` as a list.
+    fn keyword_list(&self) -> Vec<String>;
 }
 
 
@@ -119,6 +138,8 @@ pub struct ITunesChannelExtensionBuilder
     subtitle: Option<String>,
     summary: Option<String>,
     keywords: Option<String>,
+    show_type: Option<String>,
+    title: Option<String>,
 }
 
 
@@ -155,6 +176,21 @@ pub trait ITunesItemExtensionGetters
 
     /// Get the optional keywords that exists under `ITunesItemExtension`.
     fn keywords(&self) -> Option<String>;
+
+    /// Get the optional explicit that exists under `ITunesItemExtension` as an
+    /// `ITunesExplicit`.
+    fn explicit_value(&self) -> Option<ITunesExplicit>;
+
+    /// Get the optional block that exists under `ITunesItemExtension` as an
+    /// `ITunesBlock`.
+    fn block_value(&self) -> Option<ITunesBlock>;
+
+    /// Get the optional duration that exists under `ITunesItemExtension` as a
+    /// `Duration`.
+    fn duration_value(&self) -> Option<Duration>;
+
+    /// Get the keywords that exists under `ITunesItemExtension` as a list.
+    fn keyword_list(&self) -> Vec<String>;
 }
 
 
@@ -174,4 +210,8 @@ pub struct ITunesItemExtensionBuilder
     subtitle: Option<String>,
     summary: Option<String>,
     keywords: Option<String>,
+    title: Option<String>,
+    episode: Option<i64>,
+    season: Option<i64>,
+    episode_type: Option<String>,
 }
@@ -0,0 +1,136 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for media category by using the methods under
+//! `MediaCategoryBuilder`.
+
+
+use extension::media::{MediaCategory, MediaCategoryBuilder};
+use utils::string_utils;
+
+
+impl MediaCategoryBuilder
+{
+    /// Construct a new `MediaCategoryBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCategoryBuilder;
+    ///
+    /// let media_category_builder = MediaCategoryBuilder::new();
+    /// ```
+    pub fn new() -> MediaCategoryBuilder
+    {
+        MediaCategoryBuilder::default()
+    }
+
+
+    /// Set the category that exists under `MediaCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCategoryBuilder;
+    ///
+    /// let mut media_category_builder = MediaCategoryBuilder::new();
+    /// media_category_builder.value("Technology/Tech News");
+    /// ```
+    pub fn value(&mut self, value: &str) -> &mut MediaCategoryBuilder
+    {
+        self.value = value.to_owned();
+        self
+    }
+
+
+    /// Set the optional scheme that exists under `MediaCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCategoryBuilder;
+    ///
+    /// let mut media_category_builder = MediaCategoryBuilder::new();
+    /// media_category_builder.scheme(Some("http://www.itunes.com/dtds/podcast-1.0.dtd".to_owned()));
+    /// ```
+    pub fn scheme(&mut self, scheme: Option<String>) -> &mut MediaCategoryBuilder
+    {
+        self.scheme = scheme;
+        self
+    }
+
+
+    /// Set the optional label that exists under `MediaCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCategoryBuilder;
+    ///
+    /// let mut media_category_builder = MediaCategoryBuilder::new();
+    /// media_category_builder.label(Some("Tech News".to_owned()));
+    /// ```
+    pub fn label(&mut self, label: Option<String>) -> &mut MediaCategoryBuilder
+    {
+        self.label = label;
+        self
+    }
+
+
+    /// Validate the contents of `MediaCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCategoryBuilder;
+    ///
+    /// let media_category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .scheme(Some("http://www.itunes.com/dtds/podcast-1.0.dtd".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut MediaCategoryBuilder, String>
+    {
+        if self.value.trim().is_empty()
+        {
+            return Err("Media category must have a value.".to_owned());
+        }
+
+        if let Some(ref scheme) = self.scheme
+        {
+            string_utils::str_to_url(scheme.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `MediaCategory` from the `MediaCategoryBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCategoryBuilder;
+    ///
+    /// let media_category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<MediaCategory, String>
+    {
+        Ok(MediaCategory {
+               value: self.value.clone(),
+               scheme: self.scheme.clone(),
+               label: self.label.clone(),
+           })
+    }
+}
@@ -0,0 +1,110 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under media category can be retrieved by using the methods
+//! under `MediaCategory`.
+
+
+use extension::media::{MediaCategoryGetters, MediaCategory};
+
+
+impl MediaCategoryGetters for MediaCategory
+{
+    /// Get the category that exists under `MediaCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaCategoryGetters};
+    ///
+    /// let value = "Technology/Tech News";
+    ///
+    /// let media_category = MediaCategoryBuilder::new()
+    ///     .value(value)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_owned(), media_category.value());
+    /// ```
+    fn value(&self) -> String
+    {
+        self.value.clone()
+    }
+
+
+    /// Get the optional scheme that exists under `MediaCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaCategoryGetters};
+    ///
+    /// let scheme = Some("http://www.itunes.com/dtds/podcast-1.0.dtd".to_owned());
+    ///
+    /// let media_category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .scheme(scheme.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(scheme, media_category.scheme());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaCategoryGetters};
+    ///
+    /// let media_category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .scheme(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_category.scheme().is_none());
+    /// ```
+    fn scheme(&self) -> Option<String>
+    {
+        self.scheme.clone()
+    }
+
+
+    /// Get the optional label that exists under `MediaCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaCategoryGetters};
+    ///
+    /// let label = Some("Tech News".to_owned());
+    ///
+    /// let media_category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .label(label.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(label, media_category.label());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaCategoryGetters};
+    ///
+    /// let media_category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .label(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_category.label().is_none());
+    /// ```
+    fn label(&self) -> Option<String>
+    {
+        self.label.clone()
+    }
+}
@@ -0,0 +1,332 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for media content by using the methods under
+//! `MediaContentBuilder`.
+
+
+use extension::media::{EXPRESSIONS, MEDIUMS, MediaContent, MediaContentBuilder, MediaThumbnail};
+use mime::Mime;
+use utils::string_utils;
+
+
+impl MediaContentBuilder
+{
+    /// Construct a new `MediaContentBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let media_content_builder = MediaContentBuilder::new();
+    /// ```
+    pub fn new() -> MediaContentBuilder
+    {
+        MediaContentBuilder::default()
+    }
+
+
+    /// Set the optional url that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()));
+    /// ```
+    pub fn url(&mut self, url: Option<String>) -> &mut MediaContentBuilder
+    {
+        self.url = url;
+        self
+    }
+
+
+    /// Set the optional file size in bytes that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.file_size(Some(69796480));
+    /// ```
+    pub fn file_size(&mut self, file_size: Option<i64>) -> &mut MediaContentBuilder
+    {
+        self.file_size = file_size;
+        self
+    }
+
+
+    /// Set the optional mime type that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.mime_type(Some("audio/ogg".to_owned()));
+    /// ```
+    pub fn mime_type(&mut self, mime_type: Option<String>) -> &mut MediaContentBuilder
+    {
+        self.mime_type = mime_type;
+        self
+    }
+
+
+    /// Set the optional medium that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.medium(Some("audio".to_owned()));
+    /// ```
+    pub fn medium(&mut self, medium: Option<String>) -> &mut MediaContentBuilder
+    {
+        self.medium = medium;
+        self
+    }
+
+
+    /// Set the optional is default flag that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.is_default(Some(true));
+    /// ```
+    pub fn is_default(&mut self, is_default: Option<bool>) -> &mut MediaContentBuilder
+    {
+        self.is_default = is_default;
+        self
+    }
+
+
+    /// Set the optional expression that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.expression(Some("full".to_owned()));
+    /// ```
+    pub fn expression(&mut self, expression: Option<String>) -> &mut MediaContentBuilder
+    {
+        self.expression = expression;
+        self
+    }
+
+
+    /// Set the optional bitrate in kilobits per second that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.bitrate(Some(128));
+    /// ```
+    pub fn bitrate(&mut self, bitrate: Option<i64>) -> &mut MediaContentBuilder
+    {
+        self.bitrate = bitrate;
+        self
+    }
+
+
+    /// Set the optional duration in seconds that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.duration(Some(4362));
+    /// ```
+    pub fn duration(&mut self, duration: Option<i64>) -> &mut MediaContentBuilder
+    {
+        self.duration = duration;
+        self
+    }
+
+
+    /// Set the optional height in pixels that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.height(Some(720));
+    /// ```
+    pub fn height(&mut self, height: Option<i64>) -> &mut MediaContentBuilder
+    {
+        self.height = height;
+        self
+    }
+
+
+    /// Set the optional width in pixels that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.width(Some(1280));
+    /// ```
+    pub fn width(&mut self, width: Option<i64>) -> &mut MediaContentBuilder
+    {
+        self.width = width;
+        self
+    }
+
+
+    /// Set the optional language that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.lang(Some("en".to_owned()));
+    /// ```
+    pub fn lang(&mut self, lang: Option<String>) -> &mut MediaContentBuilder
+    {
+        self.lang = lang;
+        self
+    }
+
+
+    /// Set the thumbnails that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_content_builder = MediaContentBuilder::new();
+    /// media_content_builder.thumbnails(vec![thumbnail]);
+    /// ```
+    pub fn thumbnails(&mut self, thumbnails: Vec<MediaThumbnail>) -> &mut MediaContentBuilder
+    {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+
+    /// Validate the contents of `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .file_size(Some(69796480))
+    ///     .mime_type(Some("audio/ogg".to_owned()))
+    ///     .medium(Some("audio".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut MediaContentBuilder, String>
+    {
+        match self.url
+        {
+            Some(ref url) => string_utils::str_to_url(url.as_str()).map(|_| ())?,
+            None => return Err("Media content url must have a value.".to_owned()),
+        }
+
+        if let Some(ref mime_type) = self.mime_type
+        {
+            if let Err(err) = mime_type.parse::<Mime>()
+            {
+                return Err(format!("Error: {:?}", err));
+            }
+        }
+
+        if let Some(ref medium) = self.medium
+        {
+            if !MEDIUMS.contains(&medium.as_str())
+            {
+                return Err(format!("Invalid value: {}", medium));
+            }
+        }
+
+        if let Some(ref expression) = self.expression
+        {
+            if !EXPRESSIONS.contains(&expression.as_str())
+            {
+                return Err(format!("Invalid value: {}", expression));
+            }
+        }
+
+        let numbers = [self.file_size, self.bitrate, self.duration, self.height, self.width];
+        if numbers.iter().any(|number| number.is_some_and(|val| val < 0))
+        {
+            return Err("Media content sizes and durations cannot be negative values.".to_owned());
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `MediaContent` from the `MediaContentBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<MediaContent, String>
+    {
+        Ok(MediaContent {
+               url: self.url.clone(),
+               file_size: self.file_size,
+               mime_type: self.mime_type.clone(),
+               medium: self.medium.clone(),
+               is_default: self.is_default,
+               expression: self.expression.clone(),
+               bitrate: self.bitrate,
+               duration: self.duration,
+               height: self.height,
+               width: self.width,
+               lang: self.lang.clone(),
+               thumbnails: self.thumbnails.clone(),
+           })
+    }
+}
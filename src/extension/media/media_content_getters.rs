@@ -0,0 +1,428 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under media content can be retrieved by using the methods
+//! under `MediaContent`.
+
+
+use extension::media::{MediaContent, MediaContentGetters, MediaThumbnail};
+
+
+impl MediaContentGetters for MediaContent
+{
+    /// Get the optional url that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let url = Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned());
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(url.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(url, media_content.url());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.url().is_none());
+    /// ```
+    fn url(&self) -> Option<String>
+    {
+        self.url.clone()
+    }
+
+
+    /// Get the optional file size in bytes that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let file_size = Some(69796480);
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .file_size(file_size)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(file_size, media_content.file_size());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .file_size(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.file_size().is_none());
+    /// ```
+    fn file_size(&self) -> Option<i64>
+    {
+        self.file_size
+    }
+
+
+    /// Get the optional mime type that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let mime_type = Some("audio/ogg".to_owned());
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .mime_type(mime_type.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mime_type, media_content.mime_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .mime_type(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.mime_type().is_none());
+    /// ```
+    fn mime_type(&self) -> Option<String>
+    {
+        self.mime_type.clone()
+    }
+
+
+    /// Get the optional medium that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let medium = Some("audio".to_owned());
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .medium(medium.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(medium, media_content.medium());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .medium(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.medium().is_none());
+    /// ```
+    fn medium(&self) -> Option<String>
+    {
+        self.medium.clone()
+    }
+
+
+    /// Get the optional is default flag that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let is_default = Some(true);
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .is_default(is_default)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(is_default, media_content.is_default());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .is_default(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.is_default().is_none());
+    /// ```
+    fn is_default(&self) -> Option<bool>
+    {
+        self.is_default
+    }
+
+
+    /// Get the optional expression that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let expression = Some("full".to_owned());
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .expression(expression.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(expression, media_content.expression());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .expression(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.expression().is_none());
+    /// ```
+    fn expression(&self) -> Option<String>
+    {
+        self.expression.clone()
+    }
+
+
+    /// Get the optional bitrate in kilobits per second that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let bitrate = Some(128);
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .bitrate(bitrate)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(bitrate, media_content.bitrate());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .bitrate(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.bitrate().is_none());
+    /// ```
+    fn bitrate(&self) -> Option<i64>
+    {
+        self.bitrate
+    }
+
+
+    /// Get the optional duration in seconds that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let duration = Some(4362);
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .duration(duration)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(duration, media_content.duration());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .duration(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.duration().is_none());
+    /// ```
+    fn duration(&self) -> Option<i64>
+    {
+        self.duration
+    }
+
+
+    /// Get the optional height in pixels that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let height = Some(720);
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .height(height)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(height, media_content.height());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .height(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.height().is_none());
+    /// ```
+    fn height(&self) -> Option<i64>
+    {
+        self.height
+    }
+
+
+    /// Get the optional width in pixels that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let width = Some(1280);
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .width(width)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(width, media_content.width());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .width(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.width().is_none());
+    /// ```
+    fn width(&self) -> Option<i64>
+    {
+        self.width
+    }
+
+
+    /// Get the optional language that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let lang = Some("en".to_owned());
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .lang(lang.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(lang, media_content.lang());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters};
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .lang(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_content.lang().is_none());
+    /// ```
+    fn lang(&self) -> Option<String>
+    {
+        self.lang.clone()
+    }
+
+
+    /// Get the thumbnails that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaContentGetters, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let thumbnails = vec![thumbnail];
+    ///
+    /// let media_content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .thumbnails(thumbnails.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_content.thumbnails().len());
+    /// ```
+    fn thumbnails(&self) -> Vec<MediaThumbnail>
+    {
+        self.thumbnails.clone()
+    }
+}
@@ -0,0 +1,136 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for media credit by using the methods under
+//! `MediaCreditBuilder`.
+
+
+use extension::media::{MediaCredit, MediaCreditBuilder};
+use utils::string_utils;
+
+
+impl MediaCreditBuilder
+{
+    /// Construct a new `MediaCreditBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let media_credit_builder = MediaCreditBuilder::new();
+    /// ```
+    pub fn new() -> MediaCreditBuilder
+    {
+        MediaCreditBuilder::default()
+    }
+
+
+    /// Set the name that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let mut media_credit_builder = MediaCreditBuilder::new();
+    /// media_credit_builder.value("Jupiter Broadcasting");
+    /// ```
+    pub fn value(&mut self, value: &str) -> &mut MediaCreditBuilder
+    {
+        self.value = value.to_owned();
+        self
+    }
+
+
+    /// Set the optional role that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let mut media_credit_builder = MediaCreditBuilder::new();
+    /// media_credit_builder.role(Some("author".to_owned()));
+    /// ```
+    pub fn role(&mut self, role: Option<String>) -> &mut MediaCreditBuilder
+    {
+        self.role = role;
+        self
+    }
+
+
+    /// Set the optional scheme that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let mut media_credit_builder = MediaCreditBuilder::new();
+    /// media_credit_builder.scheme(Some("urn:ebu".to_owned()));
+    /// ```
+    pub fn scheme(&mut self, scheme: Option<String>) -> &mut MediaCreditBuilder
+    {
+        self.scheme = scheme;
+        self
+    }
+
+
+    /// Validate the contents of `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let media_credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .role(Some("author".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut MediaCreditBuilder, String>
+    {
+        if self.value.trim().is_empty()
+        {
+            return Err("Media credit must have a value.".to_owned());
+        }
+
+        if let Some(ref scheme) = self.scheme
+        {
+            string_utils::str_to_url(scheme.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `MediaCredit` from the `MediaCreditBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let media_credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<MediaCredit, String>
+    {
+        Ok(MediaCredit {
+               value: self.value.clone(),
+               role: self.role.clone(),
+               scheme: self.scheme.clone(),
+           })
+    }
+}
@@ -0,0 +1,110 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under media credit can be retrieved by using the methods
+//! under `MediaCredit`.
+
+
+use extension::media::{MediaCreditGetters, MediaCredit};
+
+
+impl MediaCreditGetters for MediaCredit
+{
+    /// Get the name that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaCreditGetters};
+    ///
+    /// let value = "Jupiter Broadcasting";
+    ///
+    /// let media_credit = MediaCreditBuilder::new()
+    ///     .value(value)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_owned(), media_credit.value());
+    /// ```
+    fn value(&self) -> String
+    {
+        self.value.clone()
+    }
+
+
+    /// Get the optional role that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaCreditGetters};
+    ///
+    /// let role = Some("author".to_owned());
+    ///
+    /// let media_credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .role(role.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(role, media_credit.role());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaCreditGetters};
+    ///
+    /// let media_credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .role(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_credit.role().is_none());
+    /// ```
+    fn role(&self) -> Option<String>
+    {
+        self.role.clone()
+    }
+
+
+    /// Get the optional scheme that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaCreditGetters};
+    ///
+    /// let scheme = Some("urn:ebu".to_owned());
+    ///
+    /// let media_credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .scheme(scheme.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(scheme, media_credit.scheme());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaCreditGetters};
+    ///
+    /// let media_credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .scheme(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_credit.scheme().is_none());
+    /// ```
+    fn scheme(&self) -> Option<String>
+    {
+        self.scheme.clone()
+    }
+}
@@ -0,0 +1,213 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for media extension by using the methods under
+//! `MediaExtensionBuilder`.
+
+
+use extension::media::{MediaCategory, MediaContent, MediaCredit, MediaExtension, MediaExtensionBuilder, MediaGroup, MediaRating, MediaThumbnail};
+
+
+impl MediaExtensionBuilder
+{
+    /// Construct a new `MediaExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media_extension_builder = MediaExtensionBuilder::new();
+    /// ```
+    pub fn new() -> MediaExtensionBuilder
+    {
+        MediaExtensionBuilder::default()
+    }
+
+
+    /// Set the groups that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaExtensionBuilder, MediaGroupBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let group = MediaGroupBuilder::new()
+    ///     .contents(vec![content])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_extension_builder = MediaExtensionBuilder::new();
+    /// media_extension_builder.groups(vec![group]);
+    /// ```
+    pub fn groups(&mut self, groups: Vec<MediaGroup>) -> &mut MediaExtensionBuilder
+    {
+        self.groups = groups;
+        self
+    }
+
+
+    /// Set the contents that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaExtensionBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_extension_builder = MediaExtensionBuilder::new();
+    /// media_extension_builder.contents(vec![content]);
+    /// ```
+    pub fn contents(&mut self, contents: Vec<MediaContent>) -> &mut MediaExtensionBuilder
+    {
+        self.contents = contents;
+        self
+    }
+
+
+    /// Set the thumbnails that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_extension_builder = MediaExtensionBuilder::new();
+    /// media_extension_builder.thumbnails(vec![thumbnail]);
+    /// ```
+    pub fn thumbnails(&mut self, thumbnails: Vec<MediaThumbnail>) -> &mut MediaExtensionBuilder
+    {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+
+    /// Set the credits that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaExtensionBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .role(Some("author".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_extension_builder = MediaExtensionBuilder::new();
+    /// media_extension_builder.credits(vec![credit]);
+    /// ```
+    pub fn credits(&mut self, credits: Vec<MediaCredit>) -> &mut MediaExtensionBuilder
+    {
+        self.credits = credits;
+        self
+    }
+
+
+    /// Set the ratings that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaRatingBuilder};
+    ///
+    /// let rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_extension_builder = MediaExtensionBuilder::new();
+    /// media_extension_builder.ratings(vec![rating]);
+    /// ```
+    pub fn ratings(&mut self, ratings: Vec<MediaRating>) -> &mut MediaExtensionBuilder
+    {
+        self.ratings = ratings;
+        self
+    }
+
+
+    /// Set the keywords that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let mut media_extension_builder = MediaExtensionBuilder::new();
+    /// media_extension_builder.keywords(vec!["Linux".to_owned(), "Ubuntu".to_owned()]);
+    /// ```
+    pub fn keywords(&mut self, keywords: Vec<String>) -> &mut MediaExtensionBuilder
+    {
+        self.keywords = keywords;
+        self
+    }
+
+
+    /// Set the categories that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaExtensionBuilder};
+    ///
+    /// let category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_extension_builder = MediaExtensionBuilder::new();
+    /// media_extension_builder.categories(vec![category]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<MediaCategory>) -> &mut MediaExtensionBuilder
+    {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Construct the `MediaExtension` from the `MediaExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<MediaExtension, String>
+    {
+        Ok(MediaExtension {
+               groups: self.groups.clone(),
+               contents: self.contents.clone(),
+               thumbnails: self.thumbnails.clone(),
+               credits: self.credits.clone(),
+               ratings: self.ratings.clone(),
+               keywords: self.keywords.clone(),
+               categories: self.categories.clone(),
+           })
+    }
+}
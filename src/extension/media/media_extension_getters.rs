@@ -0,0 +1,202 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under media extension can be retrieved by using the methods
+//! under `MediaExtension`.
+
+
+use extension::media::{MediaCategory, MediaContent, MediaCredit, MediaExtension, MediaExtensionGetters, MediaGroup, MediaRating, MediaThumbnail};
+
+
+impl MediaExtensionGetters for MediaExtension
+{
+    /// Get the groups that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaExtensionBuilder, MediaExtensionGetters, MediaGroupBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let group = MediaGroupBuilder::new()
+    ///     .contents(vec![content])
+    ///     .finalize()
+    ///     .unwrap();
+    /// let groups = vec![group];
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .groups(groups.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_extension.groups().len());
+    /// ```
+    fn groups(&self) -> Vec<MediaGroup>
+    {
+        self.groups.clone()
+    }
+
+
+    /// Get the contents that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaExtensionBuilder, MediaExtensionGetters};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let contents = vec![content];
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .contents(contents.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_extension.contents().len());
+    /// ```
+    fn contents(&self) -> Vec<MediaContent>
+    {
+        self.contents.clone()
+    }
+
+
+    /// Get the thumbnails that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaExtensionGetters, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let thumbnails = vec![thumbnail];
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .thumbnails(thumbnails.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_extension.thumbnails().len());
+    /// ```
+    fn thumbnails(&self) -> Vec<MediaThumbnail>
+    {
+        self.thumbnails.clone()
+    }
+
+
+    /// Get the credits that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaExtensionBuilder, MediaExtensionGetters};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .role(Some("author".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let credits = vec![credit];
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .credits(credits.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_extension.credits().len());
+    /// ```
+    fn credits(&self) -> Vec<MediaCredit>
+    {
+        self.credits.clone()
+    }
+
+
+    /// Get the ratings that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaExtensionGetters, MediaRatingBuilder};
+    ///
+    /// let rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let ratings = vec![rating];
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .ratings(ratings.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_extension.ratings().len());
+    /// ```
+    fn ratings(&self) -> Vec<MediaRating>
+    {
+        self.ratings.clone()
+    }
+
+
+    /// Get the keywords that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaExtensionGetters};
+    ///
+    /// let keywords = vec!["Linux".to_owned(), "Ubuntu".to_owned()];
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .keywords(keywords.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, media_extension.keywords().len());
+    /// ```
+    fn keywords(&self) -> Vec<String>
+    {
+        self.keywords.clone()
+    }
+
+
+    /// Get the categories that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaExtensionBuilder, MediaExtensionGetters};
+    ///
+    /// let category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let categories = vec![category];
+    ///
+    /// let media_extension = MediaExtensionBuilder::new()
+    ///     .categories(categories.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_extension.categories().len());
+    /// ```
+    fn categories(&self) -> Vec<MediaCategory>
+    {
+        self.categories.clone()
+    }
+}
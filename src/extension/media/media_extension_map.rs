@@ -0,0 +1,455 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing the `media` extensions of an `Item` or a `Channel` as
+//! a `MediaExtension`.
+
+
+use extension::media::{MediaCategory, MediaContent, MediaCredit, MediaExtension, MediaGroup, MediaRating,
+                       MediaThumbnail};
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+
+
+// The elements covered by `MediaExtension`, by local name.
+const ELEMENTS: &[&str] = &["group", "content", "thumbnail", "credit", "rating", "keywords", "category"];
+
+
+impl MediaExtension
+{
+    /// Construct a `MediaExtension` from the `media` extensions of an `Item`
+    /// or a `Channel`.
+    ///
+    /// Numbers that cannot be read are left out, where `try_from_map` fails on
+    /// them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::media::MediaExtension;
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/sample_rss.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let media = MediaExtension::from_map(&channel.items[0].extensions["media"]);
+    ///
+    ///     assert_eq!(Some(69796480), media.contents[0].file_size);
+    ///     assert_eq!(1, media.thumbnails.len());
+    /// }
+    /// ```
+    pub fn from_map(map: &HashMap<String, Vec<Extension>>) -> MediaExtension
+    {
+        MediaExtension {
+            groups: elements(map, "group").map(group_from_extension).collect(),
+            contents: elements(map, "content").map(content_from_extension).collect(),
+            thumbnails: elements(map, "thumbnail").map(thumbnail_from_extension).collect(),
+            credits: elements(map, "credit").map(credit_from_extension).collect(),
+            ratings: elements(map, "rating").map(rating_from_extension).collect(),
+            keywords: keywords_from_map(map),
+            categories: elements(map, "category").map(category_from_extension).collect(),
+        }
+    }
+
+
+    /// Construct a `MediaExtension` from the `media` extensions of an `Item`
+    /// or a `Channel`, failing on numbers that cannot be read instead of
+    /// leaving them out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::media::MediaExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:media=\"http://search.yahoo.com/mrss/\">\
+    ///                <channel><title>Linux Action Show</title>\
+    ///                <item><title>LAS 409</title><media:group>\
+    ///                <media:content url=\"http://traffic.libsyn.com/jnite/las-409.ogg\" \
+    ///                width=\"wide\" /></media:group></item>\
+    ///                </channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let result = MediaExtension::try_from_map(&channel.items[0].extensions["media"]);
+    ///     assert_eq!(Err("Invalid width: wide".to_owned()), result.map(|_| ()));
+    /// }
+    /// ```
+    pub fn try_from_map(map: &HashMap<String, Vec<Extension>>) -> Result<MediaExtension, String>
+    {
+        check_numbers(map)?;
+        Ok(MediaExtension::from_map(map))
+    }
+
+
+    /// Convert the `MediaExtension` to `media` extensions, keyed by local
+    /// name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let media = MediaExtensionBuilder::new()
+    ///     .thumbnails(vec![thumbnail])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let map = media.to_map();
+    /// assert_eq!("media:thumbnail", map["thumbnail"][0].name);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    {
+        let mut map = HashMap::new();
+
+        insert(&mut map, "group", self.groups.iter().map(group_to_extension).collect());
+        insert(&mut map, "content", self.contents.iter().map(content_to_extension).collect());
+        insert_common(&mut map,
+                      &self.thumbnails,
+                      &self.credits,
+                      &self.ratings,
+                      &self.keywords,
+                      &self.categories);
+
+        map
+    }
+
+
+    /// Replace the `media` extensions of an `ExtensionMap` with those of the
+    /// `MediaExtension`, keeping the Media RSS elements it does not cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::media::{MediaExtension, MediaExtensionBuilder};
+    /// use rss::Channel;
+    /// use std::fs::File;
+    /// use std::io::Read;
+    ///
+    /// fn main()
+    /// {
+    ///     let mut xml = String::new();
+    ///     File::open("rsc/example2.xml").unwrap().read_to_string(&mut xml).unwrap();
+    ///     let mut channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let media = MediaExtensionBuilder::new()
+    ///         .keywords(vec!["Linux".to_owned()])
+    ///         .finalize()
+    ///         .unwrap();
+    ///     media.insert_into(&mut channel.extensions);
+    ///
+    ///     assert!(channel.extensions["media"].contains_key("description"));
+    ///     assert!(!channel.extensions["media"].contains_key("credit"));
+    ///     assert_eq!(vec!["Linux".to_owned()],
+    ///                MediaExtension::from_map(&channel.extensions["media"]).keywords);
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        let map = extensions.entry("media".to_owned()).or_default();
+        for name in ELEMENTS
+        {
+            map.remove(*name);
+        }
+        map.extend(self.to_map());
+
+        if map.is_empty()
+        {
+            extensions.remove("media");
+        }
+    }
+}
+
+
+fn elements<'a>(map: &'a HashMap<String, Vec<Extension>>, name: &str) -> ::std::slice::Iter<'a, Extension>
+{
+    match map.get(name)
+    {
+        Some(elements) => elements.iter(),
+        None => [].iter(),
+    }
+}
+
+
+fn keywords_from_map(map: &HashMap<String, Vec<Extension>>) -> Vec<String>
+{
+    elements(map, "keywords")
+        .filter_map(|keywords| keywords.value.as_ref())
+        .flat_map(|keywords| keywords.split(','))
+        .map(|keyword| keyword.trim().to_owned())
+        .filter(|keyword| !keyword.is_empty())
+        .collect()
+}
+
+
+fn text(extension: &Extension) -> String
+{
+    extension.value.clone().unwrap_or_default().trim().to_owned()
+}
+
+
+// Read a number, rounding one with a fraction.
+fn number(extension: &Extension, name: &str) -> Option<i64>
+{
+    let value = extension.attrs.get(name)?.trim();
+    value.parse::<i64>().ok().or_else(|| value.parse::<f64>().ok().map(|val| val.round() as i64))
+}
+
+
+// Fail on the numbers of the contents and thumbnails, including those in
+// groups, that cannot be read.
+fn check_numbers(map: &HashMap<String, Vec<Extension>>) -> Result<(), String>
+{
+    for group in elements(map, "group")
+    {
+        check_numbers(&group.children)?;
+    }
+
+    for content in elements(map, "content")
+    {
+        check_number(content, "fileSize")?;
+        check_number(content, "bitrate")?;
+        check_number(content, "duration")?;
+        check_number(content, "height")?;
+        check_number(content, "width")?;
+        check_numbers(&content.children)?;
+    }
+
+    for thumbnail in elements(map, "thumbnail")
+    {
+        check_number(thumbnail, "width")?;
+        check_number(thumbnail, "height")?;
+    }
+
+    Ok(())
+}
+
+
+fn check_number(extension: &Extension, name: &str) -> Result<(), String>
+{
+    match extension.attrs.get(name)
+    {
+        Some(value) if number(extension, name).is_none() => Err(format!("Invalid {}: {}", name, value)),
+        _ => Ok(()),
+    }
+}
+
+
+fn group_from_extension(extension: &Extension) -> MediaGroup
+{
+    let media = MediaExtension::from_map(&extension.children);
+    MediaGroup {
+        contents: media.contents,
+        thumbnails: media.thumbnails,
+        credits: media.credits,
+        ratings: media.ratings,
+        keywords: media.keywords,
+        categories: media.categories,
+    }
+}
+
+
+fn content_from_extension(extension: &Extension) -> MediaContent
+{
+    MediaContent {
+        url: extension.attrs.get("url").cloned(),
+        file_size: number(extension, "fileSize"),
+        mime_type: extension.attrs.get("type").cloned(),
+        medium: extension.attrs.get("medium").cloned(),
+        is_default: extension.attrs.get("isDefault").map(|val| val.trim() == "true"),
+        expression: extension.attrs.get("expression").cloned(),
+        bitrate: number(extension, "bitrate"),
+        duration: number(extension, "duration"),
+        height: number(extension, "height"),
+        width: number(extension, "width"),
+        lang: extension.attrs.get("lang").cloned(),
+        thumbnails: elements(&extension.children, "thumbnail").map(thumbnail_from_extension).collect(),
+    }
+}
+
+
+fn thumbnail_from_extension(extension: &Extension) -> MediaThumbnail
+{
+    MediaThumbnail {
+        url: extension.attrs.get("url").cloned().unwrap_or_default(),
+        width: number(extension, "width"),
+        height: number(extension, "height"),
+        time: extension.attrs.get("time").cloned(),
+    }
+}
+
+
+fn credit_from_extension(extension: &Extension) -> MediaCredit
+{
+    MediaCredit {
+        value: text(extension),
+        role: extension.attrs.get("role").cloned(),
+        scheme: extension.attrs.get("scheme").cloned(),
+    }
+}
+
+
+fn rating_from_extension(extension: &Extension) -> MediaRating
+{
+    MediaRating {
+        value: text(extension),
+        scheme: extension.attrs.get("scheme").cloned(),
+    }
+}
+
+
+fn category_from_extension(extension: &Extension) -> MediaCategory
+{
+    MediaCategory {
+        value: text(extension),
+        scheme: extension.attrs.get("scheme").cloned(),
+        label: extension.attrs.get("label").cloned(),
+    }
+}
+
+
+fn insert(map: &mut HashMap<String, Vec<Extension>>, name: &str, extensions: Vec<Extension>)
+{
+    if !extensions.is_empty()
+    {
+        map.insert(name.to_owned(), extensions);
+    }
+}
+
+
+fn insert_common(map: &mut HashMap<String, Vec<Extension>>,
+                 thumbnails: &[MediaThumbnail],
+                 credits: &[MediaCredit],
+                 ratings: &[MediaRating],
+                 keywords: &[String],
+                 categories: &[MediaCategory])
+{
+    insert(map, "thumbnail", thumbnails.iter().map(thumbnail_to_extension).collect());
+    insert(map,
+           "credit",
+           credits
+               .iter()
+               .map(|credit| {
+                        extension("credit",
+                                  Some(credit.value.clone()),
+                                  &[("role", credit.role.clone()), ("scheme", credit.scheme.clone())])
+                    })
+               .collect());
+    insert(map,
+           "rating",
+           ratings
+               .iter()
+               .map(|rating| extension("rating", Some(rating.value.clone()), &[("scheme", rating.scheme.clone())]))
+               .collect());
+
+    if !keywords.is_empty()
+    {
+        insert(map, "keywords", vec![extension("keywords", Some(keywords.join(", ")), &[])]);
+    }
+
+    insert(map,
+           "category",
+           categories
+               .iter()
+               .map(|category| {
+                        extension("category",
+                                  Some(category.value.clone()),
+                                  &[("scheme", category.scheme.clone()), ("label", category.label.clone())])
+                    })
+               .collect());
+}
+
+
+fn group_to_extension(group: &MediaGroup) -> Extension
+{
+    let mut children = HashMap::new();
+    insert(&mut children, "content", group.contents.iter().map(content_to_extension).collect());
+    insert_common(&mut children,
+                  &group.thumbnails,
+                  &group.credits,
+                  &group.ratings,
+                  &group.keywords,
+                  &group.categories);
+
+    Extension {
+        name: "media:group".to_owned(),
+        children,
+        ..Default::default()
+    }
+}
+
+
+fn content_to_extension(content: &MediaContent) -> Extension
+{
+    let number = |val: Option<i64>| val.map(|val| val.to_string());
+
+    let mut extension = extension("content",
+                                  None,
+                                  &[("url", content.url.clone()),
+                                    ("fileSize", number(content.file_size)),
+                                    ("type", content.mime_type.clone()),
+                                    ("medium", content.medium.clone()),
+                                    ("isDefault", content.is_default.map(|val| val.to_string())),
+                                    ("expression", content.expression.clone()),
+                                    ("bitrate", number(content.bitrate)),
+                                    ("duration", number(content.duration)),
+                                    ("height", number(content.height)),
+                                    ("width", number(content.width)),
+                                    ("lang", content.lang.clone())]);
+    insert(&mut extension.children,
+           "thumbnail",
+           content.thumbnails.iter().map(thumbnail_to_extension).collect());
+    extension
+}
+
+
+fn thumbnail_to_extension(thumbnail: &MediaThumbnail) -> Extension
+{
+    extension("thumbnail",
+              None,
+              &[("url", Some(thumbnail.url.clone())),
+                ("width", thumbnail.width.map(|val| val.to_string())),
+                ("height", thumbnail.height.map(|val| val.to_string())),
+                ("time", thumbnail.time.clone())])
+}
+
+
+fn extension(name: &str, value: Option<String>, attrs: &[(&str, Option<String>)]) -> Extension
+{
+    Extension {
+        name: format!("media:{}", name),
+        value,
+        attrs: attrs
+            .iter()
+            .filter_map(|attr| attr.1.clone().map(|val| (attr.0.to_owned(), val)))
+            .collect(),
+        children: HashMap::new(),
+    }
+}
@@ -0,0 +1,221 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for media group by using the methods under
+//! `MediaGroupBuilder`.
+
+
+use extension::media::{MediaCategory, MediaContent, MediaCredit, MediaGroup, MediaGroupBuilder, MediaRating, MediaThumbnail};
+
+
+impl MediaGroupBuilder
+{
+    /// Construct a new `MediaGroupBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaGroupBuilder;
+    ///
+    /// let media_group_builder = MediaGroupBuilder::new();
+    /// ```
+    pub fn new() -> MediaGroupBuilder
+    {
+        MediaGroupBuilder::default()
+    }
+
+
+    /// Set the contents that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaGroupBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_group_builder = MediaGroupBuilder::new();
+    /// media_group_builder.contents(vec![content]);
+    /// ```
+    pub fn contents(&mut self, contents: Vec<MediaContent>) -> &mut MediaGroupBuilder
+    {
+        self.contents = contents;
+        self
+    }
+
+
+    /// Set the thumbnails that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_group_builder = MediaGroupBuilder::new();
+    /// media_group_builder.thumbnails(vec![thumbnail]);
+    /// ```
+    pub fn thumbnails(&mut self, thumbnails: Vec<MediaThumbnail>) -> &mut MediaGroupBuilder
+    {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+
+    /// Set the credits that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaGroupBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .role(Some("author".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_group_builder = MediaGroupBuilder::new();
+    /// media_group_builder.credits(vec![credit]);
+    /// ```
+    pub fn credits(&mut self, credits: Vec<MediaCredit>) -> &mut MediaGroupBuilder
+    {
+        self.credits = credits;
+        self
+    }
+
+
+    /// Set the ratings that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaRatingBuilder};
+    ///
+    /// let rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_group_builder = MediaGroupBuilder::new();
+    /// media_group_builder.ratings(vec![rating]);
+    /// ```
+    pub fn ratings(&mut self, ratings: Vec<MediaRating>) -> &mut MediaGroupBuilder
+    {
+        self.ratings = ratings;
+        self
+    }
+
+
+    /// Set the keywords that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaGroupBuilder;
+    ///
+    /// let mut media_group_builder = MediaGroupBuilder::new();
+    /// media_group_builder.keywords(vec!["Linux".to_owned(), "Ubuntu".to_owned()]);
+    /// ```
+    pub fn keywords(&mut self, keywords: Vec<String>) -> &mut MediaGroupBuilder
+    {
+        self.keywords = keywords;
+        self
+    }
+
+
+    /// Set the categories that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaGroupBuilder};
+    ///
+    /// let category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut media_group_builder = MediaGroupBuilder::new();
+    /// media_group_builder.categories(vec![category]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<MediaCategory>) -> &mut MediaGroupBuilder
+    {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Validate the contents of `MediaGroup`.
+    ///
+    /// A group must hold at least one content, and at most one of them can be
+    /// the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaGroupBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .contents(vec![content])
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut MediaGroupBuilder, String>
+    {
+        if self.contents.is_empty()
+        {
+            return Err("Media group must have at least one content.".to_owned());
+        }
+
+        if self.contents.iter().filter(|content| content.is_default == Some(true)).count() > 1
+        {
+            return Err("Media group can only have one default content.".to_owned());
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `MediaGroup` from the `MediaGroupBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaGroupBuilder;
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<MediaGroup, String>
+    {
+        Ok(MediaGroup {
+               contents: self.contents.clone(),
+               thumbnails: self.thumbnails.clone(),
+               credits: self.credits.clone(),
+               ratings: self.ratings.clone(),
+               keywords: self.keywords.clone(),
+               categories: self.categories.clone(),
+           })
+    }
+}
@@ -0,0 +1,171 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under media group can be retrieved by using the methods
+//! under `MediaGroup`.
+
+
+use extension::media::{MediaCategory, MediaContent, MediaCredit, MediaGroup, MediaGroupGetters, MediaRating, MediaThumbnail};
+
+
+impl MediaGroupGetters for MediaGroup
+{
+    /// Get the contents that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaGroupBuilder, MediaGroupGetters};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url(Some("http://www.podtrac.com/pts/redirect.ogg/traffic.libsyn.com/jbmirror/linuxactionshowep409.ogg".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let contents = vec![content];
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .contents(contents.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_group.contents().len());
+    /// ```
+    fn contents(&self) -> Vec<MediaContent>
+    {
+        self.contents.clone()
+    }
+
+
+    /// Get the thumbnails that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaGroupGetters, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let thumbnails = vec![thumbnail];
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .thumbnails(thumbnails.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_group.thumbnails().len());
+    /// ```
+    fn thumbnails(&self) -> Vec<MediaThumbnail>
+    {
+        self.thumbnails.clone()
+    }
+
+
+    /// Get the credits that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaGroupBuilder, MediaGroupGetters};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .value("Jupiter Broadcasting")
+    ///     .role(Some("author".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let credits = vec![credit];
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .credits(credits.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_group.credits().len());
+    /// ```
+    fn credits(&self) -> Vec<MediaCredit>
+    {
+        self.credits.clone()
+    }
+
+
+    /// Get the ratings that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaGroupGetters, MediaRatingBuilder};
+    ///
+    /// let rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let ratings = vec![rating];
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .ratings(ratings.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_group.ratings().len());
+    /// ```
+    fn ratings(&self) -> Vec<MediaRating>
+    {
+        self.ratings.clone()
+    }
+
+
+    /// Get the keywords that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaGroupGetters};
+    ///
+    /// let keywords = vec!["Linux".to_owned(), "Ubuntu".to_owned()];
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .keywords(keywords.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, media_group.keywords().len());
+    /// ```
+    fn keywords(&self) -> Vec<String>
+    {
+        self.keywords.clone()
+    }
+
+
+    /// Get the categories that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCategoryBuilder, MediaGroupBuilder, MediaGroupGetters};
+    ///
+    /// let category = MediaCategoryBuilder::new()
+    ///     .value("Technology/Tech News")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let categories = vec![category];
+    ///
+    /// let media_group = MediaGroupBuilder::new()
+    ///     .categories(categories.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, media_group.categories().len());
+    /// ```
+    fn categories(&self) -> Vec<MediaCategory>
+    {
+        self.categories.clone()
+    }
+}
@@ -0,0 +1,130 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for media rating by using the methods under
+//! `MediaRatingBuilder`.
+
+
+use extension::media::{MediaRating, MediaRatingBuilder};
+use utils::string_utils;
+
+
+impl MediaRatingBuilder
+{
+    /// Construct a new `MediaRatingBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaRatingBuilder;
+    ///
+    /// let media_rating_builder = MediaRatingBuilder::new();
+    /// ```
+    pub fn new() -> MediaRatingBuilder
+    {
+        MediaRatingBuilder::default()
+    }
+
+
+    /// Set the rating that exists under `MediaRating`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaRatingBuilder;
+    ///
+    /// let mut media_rating_builder = MediaRatingBuilder::new();
+    /// media_rating_builder.value("nonadult");
+    /// ```
+    pub fn value(&mut self, value: &str) -> &mut MediaRatingBuilder
+    {
+        self.value = value.to_owned();
+        self
+    }
+
+
+    /// Set the optional scheme that exists under `MediaRating`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaRatingBuilder;
+    ///
+    /// let mut media_rating_builder = MediaRatingBuilder::new();
+    /// media_rating_builder.scheme(Some("urn:simple".to_owned()));
+    /// ```
+    pub fn scheme(&mut self, scheme: Option<String>) -> &mut MediaRatingBuilder
+    {
+        self.scheme = scheme;
+        self
+    }
+
+
+    /// Validate the contents of `MediaRating`.
+    ///
+    /// The values of the `urn:simple`, `urn:mpaa` and `urn:v-chip` schemes are
+    /// checked, `urn:simple` being the scheme when none is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaRatingBuilder;
+    ///
+    /// let media_rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .scheme(Some("urn:simple".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut MediaRatingBuilder, String>
+    {
+        let scheme = self.scheme.clone().unwrap_or_else(|| "urn:simple".to_owned());
+        let values: &[&str] = match scheme.as_str()
+        {
+            "urn:simple" => &["adult", "nonadult"],
+            "urn:mpaa" => &["g", "pg", "pg-13", "r", "nc-17"],
+            "urn:v-chip" => &["tv-y", "tv-y7", "tv-y7-fv", "tv-g", "tv-pg", "tv-14", "tv-ma"],
+            _ => &[],
+        };
+
+        if !values.is_empty() && !values.contains(&self.value.to_lowercase().as_str())
+        {
+            return Err(format!("Invalid value: {}", self.value));
+        }
+
+        if values.is_empty()
+        {
+            string_utils::str_to_url(scheme.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `MediaRating` from the `MediaRatingBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaRatingBuilder;
+    ///
+    /// let media_rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<MediaRating, String>
+    {
+        Ok(MediaRating {
+               value: self.value.clone(),
+               scheme: self.scheme.clone(),
+           })
+    }
+}
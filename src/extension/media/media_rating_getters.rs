@@ -0,0 +1,75 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under media rating can be retrieved by using the methods
+//! under `MediaRating`.
+
+
+use extension::media::{MediaRatingGetters, MediaRating};
+
+
+impl MediaRatingGetters for MediaRating
+{
+    /// Get the rating that exists under `MediaRating`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaRatingBuilder, MediaRatingGetters};
+    ///
+    /// let value = "nonadult";
+    ///
+    /// let media_rating = MediaRatingBuilder::new()
+    ///     .value(value)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_owned(), media_rating.value());
+    /// ```
+    fn value(&self) -> String
+    {
+        self.value.clone()
+    }
+
+
+    /// Get the optional scheme that exists under `MediaRating`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaRatingBuilder, MediaRatingGetters};
+    ///
+    /// let scheme = Some("urn:simple".to_owned());
+    ///
+    /// let media_rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .scheme(scheme.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(scheme, media_rating.scheme());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaRatingBuilder, MediaRatingGetters};
+    ///
+    /// let media_rating = MediaRatingBuilder::new()
+    ///     .value("nonadult")
+    ///     .scheme(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_rating.scheme().is_none());
+    /// ```
+    fn scheme(&self) -> Option<String>
+    {
+        self.scheme.clone()
+    }
+}
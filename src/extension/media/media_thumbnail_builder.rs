@@ -0,0 +1,152 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for media thumbnail by using the methods under
+//! `MediaThumbnailBuilder`.
+
+
+use extension::media::{MediaThumbnail, MediaThumbnailBuilder};
+use utils::string_utils;
+
+
+impl MediaThumbnailBuilder
+{
+    /// Construct a new `MediaThumbnailBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let media_thumbnail_builder = MediaThumbnailBuilder::new();
+    /// ```
+    pub fn new() -> MediaThumbnailBuilder
+    {
+        MediaThumbnailBuilder::default()
+    }
+
+
+    /// Set the url that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut media_thumbnail_builder = MediaThumbnailBuilder::new();
+    /// media_thumbnail_builder.url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut MediaThumbnailBuilder
+    {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the optional width in pixels that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut media_thumbnail_builder = MediaThumbnailBuilder::new();
+    /// media_thumbnail_builder.width(Some(640));
+    /// ```
+    pub fn width(&mut self, width: Option<i64>) -> &mut MediaThumbnailBuilder
+    {
+        self.width = width;
+        self
+    }
+
+
+    /// Set the optional height in pixels that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut media_thumbnail_builder = MediaThumbnailBuilder::new();
+    /// media_thumbnail_builder.height(Some(360));
+    /// ```
+    pub fn height(&mut self, height: Option<i64>) -> &mut MediaThumbnailBuilder
+    {
+        self.height = height;
+        self
+    }
+
+
+    /// Set the optional time offset that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut media_thumbnail_builder = MediaThumbnailBuilder::new();
+    /// media_thumbnail_builder.time(Some("12:05:01.123".to_owned()));
+    /// ```
+    pub fn time(&mut self, time: Option<String>) -> &mut MediaThumbnailBuilder
+    {
+        self.time = time;
+        self
+    }
+
+
+    /// Validate the contents of `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .width(Some(640))
+    ///     .height(Some(360))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut MediaThumbnailBuilder, String>
+    {
+        string_utils::str_to_url(self.url.as_str())?;
+
+        if self.width.is_some_and(|width| width < 0) || self.height.is_some_and(|height| height < 0)
+        {
+            return Err("Media thumbnail width and height cannot be negative values.".to_owned());
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `MediaThumbnail` from the `MediaThumbnailBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<MediaThumbnail, String>
+    {
+        Ok(MediaThumbnail {
+               url: self.url.clone(),
+               width: self.width,
+               height: self.height,
+               time: self.time.clone(),
+           })
+    }
+}
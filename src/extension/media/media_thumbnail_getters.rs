@@ -0,0 +1,145 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under media thumbnail can be retrieved by using the methods
+//! under `MediaThumbnail`.
+
+
+use extension::media::{MediaThumbnailGetters, MediaThumbnail};
+
+
+impl MediaThumbnailGetters for MediaThumbnail
+{
+    /// Get the url that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaThumbnailBuilder, MediaThumbnailGetters};
+    ///
+    /// let url = "http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg";
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url(url)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(url.to_owned(), media_thumbnail.url());
+    /// ```
+    fn url(&self) -> String
+    {
+        self.url.clone()
+    }
+
+
+    /// Get the optional width in pixels that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaThumbnailBuilder, MediaThumbnailGetters};
+    ///
+    /// let width = Some(640);
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .width(width)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(width, media_thumbnail.width());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaThumbnailBuilder, MediaThumbnailGetters};
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .width(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_thumbnail.width().is_none());
+    /// ```
+    fn width(&self) -> Option<i64>
+    {
+        self.width
+    }
+
+
+    /// Get the optional height in pixels that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaThumbnailBuilder, MediaThumbnailGetters};
+    ///
+    /// let height = Some(360);
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .height(height)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(height, media_thumbnail.height());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaThumbnailBuilder, MediaThumbnailGetters};
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .height(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_thumbnail.height().is_none());
+    /// ```
+    fn height(&self) -> Option<i64>
+    {
+        self.height
+    }
+
+
+    /// Get the optional time offset that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaThumbnailBuilder, MediaThumbnailGetters};
+    ///
+    /// let time = Some("12:05:01.123".to_owned());
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .time(time.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(time, media_thumbnail.time());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaThumbnailBuilder, MediaThumbnailGetters};
+    ///
+    /// let media_thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://www.jupiterbroadcasting.com/wp-content/uploads/2016/03/las409-v.jpg")
+    ///     .time(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(media_thumbnail.time().is_none());
+    /// ```
+    fn time(&self) -> Option<String>
+    {
+        self.time.clone()
+    }
+}
@@ -0,0 +1,399 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for Media RSS.
+//!
+//! The elements are read from and written to the `media` extensions of an
+//! `Item` or a `Channel`, so the elements this module does not cover are
+//! kept as they are.
+
+
+pub mod media_category_builder;
+pub mod media_category_getters;
+pub mod media_content_builder;
+pub mod media_content_getters;
+pub mod media_credit_builder;
+pub mod media_credit_getters;
+pub mod media_extension_builder;
+pub mod media_extension_getters;
+pub mod media_extension_map;
+pub mod media_group_builder;
+pub mod media_group_getters;
+pub mod media_rating_builder;
+pub mod media_rating_getters;
+pub mod media_thumbnail_builder;
+pub mod media_thumbnail_getters;
+
+
+/// The namespace of Media RSS.
+pub const NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+
+
+// The values of the `medium` attribute of `media:content`.
+const MEDIUMS: &[&str] = &["image", "audio", "video", "document", "executable"];
+
+
+// The values of the `expression` attribute of `media:content`.
+const EXPRESSIONS: &[&str] = &["sample", "full", "nonstop"];
+
+
+/// The Media RSS elements of an `Item` or a `Channel`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaExtension
+{
+    /// The groups of media objects.
+    pub groups: Vec<MediaGroup>,
+    /// The media objects that are not in a group.
+    pub contents: Vec<MediaContent>,
+    /// The thumbnails of the media objects.
+    pub thumbnails: Vec<MediaThumbnail>,
+    /// The credits of the media objects.
+    pub credits: Vec<MediaCredit>,
+    /// The ratings of the media objects.
+    pub ratings: Vec<MediaRating>,
+    /// The keywords of the media objects.
+    pub keywords: Vec<String>,
+    /// The categories of the media objects.
+    pub categories: Vec<MediaCategory>,
+}
+
+
+/// The Getter functions for `MediaExtension`
+pub trait MediaExtensionGetters
+{
+    /// Get the groups that exists under `MediaExtension`.
+    fn groups(&self) -> Vec<MediaGroup>;
+
+    /// Get the contents that exists under `MediaExtension`.
+    fn contents(&self) -> Vec<MediaContent>;
+
+    /// Get the thumbnails that exists under `MediaExtension`.
+    fn thumbnails(&self) -> Vec<MediaThumbnail>;
+
+    /// Get the credits that exists under `MediaExtension`.
+    fn credits(&self) -> Vec<MediaCredit>;
+
+    /// Get the ratings that exists under `MediaExtension`.
+    fn ratings(&self) -> Vec<MediaRating>;
+
+    /// Get the keywords that exists under `MediaExtension`.
+    fn keywords(&self) -> Vec<String>;
+
+    /// Get the categories that exists under `MediaExtension`.
+    fn categories(&self) -> Vec<MediaCategory>;
+}
+
+
+/// This `MediaExtensionBuilder` struct creates the `MediaExtension`.
+#[derive(Clone, Default)]
+pub struct MediaExtensionBuilder
+{
+    groups: Vec<MediaGroup>,
+    contents: Vec<MediaContent>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+    ratings: Vec<MediaRating>,
+    keywords: Vec<String>,
+    categories: Vec<MediaCategory>,
+}
+
+
+/// Alternate versions of the same media object, read from `media:group`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaGroup
+{
+    /// The versions of the media object.
+    pub contents: Vec<MediaContent>,
+    /// The thumbnails of the group.
+    pub thumbnails: Vec<MediaThumbnail>,
+    /// The credits of the group.
+    pub credits: Vec<MediaCredit>,
+    /// The ratings of the group.
+    pub ratings: Vec<MediaRating>,
+    /// The keywords of the group.
+    pub keywords: Vec<String>,
+    /// The categories of the group.
+    pub categories: Vec<MediaCategory>,
+}
+
+
+/// The Getter functions for `MediaGroup`
+pub trait MediaGroupGetters
+{
+    /// Get the contents that exists under `MediaGroup`.
+    fn contents(&self) -> Vec<MediaContent>;
+
+    /// Get the thumbnails that exists under `MediaGroup`.
+    fn thumbnails(&self) -> Vec<MediaThumbnail>;
+
+    /// Get the credits that exists under `MediaGroup`.
+    fn credits(&self) -> Vec<MediaCredit>;
+
+    /// Get the ratings that exists under `MediaGroup`.
+    fn ratings(&self) -> Vec<MediaRating>;
+
+    /// Get the keywords that exists under `MediaGroup`.
+    fn keywords(&self) -> Vec<String>;
+
+    /// Get the categories that exists under `MediaGroup`.
+    fn categories(&self) -> Vec<MediaCategory>;
+}
+
+
+/// This `MediaGroupBuilder` struct creates the `MediaGroup`.
+#[derive(Clone, Default)]
+pub struct MediaGroupBuilder
+{
+    contents: Vec<MediaContent>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+    ratings: Vec<MediaRating>,
+    keywords: Vec<String>,
+    categories: Vec<MediaCategory>,
+}
+
+
+/// A media object, read from `media:content`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaContent
+{
+    /// The URL of the media object.
+    pub url: Option<String>,
+    /// The size of the media object in bytes.
+    pub file_size: Option<i64>,
+    /// The MIME type of the media object.
+    pub mime_type: Option<String>,
+    /// The kind of media object: image, audio, video, document or executable.
+    pub medium: Option<String>,
+    /// Whether this is the default media object of its group.
+    pub is_default: Option<bool>,
+    /// Whether the media object is a sample, the full version or a nonstop stream.
+    pub expression: Option<String>,
+    /// The bitrate in kilobits per second.
+    pub bitrate: Option<i64>,
+    /// The number of seconds the media object plays for.
+    pub duration: Option<i64>,
+    /// The height in pixels.
+    pub height: Option<i64>,
+    /// The width in pixels.
+    pub width: Option<i64>,
+    /// The language of the media object.
+    pub lang: Option<String>,
+    /// The thumbnails of the media object.
+    pub thumbnails: Vec<MediaThumbnail>,
+}
+
+
+/// The Getter functions for `MediaContent`
+pub trait MediaContentGetters
+{
+    /// Get the optional url that exists under `MediaContent`.
+    fn url(&self) -> Option<String>;
+
+    /// Get the optional file size in bytes that exists under `MediaContent`.
+    fn file_size(&self) -> Option<i64>;
+
+    /// Get the optional mime type that exists under `MediaContent`.
+    fn mime_type(&self) -> Option<String>;
+
+    /// Get the optional medium that exists under `MediaContent`.
+    fn medium(&self) -> Option<String>;
+
+    /// Get the optional is default flag that exists under `MediaContent`.
+    fn is_default(&self) -> Option<bool>;
+
+    /// Get the optional expression that exists under `MediaContent`.
+    fn expression(&self) -> Option<String>;
+
+    /// Get the optional bitrate in kilobits per second that exists under `MediaContent`.
+    fn bitrate(&self) -> Option<i64>;
+
+    /// Get the optional duration in seconds that exists under `MediaContent`.
+    fn duration(&self) -> Option<i64>;
+
+    /// Get the optional height in pixels that exists under `MediaContent`.
+    fn height(&self) -> Option<i64>;
+
+    /// Get the optional width in pixels that exists under `MediaContent`.
+    fn width(&self) -> Option<i64>;
+
+    /// Get the optional language that exists under `MediaContent`.
+    fn lang(&self) -> Option<String>;
+
+    /// Get the thumbnails that exists under `MediaContent`.
+    fn thumbnails(&self) -> Vec<MediaThumbnail>;
+}
+
+
+/// This `MediaContentBuilder` struct creates the `MediaContent`.
+#[derive(Clone, Default)]
+pub struct MediaContentBuilder
+{
+    url: Option<String>,
+    file_size: Option<i64>,
+    mime_type: Option<String>,
+    medium: Option<String>,
+    is_default: Option<bool>,
+    expression: Option<String>,
+    bitrate: Option<i64>,
+    duration: Option<i64>,
+    height: Option<i64>,
+    width: Option<i64>,
+    lang: Option<String>,
+    thumbnails: Vec<MediaThumbnail>,
+}
+
+
+/// An image that represents a media object, read from `media:thumbnail`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaThumbnail
+{
+    /// The URL of the image.
+    pub url: String,
+    /// The width of the image in pixels.
+    pub width: Option<i64>,
+    /// The height of the image in pixels.
+    pub height: Option<i64>,
+    /// The time offset in the media object the image is taken from, in NTP format.
+    pub time: Option<String>,
+}
+
+
+/// The Getter functions for `MediaThumbnail`
+pub trait MediaThumbnailGetters
+{
+    /// Get the url that exists under `MediaThumbnail`.
+    fn url(&self) -> String;
+
+    /// Get the optional width in pixels that exists under `MediaThumbnail`.
+    fn width(&self) -> Option<i64>;
+
+    /// Get the optional height in pixels that exists under `MediaThumbnail`.
+    fn height(&self) -> Option<i64>;
+
+    /// Get the optional time offset that exists under `MediaThumbnail`.
+    fn time(&self) -> Option<String>;
+}
+
+
+/// This `MediaThumbnailBuilder` struct creates the `MediaThumbnail`.
+#[derive(Clone, Default)]
+pub struct MediaThumbnailBuilder
+{
+    url: String,
+    width: Option<i64>,
+    height: Option<i64>,
+    time: Option<String>,
+}
+
+
+/// An entity that contributed to a media object, read from `media:credit`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaCredit
+{
+    /// The name of the entity.
+    pub value: String,
+    /// The role the entity played.
+    pub role: Option<String>,
+    /// The URI of the scheme the role belongs to.
+    pub scheme: Option<String>,
+}
+
+
+/// The Getter functions for `MediaCredit`
+pub trait MediaCreditGetters
+{
+    /// Get the name that exists under `MediaCredit`.
+    fn value(&self) -> String;
+
+    /// Get the optional role that exists under `MediaCredit`.
+    fn role(&self) -> Option<String>;
+
+    /// Get the optional scheme that exists under `MediaCredit`.
+    fn scheme(&self) -> Option<String>;
+}
+
+
+/// This `MediaCreditBuilder` struct creates the `MediaCredit`.
+#[derive(Clone, Default)]
+pub struct MediaCreditBuilder
+{
+    value: String,
+    role: Option<String>,
+    scheme: Option<String>,
+}
+
+
+/// The permissible audience of a media object, read from `media:rating`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaRating
+{
+    /// The rating.
+    pub value: String,
+    /// The URI of the scheme the rating belongs to, `urn:simple` when absent.
+    pub scheme: Option<String>,
+}
+
+
+/// The Getter functions for `MediaRating`
+pub trait MediaRatingGetters
+{
+    /// Get the rating that exists under `MediaRating`.
+    fn value(&self) -> String;
+
+    /// Get the optional scheme that exists under `MediaRating`.
+    fn scheme(&self) -> Option<String>;
+}
+
+
+/// This `MediaRatingBuilder` struct creates the `MediaRating`.
+#[derive(Clone, Default)]
+pub struct MediaRatingBuilder
+{
+    value: String,
+    scheme: Option<String>,
+}
+
+
+/// A category of a media object, read from `media:category`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaCategory
+{
+    /// The category.
+    pub value: String,
+    /// The URI of the scheme the category belongs to.
+    pub scheme: Option<String>,
+    /// The human readable label of the category.
+    pub label: Option<String>,
+}
+
+
+/// The Getter functions for `MediaCategory`
+pub trait MediaCategoryGetters
+{
+    /// Get the category that exists under `MediaCategory`.
+    fn value(&self) -> String;
+
+    /// Get the optional scheme that exists under `MediaCategory`.
+    fn scheme(&self) -> Option<String>;
+
+    /// Get the optional label that exists under `MediaCategory`.
+    fn label(&self) -> Option<String>;
+}
+
+
+/// This `MediaCategoryBuilder` struct creates the `MediaCategory`.
+#[derive(Clone, Default)]
+pub struct MediaCategoryBuilder
+{
+    value: String,
+    scheme: Option<String>,
+    label: Option<String>,
+}
@@ -11,4 +11,14 @@
 //! All the extensions for `Channel`
 
 
+pub mod atom;
+pub mod comments;
+pub mod content;
+pub mod dublincore;
+pub mod georss;
+pub mod googleplay;
+pub mod media;
+pub mod podcast;
+pub mod registry;
+pub mod syndication;
 pub mod itunes;
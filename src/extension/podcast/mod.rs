@@ -0,0 +1,863 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for the Podcasting 2.0 namespace.
+//!
+//! The elements are read from and written to the `podcast` extensions of an
+//! `Item` or a `Channel`. The documents the elements link to, such as
+//! chapters and transcripts, can be read into `PodcastChapter`s and
+//! `PodcastCue`s.
+
+
+pub mod podcast_alternate_enclosure;
+pub mod podcast_alternate_enclosure_builder;
+pub mod podcast_alternate_enclosure_getters;
+pub mod podcast_channel_extension_builder;
+pub mod podcast_channel_extension_getters;
+pub mod podcast_chapter;
+pub mod podcast_chapters_builder;
+pub mod podcast_chapters_getters;
+pub mod podcast_cue;
+pub mod podcast_episode_builder;
+pub mod podcast_episode_getters;
+pub mod podcast_extension_map;
+pub mod podcast_funding_builder;
+pub mod podcast_funding_getters;
+pub mod podcast_integrity_builder;
+pub mod podcast_integrity_getters;
+pub mod podcast_item_extension_builder;
+pub mod podcast_item_extension_getters;
+pub mod podcast_live_item_builder;
+pub mod podcast_live_item_getters;
+pub mod podcast_location_builder;
+pub mod podcast_location_getters;
+pub mod podcast_locked_builder;
+pub mod podcast_locked_getters;
+pub mod podcast_person_builder;
+pub mod podcast_person_getters;
+pub mod podcast_season_builder;
+pub mod podcast_season_getters;
+pub mod podcast_soundbite_builder;
+pub mod podcast_soundbite_getters;
+pub mod podcast_source_builder;
+pub mod podcast_source_getters;
+pub mod podcast_transcript_builder;
+pub mod podcast_transcript_getters;
+pub mod podcast_value;
+pub mod podcast_value_builder;
+pub mod podcast_value_getters;
+pub mod podcast_value_recipient_builder;
+pub mod podcast_value_recipient_getters;
+
+
+use rss::Item;
+
+
+/// The namespace of Podcasting 2.0.
+pub const NAMESPACE: &str = "https://podcastindex.org/namespace/1.0";
+
+
+// The address types `podcast:valueRecipient` accepts.
+const RECIPIENT_TYPES: &[&str] = &["node", "lnaddress"];
+
+// The statuses `podcast:liveItem` accepts.
+const LIVE_STATUSES: &[&str] = &["pending", "live", "ended"];
+
+
+/// The Podcasting 2.0 elements of a `Channel`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastChannelExtension
+{
+    /// The links to where the show can be supported.
+    pub funding: Vec<PodcastFunding>,
+    /// The value block of the show.
+    pub value: Option<PodcastValue>,
+    /// The people in the show.
+    pub persons: Vec<PodcastPerson>,
+    /// The location the show is about.
+    pub location: Option<PodcastLocation>,
+    /// The globally unique identifier of the show, a UUID.
+    pub guid: Option<String>,
+    /// Whether other platforms may import the show.
+    pub locked: Option<PodcastLocked>,
+    /// The live streams of the show.
+    pub live_items: Vec<PodcastLiveItem>,
+}
+
+
+/// The Getter functions for `PodcastChannelExtension`
+pub trait PodcastChannelExtensionGetters
+{
+    /// Get the funding that exists under `PodcastChannelExtension`.
+    fn funding(&self) -> Vec<PodcastFunding>;
+
+    /// Get the optional value that exists under `PodcastChannelExtension`.
+    fn value(&self) -> Option<PodcastValue>;
+
+    /// Get the persons that exists under `PodcastChannelExtension`.
+    fn persons(&self) -> Vec<PodcastPerson>;
+
+    /// Get the optional location that exists under `PodcastChannelExtension`.
+    fn location(&self) -> Option<PodcastLocation>;
+
+    /// Get the optional guid that exists under `PodcastChannelExtension`.
+    fn guid(&self) -> Option<String>;
+
+    /// Get the optional locked that exists under `PodcastChannelExtension`.
+    fn locked(&self) -> Option<PodcastLocked>;
+
+    /// Get the live items that exists under `PodcastChannelExtension`.
+    fn live_items(&self) -> Vec<PodcastLiveItem>;
+}
+
+
+/// This `PodcastChannelExtensionBuilder` struct creates the `PodcastChannelExtension`.
+#[derive(Clone, Default)]
+pub struct PodcastChannelExtensionBuilder
+{
+    funding: Vec<PodcastFunding>,
+    value: Option<PodcastValue>,
+    persons: Vec<PodcastPerson>,
+    location: Option<PodcastLocation>,
+    guid: Option<String>,
+    locked: Option<PodcastLocked>,
+    live_items: Vec<PodcastLiveItem>,
+}
+
+
+/// The Podcasting 2.0 elements of an `Item`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastItemExtension
+{
+    /// The transcripts of the episode.
+    pub transcripts: Vec<PodcastTranscript>,
+    /// The chapters of the episode.
+    pub chapters: Option<PodcastChapters>,
+    /// The value block of the episode, which overrides that of the channel.
+    pub value: Option<PodcastValue>,
+    /// The people in the episode.
+    pub persons: Vec<PodcastPerson>,
+    /// The location the episode is about.
+    pub location: Option<PodcastLocation>,
+    /// The season the episode belongs to.
+    pub season: Option<PodcastSeason>,
+    /// The number of the episode.
+    pub episode: Option<PodcastEpisode>,
+    /// The soundbites of the episode.
+    pub soundbites: Vec<PodcastSoundbite>,
+    /// The other versions of the media of the episode.
+    pub alternate_enclosures: Vec<PodcastAlternateEnclosure>,
+}
+
+
+/// The Getter functions for `PodcastItemExtension`
+pub trait PodcastItemExtensionGetters
+{
+    /// Get the transcripts that exists under `PodcastItemExtension`.
+    fn transcripts(&self) -> Vec<PodcastTranscript>;
+
+    /// Get the optional chapters that exists under `PodcastItemExtension`.
+    fn chapters(&self) -> Option<PodcastChapters>;
+
+    /// Get the optional value that exists under `PodcastItemExtension`.
+    fn value(&self) -> Option<PodcastValue>;
+
+    /// Get the persons that exists under `PodcastItemExtension`.
+    fn persons(&self) -> Vec<PodcastPerson>;
+
+    /// Get the optional location that exists under `PodcastItemExtension`.
+    fn location(&self) -> Option<PodcastLocation>;
+
+    /// Get the optional season that exists under `PodcastItemExtension`.
+    fn season(&self) -> Option<PodcastSeason>;
+
+    /// Get the optional episode that exists under `PodcastItemExtension`.
+    fn episode(&self) -> Option<PodcastEpisode>;
+
+    /// Get the soundbites that exists under `PodcastItemExtension`.
+    fn soundbites(&self) -> Vec<PodcastSoundbite>;
+
+    /// Get the alternate enclosures that exists under `PodcastItemExtension`.
+    fn alternate_enclosures(&self) -> Vec<PodcastAlternateEnclosure>;
+}
+
+
+/// This `PodcastItemExtensionBuilder` struct creates the `PodcastItemExtension`.
+#[derive(Clone, Default)]
+pub struct PodcastItemExtensionBuilder
+{
+    transcripts: Vec<PodcastTranscript>,
+    chapters: Option<PodcastChapters>,
+    value: Option<PodcastValue>,
+    persons: Vec<PodcastPerson>,
+    location: Option<PodcastLocation>,
+    season: Option<PodcastSeason>,
+    episode: Option<PodcastEpisode>,
+    soundbites: Vec<PodcastSoundbite>,
+    alternate_enclosures: Vec<PodcastAlternateEnclosure>,
+}
+
+
+/// A transcript or captions of an episode, read from `podcast:transcript`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastTranscript
+{
+    /// The URL of the transcript.
+    pub url: String,
+    /// The MIME type of the transcript, such as `text/vtt` or
+    /// `application/x-subrip`.
+    pub mime_type: String,
+    /// The language of the transcript.
+    pub language: Option<String>,
+    /// The relation of the transcript, `captions` when it is a captions file.
+    pub rel: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastTranscript`
+pub trait PodcastTranscriptGetters
+{
+    /// Get the url that exists under `PodcastTranscript`.
+    fn url(&self) -> String;
+
+    /// Get the mime type that exists under `PodcastTranscript`.
+    fn mime_type(&self) -> String;
+
+    /// Get the optional language that exists under `PodcastTranscript`.
+    fn language(&self) -> Option<String>;
+
+    /// Get the optional relation that exists under `PodcastTranscript`.
+    fn rel(&self) -> Option<String>;
+}
+
+
+/// This `PodcastTranscriptBuilder` struct creates the `PodcastTranscript`.
+#[derive(Clone, Default)]
+pub struct PodcastTranscriptBuilder
+{
+    url: String,
+    mime_type: String,
+    language: Option<String>,
+    rel: Option<String>,
+}
+
+
+/// The chapters of an episode, read from `podcast:chapters`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastChapters
+{
+    /// The URL of the chapters document.
+    pub url: String,
+    /// The MIME type of the chapters document, such as
+    /// `application/json+chapters`.
+    pub mime_type: String,
+}
+
+
+/// The Getter functions for `PodcastChapters`
+pub trait PodcastChaptersGetters
+{
+    /// Get the url that exists under `PodcastChapters`.
+    fn url(&self) -> String;
+
+    /// Get the mime type that exists under `PodcastChapters`.
+    fn mime_type(&self) -> String;
+}
+
+
+/// This `PodcastChaptersBuilder` struct creates the `PodcastChapters`.
+#[derive(Clone, Default)]
+pub struct PodcastChaptersBuilder
+{
+    url: String,
+    mime_type: String,
+}
+
+
+/// A link to where a show can be supported, read from `podcast:funding`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastFunding
+{
+    /// The URL of the donation or membership page.
+    pub url: String,
+    /// The text of the link, such as `Support the show!`.
+    pub value: String,
+}
+
+
+/// The Getter functions for `PodcastFunding`
+pub trait PodcastFundingGetters
+{
+    /// Get the url that exists under `PodcastFunding`.
+    fn url(&self) -> String;
+
+    /// Get the value that exists under `PodcastFunding`.
+    fn value(&self) -> String;
+}
+
+
+/// This `PodcastFundingBuilder` struct creates the `PodcastFunding`.
+#[derive(Clone, Default)]
+pub struct PodcastFundingBuilder
+{
+    url: String,
+    value: String,
+}
+
+
+/// How listeners can send payments to the show, read from `podcast:value`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastValue
+{
+    /// The service or protocol of the payments, such as `lightning`.
+    pub value_type: String,
+    /// The method of sending the payments, such as `keysend`.
+    pub method: String,
+    /// The suggested amount to send, as a decimal number.
+    pub suggested: Option<String>,
+    /// The recipients the payments are split between.
+    pub recipients: Vec<PodcastValueRecipient>,
+}
+
+
+/// The Getter functions for `PodcastValue`
+pub trait PodcastValueGetters
+{
+    /// Get the value type that exists under `PodcastValue`.
+    fn value_type(&self) -> String;
+
+    /// Get the method that exists under `PodcastValue`.
+    fn method(&self) -> String;
+
+    /// Get the optional suggested amount that exists under `PodcastValue`.
+    fn suggested(&self) -> Option<String>;
+
+    /// Get the recipients that exists under `PodcastValue`.
+    fn recipients(&self) -> Vec<PodcastValueRecipient>;
+}
+
+
+/// This `PodcastValueBuilder` struct creates the `PodcastValue`.
+#[derive(Clone, Default)]
+pub struct PodcastValueBuilder
+{
+    value_type: String,
+    method: String,
+    suggested: Option<String>,
+    recipients: Vec<PodcastValueRecipient>,
+}
+
+
+/// A recipient of the payments sent to the show, read from
+/// `podcast:valueRecipient`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastValueRecipient
+{
+    /// The name of the recipient.
+    pub name: Option<String>,
+    /// The name of a custom record for the payment.
+    pub custom_key: Option<String>,
+    /// The value of the custom record for the payment.
+    pub custom_value: Option<String>,
+    /// The type of the address, `node` or `lnaddress`.
+    pub recipient_type: String,
+    /// The address of the recipient.
+    pub address: String,
+    /// The number of shares of the payments the recipient gets, or the
+    /// percentage taken if the split is a fee.
+    pub split: i64,
+    /// Whether the split is a fee taken before the other splits.
+    pub fee: Option<bool>,
+}
+
+
+/// The Getter functions for `PodcastValueRecipient`
+pub trait PodcastValueRecipientGetters
+{
+    /// Get the optional name that exists under `PodcastValueRecipient`.
+    fn name(&self) -> Option<String>;
+
+    /// Get the optional custom key that exists under `PodcastValueRecipient`.
+    fn custom_key(&self) -> Option<String>;
+
+    /// Get the optional custom value that exists under `PodcastValueRecipient`.
+    fn custom_value(&self) -> Option<String>;
+
+    /// Get the recipient type that exists under `PodcastValueRecipient`.
+    fn recipient_type(&self) -> String;
+
+    /// Get the address that exists under `PodcastValueRecipient`.
+    fn address(&self) -> String;
+
+    /// Get the split that exists under `PodcastValueRecipient`.
+    fn split(&self) -> i64;
+
+    /// Get the optional fee flag that exists under `PodcastValueRecipient`.
+    fn fee(&self) -> Option<bool>;
+}
+
+
+/// This `PodcastValueRecipientBuilder` struct creates the `PodcastValueRecipient`.
+#[derive(Clone, Default)]
+pub struct PodcastValueRecipientBuilder
+{
+    name: Option<String>,
+    custom_key: Option<String>,
+    custom_value: Option<String>,
+    recipient_type: String,
+    address: String,
+    split: i64,
+    fee: Option<bool>,
+}
+
+
+/// A person in a show or an episode, read from `podcast:person`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastPerson
+{
+    /// The name of the person.
+    pub name: String,
+    /// The role of the person, such as `host` or `guest`.
+    pub role: Option<String>,
+    /// The group of the role, such as `cast` or `writing`.
+    pub group: Option<String>,
+    /// The URL of a picture of the person.
+    pub img: Option<String>,
+    /// The URL of a page about the person.
+    pub href: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastPerson`
+pub trait PodcastPersonGetters
+{
+    /// Get the name that exists under `PodcastPerson`.
+    fn name(&self) -> String;
+
+    /// Get the optional role that exists under `PodcastPerson`.
+    fn role(&self) -> Option<String>;
+
+    /// Get the optional group that exists under `PodcastPerson`.
+    fn group(&self) -> Option<String>;
+
+    /// Get the optional image that exists under `PodcastPerson`.
+    fn img(&self) -> Option<String>;
+
+    /// Get the optional link that exists under `PodcastPerson`.
+    fn href(&self) -> Option<String>;
+}
+
+
+/// This `PodcastPersonBuilder` struct creates the `PodcastPerson`.
+#[derive(Clone, Default)]
+pub struct PodcastPersonBuilder
+{
+    name: String,
+    role: Option<String>,
+    group: Option<String>,
+    img: Option<String>,
+    href: Option<String>,
+}
+
+
+/// The location a show or an episode is about, read from `podcast:location`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastLocation
+{
+    /// The name of the location.
+    pub name: String,
+    /// The geo URI of the location, such as `geo:30.2672,-97.7431`.
+    pub geo: Option<String>,
+    /// The OpenStreetMap identifier of the location, such as `R113314`.
+    pub osm: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastLocation`
+pub trait PodcastLocationGetters
+{
+    /// Get the name that exists under `PodcastLocation`.
+    fn name(&self) -> String;
+
+    /// Get the optional geo URI that exists under `PodcastLocation`.
+    fn geo(&self) -> Option<String>;
+
+    /// Get the optional OpenStreetMap identifier that exists under `PodcastLocation`.
+    fn osm(&self) -> Option<String>;
+}
+
+
+/// This `PodcastLocationBuilder` struct creates the `PodcastLocation`.
+#[derive(Clone, Default)]
+pub struct PodcastLocationBuilder
+{
+    name: String,
+    geo: Option<String>,
+    osm: Option<String>,
+}
+
+
+/// The season of an episode, read from `podcast:season`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastSeason
+{
+    /// The number of the season.
+    pub number: i64,
+    /// The name of the season.
+    pub name: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastSeason`
+pub trait PodcastSeasonGetters
+{
+    /// Get the number that exists under `PodcastSeason`.
+    fn number(&self) -> i64;
+
+    /// Get the optional name that exists under `PodcastSeason`.
+    fn name(&self) -> Option<String>;
+}
+
+
+/// This `PodcastSeasonBuilder` struct creates the `PodcastSeason`.
+#[derive(Clone, Default)]
+pub struct PodcastSeasonBuilder
+{
+    number: i64,
+    name: Option<String>,
+}
+
+
+/// The number of an episode, read from `podcast:episode`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastEpisode
+{
+    /// The number of the episode, which can have a fraction.
+    pub number: f64,
+    /// The text to show instead of the number.
+    pub display: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastEpisode`
+pub trait PodcastEpisodeGetters
+{
+    /// Get the number that exists under `PodcastEpisode`.
+    fn number(&self) -> f64;
+
+    /// Get the optional display text that exists under `PodcastEpisode`.
+    fn display(&self) -> Option<String>;
+}
+
+
+/// This `PodcastEpisodeBuilder` struct creates the `PodcastEpisode`.
+#[derive(Clone, Default)]
+pub struct PodcastEpisodeBuilder
+{
+    number: f64,
+    display: Option<String>,
+}
+
+
+/// A part of an episode suited to be shared, read from `podcast:soundbite`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastSoundbite
+{
+    /// The number of seconds into the episode the soundbite starts at.
+    pub start_time: f64,
+    /// The length of the soundbite in seconds.
+    pub duration: f64,
+    /// The title of the soundbite.
+    pub title: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastSoundbite`
+pub trait PodcastSoundbiteGetters
+{
+    /// Get the start time that exists under `PodcastSoundbite`.
+    fn start_time(&self) -> f64;
+
+    /// Get the duration that exists under `PodcastSoundbite`.
+    fn duration(&self) -> f64;
+
+    /// Get the optional title that exists under `PodcastSoundbite`.
+    fn title(&self) -> Option<String>;
+}
+
+
+/// This `PodcastSoundbiteBuilder` struct creates the `PodcastSoundbite`.
+#[derive(Clone, Default)]
+pub struct PodcastSoundbiteBuilder
+{
+    start_time: f64,
+    duration: f64,
+    title: Option<String>,
+}
+
+
+/// Whether a show may be imported to other platforms, read from
+/// `podcast:locked`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastLocked
+{
+    /// Whether the show is locked, `yes` or `no`.
+    pub value: String,
+    /// The email address of the owner of the show.
+    pub owner: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastLocked`
+pub trait PodcastLockedGetters
+{
+    /// Get the value that exists under `PodcastLocked`.
+    fn value(&self) -> String;
+
+    /// Get the optional owner that exists under `PodcastLocked`.
+    fn owner(&self) -> Option<String>;
+}
+
+
+/// This `PodcastLockedBuilder` struct creates the `PodcastLocked`.
+#[derive(Clone, Default)]
+pub struct PodcastLockedBuilder
+{
+    value: String,
+    owner: Option<String>,
+}
+
+
+/// Another version of the media of an episode, read from
+/// `podcast:alternateEnclosure`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastAlternateEnclosure
+{
+    /// The MIME type of the media.
+    pub mime_type: String,
+    /// The length of the media in bytes.
+    pub length: Option<i64>,
+    /// The average bitrate of the media in bits per second.
+    pub bitrate: Option<f64>,
+    /// The height of the video in pixels.
+    pub height: Option<i64>,
+    /// The language of the media.
+    pub lang: Option<String>,
+    /// The title of the version, such as `Standard`.
+    pub title: Option<String>,
+    /// The relation that groups versions with the same content.
+    pub rel: Option<String>,
+    /// The codecs of the media, as in RFC 6381, such as `opus`.
+    pub codecs: Option<String>,
+    /// Whether the version is the same as the `enclosure` of the item.
+    pub is_default: Option<bool>,
+    /// The URIs the media can be got from.
+    pub sources: Vec<PodcastSource>,
+    /// The hash or signature of the media.
+    pub integrity: Option<PodcastIntegrity>,
+}
+
+
+/// The Getter functions for `PodcastAlternateEnclosure`
+pub trait PodcastAlternateEnclosureGetters
+{
+    /// Get the mime type that exists under `PodcastAlternateEnclosure`.
+    fn mime_type(&self) -> String;
+
+    /// Get the optional length in bytes that exists under `PodcastAlternateEnclosure`.
+    fn length(&self) -> Option<i64>;
+
+    /// Get the optional bitrate that exists under `PodcastAlternateEnclosure`.
+    fn bitrate(&self) -> Option<f64>;
+
+    /// Get the optional height that exists under `PodcastAlternateEnclosure`.
+    fn height(&self) -> Option<i64>;
+
+    /// Get the optional language that exists under `PodcastAlternateEnclosure`.
+    fn lang(&self) -> Option<String>;
+
+    /// Get the optional title that exists under `PodcastAlternateEnclosure`.
+    fn title(&self) -> Option<String>;
+
+    /// Get the optional relation that exists under `PodcastAlternateEnclosure`.
+    fn rel(&self) -> Option<String>;
+
+    /// Get the optional codecs that exists under `PodcastAlternateEnclosure`.
+    fn codecs(&self) -> Option<String>;
+
+    /// Get the optional is default flag that exists under `PodcastAlternateEnclosure`.
+    fn is_default(&self) -> Option<bool>;
+
+    /// Get the sources that exists under `PodcastAlternateEnclosure`.
+    fn sources(&self) -> Vec<PodcastSource>;
+
+    /// Get the optional integrity that exists under `PodcastAlternateEnclosure`.
+    fn integrity(&self) -> Option<PodcastIntegrity>;
+}
+
+
+/// This `PodcastAlternateEnclosureBuilder` struct creates the `PodcastAlternateEnclosure`.
+#[derive(Clone, Default)]
+pub struct PodcastAlternateEnclosureBuilder
+{
+    mime_type: String,
+    length: Option<i64>,
+    bitrate: Option<f64>,
+    height: Option<i64>,
+    lang: Option<String>,
+    title: Option<String>,
+    rel: Option<String>,
+    codecs: Option<String>,
+    is_default: Option<bool>,
+    sources: Vec<PodcastSource>,
+    integrity: Option<PodcastIntegrity>,
+}
+
+
+/// A URI an alternate enclosure can be got from, read from `podcast:source`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastSource
+{
+    /// The URI of the media, such as an HTTP, IPFS or magnet URI.
+    pub uri: String,
+    /// The MIME type of the media at the URI, when it differs from that of the
+    /// alternate enclosure.
+    pub content_type: Option<String>,
+}
+
+
+/// The Getter functions for `PodcastSource`
+pub trait PodcastSourceGetters
+{
+    /// Get the uri that exists under `PodcastSource`.
+    fn uri(&self) -> String;
+
+    /// Get the optional content type that exists under `PodcastSource`.
+    fn content_type(&self) -> Option<String>;
+}
+
+
+/// This `PodcastSourceBuilder` struct creates the `PodcastSource`.
+#[derive(Clone, Default)]
+pub struct PodcastSourceBuilder
+{
+    uri: String,
+    content_type: Option<String>,
+}
+
+
+/// A hash or signature to check an alternate enclosure with, read from
+/// `podcast:integrity`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastIntegrity
+{
+    /// The type of the integrity, `sri` or `pgp-signature`.
+    pub integrity_type: String,
+    /// The hash, such as `sha384-...`, or the signature.
+    pub value: String,
+}
+
+
+/// The Getter functions for `PodcastIntegrity`
+pub trait PodcastIntegrityGetters
+{
+    /// Get the integrity type that exists under `PodcastIntegrity`.
+    fn integrity_type(&self) -> String;
+
+    /// Get the value that exists under `PodcastIntegrity`.
+    fn value(&self) -> String;
+}
+
+
+/// This `PodcastIntegrityBuilder` struct creates the `PodcastIntegrity`.
+#[derive(Clone, Default)]
+pub struct PodcastIntegrityBuilder
+{
+    integrity_type: String,
+    value: String,
+}
+
+
+/// A live stream of the show, read from `podcast:liveItem`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastLiveItem
+{
+    /// The status of the stream, `pending`, `live` or `ended`.
+    pub status: String,
+    /// When the stream starts, as an ISO 8601 date.
+    pub start: String,
+    /// When the stream ends, as an ISO 8601 date.
+    pub end: Option<String>,
+    /// The elements of the stream, which are those of an `Item`.
+    pub item: Item,
+}
+
+
+/// The Getter functions for `PodcastLiveItem`
+pub trait PodcastLiveItemGetters
+{
+    /// Get the status that exists under `PodcastLiveItem`.
+    fn status(&self) -> String;
+
+    /// Get the start that exists under `PodcastLiveItem`.
+    fn start(&self) -> String;
+
+    /// Get the optional end that exists under `PodcastLiveItem`.
+    fn end(&self) -> Option<String>;
+
+    /// Get the item that exists under `PodcastLiveItem`.
+    fn item(&self) -> Item;
+}
+
+
+/// This `PodcastLiveItemBuilder` struct creates the `PodcastLiveItem`.
+#[derive(Clone, Default)]
+pub struct PodcastLiveItemBuilder
+{
+    status: String,
+    start: String,
+    end: Option<String>,
+    item: Item,
+}
+
+
+/// A chapter of an episode, read from a JSON chapters or a Podlove Simple
+/// Chapters document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastChapter
+{
+    /// The number of seconds into the episode the chapter starts at.
+    pub start_time: f64,
+    /// The number of seconds into the episode the chapter ends at.
+    pub end_time: Option<f64>,
+    /// The title of the chapter.
+    pub title: Option<String>,
+    /// The URL of the artwork of the chapter.
+    pub image: Option<String>,
+    /// The URL of a web page about the chapter.
+    pub url: Option<String>,
+    /// Whether the chapter belongs in a table of contents.
+    pub toc: bool,
+}
+
+
+/// A cue of a transcript, read from a WebVTT, SRT or JSON transcript.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodcastCue
+{
+    /// The number of seconds into the episode the cue starts at.
+    pub start_time: f64,
+    /// The number of seconds into the episode the cue ends at.
+    pub end_time: f64,
+    /// The speaker of the cue.
+    pub speaker: Option<String>,
+    /// The text of the cue, without markup.
+    pub text: String,
+}
@@ -0,0 +1,145 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Picking the alternate enclosure that suits a client best.
+
+
+use extension::podcast::PodcastAlternateEnclosure;
+use std::cmp::Ordering;
+
+
+impl PodcastAlternateEnclosure
+{
+    /// Pick the alternate enclosure that suits a client best, given the
+    /// bitrate its connection can sustain in bits per second and the codecs
+    /// it can play, most preferred first.
+    ///
+    /// Alternate enclosures without a source are passed over. Of those within
+    /// the bandwidth, the one with the most preferred codec and then the
+    /// highest bitrate is picked. When none are within it, the one with the
+    /// lowest bitrate and then the most preferred codec is picked. Ties go to
+    /// the default one. A codec matches the codecs or the MIME type of an
+    /// alternate enclosure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosure, PodcastAlternateEnclosureBuilder,
+    ///                                PodcastSourceBuilder};
+    ///
+    /// let alternate = |mime_type: &str, bitrate: f64| {
+    ///     let source = PodcastSourceBuilder::new()
+    ///         .uri("https://example.com/episode1")
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     PodcastAlternateEnclosureBuilder::new()
+    ///         .mime_type(mime_type)
+    ///         .bitrate(Some(bitrate))
+    ///         .sources(vec![source])
+    ///         .finalize()
+    ///         .unwrap()
+    /// };
+    ///
+    /// let alternates = vec![alternate("audio/mpeg", 128000.0),
+    ///                       alternate("audio/opus", 32000.0),
+    ///                       alternate("audio/opus", 96000.0),
+    ///                       alternate("audio/flac", 1000000.0)];
+    ///
+    /// let best = PodcastAlternateEnclosure::best(&alternates, Some(100000.0), &["opus", "mpeg"]).unwrap();
+    /// assert_eq!(Some(96000.0), best.bitrate);
+    ///
+    /// let best = PodcastAlternateEnclosure::best(&alternates, Some(16000.0), &["flac"]).unwrap();
+    /// assert_eq!(Some(32000.0), best.bitrate);
+    ///
+    /// let best = PodcastAlternateEnclosure::best(&alternates, None, &["flac"]).unwrap();
+    /// assert_eq!("audio/flac", best.mime_type);
+    /// ```
+    pub fn best<'a>(alternates: &'a [PodcastAlternateEnclosure],
+                    bandwidth: Option<f64>,
+                    codecs: &[&str])
+        -> Option<&'a PodcastAlternateEnclosure>
+    {
+        alternates
+            .iter()
+            .filter(|alternate| !alternate.sources.is_empty())
+            .min_by(|a, b| compare(a, b, bandwidth, codecs))
+    }
+
+
+    /// Check whether the alternate enclosure is within a bandwidth, in bits
+    /// per second. One without a bitrate is taken to be within it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .bitrate(Some(96000.0))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(alternate.fits(128000.0));
+    /// assert!(!alternate.fits(64000.0));
+    /// ```
+    pub fn fits(&self, bandwidth: f64) -> bool
+    {
+        self.bitrate.is_none_or(|bitrate| bitrate <= bandwidth)
+    }
+
+
+    // Get the position of the first of the codecs the alternate enclosure
+    // matches, or the number of codecs when it matches none.
+    fn codec_rank(&self, codecs: &[&str]) -> usize
+    {
+        let mime_type = self.mime_type.to_lowercase();
+        let own: Vec<String> = self.codecs
+            .as_ref()
+            .map(|own| own.split(',').map(|codec| codec.trim().to_lowercase()).collect())
+            .unwrap_or_default();
+
+        codecs
+            .iter()
+            .map(|codec| codec.to_lowercase())
+            .position(|codec| {
+                          own.iter().any(|own| own == &codec || own.starts_with(&format!("{}.", codec))) ||
+                          mime_type.split('/').nth(1) == Some(codec.as_str())
+                      })
+            .unwrap_or(codecs.len())
+    }
+}
+
+
+// Order two alternate enclosures from the one that suits the client best.
+fn compare(a: &PodcastAlternateEnclosure,
+           b: &PodcastAlternateEnclosure,
+           bandwidth: Option<f64>,
+           codecs: &[&str])
+    -> Ordering
+{
+    let fits = |alternate: &PodcastAlternateEnclosure| bandwidth.is_none_or(|bandwidth| alternate.fits(bandwidth));
+    let bitrate = |alternate: &PodcastAlternateEnclosure| alternate.bitrate.unwrap_or(0.0);
+
+    let codec = a.codec_rank(codecs).cmp(&b.codec_rank(codecs));
+
+    fits(b)
+        .cmp(&fits(a))
+        .then_with(|| if fits(a)
+                   {
+                       codec.then_with(|| bitrate(b).total_cmp(&bitrate(a)))
+                   }
+                   else
+                   {
+                       bitrate(a).total_cmp(&bitrate(b)).then(codec)
+                   })
+        .then_with(|| b.is_default.unwrap_or(false).cmp(&a.is_default.unwrap_or(false)))
+}
@@ -0,0 +1,324 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast alternate enclosure by using the methods under
+//! `PodcastAlternateEnclosureBuilder`.
+
+
+use extension::podcast::{PodcastAlternateEnclosure, PodcastAlternateEnclosureBuilder, PodcastIntegrity, PodcastSource};
+use mime::Mime;
+
+
+impl PodcastAlternateEnclosureBuilder
+{
+    /// Construct a new `PodcastAlternateEnclosureBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// ```
+    pub fn new() -> PodcastAlternateEnclosureBuilder
+    {
+        PodcastAlternateEnclosureBuilder::default()
+    }
+
+
+    /// Set the mime type that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.mime_type("audio/opus");
+    /// ```
+    pub fn mime_type(&mut self, mime_type: &str) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.mime_type = mime_type.to_owned();
+        self
+    }
+
+
+    /// Set the optional length in bytes that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.length(Some(32400000));
+    /// ```
+    pub fn length(&mut self, length: Option<i64>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.length = length;
+        self
+    }
+
+
+    /// Set the optional bitrate that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.bitrate(Some(96000.0));
+    /// ```
+    pub fn bitrate(&mut self, bitrate: Option<f64>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.bitrate = bitrate;
+        self
+    }
+
+
+    /// Set the optional height that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.height(Some(1080));
+    /// ```
+    pub fn height(&mut self, height: Option<i64>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.height = height;
+        self
+    }
+
+
+    /// Set the optional language that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.lang(Some("en".to_owned()));
+    /// ```
+    pub fn lang(&mut self, lang: Option<String>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.lang = lang;
+        self
+    }
+
+
+    /// Set the optional title that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.title(Some("Standard".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional relation that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.rel(Some("Off stage".to_owned()));
+    /// ```
+    pub fn rel(&mut self, rel: Option<String>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.rel = rel;
+        self
+    }
+
+
+    /// Set the optional codecs that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.codecs(Some("opus".to_owned()));
+    /// ```
+    pub fn codecs(&mut self, codecs: Option<String>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.codecs = codecs;
+        self
+    }
+
+
+    /// Set the optional is default flag that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.is_default(Some(true));
+    /// ```
+    pub fn is_default(&mut self, is_default: Option<bool>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.is_default = is_default;
+        self
+    }
+
+
+    /// Set the sources that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastSourceBuilder};
+    ///
+    /// let source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.sources(vec![source]);
+    /// ```
+    pub fn sources(&mut self, sources: Vec<PodcastSource>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.sources = sources;
+        self
+    }
+
+
+    /// Set the optional integrity that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastIntegrityBuilder};
+    ///
+    /// let integrity = PodcastIntegrityBuilder::new()
+    ///     .integrity_type("sri")
+    ///     .value("sha384-ExVqijgYHm15PqQqdXfW95x+Rs6C+d6E/ICxyQOeFevnxNLR/wtJNrNYTjIysUBo")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_alternate_enclosure_builder = PodcastAlternateEnclosureBuilder::new();
+    /// podcast_alternate_enclosure_builder.integrity(Some(integrity));
+    /// ```
+    pub fn integrity(&mut self, integrity: Option<PodcastIntegrity>) -> &mut PodcastAlternateEnclosureBuilder
+    {
+        self.integrity = integrity;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastAlternateEnclosure`.
+    ///
+    /// The numbers cannot be negative, and there must be at least one source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastSourceBuilder};
+    ///
+    /// let source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .bitrate(Some(96000.0))
+    ///     .codecs(Some("opus".to_owned()))
+    ///     .sources(vec![source])
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastAlternateEnclosureBuilder, String>
+    {
+        if let Err(err) = self.mime_type.parse::<Mime>()
+        {
+            return Err(format!("Error: {:?}", err));
+        }
+
+        if let Some(length) = self.length
+        {
+            if length < 0
+            {
+                return Err(format!("Invalid value: {}", length));
+            }
+        }
+
+        if let Some(bitrate) = self.bitrate
+        {
+            if bitrate.is_nan() || bitrate < 0.0
+            {
+                return Err(format!("Invalid value: {}", bitrate));
+            }
+        }
+
+        if let Some(height) = self.height
+        {
+            if height < 0
+            {
+                return Err(format!("Invalid value: {}", height));
+            }
+        }
+
+        if self.sources.is_empty()
+        {
+            return Err("Alternate enclosure must have a source.".to_owned());
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastAlternateEnclosure` from the `PodcastAlternateEnclosureBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastAlternateEnclosure, String>
+    {
+        Ok(PodcastAlternateEnclosure {
+               mime_type: self.mime_type.clone(),
+               length: self.length,
+               bitrate: self.bitrate,
+               height: self.height,
+               lang: self.lang.clone(),
+               title: self.title.clone(),
+               rel: self.rel.clone(),
+               codecs: self.codecs.clone(),
+               is_default: self.is_default,
+               sources: self.sources.clone(),
+               integrity: self.integrity.clone(),
+           })
+    }
+}
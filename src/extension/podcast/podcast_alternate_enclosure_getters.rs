@@ -0,0 +1,390 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast alternate enclosure can be retrieved by using the methods
+//! under `PodcastAlternateEnclosure`.
+
+
+use extension::podcast::{PodcastAlternateEnclosure, PodcastAlternateEnclosureGetters,
+                         PodcastIntegrity, PodcastSource};
+
+
+impl PodcastAlternateEnclosureGetters for PodcastAlternateEnclosure
+{
+    /// Get the mime type that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let mime_type = "audio/opus";
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type(mime_type)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mime_type.to_owned(), podcast_alternate_enclosure.mime_type());
+    /// ```
+    fn mime_type(&self) -> String
+    {
+        self.mime_type.clone()
+    }
+
+
+    /// Get the optional length in bytes that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let length = Some(32400000);
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .length(length)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(length, podcast_alternate_enclosure.length());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .length(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.length().is_none());
+    /// ```
+    fn length(&self) -> Option<i64>
+    {
+        self.length
+    }
+
+
+    /// Get the optional bitrate that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let bitrate = Some(96000.0);
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .bitrate(bitrate)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(bitrate, podcast_alternate_enclosure.bitrate());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .bitrate(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.bitrate().is_none());
+    /// ```
+    fn bitrate(&self) -> Option<f64>
+    {
+        self.bitrate
+    }
+
+
+    /// Get the optional height that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let height = Some(1080);
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .height(height)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(height, podcast_alternate_enclosure.height());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .height(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.height().is_none());
+    /// ```
+    fn height(&self) -> Option<i64>
+    {
+        self.height
+    }
+
+
+    /// Get the optional language that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let lang = Some("en".to_owned());
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .lang(lang.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(lang, podcast_alternate_enclosure.lang());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .lang(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.lang().is_none());
+    /// ```
+    fn lang(&self) -> Option<String>
+    {
+        self.lang.clone()
+    }
+
+
+    /// Get the optional title that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let title = Some("Standard".to_owned());
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .title(title.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(title, podcast_alternate_enclosure.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .title(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.title().is_none());
+    /// ```
+    fn title(&self) -> Option<String>
+    {
+        self.title.clone()
+    }
+
+
+    /// Get the optional relation that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let rel = Some("Off stage".to_owned());
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .rel(rel.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rel, podcast_alternate_enclosure.rel());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .rel(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.rel().is_none());
+    /// ```
+    fn rel(&self) -> Option<String>
+    {
+        self.rel.clone()
+    }
+
+
+    /// Get the optional codecs that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let codecs = Some("opus".to_owned());
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .codecs(codecs.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(codecs, podcast_alternate_enclosure.codecs());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .codecs(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.codecs().is_none());
+    /// ```
+    fn codecs(&self) -> Option<String>
+    {
+        self.codecs.clone()
+    }
+
+
+    /// Get the optional is default flag that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let is_default = Some(true);
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .is_default(is_default)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(is_default, podcast_alternate_enclosure.is_default());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .is_default(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.is_default().is_none());
+    /// ```
+    fn is_default(&self) -> Option<bool>
+    {
+        self.is_default
+    }
+
+
+    /// Get the sources that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters,
+    ///                                PodcastSourceBuilder};
+    ///
+    /// let source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let sources = vec![source];
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .sources(sources.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_alternate_enclosure.sources().len());
+    /// ```
+    fn sources(&self) -> Vec<PodcastSource>
+    {
+        self.sources.clone()
+    }
+
+
+    /// Get the optional integrity that exists under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters,
+    ///                                PodcastIntegrityBuilder};
+    ///
+    /// let integrity = PodcastIntegrityBuilder::new()
+    ///     .integrity_type("sri")
+    ///     .value("sha384-ExVqijgYHm15PqQqdXfW95x+Rs6C+d6E/ICxyQOeFevnxNLR/wtJNrNYTjIysUBo")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let integrity = Some(integrity);
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .integrity(integrity.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.integrity().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastAlternateEnclosureGetters};
+    ///
+    /// let podcast_alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .integrity(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_alternate_enclosure.integrity().is_none());
+    /// ```
+    fn integrity(&self) -> Option<PodcastIntegrity>
+    {
+        self.integrity.clone()
+    }
+}
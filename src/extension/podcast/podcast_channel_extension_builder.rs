@@ -0,0 +1,248 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast channel extension by using the methods under
+//! `PodcastChannelExtensionBuilder`.
+
+
+use extension::podcast::{PodcastChannelExtension, PodcastChannelExtensionBuilder, PodcastFunding,
+                         PodcastLiveItem, PodcastLocation, PodcastLocked, PodcastPerson,
+                         PodcastValue};
+
+
+impl PodcastChannelExtensionBuilder
+{
+    /// Construct a new `PodcastChannelExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// ```
+    pub fn new() -> PodcastChannelExtensionBuilder
+    {
+        PodcastChannelExtensionBuilder::default()
+    }
+
+
+    /// Set the funding that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastFundingBuilder};
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value("Support the show!")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_channel_extension_builder.funding(vec![funding]);
+    /// ```
+    pub fn funding(&mut self, funding: Vec<PodcastFunding>) -> &mut PodcastChannelExtensionBuilder
+    {
+        self.funding = funding;
+        self
+    }
+
+
+    /// Set the optional value that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_channel_extension_builder.value(Some(value));
+    /// ```
+    pub fn value(&mut self, value: Option<PodcastValue>) -> &mut PodcastChannelExtensionBuilder
+    {
+        self.value = value;
+        self
+    }
+
+
+    /// Set the persons that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .role(Some("host".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_channel_extension_builder.persons(vec![person]);
+    /// ```
+    pub fn persons(&mut self, persons: Vec<PodcastPerson>) -> &mut PodcastChannelExtensionBuilder
+    {
+        self.persons = persons;
+        self
+    }
+
+
+    /// Set the optional location that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastLocationBuilder};
+    ///
+    /// let location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .geo(Some("geo:30.2672,-97.7431".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_channel_extension_builder.location(Some(location));
+    /// ```
+    pub fn location(&mut self, location: Option<PodcastLocation>) -> &mut PodcastChannelExtensionBuilder
+    {
+        self.location = location;
+        self
+    }
+
+
+    /// Set the optional guid that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let mut podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_channel_extension_builder.guid(Some("ead4c236-bf58-58c6-a2c6-a6b28d128cb6".to_owned()));
+    /// ```
+    pub fn guid(&mut self, guid: Option<String>) -> &mut PodcastChannelExtensionBuilder
+    {
+        self.guid = guid;
+        self
+    }
+
+
+    /// Set the optional locked that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastLockedBuilder};
+    ///
+    /// let locked = PodcastLockedBuilder::new()
+    ///     .value("yes")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_channel_extension_builder.locked(Some(locked));
+    /// ```
+    pub fn locked(&mut self, locked: Option<PodcastLocked>) -> &mut PodcastChannelExtensionBuilder
+    {
+        self.locked = locked;
+        self
+    }
+
+
+    /// Set the live items that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastLiveItemBuilder};
+    ///
+    /// let live_item = PodcastLiveItemBuilder::new()
+    ///     .status("live")
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_channel_extension_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_channel_extension_builder.live_items(vec![live_item]);
+    /// ```
+    pub fn live_items(&mut self, live_items: Vec<PodcastLiveItem>) -> &mut PodcastChannelExtensionBuilder
+    {
+        self.live_items = live_items;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastChannelExtension`.
+    ///
+    /// The guid must be a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .guid(Some("ead4c236-bf58-58c6-a2c6-a6b28d128cb6".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastChannelExtensionBuilder, String>
+    {
+        if let Some(ref guid) = self.guid
+        {
+            let valid = guid.len() == 36 &&
+                        guid.char_indices().all(|(pos, c)| match pos
+                                                {
+                                                    8 | 13 | 18 | 23 => c == '-',
+                                                    _ => c.is_ascii_hexdigit(),
+                                                });
+            if !valid
+            {
+                return Err(format!("Invalid value: {}", guid));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastChannelExtension` from the `PodcastChannelExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastChannelExtension, String>
+    {
+        Ok(PodcastChannelExtension {
+               funding: self.funding.clone(),
+               value: self.value.clone(),
+               persons: self.persons.clone(),
+               location: self.location.clone(),
+               guid: self.guid.clone(),
+               locked: self.locked.clone(),
+               live_items: self.live_items.clone(),
+           })
+    }
+}
@@ -0,0 +1,253 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast channel extension can be retrieved by using the methods
+//! under `PodcastChannelExtension`.
+
+
+use extension::podcast::{PodcastChannelExtension, PodcastChannelExtensionGetters, PodcastFunding,
+                         PodcastLiveItem, PodcastLocation, PodcastLocked, PodcastPerson,
+                         PodcastValue};
+
+
+impl PodcastChannelExtensionGetters for PodcastChannelExtension
+{
+    /// Get the funding that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters,
+    ///                                PodcastFundingBuilder};
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value("Support the show!")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let funding = vec![funding];
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .funding(funding.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_channel_extension.funding().len());
+    /// ```
+    fn funding(&self) -> Vec<PodcastFunding>
+    {
+        self.funding.clone()
+    }
+
+
+    /// Get the optional value that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters,
+    ///                                PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let value = Some(value);
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .value(value.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_channel_extension.value().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters};
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .value(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_channel_extension.value().is_none());
+    /// ```
+    fn value(&self) -> Option<PodcastValue>
+    {
+        self.value.clone()
+    }
+
+
+    /// Get the persons that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters,
+    ///                                PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .role(Some("host".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let persons = vec![person];
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .persons(persons.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_channel_extension.persons().len());
+    /// ```
+    fn persons(&self) -> Vec<PodcastPerson>
+    {
+        self.persons.clone()
+    }
+
+
+    /// Get the optional location that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters,
+    ///                                PodcastLocationBuilder};
+    ///
+    /// let location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .geo(Some("geo:30.2672,-97.7431".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let location = Some(location);
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .location(location.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_channel_extension.location().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters};
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .location(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_channel_extension.location().is_none());
+    /// ```
+    fn location(&self) -> Option<PodcastLocation>
+    {
+        self.location.clone()
+    }
+
+
+    /// Get the optional guid that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters};
+    ///
+    /// let guid = Some("ead4c236-bf58-58c6-a2c6-a6b28d128cb6".to_owned());
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .guid(guid.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(guid, podcast_channel_extension.guid());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters};
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .guid(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_channel_extension.guid().is_none());
+    /// ```
+    fn guid(&self) -> Option<String>
+    {
+        self.guid.clone()
+    }
+
+
+    /// Get the optional locked that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters,
+    ///                                PodcastLockedBuilder};
+    ///
+    /// let locked = PodcastLockedBuilder::new()
+    ///     .value("yes")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let locked = Some(locked);
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .locked(locked.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_channel_extension.locked().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters};
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .locked(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_channel_extension.locked().is_none());
+    /// ```
+    fn locked(&self) -> Option<PodcastLocked>
+    {
+        self.locked.clone()
+    }
+
+
+    /// Get the live items that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastChannelExtensionGetters,
+    ///                                PodcastLiveItemBuilder};
+    ///
+    /// let live_item = PodcastLiveItemBuilder::new()
+    ///     .status("live")
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let live_items = vec![live_item];
+    ///
+    /// let podcast_channel_extension = PodcastChannelExtensionBuilder::new()
+    ///     .live_items(live_items.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_channel_extension.live_items().len());
+    /// ```
+    fn live_items(&self) -> Vec<PodcastLiveItem>
+    {
+        self.live_items.clone()
+    }
+}
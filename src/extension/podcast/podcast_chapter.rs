@@ -0,0 +1,200 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading the chapters of an episode from JSON chapters and Podlove Simple
+//! Chapters documents.
+
+
+use extension::podcast::{PodcastChapter, PodcastChapters};
+use serde_json::{self, Value};
+use utils::reader_utils::{self, XmlElement};
+use utils::{string_utils, url_utils};
+
+
+impl PodcastChapter
+{
+    /// Read the chapters of a JSON chapters document, in the order they
+    /// start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChapter;
+    ///
+    /// let json = r#"{
+    ///     "version": "1.2.0",
+    ///     "chapters": [
+    ///         { "startTime": 0, "title": "Intro" },
+    ///         { "startTime": 90.5, "title": "News", "url": "https://example.com/news" },
+    ///         { "startTime": 600, "title": "Sponsor", "toc": false }
+    ///     ]
+    /// }"#;
+    ///
+    /// let chapters = PodcastChapter::from_json(json).unwrap();
+    ///
+    /// assert_eq!(3, chapters.len());
+    /// assert_eq!(90.5, chapters[1].start_time);
+    /// assert_eq!(Some("News".to_owned()), chapters[1].title);
+    /// assert!(!chapters[2].toc);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Vec<PodcastChapter>, String>
+    {
+        let root: Value = serde_json::from_str(json).map_err(|err| format!("Error: {}", err))?;
+
+        let entries = match root.get("chapters").and_then(Value::as_array)
+        {
+            Some(entries) => entries,
+            None => return Err("JSON chapters must have a chapters array.".to_owned()),
+        };
+
+        let mut chapters = Vec::new();
+        for entry in entries
+        {
+            let start_time = match entry.get("startTime").and_then(Value::as_f64)
+            {
+                Some(start_time) => start_time,
+                None => return Err("JSON chapter must have a start time.".to_owned()),
+            };
+
+            let text = |name: &str| entry.get(name).and_then(Value::as_str).map(|val| val.to_owned());
+
+            chapters.push(PodcastChapter {
+                              start_time,
+                              end_time: entry.get("endTime").and_then(Value::as_f64),
+                              title: text("title"),
+                              image: text("img"),
+                              url: text("url"),
+                              toc: entry.get("toc").and_then(Value::as_bool).unwrap_or(true),
+                          });
+        }
+
+        sort(&mut chapters);
+        Ok(chapters)
+    }
+
+
+    /// Read the chapters of a Podlove Simple Chapters document, in the order
+    /// they start.
+    ///
+    /// The `psc:chapters` element can be the root of the document or be
+    /// inside it, as it is in an RSS feed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChapter;
+    ///
+    /// let xml = r#"<psc:chapters version="1.2" xmlns:psc="http://podlove.org/simple-chapters">
+    ///     <psc:chapter start="00:00:00.000" title="Intro" />
+    ///     <psc:chapter start="00:01:30.500" title="News" href="https://example.com/news" />
+    /// </psc:chapters>"#;
+    ///
+    /// let chapters = PodcastChapter::from_psc(xml).unwrap();
+    ///
+    /// assert_eq!(2, chapters.len());
+    /// assert_eq!(90.5, chapters[1].start_time);
+    /// assert_eq!(Some("https://example.com/news".to_owned()), chapters[1].url);
+    /// ```
+    pub fn from_psc(xml: &str) -> Result<Vec<PodcastChapter>, String>
+    {
+        let root = reader_utils::read_document(xml)?;
+
+        let mut chapters = Vec::new();
+        match find_chapters(&root)
+        {
+            Some(element) =>
+            {
+                for chapter in element.children.iter().filter(|child| child.local_name() == "chapter")
+                {
+                    let start_time = match chapter.attr("start")
+                    {
+                        Some(start) => string_utils::timestamp_to_seconds(start.as_str())?,
+                        None => return Err("Podlove Simple Chapter must have a start.".to_owned()),
+                    };
+
+                    chapters.push(PodcastChapter {
+                                      start_time,
+                                      end_time: None,
+                                      title: chapter.attr("title"),
+                                      image: chapter.attr("image"),
+                                      url: chapter.attr("href"),
+                                      toc: true,
+                                  });
+                }
+            }
+            None => return Err("Podlove Simple Chapters must have a chapters element.".to_owned()),
+        }
+
+        sort(&mut chapters);
+        Ok(chapters)
+    }
+}
+
+
+impl PodcastChapters
+{
+    /// Fetch the chapters document the `PodcastChapters` links to and read
+    /// its chapters.
+    ///
+    /// JSON chapters are read when the MIME type is JSON, and Podlove Simple
+    /// Chapters when it is XML.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .url("https://example.com/episode1/chapters.json")
+    ///     .mime_type("application/json+chapters")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// for chapter in chapters.fetch().unwrap()
+    /// {
+    ///     println!("{} {:?}", chapter.start_time, chapter.title);
+    /// }
+    /// ```
+    pub fn fetch(&self) -> Result<Vec<PodcastChapter>, String>
+    {
+        let (body, _) = url_utils::fetch(self.url.as_str())?;
+        let text = String::from_utf8(body).map_err(|err| format!("Error: {}", err))?;
+
+        if self.mime_type.contains("json")
+        {
+            PodcastChapter::from_json(text.as_str())
+        }
+        else if self.mime_type.contains("xml")
+        {
+            PodcastChapter::from_psc(text.as_str())
+        }
+        else
+        {
+            Err(format!("Invalid value: {}", self.mime_type))
+        }
+    }
+}
+
+
+// Find the `chapters` element, depth first.
+fn find_chapters(element: &XmlElement) -> Option<&XmlElement>
+{
+    if element.local_name() == "chapters"
+    {
+        return Some(element);
+    }
+    element.children.iter().filter_map(find_chapters).next()
+}
+
+
+fn sort(chapters: &mut [PodcastChapter])
+{
+    chapters.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+}
@@ -0,0 +1,117 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast chapters by using the methods under
+//! `PodcastChaptersBuilder`.
+
+
+use extension::podcast::{PodcastChapters, PodcastChaptersBuilder};
+use mime::Mime;
+use utils::string_utils;
+
+
+impl PodcastChaptersBuilder
+{
+    /// Construct a new `PodcastChaptersBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let podcast_chapters_builder = PodcastChaptersBuilder::new();
+    /// ```
+    pub fn new() -> PodcastChaptersBuilder
+    {
+        PodcastChaptersBuilder::default()
+    }
+
+
+    /// Set the url that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let mut podcast_chapters_builder = PodcastChaptersBuilder::new();
+    /// podcast_chapters_builder.url("https://example.com/episode1/chapters.json");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut PodcastChaptersBuilder
+    {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the mime type that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let mut podcast_chapters_builder = PodcastChaptersBuilder::new();
+    /// podcast_chapters_builder.mime_type("application/json+chapters");
+    /// ```
+    pub fn mime_type(&mut self, mime_type: &str) -> &mut PodcastChaptersBuilder
+    {
+        self.mime_type = mime_type.to_owned();
+        self
+    }
+
+
+    /// Validate the contents of `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let podcast_chapters = PodcastChaptersBuilder::new()
+    ///     .url("https://example.com/episode1/chapters.json")
+    ///     .mime_type("application/json+chapters")
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastChaptersBuilder, String>
+    {
+        string_utils::str_to_url(self.url.as_str())?;
+
+        if let Err(err) = self.mime_type.parse::<Mime>()
+        {
+            return Err(format!("Error: {:?}", err));
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastChapters` from the `PodcastChaptersBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let podcast_chapters = PodcastChaptersBuilder::new()
+    ///     .url("https://example.com/episode1/chapters.json")
+    ///     .mime_type("application/json+chapters")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastChapters, String>
+    {
+        Ok(PodcastChapters {
+               url: self.url.clone(),
+               mime_type: self.mime_type.clone(),
+           })
+    }
+}
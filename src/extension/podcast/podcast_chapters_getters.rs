@@ -0,0 +1,64 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast chapters can be retrieved by using the methods
+//! under `PodcastChapters`.
+
+
+use extension::podcast::{PodcastChapters, PodcastChaptersGetters};
+
+
+impl PodcastChaptersGetters for PodcastChapters
+{
+    /// Get the url that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastChaptersGetters};
+    ///
+    /// let url = "https://example.com/episode1/chapters.json";
+    ///
+    /// let podcast_chapters = PodcastChaptersBuilder::new()
+    ///     .mime_type("application/json+chapters")
+    ///     .url(url)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(url.to_owned(), podcast_chapters.url());
+    /// ```
+    fn url(&self) -> String
+    {
+        self.url.clone()
+    }
+
+
+    /// Get the mime type that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastChaptersGetters};
+    ///
+    /// let mime_type = "application/json+chapters";
+    ///
+    /// let podcast_chapters = PodcastChaptersBuilder::new()
+    ///     .url("https://example.com/episode1/chapters.json")
+    ///     .mime_type(mime_type)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mime_type.to_owned(), podcast_chapters.mime_type());
+    /// ```
+    fn mime_type(&self) -> String
+    {
+        self.mime_type.clone()
+    }
+}
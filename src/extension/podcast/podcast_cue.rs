@@ -0,0 +1,295 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading the cues of a transcript from WebVTT, SRT and JSON documents.
+
+
+use extension::podcast::{PodcastCue, PodcastTranscript};
+use serde_json::{self, Value};
+use utils::{string_utils, url_utils};
+
+
+impl PodcastCue
+{
+    /// Read the cues of a WebVTT document.
+    ///
+    /// The speaker is taken from a voice tag at the start of the cue, and
+    /// other markup is removed from the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastCue;
+    ///
+    /// let vtt = "WEBVTT\n\
+    ///            \n\
+    ///            NOTE Episode 1\n\
+    ///            \n\
+    ///            1\n\
+    ///            00:00:00.000 --> 00:00:04.500 align:start\n\
+    ///            <v Chris>Welcome to <i>the show</i>.\n\
+    ///            \n\
+    ///            01:02.000 --> 01:05.250\n\
+    ///            Thanks &amp; goodbye.\n";
+    ///
+    /// let cues = PodcastCue::from_webvtt(vtt).unwrap();
+    ///
+    /// assert_eq!(2, cues.len());
+    /// assert_eq!(Some("Chris".to_owned()), cues[0].speaker);
+    /// assert_eq!("Welcome to the show.", cues[0].text);
+    /// assert_eq!(62.0, cues[1].start_time);
+    /// assert_eq!("Thanks & goodbye.", cues[1].text);
+    /// ```
+    pub fn from_webvtt(vtt: &str) -> Result<Vec<PodcastCue>, String>
+    {
+        let vtt = vtt.trim_start_matches('\u{FEFF}');
+        if !vtt.starts_with("WEBVTT")
+        {
+            return Err("WebVTT document must start with WEBVTT.".to_owned());
+        }
+
+        let mut cues = Vec::new();
+        for block in blocks(vtt).into_iter().skip(1)
+        {
+            if let Some(mut cue) = read_block(&block)?
+            {
+                let text = cue.text.clone();
+                if text.starts_with("<v ") || text.starts_with("<v.")
+                {
+                    if let Some(end) = text.find('>')
+                    {
+                        let tag = &text[2..end];
+                        let name = match tag.find(' ')
+                        {
+                            Some(pos) => tag[pos..].trim(),
+                            None => "",
+                        };
+                        if !name.is_empty()
+                        {
+                            cue.speaker = Some(name.to_owned());
+                        }
+                    }
+                }
+                cue.text = plain_text(text.as_str());
+                cues.push(cue);
+            }
+        }
+
+        Ok(cues)
+    }
+
+
+    /// Read the cues of an SRT document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastCue;
+    ///
+    /// let srt = "1\r\n\
+    ///            00:00:00,000 --> 00:00:04,500\r\n\
+    ///            Welcome to <i>the show</i>.\r\n\
+    ///            \r\n\
+    ///            2\r\n\
+    ///            00:01:02,000 --> 00:01:05,250\r\n\
+    ///            Thanks and\r\n\
+    ///            goodbye.\r\n";
+    ///
+    /// let cues = PodcastCue::from_srt(srt).unwrap();
+    ///
+    /// assert_eq!(2, cues.len());
+    /// assert_eq!(4.5, cues[0].end_time);
+    /// assert_eq!("Thanks and\ngoodbye.", cues[1].text);
+    /// ```
+    pub fn from_srt(srt: &str) -> Result<Vec<PodcastCue>, String>
+    {
+        let mut cues = Vec::new();
+        for block in blocks(srt.trim_start_matches('\u{FEFF}'))
+        {
+            if let Some(mut cue) = read_block(&block)?
+            {
+                cue.text = plain_text(cue.text.as_str());
+                cues.push(cue);
+            }
+        }
+
+        Ok(cues)
+    }
+
+
+    /// Read the cues of a JSON transcript, from its segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastCue;
+    ///
+    /// let json = r#"{
+    ///     "version": "1.0.0",
+    ///     "segments": [
+    ///         { "speaker": "Chris", "startTime": 0, "endTime": 4.5, "body": "Welcome to the show." }
+    ///     ]
+    /// }"#;
+    ///
+    /// let cues = PodcastCue::from_json(json).unwrap();
+    ///
+    /// assert_eq!(Some("Chris".to_owned()), cues[0].speaker);
+    /// assert_eq!("Welcome to the show.", cues[0].text);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Vec<PodcastCue>, String>
+    {
+        let root: Value = serde_json::from_str(json).map_err(|err| format!("Error: {}", err))?;
+
+        let segments = match root.get("segments").and_then(Value::as_array)
+        {
+            Some(segments) => segments,
+            None => return Err("JSON transcript must have a segments array.".to_owned()),
+        };
+
+        let mut cues = Vec::new();
+        for segment in segments
+        {
+            let time = |name: &str| segment.get(name).and_then(Value::as_f64);
+            let (start_time, end_time) = match (time("startTime"), time("endTime"))
+            {
+                (Some(start_time), Some(end_time)) => (start_time, end_time),
+                _ => return Err("JSON transcript segment must have a start and end time.".to_owned()),
+            };
+
+            cues.push(PodcastCue {
+                          start_time,
+                          end_time,
+                          speaker: segment.get("speaker").and_then(Value::as_str).map(|val| val.to_owned()),
+                          text: segment.get("body").and_then(Value::as_str).unwrap_or_default().to_owned(),
+                      });
+        }
+
+        Ok(cues)
+    }
+}
+
+
+impl PodcastTranscript
+{
+    /// Fetch the transcript the `PodcastTranscript` links to and read its
+    /// cues.
+    ///
+    /// WebVTT (`text/vtt`), SRT (`application/x-subrip` or `application/srt`)
+    /// and JSON (`application/json`) transcripts can be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// for cue in transcript.fetch().unwrap()
+    /// {
+    ///     println!("{} {}", cue.start_time, cue.text);
+    /// }
+    /// ```
+    pub fn fetch(&self) -> Result<Vec<PodcastCue>, String>
+    {
+        let (body, _) = url_utils::fetch(self.url.as_str())?;
+        let text = String::from_utf8(body).map_err(|err| format!("Error: {}", err))?;
+
+        match self.mime_type.as_str()
+        {
+            "text/vtt" => PodcastCue::from_webvtt(text.as_str()),
+            "application/x-subrip" | "application/srt" => PodcastCue::from_srt(text.as_str()),
+            "application/json" => PodcastCue::from_json(text.as_str()),
+            _ => Err(format!("Invalid value: {}", self.mime_type)),
+        }
+    }
+}
+
+
+// Split a document into the blocks of lines between blank lines.
+fn blocks(text: &str) -> Vec<Vec<&str>>
+{
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+    for line in text.lines()
+    {
+        if line.trim().is_empty()
+        {
+            if !block.is_empty()
+            {
+                blocks.push(block);
+                block = Vec::new();
+            }
+        }
+        else
+        {
+            block.push(line);
+        }
+    }
+    if !block.is_empty()
+    {
+        blocks.push(block);
+    }
+    blocks
+}
+
+
+// Read a cue from a block, skipping blocks without a timing line.
+fn read_block(block: &[&str]) -> Result<Option<PodcastCue>, String>
+{
+    let timing = match block.iter().position(|line| line.contains("-->"))
+    {
+        Some(pos) => pos,
+        None => return Ok(None),
+    };
+
+    let mut times = block[timing].splitn(2, "-->");
+    let start = times.next().unwrap_or_default();
+    let end = times
+        .next()
+        .and_then(|rest| rest.split_whitespace().next())
+        .unwrap_or_default();
+
+    Ok(Some(PodcastCue {
+                start_time: string_utils::timestamp_to_seconds(start)?,
+                end_time: string_utils::timestamp_to_seconds(end)?,
+                speaker: None,
+                text: block[timing + 1..].join("\n"),
+            }))
+}
+
+
+// Remove the tags from the text of a cue and unescape its entities.
+fn plain_text(text: &str) -> String
+{
+    let mut plain = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars()
+    {
+        match c
+        {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => plain.push(c),
+            _ => (),
+        }
+    }
+
+    plain
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", "\u{A0}")
+        .replace("&amp;", "&")
+        .trim()
+        .to_owned()
+}
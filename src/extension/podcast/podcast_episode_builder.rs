@@ -0,0 +1,123 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast episode by using the methods under
+//! `PodcastEpisodeBuilder`.
+
+
+use extension::podcast::{PodcastEpisode, PodcastEpisodeBuilder};
+
+
+impl PodcastEpisodeBuilder
+{
+    /// Construct a new `PodcastEpisodeBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastEpisodeBuilder;
+    ///
+    /// let podcast_episode_builder = PodcastEpisodeBuilder::new();
+    /// ```
+    pub fn new() -> PodcastEpisodeBuilder
+    {
+        PodcastEpisodeBuilder::default()
+    }
+
+
+    /// Set the number that exists under `PodcastEpisode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastEpisodeBuilder;
+    ///
+    /// let mut podcast_episode_builder = PodcastEpisodeBuilder::new();
+    /// podcast_episode_builder.number(408.0);
+    /// ```
+    pub fn number(&mut self, number: f64) -> &mut PodcastEpisodeBuilder
+    {
+        self.number = number;
+        self
+    }
+
+
+    /// Set the optional display text that exists under `PodcastEpisode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastEpisodeBuilder;
+    ///
+    /// let mut podcast_episode_builder = PodcastEpisodeBuilder::new();
+    /// podcast_episode_builder.display(Some("LAS 408".to_owned()));
+    /// ```
+    pub fn display(&mut self, display: Option<String>) -> &mut PodcastEpisodeBuilder
+    {
+        self.display = display;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastEpisode`.
+    ///
+    /// The number cannot be negative, and the display text can be at most 32
+    /// characters long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastEpisodeBuilder;
+    ///
+    /// let podcast_episode = PodcastEpisodeBuilder::new()
+    ///     .number(408.0)
+    ///     .display(Some("LAS 408".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastEpisodeBuilder, String>
+    {
+        if self.number.is_nan() || self.number < 0.0
+        {
+            return Err(format!("Invalid value: {}", self.number));
+        }
+
+        if let Some(ref display) = self.display
+        {
+            if display.chars().count() > 32
+            {
+                return Err("Episode display text must be at most 32 characters long.".to_owned());
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastEpisode` from the `PodcastEpisodeBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastEpisodeBuilder;
+    ///
+    /// let podcast_episode = PodcastEpisodeBuilder::new()
+    ///     .number(408.0)
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastEpisode, String>
+    {
+        Ok(PodcastEpisode {
+               number: self.number,
+               display: self.display.clone(),
+           })
+    }
+}
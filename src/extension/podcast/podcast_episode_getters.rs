@@ -0,0 +1,75 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast episode can be retrieved by using the methods
+//! under `PodcastEpisode`.
+
+
+use extension::podcast::{PodcastEpisode, PodcastEpisodeGetters};
+
+
+impl PodcastEpisodeGetters for PodcastEpisode
+{
+    /// Get the number that exists under `PodcastEpisode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastEpisodeBuilder, PodcastEpisodeGetters};
+    ///
+    /// let number = 408.0;
+    ///
+    /// let podcast_episode = PodcastEpisodeBuilder::new()
+    ///     .number(number)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(number, podcast_episode.number());
+    /// ```
+    fn number(&self) -> f64
+    {
+        self.number
+    }
+
+
+    /// Get the optional display text that exists under `PodcastEpisode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastEpisodeBuilder, PodcastEpisodeGetters};
+    ///
+    /// let display = Some("LAS 408".to_owned());
+    ///
+    /// let podcast_episode = PodcastEpisodeBuilder::new()
+    ///     .number(408.0)
+    ///     .display(display.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(display, podcast_episode.display());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastEpisodeBuilder, PodcastEpisodeGetters};
+    ///
+    /// let podcast_episode = PodcastEpisodeBuilder::new()
+    ///     .number(408.0)
+    ///     .display(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_episode.display().is_none());
+    /// ```
+    fn display(&self) -> Option<String>
+    {
+        self.display.clone()
+    }
+}
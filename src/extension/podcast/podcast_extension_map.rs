@@ -0,0 +1,839 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing the `podcast` extensions of an `Item` or a `Channel`.
+
+
+use extension::podcast::{PodcastAlternateEnclosure, PodcastChannelExtension, PodcastChapters, PodcastEpisode,
+                         PodcastFunding, PodcastIntegrity, PodcastItemExtension, PodcastLiveItem, PodcastLocation,
+                         PodcastLocked, PodcastPerson, PodcastSeason, PodcastSoundbite, PodcastSource,
+                         PodcastTranscript, PodcastValue, PodcastValueRecipient};
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+use std::str::FromStr;
+use utils::{reader_utils, writer_utils};
+
+
+// The elements covered by `PodcastChannelExtension`, by local name.
+const CHANNEL_ELEMENTS: &[&str] = &["funding", "value", "person", "location", "guid", "locked", "liveItem"];
+
+// The elements covered by `PodcastItemExtension`, by local name.
+const ITEM_ELEMENTS: &[&str] = &["transcript", "chapters", "value", "person", "location", "season", "episode",
+                                  "soundbite", "alternateEnclosure"];
+
+
+impl PodcastChannelExtension
+{
+    /// Construct a `PodcastChannelExtension` from the `podcast` extensions of
+    /// a `Channel`.
+    ///
+    /// Splits that cannot be read are taken as 0, where `try_from_map` fails
+    /// on them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::podcast::PodcastChannelExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+    ///     <channel><title>Linux Action Show</title>
+    ///     <podcast:funding url="https://example.com/donate">Support the show!</podcast:funding>
+    ///     <podcast:value type="lightning" method="keysend" suggested="0.00000015000">
+    ///         <podcast:valueRecipient name="Host" type="node" split="95"
+    ///             address="02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52" />
+    ///         <podcast:valueRecipient name="App" type="node" split="5"
+    ///             address="03ae9f91a0cb8ff43840e3c322c4c61f019d8c1c3cea15a25cfc425ac605e61a4a" />
+    ///     </podcast:value>
+    ///     </channel></rss>"#;
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let podcast = PodcastChannelExtension::from_map(&channel.extensions["podcast"]);
+    ///
+    ///     assert_eq!("Support the show!", podcast.funding[0].value);
+    ///     assert_eq!(2, podcast.value.as_ref().unwrap().recipients.len());
+    /// }
+    /// ```
+    pub fn from_map(map: &HashMap<String, Vec<Extension>>) -> PodcastChannelExtension
+    {
+        PodcastChannelExtension {
+            funding: elements(map, "funding")
+                .map(|element| {
+                         PodcastFunding {
+                             url: attr(element, "url").unwrap_or_default(),
+                             value: text(element),
+                         }
+                     })
+                .collect(),
+            value: elements(map, "value").map(value_from_extension).next(),
+            persons: elements(map, "person").map(person_from_extension).collect(),
+            location: elements(map, "location").map(location_from_extension).next(),
+            guid: elements(map, "guid").map(text).next(),
+            locked: elements(map, "locked")
+                .map(|element| {
+                         PodcastLocked {
+                             value: text(element),
+                             owner: attr(element, "owner"),
+                         }
+                     })
+                .next(),
+            live_items: elements(map, "liveItem").map(live_item_from_extension).collect(),
+        }
+    }
+
+
+    /// Construct a `PodcastChannelExtension` from the `podcast` extensions of
+    /// a `Channel`, failing on splits that cannot be read instead of taking
+    /// them as 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::podcast::PodcastChannelExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+    ///     <channel><title>Linux Action Show</title>
+    ///     <podcast:value type="lightning" method="keysend">
+    ///         <podcast:valueRecipient name="Host" type="node" split="ninety"
+    ///             address="02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52" />
+    ///     </podcast:value>
+    ///     </channel></rss>"#;
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let result = PodcastChannelExtension::try_from_map(&channel.extensions["podcast"]);
+    ///     assert_eq!(Err("Invalid split: ninety".to_owned()), result.map(|_| ()));
+    /// }
+    /// ```
+    pub fn try_from_map(map: &HashMap<String, Vec<Extension>>) -> Result<PodcastChannelExtension, String>
+    {
+        for element in elements(map, "value")
+        {
+            check_value(element)?;
+        }
+
+        Ok(PodcastChannelExtension::from_map(map))
+    }
+
+
+    /// Convert the `PodcastChannelExtension` to `podcast` extensions, keyed
+    /// by local name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastFundingBuilder};
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value("Support the show!")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .funding(vec![funding])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let map = podcast.to_map();
+    /// assert_eq!(Some("Support the show!".to_owned()), map["funding"][0].value);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    {
+        let mut map = HashMap::new();
+
+        insert(&mut map,
+               "funding",
+               self.funding
+                   .iter()
+                   .map(|funding| {
+                            extension("funding", Some(funding.value.clone()), &[("url", Some(funding.url.clone()))])
+                        })
+                   .collect());
+        insert(&mut map, "value", self.value.iter().map(value_to_extension).collect());
+        insert(&mut map, "person", self.persons.iter().map(person_to_extension).collect());
+        insert(&mut map, "location", self.location.iter().map(location_to_extension).collect());
+        insert(&mut map,
+               "guid",
+               self.guid.iter().map(|guid| extension("guid", Some(guid.clone()), &[])).collect());
+        insert(&mut map,
+               "locked",
+               self.locked
+                   .iter()
+                   .map(|locked| extension("locked", Some(locked.value.clone()), &[("owner", locked.owner.clone())]))
+                   .collect());
+        insert(&mut map, "liveItem", self.live_items.iter().map(live_item_to_extension).collect());
+
+        map
+    }
+
+
+    /// Replace the `podcast` extensions of an `ExtensionMap` with those of
+    /// the `PodcastChannelExtension`, keeping the elements it does not cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::podcast::{PodcastChannelExtension, PodcastChannelExtensionBuilder, PodcastValueBuilder,
+    ///                                PodcastValueRecipientBuilder};
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let recipient = PodcastValueRecipientBuilder::new()
+    ///         .recipient_type("node")
+    ///         .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///         .split(100)
+    ///         .fee(Some(false))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let value = PodcastValueBuilder::new()
+    ///         .value_type("lightning")
+    ///         .method("keysend")
+    ///         .recipients(vec![recipient])
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let podcast = PodcastChannelExtensionBuilder::new()
+    ///         .value(Some(value))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     podcast.insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(podcast, PodcastChannelExtension::from_map(&extensions["podcast"]));
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        replace(extensions, CHANNEL_ELEMENTS, self.to_map());
+    }
+}
+
+
+impl PodcastItemExtension
+{
+    /// Construct a `PodcastItemExtension` from the `podcast` extensions of an
+    /// `Item`.
+    ///
+    /// Seasons, episodes and soundbites whose numbers cannot be read are left
+    /// out, as are the alternate enclosure numbers that cannot be read, and
+    /// splits that cannot be read are taken as 0, where `try_from_map` fails
+    /// on them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::podcast::PodcastItemExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+    ///     <channel><item><title>Episode 1</title>
+    ///     <podcast:transcript url="https://example.com/episode1/transcript.vtt" type="text/vtt" rel="captions" />
+    ///     <podcast:chapters url="https://example.com/episode1/chapters.json" type="application/json+chapters" />
+    ///     </item></channel></rss>"#;
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let podcast = PodcastItemExtension::from_map(&channel.items[0].extensions["podcast"]);
+    ///
+    ///     assert_eq!(Some("captions".to_owned()), podcast.transcripts[0].rel);
+    ///     assert_eq!("application/json+chapters", podcast.chapters.unwrap().mime_type);
+    /// }
+    /// ```
+    pub fn from_map(map: &HashMap<String, Vec<Extension>>) -> PodcastItemExtension
+    {
+        PodcastItemExtension {
+            transcripts: elements(map, "transcript")
+                .map(|element| {
+                         PodcastTranscript {
+                             url: attr(element, "url").unwrap_or_default(),
+                             mime_type: attr(element, "type").unwrap_or_default(),
+                             language: attr(element, "language"),
+                             rel: attr(element, "rel"),
+                         }
+                     })
+                .collect(),
+            chapters: elements(map, "chapters")
+                .map(|element| {
+                         PodcastChapters {
+                             url: attr(element, "url").unwrap_or_default(),
+                             mime_type: attr(element, "type").unwrap_or_default(),
+                         }
+                     })
+                .next(),
+            value: elements(map, "value").map(value_from_extension).next(),
+            persons: elements(map, "person").map(person_from_extension).collect(),
+            location: elements(map, "location").map(location_from_extension).next(),
+            season: elements(map, "season")
+                .filter_map(|element| {
+                                text(element)
+                                    .parse()
+                                    .ok()
+                                    .map(|number| {
+                                             PodcastSeason {
+                                                 number,
+                                                 name: attr(element, "name"),
+                                             }
+                                         })
+                            })
+                .next(),
+            episode: elements(map, "episode")
+                .filter_map(|element| {
+                                text(element)
+                                    .parse()
+                                    .ok()
+                                    .map(|number| {
+                                             PodcastEpisode {
+                                                 number,
+                                                 display: attr(element, "display"),
+                                             }
+                                         })
+                            })
+                .next(),
+            soundbites: elements(map, "soundbite").filter_map(soundbite_from_extension).collect(),
+            alternate_enclosures: elements(map, "alternateEnclosure")
+                .map(alternate_enclosure_from_extension)
+                .collect(),
+        }
+    }
+
+
+    /// Construct a `PodcastItemExtension` from the `podcast` extensions of an
+    /// `Item`, failing on numbers that cannot be read instead of leaving them
+    /// out or taking them as 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::podcast::PodcastItemExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+    ///     <channel><item><title>Episode 1</title>
+    ///     <podcast:value type="lightning" method="keysend">
+    ///         <podcast:valueRecipient name="Host" type="node" split="ninety"
+    ///             address="02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52" />
+    ///     </podcast:value>
+    ///     </item>
+    ///     <item><title>Episode 2</title><podcast:season>two</podcast:season></item>
+    ///     <item><title>Episode 3</title><podcast:episode>3a</podcast:episode></item>
+    ///     <item><title>Episode 4</title><podcast:soundbite startTime="1:30" duration="60" /></item>
+    ///     <item><title>Episode 5</title><podcast:alternateEnclosure type="audio/opus" length="32MB">
+    ///         <podcast:source uri="https://example.com/episode5.opus" />
+    ///     </podcast:alternateEnclosure></item>
+    ///     </channel></rss>"#;
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let result = PodcastItemExtension::try_from_map(&channel.items[0].extensions["podcast"]);
+    ///     assert_eq!(Err("Invalid split: ninety".to_owned()), result.map(|_| ()));
+    ///
+    ///     let result = PodcastItemExtension::try_from_map(&channel.items[1].extensions["podcast"]);
+    ///     assert_eq!(Err("Invalid season: two".to_owned()), result.map(|_| ()));
+    ///
+    ///     let result = PodcastItemExtension::try_from_map(&channel.items[2].extensions["podcast"]);
+    ///     assert_eq!(Err("Invalid episode: 3a".to_owned()), result.map(|_| ()));
+    ///
+    ///     let result = PodcastItemExtension::try_from_map(&channel.items[3].extensions["podcast"]);
+    ///     assert_eq!(Err("Invalid startTime: 1:30".to_owned()), result.map(|_| ()));
+    ///
+    ///     let result = PodcastItemExtension::try_from_map(&channel.items[4].extensions["podcast"]);
+    ///     assert_eq!(Err("Invalid length: 32MB".to_owned()), result.map(|_| ()));
+    /// }
+    /// ```
+    pub fn try_from_map(map: &HashMap<String, Vec<Extension>>) -> Result<PodcastItemExtension, String>
+    {
+        for element in elements(map, "value")
+        {
+            check_value(element)?;
+        }
+
+        for element in elements(map, "season")
+        {
+            check_text::<i64>(element, "season")?;
+        }
+
+        for element in elements(map, "episode")
+        {
+            check_text::<f64>(element, "episode")?;
+        }
+
+        for element in elements(map, "soundbite")
+        {
+            for name in &["startTime", "duration"]
+            {
+                if attr(element, name).is_none()
+                {
+                    return Err(format!("Soundbite must have a {}.", name));
+                }
+                check_attr::<f64>(element, name)?;
+            }
+        }
+
+        for element in elements(map, "alternateEnclosure")
+        {
+            check_attr::<i64>(element, "length")?;
+            check_attr::<f64>(element, "bitrate")?;
+            check_attr::<i64>(element, "height")?;
+        }
+
+        Ok(PodcastItemExtension::from_map(map))
+    }
+
+
+    /// Convert the `PodcastItemExtension` to `podcast` extensions, keyed by
+    /// local name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastTranscriptBuilder};
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.srt")
+    ///     .mime_type("application/x-subrip")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .transcripts(vec![transcript])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let map = podcast.to_map();
+    /// assert_eq!("application/x-subrip", map["transcript"][0].attrs["type"]);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    {
+        let mut map = HashMap::new();
+
+        insert(&mut map,
+               "transcript",
+               self.transcripts
+                   .iter()
+                   .map(|transcript| {
+                            extension("transcript",
+                                      None,
+                                      &[("url", Some(transcript.url.clone())),
+                                        ("type", Some(transcript.mime_type.clone())),
+                                        ("language", transcript.language.clone()),
+                                        ("rel", transcript.rel.clone())])
+                        })
+                   .collect());
+        insert(&mut map,
+               "chapters",
+               self.chapters
+                   .iter()
+                   .map(|chapters| {
+                            extension("chapters",
+                                      None,
+                                      &[("url", Some(chapters.url.clone())),
+                                        ("type", Some(chapters.mime_type.clone()))])
+                        })
+                   .collect());
+        insert(&mut map, "value", self.value.iter().map(value_to_extension).collect());
+        insert(&mut map, "person", self.persons.iter().map(person_to_extension).collect());
+        insert(&mut map, "location", self.location.iter().map(location_to_extension).collect());
+        insert(&mut map,
+               "season",
+               self.season
+                   .iter()
+                   .map(|season| extension("season", Some(season.number.to_string()), &[("name", season.name.clone())]))
+                   .collect());
+        insert(&mut map,
+               "episode",
+               self.episode
+                   .iter()
+                   .map(|episode| {
+                            extension("episode",
+                                      Some(episode.number.to_string()),
+                                      &[("display", episode.display.clone())])
+                        })
+                   .collect());
+        insert(&mut map,
+               "soundbite",
+               self.soundbites
+                   .iter()
+                   .map(|soundbite| {
+                            extension("soundbite",
+                                      soundbite.title.clone(),
+                                      &[("startTime", Some(soundbite.start_time.to_string())),
+                                        ("duration", Some(soundbite.duration.to_string()))])
+                        })
+                   .collect());
+        insert(&mut map,
+               "alternateEnclosure",
+               self.alternate_enclosures.iter().map(alternate_enclosure_to_extension).collect());
+
+        map
+    }
+
+
+    /// Replace the `podcast` extensions of an `ExtensionMap` with those of
+    /// the `PodcastItemExtension`, keeping the elements it does not cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastItemExtension, PodcastItemExtensionBuilder};
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let chapters = PodcastChaptersBuilder::new()
+    ///         .url("https://example.com/episode1/chapters.json")
+    ///         .mime_type("application/json+chapters")
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let podcast = PodcastItemExtensionBuilder::new()
+    ///         .chapters(Some(chapters))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     podcast.insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(podcast, PodcastItemExtension::from_map(&extensions["podcast"]));
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        replace(extensions, ITEM_ELEMENTS, self.to_map());
+    }
+}
+
+
+fn elements<'a>(map: &'a HashMap<String, Vec<Extension>>, name: &str) -> ::std::slice::Iter<'a, Extension>
+{
+    match map.get(name)
+    {
+        Some(elements) => elements.iter(),
+        None => [].iter(),
+    }
+}
+
+
+fn attr(element: &Extension, name: &str) -> Option<String>
+{
+    element.attrs.get(name).cloned()
+}
+
+
+fn text(element: &Extension) -> String
+{
+    element.value.clone().unwrap_or_default().trim().to_owned()
+}
+
+
+fn person_from_extension(element: &Extension) -> PodcastPerson
+{
+    PodcastPerson {
+        name: text(element),
+        role: attr(element, "role"),
+        group: attr(element, "group"),
+        img: attr(element, "img"),
+        href: attr(element, "href"),
+    }
+}
+
+
+fn person_to_extension(person: &PodcastPerson) -> Extension
+{
+    extension("person",
+              Some(person.name.clone()),
+              &[("role", person.role.clone()),
+                ("group", person.group.clone()),
+                ("img", person.img.clone()),
+                ("href", person.href.clone())])
+}
+
+
+fn location_from_extension(element: &Extension) -> PodcastLocation
+{
+    PodcastLocation {
+        name: text(element),
+        geo: attr(element, "geo"),
+        osm: attr(element, "osm"),
+    }
+}
+
+
+fn location_to_extension(location: &PodcastLocation) -> Extension
+{
+    extension("location",
+              Some(location.name.clone()),
+              &[("geo", location.geo.clone()), ("osm", location.osm.clone())])
+}
+
+
+// Read a soundbite, leaving it out when its times cannot be read.
+fn soundbite_from_extension(element: &Extension) -> Option<PodcastSoundbite>
+{
+    let time = |name: &str| attr(element, name).and_then(|val| val.trim().parse::<f64>().ok());
+    let title = text(element);
+
+    Some(PodcastSoundbite {
+             start_time: time("startTime")?,
+             duration: time("duration")?,
+             title: if title.is_empty() { None } else { Some(title) },
+         })
+}
+
+
+fn value_from_extension(element: &Extension) -> PodcastValue
+{
+    PodcastValue {
+        value_type: attr(element, "type").unwrap_or_default(),
+        method: attr(element, "method").unwrap_or_default(),
+        suggested: attr(element, "suggested"),
+        recipients: elements(&element.children, "valueRecipient")
+            .map(|recipient| {
+                     PodcastValueRecipient {
+                         name: attr(recipient, "name"),
+                         custom_key: attr(recipient, "customKey"),
+                         custom_value: attr(recipient, "customValue"),
+                         recipient_type: attr(recipient, "type").unwrap_or_default(),
+                         address: attr(recipient, "address").unwrap_or_default(),
+                         split: attr(recipient, "split").and_then(|split| split.trim().parse().ok()).unwrap_or(0),
+                         fee: attr(recipient, "fee").map(|fee| fee.trim() == "true"),
+                     }
+                 })
+            .collect(),
+    }
+}
+
+
+// Fail on the splits of a value that cannot be read.
+fn check_value(element: &Extension) -> Result<(), String>
+{
+    for recipient in elements(&element.children, "valueRecipient")
+    {
+        check_attr::<i64>(recipient, "split")?;
+    }
+    Ok(())
+}
+
+
+fn value_to_extension(value: &PodcastValue) -> Extension
+{
+    let mut element = extension("value",
+                                None,
+                                &[("type", Some(value.value_type.clone())),
+                                  ("method", Some(value.method.clone())),
+                                  ("suggested", value.suggested.clone())]);
+    insert(&mut element.children,
+           "valueRecipient",
+           value.recipients
+               .iter()
+               .map(|recipient| {
+                        extension("valueRecipient",
+                                  None,
+                                  &[("name", recipient.name.clone()),
+                                    ("customKey", recipient.custom_key.clone()),
+                                    ("customValue", recipient.custom_value.clone()),
+                                    ("type", Some(recipient.recipient_type.clone())),
+                                    ("address", Some(recipient.address.clone())),
+                                    ("split", Some(recipient.split.to_string())),
+                                    ("fee", recipient.fee.map(|fee| fee.to_string()))])
+                    })
+               .collect());
+    element
+}
+
+
+// Read an alternate enclosure, leaving out the numbers that cannot be read.
+fn alternate_enclosure_from_extension(element: &Extension) -> PodcastAlternateEnclosure
+{
+    let number = |name: &str| attr(element, name).and_then(|val| val.trim().parse().ok());
+
+    PodcastAlternateEnclosure {
+        mime_type: attr(element, "type").unwrap_or_default(),
+        length: number("length"),
+        bitrate: attr(element, "bitrate").and_then(|val| val.trim().parse().ok()),
+        height: number("height"),
+        lang: attr(element, "lang"),
+        title: attr(element, "title"),
+        rel: attr(element, "rel"),
+        codecs: attr(element, "codecs"),
+        is_default: attr(element, "default").map(|val| val.trim() == "true"),
+        sources: elements(&element.children, "source")
+            .map(|source| {
+                     PodcastSource {
+                         uri: attr(source, "uri").unwrap_or_default(),
+                         content_type: attr(source, "contentType"),
+                     }
+                 })
+            .collect(),
+        integrity: elements(&element.children, "integrity")
+            .map(|integrity| {
+                     PodcastIntegrity {
+                         integrity_type: attr(integrity, "type").unwrap_or_default(),
+                         value: attr(integrity, "value").unwrap_or_else(|| text(integrity)),
+                     }
+                 })
+            .next(),
+    }
+}
+
+
+fn alternate_enclosure_to_extension(alternate: &PodcastAlternateEnclosure) -> Extension
+{
+    let mut element = extension("alternateEnclosure",
+                                None,
+                                &[("type", Some(alternate.mime_type.clone())),
+                                  ("length", alternate.length.map(|length| length.to_string())),
+                                  ("bitrate", alternate.bitrate.map(|bitrate| bitrate.to_string())),
+                                  ("height", alternate.height.map(|height| height.to_string())),
+                                  ("lang", alternate.lang.clone()),
+                                  ("title", alternate.title.clone()),
+                                  ("rel", alternate.rel.clone()),
+                                  ("codecs", alternate.codecs.clone()),
+                                  ("default", alternate.is_default.map(|default| default.to_string()))]);
+    insert(&mut element.children,
+           "source",
+           alternate.sources
+               .iter()
+               .map(|source| {
+                        extension("source",
+                                  None,
+                                  &[("uri", Some(source.uri.clone())), ("contentType", source.content_type.clone())])
+                    })
+               .collect());
+    insert(&mut element.children,
+           "integrity",
+           alternate.integrity
+               .iter()
+               .map(|integrity| {
+                        extension("integrity",
+                                  None,
+                                  &[("type", Some(integrity.integrity_type.clone())),
+                                    ("value", Some(integrity.value.clone()))])
+                    })
+               .collect());
+    element
+}
+
+
+// Read a live item, its children the way the `rss` crate reads an `<item>`.
+fn live_item_from_extension(element: &Extension) -> PodcastLiveItem
+{
+    PodcastLiveItem {
+        status: attr(element, "status").unwrap_or_default(),
+        start: attr(element, "start").unwrap_or_default(),
+        end: attr(element, "end"),
+        item: reader_utils::element_to_item(&writer_utils::extension_element(element)),
+    }
+}
+
+
+fn live_item_to_extension(live_item: &PodcastLiveItem) -> Extension
+{
+    let mut element = writer_utils::item_element(&live_item.item);
+    element.name = "podcast:liveItem".to_owned();
+    element.attrs.push(("status".to_owned(), live_item.status.clone()));
+    element.attrs.push(("start".to_owned(), live_item.start.clone()));
+    if let Some(ref end) = live_item.end
+    {
+        element.attrs.push(("end".to_owned(), end.clone()));
+    }
+    element.to_extension()
+}
+
+
+// Fail on the `name` attribute of an element when it is there but cannot be
+// read as a `T`.
+fn check_attr<T: FromStr>(element: &Extension, name: &str) -> Result<(), String>
+{
+    match attr(element, name)
+    {
+        Some(ref val) if val.trim().parse::<T>().is_err() => Err(format!("Invalid {}: {}", name, val)),
+        _ => Ok(()),
+    }
+}
+
+
+// Fail on the text of a `name` element that cannot be read as a `T`.
+fn check_text<T: FromStr>(element: &Extension, name: &str) -> Result<(), String>
+{
+    let val = text(element);
+    match val.parse::<T>()
+    {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Invalid {}: {}", name, val)),
+    }
+}
+
+
+fn insert(map: &mut HashMap<String, Vec<Extension>>, name: &str, extensions: Vec<Extension>)
+{
+    if !extensions.is_empty()
+    {
+        map.insert(name.to_owned(), extensions);
+    }
+}
+
+
+fn extension(name: &str, value: Option<String>, attrs: &[(&str, Option<String>)]) -> Extension
+{
+    Extension {
+        name: format!("podcast:{}", name),
+        value,
+        attrs: attrs
+            .iter()
+            .filter_map(|attr| attr.1.clone().map(|val| (attr.0.to_owned(), val)))
+            .collect(),
+        children: HashMap::new(),
+    }
+}
+
+
+// Replace the `names` elements under the `podcast` prefix.
+fn replace(extensions: &mut ExtensionMap, names: &[&str], elements: HashMap<String, Vec<Extension>>)
+{
+    let map = extensions.entry("podcast".to_owned()).or_default();
+    for name in names
+    {
+        map.remove(*name);
+    }
+    map.extend(elements);
+
+    if map.is_empty()
+    {
+        extensions.remove("podcast");
+    }
+}
@@ -0,0 +1,118 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast funding by using the methods under
+//! `PodcastFundingBuilder`.
+
+
+use extension::podcast::{PodcastFunding, PodcastFundingBuilder};
+use utils::string_utils;
+
+
+impl PodcastFundingBuilder
+{
+    /// Construct a new `PodcastFundingBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let podcast_funding_builder = PodcastFundingBuilder::new();
+    /// ```
+    pub fn new() -> PodcastFundingBuilder
+    {
+        PodcastFundingBuilder::default()
+    }
+
+
+    /// Set the url that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let mut podcast_funding_builder = PodcastFundingBuilder::new();
+    /// podcast_funding_builder.url("https://example.com/donate");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut PodcastFundingBuilder
+    {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the value that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let mut podcast_funding_builder = PodcastFundingBuilder::new();
+    /// podcast_funding_builder.value("Support the show!");
+    /// ```
+    pub fn value(&mut self, value: &str) -> &mut PodcastFundingBuilder
+    {
+        self.value = value.to_owned();
+        self
+    }
+
+
+    /// Validate the contents of `PodcastFunding`.
+    ///
+    /// The value can be at most 128 characters long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let podcast_funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value("Support the show!")
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastFundingBuilder, String>
+    {
+        string_utils::str_to_url(self.url.as_str())?;
+
+        if self.value.chars().count() > 128
+        {
+            return Err("Funding value must be at most 128 characters long.".to_owned());
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastFunding` from the `PodcastFundingBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let podcast_funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value("Support the show!")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastFunding, String>
+    {
+        Ok(PodcastFunding {
+               url: self.url.clone(),
+               value: self.value.clone(),
+           })
+    }
+}
@@ -0,0 +1,64 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast funding can be retrieved by using the methods
+//! under `PodcastFunding`.
+
+
+use extension::podcast::{PodcastFunding, PodcastFundingGetters};
+
+
+impl PodcastFundingGetters for PodcastFunding
+{
+    /// Get the url that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastFundingBuilder, PodcastFundingGetters};
+    ///
+    /// let url = "https://example.com/donate";
+    ///
+    /// let podcast_funding = PodcastFundingBuilder::new()
+    ///     .value("Support the show!")
+    ///     .url(url)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(url.to_owned(), podcast_funding.url());
+    /// ```
+    fn url(&self) -> String
+    {
+        self.url.clone()
+    }
+
+
+    /// Get the value that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastFundingBuilder, PodcastFundingGetters};
+    ///
+    /// let value = "Support the show!";
+    ///
+    /// let podcast_funding = PodcastFundingBuilder::new()
+    ///     .url("https://example.com/donate")
+    ///     .value(value)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_owned(), podcast_funding.value());
+    /// ```
+    fn value(&self) -> String
+    {
+        self.value.clone()
+    }
+}
@@ -0,0 +1,140 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast integrity by using the methods under
+//! `PodcastIntegrityBuilder`.
+
+
+use extension::podcast::{PodcastIntegrity, PodcastIntegrityBuilder};
+
+
+impl PodcastIntegrityBuilder
+{
+    /// Construct a new `PodcastIntegrityBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastIntegrityBuilder;
+    ///
+    /// let podcast_integrity_builder = PodcastIntegrityBuilder::new();
+    /// ```
+    pub fn new() -> PodcastIntegrityBuilder
+    {
+        PodcastIntegrityBuilder::default()
+    }
+
+
+    /// Set the integrity type that exists under `PodcastIntegrity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastIntegrityBuilder;
+    ///
+    /// let mut podcast_integrity_builder = PodcastIntegrityBuilder::new();
+    /// podcast_integrity_builder.integrity_type("sri");
+    /// ```
+    pub fn integrity_type(&mut self, integrity_type: &str) -> &mut PodcastIntegrityBuilder
+    {
+        self.integrity_type = integrity_type.to_owned();
+        self
+    }
+
+
+    /// Set the value that exists under `PodcastIntegrity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastIntegrityBuilder;
+    ///
+    /// let mut podcast_integrity_builder = PodcastIntegrityBuilder::new();
+    /// podcast_integrity_builder.value("sha384-ExVqijgYHm15PqQqdXfW95x+Rs6C+d6E/ICxyQOeFevnxNLR/wtJNrNYTjIysUBo");
+    /// ```
+    pub fn value(&mut self, value: &str) -> &mut PodcastIntegrityBuilder
+    {
+        self.value = value.to_owned();
+        self
+    }
+
+
+    /// Validate the contents of `PodcastIntegrity`.
+    ///
+    /// The type must be `sri`, with a SHA-256, SHA-384 or SHA-512 hash as the
+    /// value, or `pgp-signature`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastIntegrityBuilder;
+    ///
+    /// let podcast_integrity = PodcastIntegrityBuilder::new()
+    ///     .integrity_type("sri")
+    ///     .value("sha384-ExVqijgYHm15PqQqdXfW95x+Rs6C+d6E/ICxyQOeFevnxNLR/wtJNrNYTjIysUBo")
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastIntegrityBuilder, String>
+    {
+        match self.integrity_type.as_str()
+        {
+            "sri" =>
+            {
+                let valid = match self.value.find('-')
+                {
+                    Some(pos) =>
+                    {
+                        let hash = &self.value[pos + 1..];
+                        ["sha256", "sha384", "sha512"].contains(&&self.value[..pos]) && !hash.is_empty() &&
+                        hash.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/' || c == '=')
+                    }
+                    None => false,
+                };
+                if !valid
+                {
+                    return Err(format!("Invalid value: {}", self.value));
+                }
+            }
+            "pgp-signature" =>
+            {
+                if self.value.trim().is_empty()
+                {
+                    return Err("Integrity value must not be empty.".to_owned());
+                }
+            }
+            _ => return Err(format!("Invalid value: {}", self.integrity_type)),
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastIntegrity` from the `PodcastIntegrityBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastIntegrityBuilder;
+    ///
+    /// let podcast_integrity = PodcastIntegrityBuilder::new()
+    ///     .integrity_type("sri")
+    ///     .value("sha384-ExVqijgYHm15PqQqdXfW95x+Rs6C+d6E/ICxyQOeFevnxNLR/wtJNrNYTjIysUBo")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastIntegrity, String>
+    {
+        Ok(PodcastIntegrity {
+               integrity_type: self.integrity_type.clone(),
+               value: self.value.clone(),
+           })
+    }
+}
@@ -0,0 +1,64 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast integrity can be retrieved by using the methods
+//! under `PodcastIntegrity`.
+
+
+use extension::podcast::{PodcastIntegrity, PodcastIntegrityGetters};
+
+
+impl PodcastIntegrityGetters for PodcastIntegrity
+{
+    /// Get the integrity type that exists under `PodcastIntegrity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastIntegrityBuilder, PodcastIntegrityGetters};
+    ///
+    /// let integrity_type = "sri";
+    ///
+    /// let podcast_integrity = PodcastIntegrityBuilder::new()
+    ///     .value("sha384-ExVqijgYHm15PqQqdXfW95x+Rs6C+d6E/ICxyQOeFevnxNLR/wtJNrNYTjIysUBo")
+    ///     .integrity_type(integrity_type)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(integrity_type.to_owned(), podcast_integrity.integrity_type());
+    /// ```
+    fn integrity_type(&self) -> String
+    {
+        self.integrity_type.clone()
+    }
+
+
+    /// Get the value that exists under `PodcastIntegrity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastIntegrityBuilder, PodcastIntegrityGetters};
+    ///
+    /// let value = "sha384-ExVqijgYHm15PqQqdXfW95x+Rs6C+d6E/ICxyQOeFevnxNLR/wtJNrNYTjIysUBo";
+    ///
+    /// let podcast_integrity = PodcastIntegrityBuilder::new()
+    ///     .integrity_type("sri")
+    ///     .value(value)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_owned(), podcast_integrity.value());
+    /// ```
+    fn value(&self) -> String
+    {
+        self.value.clone()
+    }
+}
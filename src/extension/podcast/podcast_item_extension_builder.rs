@@ -0,0 +1,276 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast item extension by using the methods under
+//! `PodcastItemExtensionBuilder`.
+
+
+use extension::podcast::{PodcastAlternateEnclosure, PodcastChapters, PodcastEpisode,
+                         PodcastItemExtension, PodcastItemExtensionBuilder, PodcastLocation,
+                         PodcastPerson, PodcastSeason, PodcastSoundbite, PodcastTranscript,
+                         PodcastValue};
+
+
+impl PodcastItemExtensionBuilder
+{
+    /// Construct a new `PodcastItemExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastItemExtensionBuilder;
+    ///
+    /// let podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// ```
+    pub fn new() -> PodcastItemExtensionBuilder
+    {
+        PodcastItemExtensionBuilder::default()
+    }
+
+
+    /// Set the transcripts that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastTranscriptBuilder};
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.transcripts(vec![transcript]);
+    /// ```
+    pub fn transcripts(&mut self, transcripts: Vec<PodcastTranscript>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.transcripts = transcripts;
+        self
+    }
+
+
+    /// Set the optional chapters that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastItemExtensionBuilder};
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .url("https://example.com/episode1/chapters.json")
+    ///     .mime_type("application/json+chapters")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.chapters(Some(chapters));
+    /// ```
+    pub fn chapters(&mut self, chapters: Option<PodcastChapters>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.chapters = chapters;
+        self
+    }
+
+
+    /// Set the optional value that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.value(Some(value));
+    /// ```
+    pub fn value(&mut self, value: Option<PodcastValue>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.value = value;
+        self
+    }
+
+
+    /// Set the persons that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .role(Some("host".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.persons(vec![person]);
+    /// ```
+    pub fn persons(&mut self, persons: Vec<PodcastPerson>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.persons = persons;
+        self
+    }
+
+
+    /// Set the optional location that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastLocationBuilder};
+    ///
+    /// let location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .geo(Some("geo:30.2672,-97.7431".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.location(Some(location));
+    /// ```
+    pub fn location(&mut self, location: Option<PodcastLocation>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.location = location;
+        self
+    }
+
+
+    /// Set the optional season that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastSeasonBuilder};
+    ///
+    /// let season = PodcastSeasonBuilder::new()
+    ///     .number(2)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.season(Some(season));
+    /// ```
+    pub fn season(&mut self, season: Option<PodcastSeason>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.season = season;
+        self
+    }
+
+
+    /// Set the optional episode that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastEpisodeBuilder, PodcastItemExtensionBuilder};
+    ///
+    /// let episode = PodcastEpisodeBuilder::new()
+    ///     .number(408.0)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.episode(Some(episode));
+    /// ```
+    pub fn episode(&mut self, episode: Option<PodcastEpisode>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.episode = episode;
+        self
+    }
+
+
+    /// Set the soundbites that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastSoundbiteBuilder};
+    ///
+    /// let soundbite = PodcastSoundbiteBuilder::new()
+    ///     .start_time(73.0)
+    ///     .duration(60.0)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.soundbites(vec![soundbite]);
+    /// ```
+    pub fn soundbites(&mut self, soundbites: Vec<PodcastSoundbite>) -> &mut PodcastItemExtensionBuilder
+    {
+        self.soundbites = soundbites;
+        self
+    }
+
+
+    /// Set the alternate enclosures that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastItemExtensionBuilder,
+    ///                                PodcastSourceBuilder};
+    ///
+    /// let source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .sources(vec![source])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_item_extension_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_item_extension_builder.alternate_enclosures(vec![alternate_enclosure]);
+    /// ```
+    pub fn alternate_enclosures(&mut self,
+                                alternate_enclosures: Vec<PodcastAlternateEnclosure>)
+        -> &mut PodcastItemExtensionBuilder
+    {
+        self.alternate_enclosures = alternate_enclosures;
+        self
+    }
+
+
+    /// Construct the `PodcastItemExtension` from the `PodcastItemExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastItemExtensionBuilder;
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastItemExtension, String>
+    {
+        Ok(PodcastItemExtension {
+               transcripts: self.transcripts.clone(),
+               chapters: self.chapters.clone(),
+               value: self.value.clone(),
+               persons: self.persons.clone(),
+               location: self.location.clone(),
+               season: self.season.clone(),
+               episode: self.episode.clone(),
+               soundbites: self.soundbites.clone(),
+               alternate_enclosures: self.alternate_enclosures.clone(),
+           })
+    }
+}
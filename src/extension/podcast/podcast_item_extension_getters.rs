@@ -0,0 +1,327 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast item extension can be retrieved by using the methods
+//! under `PodcastItemExtension`.
+
+
+use extension::podcast::{PodcastAlternateEnclosure, PodcastChapters, PodcastEpisode,
+                         PodcastItemExtension, PodcastItemExtensionGetters, PodcastLocation,
+                         PodcastPerson, PodcastSeason, PodcastSoundbite, PodcastTranscript,
+                         PodcastValue};
+
+
+impl PodcastItemExtensionGetters for PodcastItemExtension
+{
+    /// Get the transcripts that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters,
+    ///                                PodcastTranscriptBuilder};
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let transcripts = vec![transcript];
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .transcripts(transcripts.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_item_extension.transcripts().len());
+    /// ```
+    fn transcripts(&self) -> Vec<PodcastTranscript>
+    {
+        self.transcripts.clone()
+    }
+
+
+    /// Get the optional chapters that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastItemExtensionBuilder,
+    ///                                PodcastItemExtensionGetters};
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .url("https://example.com/episode1/chapters.json")
+    ///     .mime_type("application/json+chapters")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let chapters = Some(chapters);
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .chapters(chapters.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.chapters().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters};
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .chapters(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.chapters().is_none());
+    /// ```
+    fn chapters(&self) -> Option<PodcastChapters>
+    {
+        self.chapters.clone()
+    }
+
+
+    /// Get the optional value that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let value = Some(value);
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .value(value.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.value().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters};
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .value(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.value().is_none());
+    /// ```
+    fn value(&self) -> Option<PodcastValue>
+    {
+        self.value.clone()
+    }
+
+
+    /// Get the persons that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters, PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .role(Some("host".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let persons = vec![person];
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .persons(persons.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_item_extension.persons().len());
+    /// ```
+    fn persons(&self) -> Vec<PodcastPerson>
+    {
+        self.persons.clone()
+    }
+
+
+    /// Get the optional location that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters,
+    ///                                PodcastLocationBuilder};
+    ///
+    /// let location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .geo(Some("geo:30.2672,-97.7431".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let location = Some(location);
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .location(location.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.location().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters};
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .location(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.location().is_none());
+    /// ```
+    fn location(&self) -> Option<PodcastLocation>
+    {
+        self.location.clone()
+    }
+
+
+    /// Get the optional season that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters, PodcastSeasonBuilder};
+    ///
+    /// let season = PodcastSeasonBuilder::new()
+    ///     .number(2)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let season = Some(season);
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .season(season.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.season().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters};
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .season(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.season().is_none());
+    /// ```
+    fn season(&self) -> Option<PodcastSeason>
+    {
+        self.season.clone()
+    }
+
+
+    /// Get the optional episode that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastEpisodeBuilder, PodcastItemExtensionBuilder, PodcastItemExtensionGetters};
+    ///
+    /// let episode = PodcastEpisodeBuilder::new()
+    ///     .number(408.0)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let episode = Some(episode);
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .episode(episode.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.episode().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters};
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .episode(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_item_extension.episode().is_none());
+    /// ```
+    fn episode(&self) -> Option<PodcastEpisode>
+    {
+        self.episode.clone()
+    }
+
+
+    /// Get the soundbites that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastItemExtensionGetters,
+    ///                                PodcastSoundbiteBuilder};
+    ///
+    /// let soundbite = PodcastSoundbiteBuilder::new()
+    ///     .start_time(73.0)
+    ///     .duration(60.0)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let soundbites = vec![soundbite];
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .soundbites(soundbites.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_item_extension.soundbites().len());
+    /// ```
+    fn soundbites(&self) -> Vec<PodcastSoundbite>
+    {
+        self.soundbites.clone()
+    }
+
+
+    /// Get the alternate enclosures that exists under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastItemExtensionBuilder,
+    ///                                PodcastItemExtensionGetters, PodcastSourceBuilder};
+    ///
+    /// let source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let alternate_enclosure = PodcastAlternateEnclosureBuilder::new()
+    ///     .mime_type("audio/opus")
+    ///     .sources(vec![source])
+    ///     .finalize()
+    ///     .unwrap();
+    /// let alternate_enclosures = vec![alternate_enclosure];
+    ///
+    /// let podcast_item_extension = PodcastItemExtensionBuilder::new()
+    ///     .alternate_enclosures(alternate_enclosures.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_item_extension.alternate_enclosures().len());
+    /// ```
+    fn alternate_enclosures(&self) -> Vec<PodcastAlternateEnclosure>
+    {
+        self.alternate_enclosures.clone()
+    }
+}
@@ -0,0 +1,170 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast live item by using the methods under
+//! `PodcastLiveItemBuilder`.
+
+
+use extension::podcast::{LIVE_STATUSES, PodcastLiveItem, PodcastLiveItemBuilder};
+use rss::Item;
+use utils::string_utils;
+
+
+impl PodcastLiveItemBuilder
+{
+    /// Construct a new `PodcastLiveItemBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLiveItemBuilder;
+    ///
+    /// let podcast_live_item_builder = PodcastLiveItemBuilder::new();
+    /// ```
+    pub fn new() -> PodcastLiveItemBuilder
+    {
+        PodcastLiveItemBuilder::default()
+    }
+
+
+    /// Set the status that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLiveItemBuilder;
+    ///
+    /// let mut podcast_live_item_builder = PodcastLiveItemBuilder::new();
+    /// podcast_live_item_builder.status("live");
+    /// ```
+    pub fn status(&mut self, status: &str) -> &mut PodcastLiveItemBuilder
+    {
+        self.status = status.to_owned();
+        self
+    }
+
+
+    /// Set the start that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLiveItemBuilder;
+    ///
+    /// let mut podcast_live_item_builder = PodcastLiveItemBuilder::new();
+    /// podcast_live_item_builder.start("2021-09-26T07:30:00-06:00");
+    /// ```
+    pub fn start(&mut self, start: &str) -> &mut PodcastLiveItemBuilder
+    {
+        self.start = start.to_owned();
+        self
+    }
+
+
+    /// Set the optional end that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLiveItemBuilder;
+    ///
+    /// let mut podcast_live_item_builder = PodcastLiveItemBuilder::new();
+    /// podcast_live_item_builder.end(Some("2021-09-26T09:30:00-06:00".to_owned()));
+    /// ```
+    pub fn end(&mut self, end: Option<String>) -> &mut PodcastLiveItemBuilder
+    {
+        self.end = end;
+        self
+    }
+
+
+    /// Set the item that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::podcast::PodcastLiveItemBuilder;
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Podcasting 2.0 Live Show".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_live_item_builder = PodcastLiveItemBuilder::new();
+    /// podcast_live_item_builder.item(item);
+    /// ```
+    pub fn item(&mut self, item: Item) -> &mut PodcastLiveItemBuilder
+    {
+        self.item = item;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastLiveItem`.
+    ///
+    /// The status must be `pending`, `live` or `ended`, and the start and end
+    /// ISO 8601 dates, the end not before the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLiveItemBuilder;
+    ///
+    /// let podcast_live_item = PodcastLiveItemBuilder::new()
+    ///     .status("ended")
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .end(Some("2021-09-26T09:30:00-06:00".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastLiveItemBuilder, String>
+    {
+        if !LIVE_STATUSES.contains(&self.status.as_str())
+        {
+            return Err(format!("Invalid value: {}", self.status));
+        }
+
+        let start = string_utils::iso8601_to_date(self.start.as_str())?;
+        if let Some(ref end) = self.end
+        {
+            if string_utils::iso8601_to_date(end.as_str())? < start
+            {
+                return Err("Live item must not end before it starts.".to_owned());
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastLiveItem` from the `PodcastLiveItemBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLiveItemBuilder;
+    ///
+    /// let podcast_live_item = PodcastLiveItemBuilder::new()
+    ///     .status("live")
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastLiveItem, String>
+    {
+        Ok(PodcastLiveItem {
+               status: self.status.clone(),
+               start: self.start.clone(),
+               end: self.end.clone(),
+               item: self.item.clone(),
+           })
+    }
+}
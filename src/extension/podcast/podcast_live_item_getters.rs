@@ -0,0 +1,130 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast live item can be retrieved by using the methods
+//! under `PodcastLiveItem`.
+
+
+use extension::podcast::{PodcastLiveItem, PodcastLiveItemGetters};
+use rss::Item;
+
+
+impl PodcastLiveItemGetters for PodcastLiveItem
+{
+    /// Get the status that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLiveItemBuilder, PodcastLiveItemGetters};
+    ///
+    /// let status = "live";
+    ///
+    /// let podcast_live_item = PodcastLiveItemBuilder::new()
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .status(status)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(status.to_owned(), podcast_live_item.status());
+    /// ```
+    fn status(&self) -> String
+    {
+        self.status.clone()
+    }
+
+
+    /// Get the start that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLiveItemBuilder, PodcastLiveItemGetters};
+    ///
+    /// let start = "2021-09-26T07:30:00-06:00";
+    ///
+    /// let podcast_live_item = PodcastLiveItemBuilder::new()
+    ///     .status("live")
+    ///     .start(start)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(start.to_owned(), podcast_live_item.start());
+    /// ```
+    fn start(&self) -> String
+    {
+        self.start.clone()
+    }
+
+
+    /// Get the optional end that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLiveItemBuilder, PodcastLiveItemGetters};
+    ///
+    /// let end = Some("2021-09-26T09:30:00-06:00".to_owned());
+    ///
+    /// let podcast_live_item = PodcastLiveItemBuilder::new()
+    ///     .status("live")
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .end(end.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(end, podcast_live_item.end());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLiveItemBuilder, PodcastLiveItemGetters};
+    ///
+    /// let podcast_live_item = PodcastLiveItemBuilder::new()
+    ///     .status("live")
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .end(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_live_item.end().is_none());
+    /// ```
+    fn end(&self) -> Option<String>
+    {
+        self.end.clone()
+    }
+
+
+    /// Get the item that exists under `PodcastLiveItem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ItemBuilder;
+    /// use feed::extension::podcast::{PodcastLiveItemBuilder, PodcastLiveItemGetters};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Podcasting 2.0 Live Show".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast_live_item = PodcastLiveItemBuilder::new()
+    ///     .status("live")
+    ///     .start("2021-09-26T07:30:00-06:00")
+    ///     .item(item.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some("Podcasting 2.0 Live Show".to_owned()), podcast_live_item.item().title);
+    /// ```
+    fn item(&self) -> Item
+    {
+        self.item.clone()
+    }
+}
@@ -0,0 +1,176 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast location by using the methods under
+//! `PodcastLocationBuilder`.
+
+
+use extension::podcast::{PodcastLocation, PodcastLocationBuilder};
+
+
+impl PodcastLocationBuilder
+{
+    /// Construct a new `PodcastLocationBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLocationBuilder;
+    ///
+    /// let podcast_location_builder = PodcastLocationBuilder::new();
+    /// ```
+    pub fn new() -> PodcastLocationBuilder
+    {
+        PodcastLocationBuilder::default()
+    }
+
+
+    /// Set the name that exists under `PodcastLocation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLocationBuilder;
+    ///
+    /// let mut podcast_location_builder = PodcastLocationBuilder::new();
+    /// podcast_location_builder.name("Austin, TX");
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut PodcastLocationBuilder
+    {
+        self.name = name.to_owned();
+        self
+    }
+
+
+    /// Set the optional geo URI that exists under `PodcastLocation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLocationBuilder;
+    ///
+    /// let mut podcast_location_builder = PodcastLocationBuilder::new();
+    /// podcast_location_builder.geo(Some("geo:30.2672,-97.7431".to_owned()));
+    /// ```
+    pub fn geo(&mut self, geo: Option<String>) -> &mut PodcastLocationBuilder
+    {
+        self.geo = geo;
+        self
+    }
+
+
+    /// Set the optional OpenStreetMap identifier that exists under `PodcastLocation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLocationBuilder;
+    ///
+    /// let mut podcast_location_builder = PodcastLocationBuilder::new();
+    /// podcast_location_builder.osm(Some("R113314".to_owned()));
+    /// ```
+    pub fn osm(&mut self, osm: Option<String>) -> &mut PodcastLocationBuilder
+    {
+        self.osm = osm;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastLocation`.
+    ///
+    /// The geo URI must hold a latitude and a longitude, with an optional
+    /// altitude, and the OpenStreetMap identifier must be a `N`, `W` or `R` type
+    /// followed by an id and an optional revision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLocationBuilder;
+    ///
+    /// let podcast_location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .geo(Some("geo:30.2672,-97.7431".to_owned()))
+    ///     .osm(Some("R113314".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastLocationBuilder, String>
+    {
+        if self.name.trim().is_empty() || self.name.chars().count() > 128
+        {
+            return Err("Location name must be 1 to 128 characters long.".to_owned());
+        }
+
+        if let Some(ref geo) = self.geo
+        {
+            let coordinates: Vec<Option<f64>> = match geo.strip_prefix("geo:")
+            {
+                Some(uri) =>
+                {
+                    uri.split(';')
+                        .next()
+                        .unwrap_or_default()
+                        .split(',')
+                        .map(|val| val.trim().parse::<f64>().ok())
+                        .collect()
+                }
+                None => Vec::new(),
+            };
+
+            let valid = match coordinates.as_slice()
+            {
+                [Some(lat), Some(lon)] | [Some(lat), Some(lon), Some(_)] => lat.abs() <= 90.0 && lon.abs() <= 180.0,
+                _ => false,
+            };
+            if !valid
+            {
+                return Err(format!("Invalid value: {}", geo));
+            }
+        }
+
+        if let Some(ref osm) = self.osm
+        {
+            let mut parts = osm.splitn(2, '#');
+            let id = parts.next().unwrap_or_default();
+            let digits = |val: &str| !val.is_empty() && val.chars().all(|c| c.is_ascii_digit());
+
+            let valid = (id.starts_with('N') || id.starts_with('W') || id.starts_with('R')) && digits(&id[1..]) &&
+                        parts.next().is_none_or(digits);
+            if !valid
+            {
+                return Err(format!("Invalid value: {}", osm));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastLocation` from the `PodcastLocationBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLocationBuilder;
+    ///
+    /// let podcast_location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastLocation, String>
+    {
+        Ok(PodcastLocation {
+               name: self.name.clone(),
+               geo: self.geo.clone(),
+               osm: self.osm.clone(),
+           })
+    }
+}
@@ -0,0 +1,110 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast location can be retrieved by using the methods
+//! under `PodcastLocation`.
+
+
+use extension::podcast::{PodcastLocation, PodcastLocationGetters};
+
+
+impl PodcastLocationGetters for PodcastLocation
+{
+    /// Get the name that exists under `PodcastLocation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLocationBuilder, PodcastLocationGetters};
+    ///
+    /// let name = "Austin, TX";
+    ///
+    /// let podcast_location = PodcastLocationBuilder::new()
+    ///     .name(name)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(name.to_owned(), podcast_location.name());
+    /// ```
+    fn name(&self) -> String
+    {
+        self.name.clone()
+    }
+
+
+    /// Get the optional geo URI that exists under `PodcastLocation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLocationBuilder, PodcastLocationGetters};
+    ///
+    /// let geo = Some("geo:30.2672,-97.7431".to_owned());
+    ///
+    /// let podcast_location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .geo(geo.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(geo, podcast_location.geo());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLocationBuilder, PodcastLocationGetters};
+    ///
+    /// let podcast_location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .geo(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_location.geo().is_none());
+    /// ```
+    fn geo(&self) -> Option<String>
+    {
+        self.geo.clone()
+    }
+
+
+    /// Get the optional OpenStreetMap identifier that exists under `PodcastLocation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLocationBuilder, PodcastLocationGetters};
+    ///
+    /// let osm = Some("R113314".to_owned());
+    ///
+    /// let podcast_location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .osm(osm.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(osm, podcast_location.osm());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLocationBuilder, PodcastLocationGetters};
+    ///
+    /// let podcast_location = PodcastLocationBuilder::new()
+    ///     .name("Austin, TX")
+    ///     .osm(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_location.osm().is_none());
+    /// ```
+    fn osm(&self) -> Option<String>
+    {
+        self.osm.clone()
+    }
+}
@@ -0,0 +1,123 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast locked by using the methods under
+//! `PodcastLockedBuilder`.
+
+
+use extension::podcast::{PodcastLocked, PodcastLockedBuilder};
+
+
+impl PodcastLockedBuilder
+{
+    /// Construct a new `PodcastLockedBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLockedBuilder;
+    ///
+    /// let podcast_locked_builder = PodcastLockedBuilder::new();
+    /// ```
+    pub fn new() -> PodcastLockedBuilder
+    {
+        PodcastLockedBuilder::default()
+    }
+
+
+    /// Set the value that exists under `PodcastLocked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLockedBuilder;
+    ///
+    /// let mut podcast_locked_builder = PodcastLockedBuilder::new();
+    /// podcast_locked_builder.value("yes");
+    /// ```
+    pub fn value(&mut self, value: &str) -> &mut PodcastLockedBuilder
+    {
+        self.value = value.to_owned();
+        self
+    }
+
+
+    /// Set the optional owner that exists under `PodcastLocked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLockedBuilder;
+    ///
+    /// let mut podcast_locked_builder = PodcastLockedBuilder::new();
+    /// podcast_locked_builder.owner(Some("chris@jupiterbroadcasting.com".to_owned()));
+    /// ```
+    pub fn owner(&mut self, owner: Option<String>) -> &mut PodcastLockedBuilder
+    {
+        self.owner = owner;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastLocked`.
+    ///
+    /// The value must be `yes` or `no`, and the owner an email address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLockedBuilder;
+    ///
+    /// let podcast_locked = PodcastLockedBuilder::new()
+    ///     .value("yes")
+    ///     .owner(Some("chris@jupiterbroadcasting.com".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastLockedBuilder, String>
+    {
+        if self.value != "yes" && self.value != "no"
+        {
+            return Err(format!("Invalid value: {}", self.value));
+        }
+
+        if let Some(ref owner) = self.owner
+        {
+            let parts: Vec<&str> = owner.split('@').collect();
+            if parts.len() != 2 || parts[0].is_empty() || !parts[1].contains('.')
+            {
+                return Err(format!("Invalid value: {}", owner));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastLocked` from the `PodcastLockedBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastLockedBuilder;
+    ///
+    /// let podcast_locked = PodcastLockedBuilder::new()
+    ///     .value("yes")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastLocked, String>
+    {
+        Ok(PodcastLocked {
+               value: self.value.clone(),
+               owner: self.owner.clone(),
+           })
+    }
+}
@@ -0,0 +1,75 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast locked can be retrieved by using the methods
+//! under `PodcastLocked`.
+
+
+use extension::podcast::{PodcastLocked, PodcastLockedGetters};
+
+
+impl PodcastLockedGetters for PodcastLocked
+{
+    /// Get the value that exists under `PodcastLocked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLockedBuilder, PodcastLockedGetters};
+    ///
+    /// let value = "yes";
+    ///
+    /// let podcast_locked = PodcastLockedBuilder::new()
+    ///     .value(value)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_owned(), podcast_locked.value());
+    /// ```
+    fn value(&self) -> String
+    {
+        self.value.clone()
+    }
+
+
+    /// Get the optional owner that exists under `PodcastLocked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLockedBuilder, PodcastLockedGetters};
+    ///
+    /// let owner = Some("chris@jupiterbroadcasting.com".to_owned());
+    ///
+    /// let podcast_locked = PodcastLockedBuilder::new()
+    ///     .value("yes")
+    ///     .owner(owner.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(owner, podcast_locked.owner());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastLockedBuilder, PodcastLockedGetters};
+    ///
+    /// let podcast_locked = PodcastLockedBuilder::new()
+    ///     .value("yes")
+    ///     .owner(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_locked.owner().is_none());
+    /// ```
+    fn owner(&self) -> Option<String>
+    {
+        self.owner.clone()
+    }
+}
@@ -0,0 +1,182 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast person by using the methods under
+//! `PodcastPersonBuilder`.
+
+
+use extension::podcast::{PodcastPerson, PodcastPersonBuilder};
+use utils::string_utils;
+
+
+impl PodcastPersonBuilder
+{
+    /// Construct a new `PodcastPersonBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let podcast_person_builder = PodcastPersonBuilder::new();
+    /// ```
+    pub fn new() -> PodcastPersonBuilder
+    {
+        PodcastPersonBuilder::default()
+    }
+
+
+    /// Set the name that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut podcast_person_builder = PodcastPersonBuilder::new();
+    /// podcast_person_builder.name("Chris Fisher");
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut PodcastPersonBuilder
+    {
+        self.name = name.to_owned();
+        self
+    }
+
+
+    /// Set the optional role that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut podcast_person_builder = PodcastPersonBuilder::new();
+    /// podcast_person_builder.role(Some("host".to_owned()));
+    /// ```
+    pub fn role(&mut self, role: Option<String>) -> &mut PodcastPersonBuilder
+    {
+        self.role = role;
+        self
+    }
+
+
+    /// Set the optional group that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut podcast_person_builder = PodcastPersonBuilder::new();
+    /// podcast_person_builder.group(Some("cast".to_owned()));
+    /// ```
+    pub fn group(&mut self, group: Option<String>) -> &mut PodcastPersonBuilder
+    {
+        self.group = group;
+        self
+    }
+
+
+    /// Set the optional image that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut podcast_person_builder = PodcastPersonBuilder::new();
+    /// podcast_person_builder.img(Some("https://example.com/images/chris.jpg".to_owned()));
+    /// ```
+    pub fn img(&mut self, img: Option<String>) -> &mut PodcastPersonBuilder
+    {
+        self.img = img;
+        self
+    }
+
+
+    /// Set the optional link that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut podcast_person_builder = PodcastPersonBuilder::new();
+    /// podcast_person_builder.href(Some("https://example.com/hosts/chris".to_owned()));
+    /// ```
+    pub fn href(&mut self, href: Option<String>) -> &mut PodcastPersonBuilder
+    {
+        self.href = href;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastPerson`.
+    ///
+    /// The name can be at most 128 characters long, and the image and the link
+    /// must be URLs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .role(Some("host".to_owned()))
+    ///     .img(Some("https://example.com/images/chris.jpg".to_owned()))
+    ///     .href(Some("https://example.com/hosts/chris".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastPersonBuilder, String>
+    {
+        if self.name.trim().is_empty() || self.name.chars().count() > 128
+        {
+            return Err("Person name must be 1 to 128 characters long.".to_owned());
+        }
+
+        if let Some(ref img) = self.img
+        {
+            string_utils::str_to_url(img.as_str())?;
+        }
+
+        if let Some(ref href) = self.href
+        {
+            string_utils::str_to_url(href.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastPerson` from the `PodcastPersonBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastPerson, String>
+    {
+        Ok(PodcastPerson {
+               name: self.name.clone(),
+               role: self.role.clone(),
+               group: self.group.clone(),
+               img: self.img.clone(),
+               href: self.href.clone(),
+           })
+    }
+}
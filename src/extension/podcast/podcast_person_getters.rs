@@ -0,0 +1,180 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast person can be retrieved by using the methods
+//! under `PodcastPerson`.
+
+
+use extension::podcast::{PodcastPerson, PodcastPersonGetters};
+
+
+impl PodcastPersonGetters for PodcastPerson
+{
+    /// Get the name that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let name = "Chris Fisher";
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name(name)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(name.to_owned(), podcast_person.name());
+    /// ```
+    fn name(&self) -> String
+    {
+        self.name.clone()
+    }
+
+
+    /// Get the optional role that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let role = Some("host".to_owned());
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .role(role.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(role, podcast_person.role());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .role(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_person.role().is_none());
+    /// ```
+    fn role(&self) -> Option<String>
+    {
+        self.role.clone()
+    }
+
+
+    /// Get the optional group that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let group = Some("cast".to_owned());
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .group(group.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(group, podcast_person.group());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .group(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_person.group().is_none());
+    /// ```
+    fn group(&self) -> Option<String>
+    {
+        self.group.clone()
+    }
+
+
+    /// Get the optional image that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let img = Some("https://example.com/images/chris.jpg".to_owned());
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .img(img.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(img, podcast_person.img());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .img(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_person.img().is_none());
+    /// ```
+    fn img(&self) -> Option<String>
+    {
+        self.img.clone()
+    }
+
+
+    /// Get the optional link that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let href = Some("https://example.com/hosts/chris".to_owned());
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .href(href.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(href, podcast_person.href());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastPersonBuilder, PodcastPersonGetters};
+    ///
+    /// let podcast_person = PodcastPersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .href(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_person.href().is_none());
+    /// ```
+    fn href(&self) -> Option<String>
+    {
+        self.href.clone()
+    }
+}
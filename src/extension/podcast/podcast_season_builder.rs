@@ -0,0 +1,123 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast season by using the methods under
+//! `PodcastSeasonBuilder`.
+
+
+use extension::podcast::{PodcastSeason, PodcastSeasonBuilder};
+
+
+impl PodcastSeasonBuilder
+{
+    /// Construct a new `PodcastSeasonBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSeasonBuilder;
+    ///
+    /// let podcast_season_builder = PodcastSeasonBuilder::new();
+    /// ```
+    pub fn new() -> PodcastSeasonBuilder
+    {
+        PodcastSeasonBuilder::default()
+    }
+
+
+    /// Set the number that exists under `PodcastSeason`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSeasonBuilder;
+    ///
+    /// let mut podcast_season_builder = PodcastSeasonBuilder::new();
+    /// podcast_season_builder.number(2);
+    /// ```
+    pub fn number(&mut self, number: i64) -> &mut PodcastSeasonBuilder
+    {
+        self.number = number;
+        self
+    }
+
+
+    /// Set the optional name that exists under `PodcastSeason`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSeasonBuilder;
+    ///
+    /// let mut podcast_season_builder = PodcastSeasonBuilder::new();
+    /// podcast_season_builder.name(Some("Road Trip".to_owned()));
+    /// ```
+    pub fn name(&mut self, name: Option<String>) -> &mut PodcastSeasonBuilder
+    {
+        self.name = name;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastSeason`.
+    ///
+    /// The number cannot be negative, and the name can be at most 128
+    /// characters long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSeasonBuilder;
+    ///
+    /// let podcast_season = PodcastSeasonBuilder::new()
+    ///     .number(2)
+    ///     .name(Some("Road Trip".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastSeasonBuilder, String>
+    {
+        if self.number < 0
+        {
+            return Err(format!("Invalid value: {}", self.number));
+        }
+
+        if let Some(ref name) = self.name
+        {
+            if name.chars().count() > 128
+            {
+                return Err("Season name must be at most 128 characters long.".to_owned());
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastSeason` from the `PodcastSeasonBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSeasonBuilder;
+    ///
+    /// let podcast_season = PodcastSeasonBuilder::new()
+    ///     .number(2)
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastSeason, String>
+    {
+        Ok(PodcastSeason {
+               number: self.number,
+               name: self.name.clone(),
+           })
+    }
+}
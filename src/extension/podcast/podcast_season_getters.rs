@@ -0,0 +1,75 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast season can be retrieved by using the methods
+//! under `PodcastSeason`.
+
+
+use extension::podcast::{PodcastSeason, PodcastSeasonGetters};
+
+
+impl PodcastSeasonGetters for PodcastSeason
+{
+    /// Get the number that exists under `PodcastSeason`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSeasonBuilder, PodcastSeasonGetters};
+    ///
+    /// let number = 2;
+    ///
+    /// let podcast_season = PodcastSeasonBuilder::new()
+    ///     .number(number)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(number, podcast_season.number());
+    /// ```
+    fn number(&self) -> i64
+    {
+        self.number
+    }
+
+
+    /// Get the optional name that exists under `PodcastSeason`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSeasonBuilder, PodcastSeasonGetters};
+    ///
+    /// let name = Some("Road Trip".to_owned());
+    ///
+    /// let podcast_season = PodcastSeasonBuilder::new()
+    ///     .number(2)
+    ///     .name(name.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(name, podcast_season.name());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSeasonBuilder, PodcastSeasonGetters};
+    ///
+    /// let podcast_season = PodcastSeasonBuilder::new()
+    ///     .number(2)
+    ///     .name(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_season.name().is_none());
+    /// ```
+    fn name(&self) -> Option<String>
+    {
+        self.name.clone()
+    }
+}
@@ -0,0 +1,148 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast soundbite by using the methods under
+//! `PodcastSoundbiteBuilder`.
+
+
+use extension::podcast::{PodcastSoundbite, PodcastSoundbiteBuilder};
+
+
+impl PodcastSoundbiteBuilder
+{
+    /// Construct a new `PodcastSoundbiteBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSoundbiteBuilder;
+    ///
+    /// let podcast_soundbite_builder = PodcastSoundbiteBuilder::new();
+    /// ```
+    pub fn new() -> PodcastSoundbiteBuilder
+    {
+        PodcastSoundbiteBuilder::default()
+    }
+
+
+    /// Set the start time that exists under `PodcastSoundbite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSoundbiteBuilder;
+    ///
+    /// let mut podcast_soundbite_builder = PodcastSoundbiteBuilder::new();
+    /// podcast_soundbite_builder.start_time(73.0);
+    /// ```
+    pub fn start_time(&mut self, start_time: f64) -> &mut PodcastSoundbiteBuilder
+    {
+        self.start_time = start_time;
+        self
+    }
+
+
+    /// Set the duration that exists under `PodcastSoundbite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSoundbiteBuilder;
+    ///
+    /// let mut podcast_soundbite_builder = PodcastSoundbiteBuilder::new();
+    /// podcast_soundbite_builder.duration(60.0);
+    /// ```
+    pub fn duration(&mut self, duration: f64) -> &mut PodcastSoundbiteBuilder
+    {
+        self.duration = duration;
+        self
+    }
+
+
+    /// Set the optional title that exists under `PodcastSoundbite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSoundbiteBuilder;
+    ///
+    /// let mut podcast_soundbite_builder = PodcastSoundbiteBuilder::new();
+    /// podcast_soundbite_builder.title(Some("Making music".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut PodcastSoundbiteBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastSoundbite`.
+    ///
+    /// The start time cannot be negative, the duration must be positive, and
+    /// the title can be at most 128 characters long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSoundbiteBuilder;
+    ///
+    /// let podcast_soundbite = PodcastSoundbiteBuilder::new()
+    ///     .start_time(73.0)
+    ///     .duration(60.0)
+    ///     .title(Some("Making music".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastSoundbiteBuilder, String>
+    {
+        if self.start_time.is_nan() || self.start_time < 0.0
+        {
+            return Err(format!("Invalid value: {}", self.start_time));
+        }
+
+        if self.duration.is_nan() || self.duration <= 0.0
+        {
+            return Err(format!("Invalid value: {}", self.duration));
+        }
+
+        if let Some(ref title) = self.title
+        {
+            if title.chars().count() > 128
+            {
+                return Err("Soundbite title must be at most 128 characters long.".to_owned());
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastSoundbite` from the `PodcastSoundbiteBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSoundbiteBuilder;
+    ///
+    /// let podcast_soundbite = PodcastSoundbiteBuilder::new()
+    ///     .start_time(73.0)
+    ///     .duration(60.0)
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastSoundbite, String>
+    {
+        Ok(PodcastSoundbite {
+               start_time: self.start_time,
+               duration: self.duration,
+               title: self.title.clone(),
+           })
+    }
+}
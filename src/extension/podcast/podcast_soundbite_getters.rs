@@ -0,0 +1,101 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast soundbite can be retrieved by using the methods
+//! under `PodcastSoundbite`.
+
+
+use extension::podcast::{PodcastSoundbite, PodcastSoundbiteGetters};
+
+
+impl PodcastSoundbiteGetters for PodcastSoundbite
+{
+    /// Get the start time that exists under `PodcastSoundbite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSoundbiteBuilder, PodcastSoundbiteGetters};
+    ///
+    /// let start_time = 73.0;
+    ///
+    /// let podcast_soundbite = PodcastSoundbiteBuilder::new()
+    ///     .duration(60.0)
+    ///     .start_time(start_time)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(start_time, podcast_soundbite.start_time());
+    /// ```
+    fn start_time(&self) -> f64
+    {
+        self.start_time
+    }
+
+
+    /// Get the duration that exists under `PodcastSoundbite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSoundbiteBuilder, PodcastSoundbiteGetters};
+    ///
+    /// let duration = 60.0;
+    ///
+    /// let podcast_soundbite = PodcastSoundbiteBuilder::new()
+    ///     .start_time(73.0)
+    ///     .duration(duration)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(duration, podcast_soundbite.duration());
+    /// ```
+    fn duration(&self) -> f64
+    {
+        self.duration
+    }
+
+
+    /// Get the optional title that exists under `PodcastSoundbite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSoundbiteBuilder, PodcastSoundbiteGetters};
+    ///
+    /// let title = Some("Making music".to_owned());
+    ///
+    /// let podcast_soundbite = PodcastSoundbiteBuilder::new()
+    ///     .start_time(73.0)
+    ///     .duration(60.0)
+    ///     .title(title.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(title, podcast_soundbite.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSoundbiteBuilder, PodcastSoundbiteGetters};
+    ///
+    /// let podcast_soundbite = PodcastSoundbiteBuilder::new()
+    ///     .start_time(73.0)
+    ///     .duration(60.0)
+    ///     .title(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_soundbite.title().is_none());
+    /// ```
+    fn title(&self) -> Option<String>
+    {
+        self.title.clone()
+    }
+}
@@ -0,0 +1,119 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast source by using the methods under
+//! `PodcastSourceBuilder`.
+
+
+use extension::podcast::{PodcastSource, PodcastSourceBuilder};
+use mime::Mime;
+use utils::string_utils;
+
+
+impl PodcastSourceBuilder
+{
+    /// Construct a new `PodcastSourceBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSourceBuilder;
+    ///
+    /// let podcast_source_builder = PodcastSourceBuilder::new();
+    /// ```
+    pub fn new() -> PodcastSourceBuilder
+    {
+        PodcastSourceBuilder::default()
+    }
+
+
+    /// Set the uri that exists under `PodcastSource`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSourceBuilder;
+    ///
+    /// let mut podcast_source_builder = PodcastSourceBuilder::new();
+    /// podcast_source_builder.uri("https://example.com/episode1.opus");
+    /// ```
+    pub fn uri(&mut self, uri: &str) -> &mut PodcastSourceBuilder
+    {
+        self.uri = uri.to_owned();
+        self
+    }
+
+
+    /// Set the optional content type that exists under `PodcastSource`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSourceBuilder;
+    ///
+    /// let mut podcast_source_builder = PodcastSourceBuilder::new();
+    /// podcast_source_builder.content_type(Some("audio/opus".to_owned()));
+    /// ```
+    pub fn content_type(&mut self, content_type: Option<String>) -> &mut PodcastSourceBuilder
+    {
+        self.content_type = content_type;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastSource`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSourceBuilder;
+    ///
+    /// let podcast_source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .content_type(Some("audio/opus".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastSourceBuilder, String>
+    {
+        string_utils::str_to_url(self.uri.as_str())?;
+
+        if let Some(ref content_type) = self.content_type
+        {
+            if let Err(err) = content_type.parse::<Mime>()
+            {
+                return Err(format!("Error: {:?}", err));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastSource` from the `PodcastSourceBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastSourceBuilder;
+    ///
+    /// let podcast_source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastSource, String>
+    {
+        Ok(PodcastSource {
+               uri: self.uri.clone(),
+               content_type: self.content_type.clone(),
+           })
+    }
+}
@@ -0,0 +1,75 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast source can be retrieved by using the methods
+//! under `PodcastSource`.
+
+
+use extension::podcast::{PodcastSource, PodcastSourceGetters};
+
+
+impl PodcastSourceGetters for PodcastSource
+{
+    /// Get the uri that exists under `PodcastSource`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSourceBuilder, PodcastSourceGetters};
+    ///
+    /// let uri = "https://example.com/episode1.opus";
+    ///
+    /// let podcast_source = PodcastSourceBuilder::new()
+    ///     .uri(uri)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(uri.to_owned(), podcast_source.uri());
+    /// ```
+    fn uri(&self) -> String
+    {
+        self.uri.clone()
+    }
+
+
+    /// Get the optional content type that exists under `PodcastSource`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSourceBuilder, PodcastSourceGetters};
+    ///
+    /// let content_type = Some("audio/opus".to_owned());
+    ///
+    /// let podcast_source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .content_type(content_type.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(content_type, podcast_source.content_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastSourceBuilder, PodcastSourceGetters};
+    ///
+    /// let podcast_source = PodcastSourceBuilder::new()
+    ///     .uri("https://example.com/episode1.opus")
+    ///     .content_type(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_source.content_type().is_none());
+    /// ```
+    fn content_type(&self) -> Option<String>
+    {
+        self.content_type.clone()
+    }
+}
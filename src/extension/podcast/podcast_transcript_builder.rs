@@ -0,0 +1,165 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast transcript by using the methods under
+//! `PodcastTranscriptBuilder`.
+
+
+use extension::podcast::{PodcastTranscript, PodcastTranscriptBuilder};
+use mime::Mime;
+use utils::string_utils;
+
+
+impl PodcastTranscriptBuilder
+{
+    /// Construct a new `PodcastTranscriptBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let podcast_transcript_builder = PodcastTranscriptBuilder::new();
+    /// ```
+    pub fn new() -> PodcastTranscriptBuilder
+    {
+        PodcastTranscriptBuilder::default()
+    }
+
+
+    /// Set the url that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut podcast_transcript_builder = PodcastTranscriptBuilder::new();
+    /// podcast_transcript_builder.url("https://example.com/episode1/transcript.vtt");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut PodcastTranscriptBuilder
+    {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the mime type that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut podcast_transcript_builder = PodcastTranscriptBuilder::new();
+    /// podcast_transcript_builder.mime_type("text/vtt");
+    /// ```
+    pub fn mime_type(&mut self, mime_type: &str) -> &mut PodcastTranscriptBuilder
+    {
+        self.mime_type = mime_type.to_owned();
+        self
+    }
+
+
+    /// Set the optional language that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut podcast_transcript_builder = PodcastTranscriptBuilder::new();
+    /// podcast_transcript_builder.language(Some("en".to_owned()));
+    /// ```
+    pub fn language(&mut self, language: Option<String>) -> &mut PodcastTranscriptBuilder
+    {
+        self.language = language;
+        self
+    }
+
+
+    /// Set the optional relation that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut podcast_transcript_builder = PodcastTranscriptBuilder::new();
+    /// podcast_transcript_builder.rel(Some("captions".to_owned()));
+    /// ```
+    pub fn rel(&mut self, rel: Option<String>) -> &mut PodcastTranscriptBuilder
+    {
+        self.rel = rel;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastTranscript`.
+    ///
+    /// The relation can only be `captions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .language(Some("en".to_owned()))
+    ///     .rel(Some("captions".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastTranscriptBuilder, String>
+    {
+        string_utils::str_to_url(self.url.as_str())?;
+
+        if let Err(err) = self.mime_type.parse::<Mime>()
+        {
+            return Err(format!("Error: {:?}", err));
+        }
+
+        if let Some(ref rel) = self.rel
+        {
+            if rel != "captions"
+            {
+                return Err(format!("Invalid value: {}", rel));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastTranscript` from the `PodcastTranscriptBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastTranscript, String>
+    {
+        Ok(PodcastTranscript {
+               url: self.url.clone(),
+               mime_type: self.mime_type.clone(),
+               language: self.language.clone(),
+               rel: self.rel.clone(),
+           })
+    }
+}
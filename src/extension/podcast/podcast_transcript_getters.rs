@@ -0,0 +1,138 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast transcript can be retrieved by using the methods
+//! under `PodcastTranscript`.
+
+
+use extension::podcast::{PodcastTranscript, PodcastTranscriptGetters};
+
+
+impl PodcastTranscriptGetters for PodcastTranscript
+{
+    /// Get the url that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastTranscriptBuilder, PodcastTranscriptGetters};
+    ///
+    /// let url = "https://example.com/episode1/transcript.vtt";
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .mime_type("text/vtt")
+    ///     .url(url)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(url.to_owned(), podcast_transcript.url());
+    /// ```
+    fn url(&self) -> String
+    {
+        self.url.clone()
+    }
+
+
+    /// Get the mime type that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastTranscriptBuilder, PodcastTranscriptGetters};
+    ///
+    /// let mime_type = "text/vtt";
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type(mime_type)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mime_type.to_owned(), podcast_transcript.mime_type());
+    /// ```
+    fn mime_type(&self) -> String
+    {
+        self.mime_type.clone()
+    }
+
+
+    /// Get the optional language that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastTranscriptBuilder, PodcastTranscriptGetters};
+    ///
+    /// let language = Some("en".to_owned());
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .language(language.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(language, podcast_transcript.language());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastTranscriptBuilder, PodcastTranscriptGetters};
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .language(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_transcript.language().is_none());
+    /// ```
+    fn language(&self) -> Option<String>
+    {
+        self.language.clone()
+    }
+
+
+    /// Get the optional relation that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastTranscriptBuilder, PodcastTranscriptGetters};
+    ///
+    /// let rel = Some("captions".to_owned());
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .rel(rel.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rel, podcast_transcript.rel());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastTranscriptBuilder, PodcastTranscriptGetters};
+    ///
+    /// let podcast_transcript = PodcastTranscriptBuilder::new()
+    ///     .url("https://example.com/episode1/transcript.vtt")
+    ///     .mime_type("text/vtt")
+    ///     .rel(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_transcript.rel().is_none());
+    /// ```
+    fn rel(&self) -> Option<String>
+    {
+        self.rel.clone()
+    }
+}
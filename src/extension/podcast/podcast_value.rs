@@ -0,0 +1,45 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Choosing the value block that applies to an episode.
+
+
+use extension::podcast::{PodcastChannelExtension, PodcastItemExtension, PodcastValue};
+
+
+impl PodcastItemExtension
+{
+    /// Get the value block that applies to the episode: its own if it has
+    /// one, otherwise that of the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastItemExtension, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let channel = PodcastChannelExtensionBuilder::new()
+    ///     .value(Some(value.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let item = PodcastItemExtension::default();
+    /// assert_eq!(Some(value), item.value_or(&channel));
+    /// ```
+    pub fn value_or(&self, channel: &PodcastChannelExtension) -> Option<PodcastValue>
+    {
+        self.value.clone().or_else(|| channel.value.clone())
+    }
+}
@@ -0,0 +1,248 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast value by using the methods under
+//! `PodcastValueBuilder`.
+
+
+use extension::podcast::{PodcastValue, PodcastValueBuilder, PodcastValueRecipient};
+
+
+impl PodcastValueBuilder
+{
+    /// Construct a new `PodcastValueBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let podcast_value_builder = PodcastValueBuilder::new();
+    /// ```
+    pub fn new() -> PodcastValueBuilder
+    {
+        PodcastValueBuilder::default()
+    }
+
+
+    /// Set the value type that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let mut podcast_value_builder = PodcastValueBuilder::new();
+    /// podcast_value_builder.value_type("lightning");
+    /// ```
+    pub fn value_type(&mut self, value_type: &str) -> &mut PodcastValueBuilder
+    {
+        self.value_type = value_type.to_owned();
+        self
+    }
+
+
+    /// Set the method that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let mut podcast_value_builder = PodcastValueBuilder::new();
+    /// podcast_value_builder.method("keysend");
+    /// ```
+    pub fn method(&mut self, method: &str) -> &mut PodcastValueBuilder
+    {
+        self.method = method.to_owned();
+        self
+    }
+
+
+    /// Set the optional suggested amount that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let mut podcast_value_builder = PodcastValueBuilder::new();
+    /// podcast_value_builder.suggested(Some("0.00000015000".to_owned()));
+    /// ```
+    pub fn suggested(&mut self, suggested: Option<String>) -> &mut PodcastValueBuilder
+    {
+        self.suggested = suggested;
+        self
+    }
+
+
+    /// Set the recipients that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueRecipientBuilder};
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(100)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut podcast_value_builder = PodcastValueBuilder::new();
+    /// podcast_value_builder.recipients(vec![recipient]);
+    /// ```
+    pub fn recipients(&mut self, recipients: Vec<PodcastValueRecipient>) -> &mut PodcastValueBuilder
+    {
+        self.recipients = recipients;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastValue`.
+    ///
+    /// Every split must be a positive number of shares, at least one recipient
+    /// must not be a fee, and the splits of the fees, which are percentages,
+    /// must add up to at most 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueRecipientBuilder};
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(100)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .suggested(Some("0.00000015000".to_owned()))
+    ///     .recipients(vec![recipient])
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    ///
+    /// The splits are shares, so they do not have to add up to 100.
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueRecipientBuilder};
+    ///
+    /// let recipients = vec![("Alice (Podcaster)",
+    ///                        "02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52",
+    ///                        40,
+    ///                        false),
+    ///                       ("Bob (Podcaster)",
+    ///                        "032f4ffbbafffbe51726ad3c164a3d0d37ec27bc67b29a159b0f49ae8ac21b8508",
+    ///                        40,
+    ///                        false),
+    ///                       ("Carol (Producer)",
+    ///                        "02dd306e68c46681aa21d88a436fb35355a8579dd30201581cefa17cb179fc4c15",
+    ///                        15,
+    ///                        false),
+    ///                       ("Hosting Provider",
+    ///                        "03ae9f91a0cb8ff43840e3c322c4c61f019d8c1c3cea15a25cfc425ac605e61a4a",
+    ///                        5,
+    ///                        true)]
+    ///     .into_iter()
+    ///     .map(|(name, address, split, fee)| {
+    ///         PodcastValueRecipientBuilder::new()
+    ///             .name(Some(name.to_owned()))
+    ///             .recipient_type("node")
+    ///             .address(address)
+    ///             .split(split)
+    ///             .fee(Some(fee))
+    ///             .finalize()
+    ///             .unwrap()
+    ///     })
+    ///     .collect();
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .suggested(Some("0.00000005000".to_owned()))
+    ///     .recipients(recipients)
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastValueBuilder, String>
+    {
+        if self.value_type.trim().is_empty()
+        {
+            return Err("Value must have a type.".to_owned());
+        }
+
+        if self.method.trim().is_empty()
+        {
+            return Err("Value must have a method.".to_owned());
+        }
+
+        if let Some(ref suggested) = self.suggested
+        {
+            match suggested.trim().parse::<f64>()
+            {
+                Ok(amount) if amount >= 0.0 => (),
+                _ => return Err(format!("Invalid value: {}", suggested)),
+            }
+        }
+
+        if !self.recipients.is_empty()
+        {
+            let (fees, shares): (Vec<&PodcastValueRecipient>, Vec<&PodcastValueRecipient>) =
+                self.recipients.iter().partition(|recipient| recipient.fee == Some(true));
+
+            if let Some(recipient) = self.recipients.iter().find(|recipient| recipient.split < 1)
+            {
+                return Err(format!("Invalid value: {}", recipient.split));
+            }
+
+            if shares.is_empty()
+            {
+                return Err("Value must have a recipient that is not a fee.".to_owned());
+            }
+
+            let fee_splits: i64 = fees.iter().map(|recipient| recipient.split).sum();
+            if fee_splits > 100
+            {
+                return Err(format!("Value fee splits must add up to at most 100, not {}.", fee_splits));
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastValue` from the `PodcastValueBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastValue, String>
+    {
+        Ok(PodcastValue {
+               value_type: self.value_type.clone(),
+               method: self.method.clone(),
+               suggested: self.suggested.clone(),
+               recipients: self.recipients.clone(),
+           })
+    }
+}
@@ -0,0 +1,131 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast value can be retrieved by using the methods
+//! under `PodcastValue`.
+
+
+use extension::podcast::{PodcastValue, PodcastValueGetters, PodcastValueRecipient};
+
+
+impl PodcastValueGetters for PodcastValue
+{
+    /// Get the value type that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueGetters};
+    ///
+    /// let value_type = "lightning";
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .method("keysend")
+    ///     .value_type(value_type)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value_type.to_owned(), podcast_value.value_type());
+    /// ```
+    fn value_type(&self) -> String
+    {
+        self.value_type.clone()
+    }
+
+
+    /// Get the method that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueGetters};
+    ///
+    /// let method = "keysend";
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method(method)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(method.to_owned(), podcast_value.method());
+    /// ```
+    fn method(&self) -> String
+    {
+        self.method.clone()
+    }
+
+
+    /// Get the optional suggested amount that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueGetters};
+    ///
+    /// let suggested = Some("0.00000015000".to_owned());
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .suggested(suggested.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(suggested, podcast_value.suggested());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueGetters};
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .suggested(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_value.suggested().is_none());
+    /// ```
+    fn suggested(&self) -> Option<String>
+    {
+        self.suggested.clone()
+    }
+
+
+    /// Get the recipients that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueGetters, PodcastValueRecipientBuilder};
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(100)
+    ///     .finalize()
+    ///     .unwrap();
+    /// let recipients = vec![recipient];
+    ///
+    /// let podcast_value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .recipients(recipients.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, podcast_value.recipients().len());
+    /// ```
+    fn recipients(&self) -> Vec<PodcastValueRecipient>
+    {
+        self.recipients.clone()
+    }
+}
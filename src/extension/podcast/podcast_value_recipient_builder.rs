@@ -0,0 +1,225 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for podcast value recipient by using the methods under
+//! `PodcastValueRecipientBuilder`.
+
+
+use extension::podcast::{PodcastValueRecipient, PodcastValueRecipientBuilder, RECIPIENT_TYPES};
+
+
+impl PodcastValueRecipientBuilder
+{
+    /// Construct a new `PodcastValueRecipientBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// ```
+    pub fn new() -> PodcastValueRecipientBuilder
+    {
+        PodcastValueRecipientBuilder::default()
+    }
+
+
+    /// Set the optional name that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// podcast_value_recipient_builder.name(Some("Host".to_owned()));
+    /// ```
+    pub fn name(&mut self, name: Option<String>) -> &mut PodcastValueRecipientBuilder
+    {
+        self.name = name;
+        self
+    }
+
+
+    /// Set the optional custom key that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// podcast_value_recipient_builder.custom_key(Some("696969".to_owned()));
+    /// ```
+    pub fn custom_key(&mut self, custom_key: Option<String>) -> &mut PodcastValueRecipientBuilder
+    {
+        self.custom_key = custom_key;
+        self
+    }
+
+
+    /// Set the optional custom value that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// podcast_value_recipient_builder.custom_value(Some("eChoVKtO1KujpAA5HCoB".to_owned()));
+    /// ```
+    pub fn custom_value(&mut self, custom_value: Option<String>) -> &mut PodcastValueRecipientBuilder
+    {
+        self.custom_value = custom_value;
+        self
+    }
+
+
+    /// Set the recipient type that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// podcast_value_recipient_builder.recipient_type("node");
+    /// ```
+    pub fn recipient_type(&mut self, recipient_type: &str) -> &mut PodcastValueRecipientBuilder
+    {
+        self.recipient_type = recipient_type.to_owned();
+        self
+    }
+
+
+    /// Set the address that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// podcast_value_recipient_builder.address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52");
+    /// ```
+    pub fn address(&mut self, address: &str) -> &mut PodcastValueRecipientBuilder
+    {
+        self.address = address.to_owned();
+        self
+    }
+
+
+    /// Set the split that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// podcast_value_recipient_builder.split(90);
+    /// ```
+    pub fn split(&mut self, split: i64) -> &mut PodcastValueRecipientBuilder
+    {
+        self.split = split;
+        self
+    }
+
+
+    /// Set the optional fee flag that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut podcast_value_recipient_builder = PodcastValueRecipientBuilder::new();
+    /// podcast_value_recipient_builder.fee(Some(false));
+    /// ```
+    pub fn fee(&mut self, fee: Option<bool>) -> &mut PodcastValueRecipientBuilder
+    {
+        self.fee = fee;
+        self
+    }
+
+
+    /// Validate the contents of `PodcastValueRecipient`.
+    ///
+    /// The recipient type can only be `node` or `lnaddress`, and a custom key
+    /// needs a custom value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .name(Some("Host".to_owned()))
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .fee(Some(false))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PodcastValueRecipientBuilder, String>
+    {
+        if !RECIPIENT_TYPES.contains(&self.recipient_type.as_str())
+        {
+            return Err(format!("Invalid value: {}", self.recipient_type));
+        }
+
+        if self.address.trim().is_empty()
+        {
+            return Err("Value recipient must have an address.".to_owned());
+        }
+
+        if self.split < 0
+        {
+            return Err(format!("Invalid value: {}", self.split));
+        }
+
+        if self.custom_key.is_some() != self.custom_value.is_some()
+        {
+            return Err("Value recipient custom key and custom value must be set together.".to_owned());
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `PodcastValueRecipient` from the `PodcastValueRecipientBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<PodcastValueRecipient, String>
+    {
+        Ok(PodcastValueRecipient {
+               name: self.name.clone(),
+               custom_key: self.custom_key.clone(),
+               custom_value: self.custom_value.clone(),
+               recipient_type: self.recipient_type.clone(),
+               address: self.address.clone(),
+               split: self.split,
+               fee: self.fee,
+           })
+    }
+}
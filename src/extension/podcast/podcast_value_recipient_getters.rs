@@ -0,0 +1,246 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under podcast value recipient can be retrieved by using the methods
+//! under `PodcastValueRecipient`.
+
+
+use extension::podcast::{PodcastValueRecipient, PodcastValueRecipientGetters};
+
+
+impl PodcastValueRecipientGetters for PodcastValueRecipient
+{
+    /// Get the optional name that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let name = Some("Host".to_owned());
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .name(name.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(name, podcast_value_recipient.name());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .name(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_value_recipient.name().is_none());
+    /// ```
+    fn name(&self) -> Option<String>
+    {
+        self.name.clone()
+    }
+
+
+    /// Get the optional custom key that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let custom_key = Some("696969".to_owned());
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .custom_key(custom_key.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(custom_key, podcast_value_recipient.custom_key());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .custom_key(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_value_recipient.custom_key().is_none());
+    /// ```
+    fn custom_key(&self) -> Option<String>
+    {
+        self.custom_key.clone()
+    }
+
+
+    /// Get the optional custom value that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let custom_value = Some("eChoVKtO1KujpAA5HCoB".to_owned());
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .custom_value(custom_value.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(custom_value, podcast_value_recipient.custom_value());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .custom_value(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_value_recipient.custom_value().is_none());
+    /// ```
+    fn custom_value(&self) -> Option<String>
+    {
+        self.custom_value.clone()
+    }
+
+
+    /// Get the recipient type that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let recipient_type = "node";
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .recipient_type(recipient_type)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(recipient_type.to_owned(), podcast_value_recipient.recipient_type());
+    /// ```
+    fn recipient_type(&self) -> String
+    {
+        self.recipient_type.clone()
+    }
+
+
+    /// Get the address that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let address = "02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52";
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .split(90)
+    ///     .address(address)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(address.to_owned(), podcast_value_recipient.address());
+    /// ```
+    fn address(&self) -> String
+    {
+        self.address.clone()
+    }
+
+
+    /// Get the split that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let split = 90;
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(split)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(split, podcast_value_recipient.split());
+    /// ```
+    fn split(&self) -> i64
+    {
+        self.split
+    }
+
+
+    /// Get the optional fee flag that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let fee = Some(false);
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .fee(fee)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(fee, podcast_value_recipient.fee());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueRecipientBuilder, PodcastValueRecipientGetters};
+    ///
+    /// let podcast_value_recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(90)
+    ///     .fee(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(podcast_value_recipient.fee().is_none());
+    /// ```
+    fn fee(&self) -> Option<bool>
+    {
+        self.fee
+    }
+}
@@ -0,0 +1,460 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The registry of `NamespaceExtension`s implemented outside of this crate.
+//!
+//! A registered extension has its namespace declared by `ChannelBuilder`,
+//! its prefix used by the parsing functions and its contents checked by
+//! `Validate`.
+
+
+use NamespaceExtension;
+use rss::Channel;
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+
+// The prefixes of the extensions this crate models, which cannot be
+// registered.
+const BUILT_IN_PREFIXES: &[&str] = &["", "atom", "content", "dc", "georss", "gml", "googleplay", "itunes", "media",
+                                     "podcast", "slash", "sy", "thr", "wfw"];
+
+
+// Validate the elements of a `NamespaceExtension`, keyed by local name.
+type ValidateFn = fn(&HashMap<String, Vec<Extension>>) -> Result<(), String>;
+
+
+// A registered `NamespaceExtension`, with the function validating its
+// elements.
+#[derive(Clone, Copy)]
+struct Registration
+{
+    prefix: &'static str,
+    namespace: &'static str,
+    validate: ValidateFn,
+}
+
+
+static REGISTRY: Mutex<Vec<Registration>> = Mutex::new(Vec::new());
+
+
+/// Register a `NamespaceExtension`, replacing any registered with the same
+/// prefix.
+///
+/// The prefixes of the extensions this crate models, such as `itunes` and
+/// `dc`, cannot be registered.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rss;
+/// extern crate feed;
+///
+/// use feed::NamespaceExtension;
+/// use feed::extension::registry;
+/// use rss::extension::Extension;
+/// use std::collections::HashMap;
+///
+/// struct License(String);
+///
+/// impl NamespaceExtension for License
+/// {
+///     fn namespace() -> &'static str
+///     {
+///         "http://backend.userland.com/creativeCommonsRssModule"
+///     }
+///
+///     fn prefix() -> &'static str
+///     {
+///         "creativeCommons"
+///     }
+///
+///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+///     {
+///         let element = map.get("license").and_then(|elements| elements.first());
+///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+///     }
+///
+///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+///     {
+///         let license = Extension {
+///             name: "creativeCommons:license".to_owned(),
+///             value: Some(self.0.clone()),
+///             ..Default::default()
+///         };
+///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+///     }
+/// }
+///
+/// struct ITunes;
+///
+/// impl NamespaceExtension for ITunes
+/// {
+///     fn namespace() -> &'static str
+///     {
+///         "http://www.itunes.com/dtds/podcast-1.0.dtd"
+///     }
+///
+///     fn prefix() -> &'static str
+///     {
+///         "itunes"
+///     }
+///
+///     fn from_map(_: &HashMap<String, Vec<Extension>>) -> Result<ITunes, String>
+///     {
+///         Ok(ITunes)
+///     }
+///
+///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+///     {
+///         HashMap::new()
+///     }
+/// }
+///
+/// fn main()
+/// {
+///     registry::register::<License>().unwrap();
+///
+///     assert_eq!(Some("http://backend.userland.com/creativeCommonsRssModule"),
+///                registry::namespace("creativeCommons"));
+///     assert!(registry::register::<ITunes>().is_err());
+/// }
+/// ```
+pub fn register<T: NamespaceExtension>() -> Result<(), String>
+{
+    if BUILT_IN_PREFIXES.contains(&T::prefix())
+    {
+        return Err(format!("Invalid value: {}. The prefix is used by a built-in extension.", T::prefix()));
+    }
+
+    let mut registry = lock();
+    registry.retain(|registration| registration.prefix != T::prefix());
+    registry.push(Registration {
+                      prefix: T::prefix(),
+                      namespace: T::namespace(),
+                      validate: validate_map::<T>,
+                  });
+    Ok(())
+}
+
+
+/// Get the prefix and the namespace of every registered
+/// `NamespaceExtension`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rss;
+/// extern crate feed;
+///
+/// use feed::NamespaceExtension;
+/// use feed::extension::registry;
+/// use rss::extension::Extension;
+/// use std::collections::HashMap;
+///
+/// struct License(String);
+///
+/// impl NamespaceExtension for License
+/// {
+///     fn namespace() -> &'static str
+///     {
+///         "http://backend.userland.com/creativeCommonsRssModule"
+///     }
+///
+///     fn prefix() -> &'static str
+///     {
+///         "creativeCommons"
+///     }
+///
+///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+///     {
+///         let element = map.get("license").and_then(|elements| elements.first());
+///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+///     }
+///
+///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+///     {
+///         let license = Extension {
+///             name: "creativeCommons:license".to_owned(),
+///             value: Some(self.0.clone()),
+///             ..Default::default()
+///         };
+///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+///     }
+/// }
+///
+/// fn main()
+/// {
+///     registry::register::<License>().unwrap();
+///
+///     assert!(registry::registered()
+///                 .contains(&("creativeCommons", "http://backend.userland.com/creativeCommonsRssModule")));
+/// }
+/// ```
+pub fn registered() -> Vec<(&'static str, &'static str)>
+{
+    let mut registered: Vec<(&'static str, &'static str)> = lock()
+        .iter()
+        .map(|registration| (registration.prefix, registration.namespace))
+        .collect();
+    registered.sort();
+    registered
+}
+
+
+/// Get the namespace of the `NamespaceExtension` registered with a prefix.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::registry;
+///
+/// assert!(registry::namespace("unregistered").is_none());
+/// ```
+pub fn namespace(prefix: &str) -> Option<&'static str>
+{
+    lock()
+        .iter()
+        .find(|registration| registration.prefix == prefix)
+        .map(|registration| registration.namespace)
+}
+
+
+/// Validate the registered `NamespaceExtension`s found in the extensions of
+/// a `Channel` or an `Item`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rss;
+/// extern crate feed;
+///
+/// use feed::NamespaceExtension;
+/// use feed::extension::registry;
+/// use rss::extension::ExtensionMap;
+/// use rss::extension::Extension;
+/// use std::collections::HashMap;
+///
+/// struct License(String);
+///
+/// impl NamespaceExtension for License
+/// {
+///     fn namespace() -> &'static str
+///     {
+///         "http://backend.userland.com/creativeCommonsRssModule"
+///     }
+///
+///     fn prefix() -> &'static str
+///     {
+///         "creativeCommons"
+///     }
+///
+///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+///     {
+///         let element = map.get("license").and_then(|elements| elements.first());
+///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+///     }
+///
+///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+///     {
+///         let license = Extension {
+///             name: "creativeCommons:license".to_owned(),
+///             value: Some(self.0.clone()),
+///             ..Default::default()
+///         };
+///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+///     }
+///
+///     fn validate(&self) -> Result<(), String>
+///     {
+///         if self.0.starts_with("http") { Ok(()) } else { Err(format!("Invalid value: {}", self.0)) }
+///     }
+/// }
+///
+/// fn main()
+/// {
+///     registry::register::<License>().unwrap();
+///
+///     let mut extensions = ExtensionMap::new();
+///     extensions.insert("creativeCommons".to_owned(), License("by-nc-sa/1.0".to_owned()).to_map());
+///     assert!(registry::validate(&extensions).is_err());
+///
+///     extensions.insert("creativeCommons".to_owned(),
+///                       License("http://www.creativecommons.org/licenses/by-nc-sa/1.0".to_owned()).to_map());
+///     assert!(registry::validate(&extensions).is_ok());
+/// }
+/// ```
+pub fn validate(extensions: &ExtensionMap) -> Result<(), String>
+{
+    let registrations = lock().clone();
+    for registration in registrations
+    {
+        if let Some(map) = extensions.get(registration.prefix)
+        {
+            (registration.validate)(map)?;
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Move the elements of a `Channel` and its `Item`s declared under another
+/// prefix for the namespace of a registered `NamespaceExtension` to its
+/// prefix.
+///
+/// A prefix the document already declares for another namespace is left
+/// alone.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rss;
+/// extern crate feed;
+///
+/// use feed::NamespaceExtension;
+/// use feed::extension::registry;
+/// use rss::Channel;
+/// use rss::extension::Extension;
+/// use std::collections::HashMap;
+///
+/// struct License(String);
+///
+/// impl NamespaceExtension for License
+/// {
+///     fn namespace() -> &'static str
+///     {
+///         "http://backend.userland.com/creativeCommonsRssModule"
+///     }
+///
+///     fn prefix() -> &'static str
+///     {
+///         "creativeCommons"
+///     }
+///
+///     fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<License, String>
+///     {
+///         let element = map.get("license").and_then(|elements| elements.first());
+///         element.and_then(|element| element.value.clone()).map(License).ok_or_else(|| "No license".to_owned())
+///     }
+///
+///     fn to_map(&self) -> HashMap<String, Vec<Extension>>
+///     {
+///         let license = Extension {
+///             name: "creativeCommons:license".to_owned(),
+///             value: Some(self.0.clone()),
+///             ..Default::default()
+///         };
+///         vec![("license".to_owned(), vec![license])].into_iter().collect()
+///     }
+/// }
+///
+/// fn main()
+/// {
+///     registry::register::<License>().unwrap();
+///
+///     let xml = "<rss version=\"2.0\" xmlns:cc=\"http://backend.userland.com/creativeCommonsRssModule\">\
+///                <channel><title>Linux Action Show</title>\
+///                <cc:license>http://www.creativecommons.org/licenses/by-nc-sa/1.0</cc:license>\
+///                </channel></rss>";
+///     let mut channel = xml.parse::<Channel>().unwrap();
+///     registry::normalize_prefixes(&mut channel);
+///
+///     assert!(channel.extensions.contains_key("creativeCommons"));
+///     assert!(!channel.namespaces.contains_key("cc"));
+/// }
+/// ```
+pub fn normalize_prefixes(channel: &mut Channel)
+{
+    let registrations = lock().clone();
+    for registration in registrations
+    {
+        if channel
+               .namespaces
+               .get(registration.prefix)
+               .map(|namespace| namespace != registration.namespace)
+               .unwrap_or_default()
+        {
+            continue;
+        }
+
+        let prefixes: Vec<String> = channel
+            .namespaces
+            .iter()
+            .filter(|&(prefix, namespace)| namespace == registration.namespace && prefix != registration.prefix)
+            .map(|(prefix, _)| prefix.clone())
+            .collect();
+
+        for prefix in prefixes
+        {
+            channel.namespaces.remove(&prefix);
+            channel
+                .namespaces
+                .insert(registration.prefix.to_owned(), registration.namespace.to_owned());
+
+            rename_prefix(&mut channel.extensions, &prefix, registration.prefix);
+            for item in &mut channel.items
+            {
+                rename_prefix(&mut item.extensions, &prefix, registration.prefix);
+            }
+        }
+    }
+}
+
+
+fn lock() -> MutexGuard<'static, Vec<Registration>>
+{
+    match REGISTRY.lock()
+    {
+        Ok(registry) => registry,
+        Err(err) => err.into_inner(),
+    }
+}
+
+
+fn validate_map<T: NamespaceExtension>(map: &HashMap<String, Vec<Extension>>) -> Result<(), String>
+{
+    T::from_map(map)?.validate()
+}
+
+
+fn rename_prefix(extensions: &mut ExtensionMap, from: &str, to: &str)
+{
+    if let Some(map) = extensions.remove(from)
+    {
+        let target = extensions.entry(to.to_owned()).or_default();
+        for (name, mut elements) in map
+        {
+            for element in &mut elements
+            {
+                rename_element(element, from, to);
+            }
+            target.entry(name).or_default().extend(elements);
+        }
+    }
+}
+
+
+fn rename_element(element: &mut Extension, from: &str, to: &str)
+{
+    if element.name.starts_with(format!("{}:", from).as_str())
+    {
+        element.name = format!("{}{}", to, &element.name[from.len()..]);
+    }
+
+    for children in element.children.values_mut()
+    {
+        for child in children
+        {
+            rename_element(child, from, to);
+        }
+    }
+}
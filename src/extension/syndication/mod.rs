@@ -0,0 +1,69 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for the RSS 1.0 syndication module.
+//!
+//! The elements are read from and written to the `sy` extensions of a
+//! `Channel`.
+
+
+pub mod syndication_extension_builder;
+pub mod syndication_extension_getters;
+pub mod syndication_extension_map;
+
+
+use enums::SyndicationPeriod;
+use std::time::Duration;
+
+
+/// The namespace of the syndication module.
+pub const NAMESPACE: &str = "http://purl.org/rss/1.0/modules/syndication/";
+
+
+/// The syndication tags of a `Channel`, telling how often it is updated.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyndicationExtension
+{
+    /// The period the channel is updated over.
+    pub update_period: Option<SyndicationPeriod>,
+    /// How many times the channel is updated in the period.
+    pub update_frequency: Option<i64>,
+    /// The date and time the updates are counted from.
+    pub update_base: Option<String>,
+}
+
+
+/// The Getter functions for `SyndicationExtension`
+pub trait SyndicationExtensionGetters
+{
+    /// Get the optional update period that exists under `SyndicationExtension`.
+    fn update_period(&self) -> Option<SyndicationPeriod>;
+
+    /// Get the optional update frequency that exists under
+    /// `SyndicationExtension`.
+    fn update_frequency(&self) -> Option<i64>;
+
+    /// Get the optional update base that exists under `SyndicationExtension`.
+    fn update_base(&self) -> Option<String>;
+
+    /// Get how often the channel is updated.
+    fn interval(&self) -> Option<Duration>;
+}
+
+
+/// This `SyndicationExtensionBuilder` struct creates the
+/// `SyndicationExtension`.
+#[derive(Clone, Default)]
+pub struct SyndicationExtensionBuilder
+{
+    update_period: Option<SyndicationPeriod>,
+    update_frequency: Option<i64>,
+    update_base: Option<String>,
+}
@@ -0,0 +1,145 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for syndication extension by using the methods
+//! under `SyndicationExtensionBuilder`.
+
+
+use enums::SyndicationPeriod;
+use extension::syndication::{SyndicationExtension, SyndicationExtensionBuilder};
+use utils::string_utils;
+
+
+impl SyndicationExtensionBuilder
+{
+    /// Construct a new `SyndicationExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let syndication_extension_builder = SyndicationExtensionBuilder::new();
+    /// ```
+    pub fn new() -> SyndicationExtensionBuilder
+    {
+        SyndicationExtensionBuilder::default()
+    }
+
+
+    /// Set the optional update period that exists under `SyndicationExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let mut syndication_extension_builder = SyndicationExtensionBuilder::new();
+    /// syndication_extension_builder.update_period(Some(SyndicationPeriod::Hourly));
+    /// ```
+    pub fn update_period(&mut self, update_period: Option<SyndicationPeriod>) -> &mut SyndicationExtensionBuilder
+    {
+        self.update_period = update_period;
+        self
+    }
+
+
+    /// Set the optional update frequency that exists under `SyndicationExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let mut syndication_extension_builder = SyndicationExtensionBuilder::new();
+    /// syndication_extension_builder.update_frequency(Some(2));
+    /// ```
+    pub fn update_frequency(&mut self, update_frequency: Option<i64>) -> &mut SyndicationExtensionBuilder
+    {
+        self.update_frequency = update_frequency;
+        self
+    }
+
+
+    /// Set the optional update base that exists under `SyndicationExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let mut syndication_extension_builder = SyndicationExtensionBuilder::new();
+    /// syndication_extension_builder.update_base(Some("2000-01-01T12:00+00:00".to_owned()));
+    /// ```
+    pub fn update_base(&mut self, update_base: Option<String>) -> &mut SyndicationExtensionBuilder
+    {
+        self.update_base = update_base;
+        self
+    }
+
+
+    /// Validate the contents of `SyndicationExtension`.
+    ///
+    /// The update frequency must be positive and the update base a W3C date and
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .update_period(Some(SyndicationPeriod::Hourly))
+    ///     .update_frequency(Some(2))
+    ///     .update_base(Some("2000-01-01T12:00+00:00".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut SyndicationExtensionBuilder, String>
+    {
+        if let Some(update_frequency) = self.update_frequency
+        {
+            if update_frequency < 1
+            {
+                return Err(format!("Invalid value: {}", update_frequency));
+            }
+        }
+
+        if let Some(ref update_base) = self.update_base
+        {
+            string_utils::w3cdtf_to_date(update_base.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `SyndicationExtension` from the `SyndicationExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<SyndicationExtension, String>
+    {
+        Ok(SyndicationExtension {
+               update_period: self.update_period.clone(),
+               update_frequency: self.update_frequency,
+               update_base: self.update_base.clone(),
+           })
+    }
+}
@@ -0,0 +1,167 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under syndication extension can be retrieved by using the
+//! methods under `SyndicationExtension`.
+
+
+use enums::SyndicationPeriod;
+use extension::syndication::{SyndicationExtension, SyndicationExtensionGetters};
+use std::convert::TryFrom;
+use std::time::Duration;
+
+
+impl SyndicationExtensionGetters for SyndicationExtension
+{
+    /// Get the optional update period that exists under `SyndicationExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let update_period = Some(SyndicationPeriod::Hourly);
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .update_period(update_period.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(update_period, syndication_extension.update_period());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .update_period(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(syndication_extension.update_period().is_none());
+    /// ```
+    fn update_period(&self) -> Option<SyndicationPeriod>
+    {
+        self.update_period.clone()
+    }
+
+
+    /// Get the optional update frequency that exists under `SyndicationExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let update_frequency = Some(2);
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .update_frequency(update_frequency)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(update_frequency, syndication_extension.update_frequency());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .update_frequency(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(syndication_extension.update_frequency().is_none());
+    /// ```
+    fn update_frequency(&self) -> Option<i64>
+    {
+        self.update_frequency
+    }
+
+
+    /// Get the optional update base that exists under `SyndicationExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let update_base = Some("2000-01-01T12:00+00:00".to_owned());
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .update_base(update_base.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(update_base, syndication_extension.update_base());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let syndication_extension = SyndicationExtensionBuilder::new()
+    ///     .update_base(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(syndication_extension.update_base().is_none());
+    /// ```
+    fn update_base(&self) -> Option<String>
+    {
+        self.update_base.clone()
+    }
+
+
+    /// Get how often the channel is updated, the update period divided by
+    /// the update frequency. The period defaults to daily and the frequency
+    /// to once, and there is no interval when neither is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    /// use std::time::Duration;
+    ///
+    /// let syndication = SyndicationExtensionBuilder::new()
+    ///     .update_period(Some(SyndicationPeriod::Hourly))
+    ///     .update_frequency(Some(2))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(Duration::from_secs(1800)), syndication.interval());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::syndication::{SyndicationExtensionBuilder, SyndicationExtensionGetters};
+    ///
+    /// let syndication = SyndicationExtensionBuilder::new()
+    ///     .update_frequency(Some(4294967296))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(syndication.interval().is_none());
+    /// ```
+    fn interval(&self) -> Option<Duration>
+    {
+        if self.update_period.is_none() && self.update_frequency.is_none()
+        {
+            return None;
+        }
+
+        let period = self.update_period.clone().unwrap_or(SyndicationPeriod::Daily);
+        match u32::try_from(self.update_frequency.unwrap_or(1))
+        {
+            Ok(frequency) if frequency >= 1 => Some(period.duration() / frequency),
+            _ => None,
+        }
+    }
+}
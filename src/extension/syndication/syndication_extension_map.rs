@@ -0,0 +1,202 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing the `sy` extensions of a `Channel`.
+
+
+use enums::SyndicationPeriod;
+use extension::syndication::SyndicationExtension;
+use rss::extension::{Extension, ExtensionMap};
+use std::collections::HashMap;
+use utils::string_utils;
+
+
+// The elements covered by `SyndicationExtension`, by local name.
+const ELEMENTS: &[&str] = &["updatePeriod", "updateFrequency", "updateBase"];
+
+
+impl SyndicationExtension
+{
+    /// Construct a `SyndicationExtension` from the `sy` extensions of a
+    /// `Channel`.
+    ///
+    /// Update periods and frequencies that cannot be read are left out, where
+    /// `try_from_map` fails on them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::SyndicationExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:sy=\"http://purl.org/rss/1.0/modules/syndication/\">\
+    ///                <channel><title>Linux Action Show</title>\
+    ///                <sy:updatePeriod>hourly</sy:updatePeriod>\
+    ///                <sy:updateFrequency>2</sy:updateFrequency>\
+    ///                </channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let syndication = SyndicationExtension::from_map(&channel.extensions["sy"]);
+    ///
+    ///     assert_eq!(Some(SyndicationPeriod::Hourly), syndication.update_period);
+    ///     assert_eq!(Some(2), syndication.update_frequency);
+    /// }
+    /// ```
+    pub fn from_map(map: &HashMap<String, Vec<Extension>>) -> SyndicationExtension
+    {
+        SyndicationExtension {
+            update_period: text(map, "updatePeriod").and_then(|period| SyndicationPeriod::value_of(&period).ok()),
+            update_frequency: text(map, "updateFrequency").and_then(|frequency| frequency.parse().ok()),
+            update_base: text(map, "updateBase"),
+        }
+    }
+
+
+    /// Construct a `SyndicationExtension` from the `sy` extensions of a
+    /// `Channel`, failing on update periods and frequencies that cannot be
+    /// read instead of leaving them out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::syndication::SyndicationExtension;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = "<rss version=\"2.0\" xmlns:sy=\"http://purl.org/rss/1.0/modules/syndication/\">\
+    ///                <channel><title>Linux Action Show</title>\
+    ///                <sy:updatePeriod>fortnightly</sy:updatePeriod>\
+    ///                </channel></rss>";
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     assert!(SyndicationExtension::try_from_map(&channel.extensions["sy"]).is_err());
+    /// }
+    /// ```
+    pub fn try_from_map(map: &HashMap<String, Vec<Extension>>) -> Result<SyndicationExtension, String>
+    {
+        let update_period = match text(map, "updatePeriod")
+        {
+            Some(period) => Some(SyndicationPeriod::value_of(&period)?),
+            None => None,
+        };
+
+        Ok(SyndicationExtension {
+               update_period,
+               update_frequency: string_utils::option_string_to_option_i64(text(map, "updateFrequency"))?,
+               update_base: text(map, "updateBase"),
+           })
+    }
+
+
+    /// Convert the `SyndicationExtension` to `sy` extensions, keyed by local
+    /// name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::SyndicationPeriod;
+    /// use feed::extension::syndication::SyndicationExtensionBuilder;
+    ///
+    /// let syndication = SyndicationExtensionBuilder::new()
+    ///     .update_period(Some(SyndicationPeriod::Weekly))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let map = syndication.to_map();
+    /// assert_eq!(Some("weekly".to_owned()), map["updatePeriod"][0].value);
+    /// ```
+    pub fn to_map(&self) -> HashMap<String, Vec<Extension>>
+    {
+        let mut map = HashMap::new();
+        insert_text(&mut map,
+                    "updatePeriod",
+                    &self.update_period.as_ref().map(|period| period.as_str().to_owned()));
+        insert_text(&mut map,
+                    "updateFrequency",
+                    &self.update_frequency.map(|frequency| frequency.to_string()));
+        insert_text(&mut map, "updateBase", &self.update_base);
+        map
+    }
+
+
+    /// Replace the `sy` extensions of an `ExtensionMap` with those of the
+    /// `SyndicationExtension`, keeping the elements it does not cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::extension::syndication::{SyndicationExtension, SyndicationExtensionBuilder};
+    /// use rss::extension::ExtensionMap;
+    ///
+    /// fn main()
+    /// {
+    ///     let syndication = SyndicationExtensionBuilder::new()
+    ///         .update_frequency(Some(4))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let mut extensions = ExtensionMap::new();
+    ///     syndication.insert_into(&mut extensions);
+    ///
+    ///     assert_eq!(syndication, SyndicationExtension::from_map(&extensions["sy"]));
+    /// }
+    /// ```
+    pub fn insert_into(&self, extensions: &mut ExtensionMap)
+    {
+        let map = extensions.entry("sy".to_owned()).or_default();
+        for name in ELEMENTS
+        {
+            map.remove(*name);
+        }
+        map.extend(self.to_map());
+
+        if map.is_empty()
+        {
+            extensions.remove("sy");
+        }
+    }
+}
+
+
+fn text(map: &HashMap<String, Vec<Extension>>, name: &str) -> Option<String>
+{
+    map.get(name)
+        .and_then(|elements| elements.first())
+        .and_then(|element| element.value.clone())
+        .map(|value| value.trim().to_owned())
+}
+
+
+fn insert_text(map: &mut HashMap<String, Vec<Extension>>, name: &str, value: &Option<String>)
+{
+    if let Some(ref value) = *value
+    {
+        map.insert(name.to_owned(),
+                   vec![Extension {
+                            name: format!("sy:{}", name),
+                            value: Some(value.clone()),
+                            attrs: HashMap::new(),
+                            children: HashMap::new(),
+                        }]);
+    }
+}
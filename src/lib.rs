@@ -56,7 +56,7 @@
 //! ```
 //! extern crate feed;
 //!
-//! use feed::ChannelBuilder;
+//! use feed::{ChannelBuilder, ChannelWriter};
 //!
 //! fn main()
 //! {
@@ -71,7 +71,8 @@
 //!         .description(description.as_ref())
 //!         .finalize().unwrap();
 //!
-//!     println!("Feed: {:?}", channel.to_string());
+//!     let xml = ChannelWriter::new().write_string(&channel).unwrap();
+//!     println!("Feed:\n{}", xml);
 //! }
 //! ```
 //!
@@ -119,18 +120,37 @@
 extern crate chrono;
 extern crate curl;
 extern crate mime;
+extern crate quick_xml;
 extern crate rss;
+extern crate serde_json;
 extern crate url;
 
 
-mod enums;
+pub mod enums;
 pub mod extension;
 pub mod channel;
+pub mod opml;
+pub mod model;
 mod utils;
 
 
+use enums::{ComplianceProfile, RssVersion};
+use extension::atom::AtomLink;
+use extension::comments::CommentsExtension;
+use extension::georss::{GeoBox, GeoRssExtension};
+use extension::googleplay::{GooglePlayChannelExtension, GooglePlayItemExtension};
+use extension::itunes::{ITunesChannelExtensionBuilder, ITunesItemExtensionBuilder};
+use extension::media::MediaExtension;
+use extension::podcast::{PodcastChannelExtension, PodcastItemExtension};
+use extension::syndication::SyndicationExtension;
+use quick_xml::XmlReader;
 use rss::{Category, Channel, Cloud, Enclosure, Guid, Image, Item, Source, TextInput};
+use rss::extension::{Extension, ExtensionMap};
+use rss::extension::dublincore::DublinCoreExtension;
 use rss::extension::itunes::{ITunesChannelExtension, ITunesItemExtension};
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::time::Duration;
 
 /// The Getter functions for `Category`
 pub trait CategoryGetters
@@ -161,11 +181,154 @@ pub trait FromUrl
 }
 
 
+/// Comment feed function for `Item`
+pub trait CommentFeed
+{
+    /// Fetch the comment feed of `Item`, from its `wfw:commentRss`, as a
+    /// `Channel`.
+    fn comment_feed(&self) -> Result<Option<Channel>, String>;
+}
+
+
+/// An extension for a namespace this crate does not model, implemented by
+/// the crate that does. Once registered with
+/// `extension::registry::register`, it is picked up by the parsing functions,
+/// `ChannelBuilder`, `ItemBuilder` and `Validate`.
+pub trait NamespaceExtension: Sized
+{
+    /// Get the namespace URI of the extension.
+    fn namespace() -> &'static str;
+
+    /// Get the prefix the extension is read from and written to.
+    fn prefix() -> &'static str;
+
+    /// Construct the extension from its elements, keyed by local name.
+    fn from_map(map: &HashMap<String, Vec<Extension>>) -> Result<Self, String>;
+
+    /// Convert the extension to its elements, keyed by local name.
+    fn to_map(&self) -> HashMap<String, Vec<Extension>>;
+
+    /// Validate the contents of the extension.
+    fn validate(&self) -> Result<(), String>
+    {
+        Ok(())
+    }
+}
+
+
+/// This `ChannelReader` struct reads the `Channel` of an RSS document from
+/// an `io::Read`, then yields its `Item`s one at a time.
+pub struct ChannelReader<R: Read>
+{
+    reader: XmlReader<BufReader<R>>,
+    channel: Channel,
+    next_item: Option<Item>,
+    finished: bool,
+}
+
+
+/// This `ChannelWriter` struct writes a `Channel` as a pretty-printed RSS
+/// document whose output only changes when the `Channel` does.
+#[derive(Clone, Debug)]
+pub struct ChannelWriter
+{
+    indent: Option<usize>,
+    cdata: bool,
+    declaration: bool,
+    encoding: String,
+    stylesheets: Vec<String>,
+}
+
+
+/// A problem found and worked around while reading a `Channel` leniently.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseIssue
+{
+    /// The line of the document the problem is on, from 1.
+    pub line: usize,
+    /// The column of the document the problem is at, from 1.
+    pub column: usize,
+    /// What the problem was and how it was recovered from.
+    pub message: String,
+}
+
+
+/// Something a podcast directory rejects, or recommends against, in a
+/// `Channel`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComplianceIssue
+{
+    /// The path of the field involved, such as `items[2].enclosure.type`.
+    pub field: String,
+    /// What the directory expects of the field.
+    pub message: String,
+}
+
+
+/// How ready a `Channel` is for the podcast directory of a
+/// `ComplianceProfile`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComplianceReport
+{
+    /// The directory the `Channel` was checked against.
+    pub profile: ComplianceProfile,
+    /// What the directory rejects.
+    pub errors: Vec<ComplianceIssue>,
+    /// What the directory recommends against.
+    pub warnings: Vec<ComplianceIssue>,
+}
+
+
+/// Lenient read functions for `Channel`
+pub trait FromLenient
+{
+    /// Construct a `Channel` from an RSS document, repairing what can be
+    /// repaired and skipping the items that cannot be read.
+    fn from_lenient(xml: &str) -> Result<(Channel, Vec<ParseIssue>), String>;
+
+    /// Construct a `Channel` from a `Url` the way `from_lenient` does.
+    fn from_url_lenient(url: &str) -> Result<(Channel, Vec<ParseIssue>), String>;
+}
+
+
+/// Legacy dialect read functions for `Channel`
+pub trait FromLegacy
+{
+    /// Construct a `Channel` from an RSS 0.9x, 1.0 or 2.0 document and
+    /// report the `RssVersion` it was written in.
+    fn from_legacy(xml: &str) -> Result<(Channel, RssVersion), String>;
+}
+
+
+/// Legacy dialect write functions for `Channel`
+pub trait ToLegacy
+{
+    /// Write `Channel` as an RSS 0.91 document.
+    fn to_rss_091(&self) -> Result<String, String>;
+}
+
+
+/// Podcast directory compliance functions for `Channel`
+pub trait Compliance
+{
+    /// Check `Channel` and its iTunes extensions against what the directory
+    /// of a `ComplianceProfile` requires and recommends.
+    fn compliance(&self, profile: ComplianceProfile) -> ComplianceReport;
+}
+
+
 /// Validate function for `Channel`
 pub trait Validate
 {
     /// Validate `Channel`
     fn validate(&self) -> Result<Channel, String>;
+
+    /// Get the warnings about `Channel`, for what is valid but goes against
+    /// best practice. There are none unless the implementor reports them.
+    fn warnings(&self) -> Vec<String>
+    {
+        Vec::new()
+    }
 }
 
 
@@ -214,6 +377,10 @@ pub trait ChannelGetters
     /// Get the optional ttl that exists under `Channel`.
     fn ttl(&self) -> Option<String>;
 
+    /// Get how often `Channel` should be polled, from the ttl or else the
+    /// syndication extension.
+    fn refresh_interval(&self) -> Option<Duration>;
+
     /// Get the optional image that exists under `Channel`.
     fn image(&self) -> Option<Image>;
 
@@ -232,8 +399,44 @@ pub trait ChannelGetters
     /// Get the items that exists under `Channel`.
     fn items(&self) -> Vec<Item>;
 
+    /// Get the items of `Channel` whose GeoRSS location overlaps a `GeoBox`.
+    fn items_within(&self, bounding_box: &GeoBox) -> Vec<Item>;
+
+    /// Get the optional `NamespaceExtension` of type `T` under `Channel`.
+    fn namespace_ext<T: NamespaceExtension>(&self) -> Result<Option<T>, String>;
+
     /// Get the optional `ITunesChannelExtension` under `Channel`.
     fn itunes_ext(&self) -> Option<ITunesChannelExtension>;
+
+    /// Get the optional `DublinCoreExtension` under `Channel`.
+    fn dublin_core_ext(&self) -> Option<DublinCoreExtension>;
+
+    /// Get the optional `MediaExtension` under `Channel`.
+    fn media_ext(&self) -> Option<MediaExtension>;
+
+    /// Get the atom links that exist under `Channel`.
+    fn atom_links(&self) -> Vec<AtomLink>;
+
+    /// Get the optional `GooglePlayChannelExtension` under `Channel`.
+    fn googleplay_ext(&self) -> Option<GooglePlayChannelExtension>;
+
+    /// Get the optional `PodcastChannelExtension` under `Channel`.
+    fn podcast_ext(&self) -> Option<PodcastChannelExtension>;
+
+    /// Get the optional iTunes show type under `Channel`.
+    fn itunes_show_type(&self) -> Option<String>;
+
+    /// Get the optional iTunes title under `Channel`.
+    fn itunes_title(&self) -> Option<String>;
+
+    /// Get the optional `SyndicationExtension` under `Channel`.
+    fn syndication_ext(&self) -> Option<SyndicationExtension>;
+
+    /// Get the extensions that exist under `Channel`.
+    fn extensions(&self) -> ExtensionMap;
+
+    /// Get the namespaces that exist under `Channel`.
+    fn namespaces(&self) -> HashMap<String, String>;
 }
 
 
@@ -261,7 +464,16 @@ pub struct ChannelBuilder
     skip_hours: Vec<i64>,
     skip_days: Vec<String>,
     items: Vec<Item>,
-    itunes_ext: Option<ITunesChannelExtension>,
+    itunes_ext: Option<ITunesChannelExtensionBuilder>,
+    dublin_core_ext: Option<DublinCoreExtension>,
+    media_ext: Option<MediaExtension>,
+    atom_links: Option<Vec<AtomLink>>,
+    googleplay_ext: Option<GooglePlayChannelExtension>,
+    podcast_ext: Option<PodcastChannelExtension>,
+    syndication_ext: Option<SyndicationExtension>,
+    namespace_exts: ExtensionMap,
+    extensions: ExtensionMap,
+    namespaces: HashMap<String, String>,
 }
 
 
@@ -410,8 +622,54 @@ pub trait ItemGetters
     /// Get the optional source that exists under `Item`.
     fn source(&self) -> Option<Source>;
 
+    /// Get the optional content that exists under `Item`.
+    fn content(&self) -> Option<String>;
+
+    /// Get the richest body of `Item`: the content if there is any, otherwise
+    /// the description.
+    fn body(&self) -> Option<String>;
+
     /// Get the optional `ITunesItemExtension` under `Item`.
     fn itunes_ext(&self) -> Option<ITunesItemExtension>;
+
+    /// Get the optional `DublinCoreExtension` under `Item`.
+    fn dublin_core_ext(&self) -> Option<DublinCoreExtension>;
+
+    /// Get the optional `MediaExtension` under `Item`.
+    fn media_ext(&self) -> Option<MediaExtension>;
+
+    /// Get the atom links that exist under `Item`.
+    fn atom_links(&self) -> Vec<AtomLink>;
+
+    /// Get the optional `GooglePlayItemExtension` under `Item`.
+    fn googleplay_ext(&self) -> Option<GooglePlayItemExtension>;
+
+    /// Get the optional `PodcastItemExtension` under `Item`.
+    fn podcast_ext(&self) -> Option<PodcastItemExtension>;
+
+    /// Get the optional iTunes title under `Item`.
+    fn itunes_title(&self) -> Option<String>;
+
+    /// Get the optional iTunes episode number under `Item`.
+    fn itunes_episode(&self) -> Option<i64>;
+
+    /// Get the optional iTunes season number under `Item`.
+    fn itunes_season(&self) -> Option<i64>;
+
+    /// Get the optional iTunes episode type under `Item`.
+    fn itunes_episode_type(&self) -> Option<String>;
+
+    /// Get the optional `CommentsExtension` under `Item`.
+    fn comments_ext(&self) -> Option<CommentsExtension>;
+
+    /// Get the optional `GeoRssExtension` under `Item`.
+    fn georss_ext(&self) -> Option<GeoRssExtension>;
+
+    /// Get the optional `NamespaceExtension` of type `T` under `Item`.
+    fn namespace_ext<T: NamespaceExtension>(&self) -> Result<Option<T>, String>;
+
+    /// Get the extensions that exist under `Item`.
+    fn extensions(&self) -> ExtensionMap;
 }
 
 
@@ -429,7 +687,17 @@ pub struct ItemBuilder
     guid: Option<Guid>,
     pub_date: Option<String>,
     source: Option<Source>,
-    itunes_ext: Option<ITunesItemExtension>,
+    content: Option<String>,
+    itunes_ext: Option<ITunesItemExtensionBuilder>,
+    dublin_core_ext: Option<DublinCoreExtension>,
+    media_ext: Option<MediaExtension>,
+    atom_links: Option<Vec<AtomLink>>,
+    googleplay_ext: Option<GooglePlayItemExtension>,
+    podcast_ext: Option<PodcastItemExtension>,
+    comments_ext: Option<CommentsExtension>,
+    georss_ext: Option<GeoRssExtension>,
+    namespace_exts: ExtensionMap,
+    extensions: ExtensionMap,
 }
 
 
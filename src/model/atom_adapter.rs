@@ -0,0 +1,289 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing Atom 1.0 documents for `Feed`.
+
+
+use model::{Attachment, Entry, Feed, Loss, Person};
+use utils::reader_utils::XmlElement;
+use utils::string_utils;
+use utils::writer_utils;
+
+
+/// The namespace of an Atom 1.0 document.
+pub const NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+
+
+// Read a `Feed` from the root `<feed>` element of an Atom document.
+pub fn read_atom(root: &XmlElement) -> Result<(Feed, Vec<Loss>), String>
+{
+    let mut feed = Feed::default();
+    let mut losses = Vec::new();
+    feed.language = root.attr("xml:lang");
+
+    for child in &root.children
+    {
+        match child.local_name()
+        {
+            "id" => feed.id = child.text.clone(),
+            "title" => feed.title = read_text(child, "title", &mut losses).unwrap_or_default(),
+            "subtitle" => feed.description = read_text(child, "subtitle", &mut losses),
+            "rights" => feed.rights = read_text(child, "rights", &mut losses),
+            "updated" => feed.updated = child.text.clone(),
+            "generator" => feed.generator = child.text.clone(),
+            "logo" => feed.image = child.text.clone(),
+            "icon" if feed.image.is_none() => feed.image = child.text.clone(),
+            "author" => feed.authors.push(read_person(child)),
+            "category" => feed.categories.extend(child.attr("term")),
+            "link" if is_alternate(child) && feed.link.is_none() => feed.link = child.attr("href"),
+            "entry" =>
+            {
+                let path = format!("entries[{}]", feed.entries.len());
+                feed.entries.push(read_entry(child, path.as_str(), &mut losses));
+            }
+            _ => losses.push(Loss::new(child.name.as_str(), "not part of the feed model")),
+        }
+    }
+
+    Ok((feed, losses))
+}
+
+
+// Write a `Feed` as an Atom 1.0 document.
+pub fn write_atom(feed: &Feed) -> Result<(String, Vec<Loss>), String>
+{
+    let id = feed.id
+        .clone()
+        .or_else(|| feed.link.clone())
+        .ok_or_else(|| "Feed must have an id or a link to be written as Atom.".to_owned())?;
+
+    let updated = feed.updated
+        .clone()
+        .or_else(|| latest_entry_date(feed))
+        .ok_or_else(|| "Feed must have an updated date to be written as Atom.".to_owned())?;
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(format!("<feed xmlns=\"{}\"", NAMESPACE).as_str());
+    if let Some(ref language) = feed.language
+    {
+        xml.push_str(format!(" xml:lang=\"{}\"", writer_utils::escape(language.as_str())).as_str());
+    }
+    xml.push_str(">\n");
+
+    push_element(&mut xml, 1, "id", Some(&id));
+    push_element(&mut xml, 1, "title", Some(&feed.title));
+    push_element(&mut xml, 1, "subtitle", feed.description.as_ref());
+    push_link(&mut xml, 1, feed.link.as_ref());
+    push_element(&mut xml, 1, "updated", Some(&updated));
+    push_element(&mut xml, 1, "rights", feed.rights.as_ref());
+    push_element(&mut xml, 1, "generator", feed.generator.as_ref());
+    push_element(&mut xml, 1, "logo", feed.image.as_ref());
+    push_people(&mut xml, 1, &feed.authors);
+    push_categories(&mut xml, 1, &feed.categories);
+
+    for (index, entry) in feed.entries.iter().enumerate()
+    {
+        push_entry(&mut xml, entry, index)?;
+    }
+
+    xml.push_str("</feed>\n");
+    Ok((xml, Vec::new()))
+}
+
+
+fn read_entry(element: &XmlElement, path: &str, losses: &mut Vec<Loss>) -> Entry
+{
+    let mut entry = Entry::default();
+
+    for child in &element.children
+    {
+        let field = format!("{}.{}", path, child.name);
+        match child.local_name()
+        {
+            "id" => entry.id = child.text.clone(),
+            "title" => entry.title = read_text(child, field.as_str(), losses),
+            "summary" => entry.summary = read_text(child, field.as_str(), losses),
+            "content" =>
+            {
+                if child.attr("src").is_some()
+                {
+                    losses.push(Loss::new(field.as_str(), "out-of-line content is not part of the feed model"));
+                }
+                entry.content = read_text(child, field.as_str(), losses);
+            }
+            "published" => entry.published = child.text.clone(),
+            "updated" => entry.updated = child.text.clone(),
+            "author" => entry.authors.push(read_person(child)),
+            "category" => entry.categories.extend(child.attr("term")),
+            "link" if child.attr("rel").as_deref() == Some("enclosure") =>
+            {
+                entry.attachments.push(Attachment {
+                                           url: child.attr("href").unwrap_or_default(),
+                                           mime_type: child.attr("type"),
+                                           length: child.attr("length")
+                                               .and_then(|val| string_utils::string_to_i64(val.as_str()).ok()),
+                                       });
+            }
+            "link" if is_alternate(child) && entry.link.is_none() => entry.link = child.attr("href"),
+            _ => losses.push(Loss::new(field.as_str(), "not part of the feed model")),
+        }
+    }
+
+    entry
+}
+
+
+fn read_person(element: &XmlElement) -> Person
+{
+    let child_text = |name: &str| {
+        element
+            .children
+            .iter()
+            .find(|child| child.local_name() == name)
+            .and_then(|child| child.text.clone())
+    };
+
+    Person {
+        name: child_text("name").unwrap_or_default(),
+        email: child_text("email"),
+        uri: child_text("uri"),
+    }
+}
+
+
+fn read_text(element: &XmlElement, field: &str, losses: &mut Vec<Loss>) -> Option<String>
+{
+    if element.attr("type").as_deref() == Some("xhtml")
+    {
+        losses.push(Loss::new(field, "xhtml markup is reduced to its text"));
+    }
+
+    element.text.clone()
+}
+
+
+fn is_alternate(element: &XmlElement) -> bool
+{
+    match element.attr("rel")
+    {
+        Some(rel) => rel == "alternate",
+        None => true,
+    }
+}
+
+
+fn latest_entry_date(feed: &Feed) -> Option<String>
+{
+    feed.entries
+        .iter()
+        .filter_map(|entry| entry.updated.clone().or_else(|| entry.published.clone()))
+        .filter_map(|date| string_utils::option_string_to_option_rfc3339_date(Some(date)).ok().and_then(|val| val))
+        .max()
+        .map(|date| date.to_rfc3339())
+}
+
+
+fn push_entry(xml: &mut String, entry: &Entry, index: usize) -> Result<(), String>
+{
+    let id = entry.id
+        .clone()
+        .or_else(|| entry.link.clone())
+        .ok_or_else(|| format!("Entry {} must have an id or a link to be written as Atom.", index))?;
+
+    let updated = entry.updated
+        .clone()
+        .or_else(|| entry.published.clone())
+        .ok_or_else(|| format!("Entry {} must have an updated date to be written as Atom.", index))?;
+
+    xml.push_str("  <entry>\n");
+    push_element(xml, 2, "id", Some(&id));
+    push_element(xml, 2, "title", Some(&entry.title.clone().unwrap_or_default()));
+    push_link(xml, 2, entry.link.as_ref());
+
+    for attachment in &entry.attachments
+    {
+        xml.push_str(format!("    <link rel=\"enclosure\" href=\"{}\"", writer_utils::escape(attachment.url.as_str()))
+                         .as_str());
+        if let Some(ref mime_type) = attachment.mime_type
+        {
+            xml.push_str(format!(" type=\"{}\"", writer_utils::escape(mime_type.as_str())).as_str());
+        }
+        if let Some(length) = attachment.length
+        {
+            xml.push_str(format!(" length=\"{}\"", length).as_str());
+        }
+        xml.push_str("/>\n");
+    }
+
+    push_element(xml, 2, "published", entry.published.as_ref());
+    push_element(xml, 2, "updated", Some(&updated));
+    push_people(xml, 2, &entry.authors);
+    push_categories(xml, 2, &entry.categories);
+    push_element(xml, 2, "summary", entry.summary.as_ref());
+
+    if let Some(ref content) = entry.content
+    {
+        xml.push_str(format!("    <content type=\"html\">{}</content>\n", writer_utils::escape(content.as_str()))
+                         .as_str());
+    }
+
+    xml.push_str("  </entry>\n");
+    Ok(())
+}
+
+
+fn push_element(xml: &mut String, depth: usize, name: &str, value: Option<&String>)
+{
+    if let Some(val) = value
+    {
+        xml.push_str("  ".repeat(depth).as_str());
+        xml.push_str(writer_utils::text_element(name, val.as_str()).as_str());
+        xml.push('\n');
+    }
+}
+
+
+fn push_link(xml: &mut String, depth: usize, link: Option<&String>)
+{
+    if let Some(href) = link
+    {
+        xml.push_str(format!("{}<link rel=\"alternate\" href=\"{}\"/>\n",
+                             "  ".repeat(depth),
+                             writer_utils::escape(href.as_str()))
+                             .as_str());
+    }
+}
+
+
+fn push_people(xml: &mut String, depth: usize, people: &[Person])
+{
+    let indent = "  ".repeat(depth);
+    for person in people
+    {
+        xml.push_str(format!("{}<author>\n", indent).as_str());
+        push_element(xml, depth + 1, "name", Some(&person.name));
+        push_element(xml, depth + 1, "email", person.email.as_ref());
+        push_element(xml, depth + 1, "uri", person.uri.as_ref());
+        xml.push_str(format!("{}</author>\n", indent).as_str());
+    }
+}
+
+
+fn push_categories(xml: &mut String, depth: usize, categories: &[String])
+{
+    for category in categories
+    {
+        xml.push_str(format!("{}<category term=\"{}\"/>\n",
+                             "  ".repeat(depth),
+                             writer_utils::escape(category.as_str()))
+                             .as_str());
+    }
+}
@@ -0,0 +1,137 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for attachment by using the methods under
+//! `AttachmentBuilder`.
+
+
+use model::{Attachment, AttachmentBuilder};
+use utils::string_utils;
+
+
+impl AttachmentBuilder
+{
+    /// Construct a new `AttachmentBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::AttachmentBuilder;
+    ///
+    /// let attachment_builder = AttachmentBuilder::new();
+    /// ```
+    pub fn new() -> AttachmentBuilder
+    {
+        AttachmentBuilder::default()
+    }
+
+
+    /// Set the url that exists under `Attachment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::AttachmentBuilder;
+    ///
+    /// let mut attachment_builder = AttachmentBuilder::new();
+    /// attachment_builder.url("http://example.com/podcast.ogg");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut AttachmentBuilder
+    {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the optional mime type that exists under `Attachment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::AttachmentBuilder;
+    ///
+    /// let mut attachment_builder = AttachmentBuilder::new();
+    /// attachment_builder.mime_type(Some("audio/ogg".to_owned()));
+    /// ```
+    pub fn mime_type(&mut self, mime_type: Option<String>) -> &mut AttachmentBuilder
+    {
+        self.mime_type = mime_type;
+        self
+    }
+
+
+    /// Set the optional length in bytes that exists under `Attachment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::AttachmentBuilder;
+    ///
+    /// let mut attachment_builder = AttachmentBuilder::new();
+    /// attachment_builder.length(Some(70772893));
+    /// ```
+    pub fn length(&mut self, length: Option<i64>) -> &mut AttachmentBuilder
+    {
+        self.length = length;
+        self
+    }
+
+
+    /// Validate the contents of `Attachment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::AttachmentBuilder;
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .mime_type(Some("audio/ogg".to_owned()))
+    ///     .length(Some(70772893))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut AttachmentBuilder, String>
+    {
+        string_utils::str_to_url(self.url.as_str())?;
+
+        if let Some(length) = self.length
+        {
+            if length < 0
+            {
+                return Err("Attachment Length cannot be a negative value.".to_owned());
+            }
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `Attachment` from the `AttachmentBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::AttachmentBuilder;
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<Attachment, String>
+    {
+        Ok(Attachment {
+               url: self.url.clone(),
+               mime_type: self.mime_type.clone(),
+               length: self.length,
+           })
+    }
+}
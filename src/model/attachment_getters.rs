@@ -0,0 +1,110 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under attachment can be retrieved by using the methods under
+//! `Attachment`.
+
+
+use model::{Attachment, AttachmentGetters};
+
+
+impl AttachmentGetters for Attachment
+{
+    /// Get the url that exists under `Attachment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{AttachmentBuilder, AttachmentGetters};
+    ///
+    /// let url = "http://example.com/podcast.ogg";
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url(url)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(url.to_owned(), attachment.url());
+    /// ```
+    fn url(&self) -> String
+    {
+        self.url.clone()
+    }
+
+
+    /// Get the optional mime type that exists under `Attachment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{AttachmentBuilder, AttachmentGetters};
+    ///
+    /// let mime_type = Some("audio/ogg".to_owned());
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .mime_type(mime_type.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mime_type, attachment.mime_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{AttachmentBuilder, AttachmentGetters};
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .mime_type(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(attachment.mime_type().is_none());
+    /// ```
+    fn mime_type(&self) -> Option<String>
+    {
+        self.mime_type.clone()
+    }
+
+
+    /// Get the optional length in bytes that exists under `Attachment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{AttachmentBuilder, AttachmentGetters};
+    ///
+    /// let length = Some(70772893);
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .length(length.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(length, attachment.length());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{AttachmentBuilder, AttachmentGetters};
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .length(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(attachment.length().is_none());
+    /// ```
+    fn length(&self) -> Option<i64>
+    {
+        self.length
+    }
+}
@@ -0,0 +1,275 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for entry by using the methods under `EntryBuilder`.
+
+
+use model::{Attachment, Entry, EntryBuilder, Person};
+use utils::string_utils;
+
+
+impl EntryBuilder
+{
+    /// Construct a new `EntryBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_builder = EntryBuilder::new();
+    /// ```
+    pub fn new() -> EntryBuilder
+    {
+        EntryBuilder::default()
+    }
+
+
+    /// Set the optional id that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.id(Some("http://www.jupiterbroadcasting.com/?p=1".to_owned()));
+    /// ```
+    pub fn id(&mut self, id: Option<String>) -> &mut EntryBuilder
+    {
+        self.id = id;
+        self
+    }
+
+
+    /// Set the optional title that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.title(Some("Episode 1".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut EntryBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional link that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.link(Some("http://www.jupiterbroadcasting.com/episode-1".to_owned()));
+    /// ```
+    pub fn link(&mut self, link: Option<String>) -> &mut EntryBuilder
+    {
+        self.link = link;
+        self
+    }
+
+
+    /// Set the optional summary that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.summary(Some("The first episode".to_owned()));
+    /// ```
+    pub fn summary(&mut self, summary: Option<String>) -> &mut EntryBuilder
+    {
+        self.summary = summary;
+        self
+    }
+
+
+    /// Set the optional content that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.content(Some("<p>The first episode</p>".to_owned()));
+    /// ```
+    pub fn content(&mut self, content: Option<String>) -> &mut EntryBuilder
+    {
+        self.content = content;
+        self
+    }
+
+
+    /// Set the optional published date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.published(Some("2017-01-26T10:36:42-05:00".to_owned()));
+    /// ```
+    pub fn published(&mut self, published: Option<String>) -> &mut EntryBuilder
+    {
+        self.published = published;
+        self
+    }
+
+
+    /// Set the optional updated date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.updated(Some("2017-01-27T10:36:42-05:00".to_owned()));
+    /// ```
+    pub fn updated(&mut self, updated: Option<String>) -> &mut EntryBuilder
+    {
+        self.updated = updated;
+        self
+    }
+
+
+    /// Set the authors that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.authors(vec![person]);
+    /// ```
+    pub fn authors(&mut self, authors: Vec<Person>) -> &mut EntryBuilder
+    {
+        self.authors = authors;
+        self
+    }
+
+
+    /// Set the categories that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.categories(vec!["Technology".to_owned()]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<String>) -> &mut EntryBuilder
+    {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the attachments that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{AttachmentBuilder, EntryBuilder};
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.attachments(vec![attachment]);
+    /// ```
+    pub fn attachments(&mut self, attachments: Vec<Attachment>) -> &mut EntryBuilder
+    {
+        self.attachments = attachments;
+        self
+    }
+
+
+    /// Validate the contents of `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .title(Some("Episode 1".to_owned()))
+    ///     .link(Some("http://www.jupiterbroadcasting.com/episode-1".to_owned()))
+    ///     .published(Some("2017-01-26T10:36:42-05:00".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut EntryBuilder, String>
+    {
+        if self.title.is_none() && self.summary.is_none() && self.content.is_none()
+        {
+            return Err("Entry must have a title, a summary or content.".to_owned());
+        }
+
+        if let Some(ref link) = self.link
+        {
+            string_utils::str_to_url(link.as_str())?;
+        }
+
+        string_utils::option_string_to_option_rfc3339_date(self.published.clone())?;
+        string_utils::option_string_to_option_rfc3339_date(self.updated.clone())?;
+
+        Ok(self)
+    }
+
+
+    /// Construct the `Entry` from the `EntryBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .title(Some("Episode 1".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<Entry, String>
+    {
+        Ok(Entry {
+               id: self.id.clone(),
+               title: self.title.clone(),
+               link: self.link.clone(),
+               summary: self.summary.clone(),
+               content: self.content.clone(),
+               published: self.published.clone(),
+               updated: self.updated.clone(),
+               authors: self.authors.clone(),
+               categories: self.categories.clone(),
+               attachments: self.attachments.clone(),
+           })
+    }
+}
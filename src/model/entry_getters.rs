@@ -0,0 +1,322 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under entry can be retrieved by using the methods under `Entry`.
+
+
+use model::{Attachment, Entry, EntryGetters, Person};
+
+
+impl EntryGetters for Entry
+{
+    /// Get the optional id that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let id = Some("http://www.jupiterbroadcasting.com/?p=1".to_owned());
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .id(id.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(id, entry.id());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .id(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(entry.id().is_none());
+    /// ```
+    fn id(&self) -> Option<String>
+    {
+        self.id.clone()
+    }
+
+
+    /// Get the optional title that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let title = Some("Episode 1".to_owned());
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .title(title.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(title, entry.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .title(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(entry.title().is_none());
+    /// ```
+    fn title(&self) -> Option<String>
+    {
+        self.title.clone()
+    }
+
+
+    /// Get the optional link that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let link = Some("http://www.jupiterbroadcasting.com/episode-1".to_owned());
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .link(link.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(link, entry.link());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .link(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(entry.link().is_none());
+    /// ```
+    fn link(&self) -> Option<String>
+    {
+        self.link.clone()
+    }
+
+
+    /// Get the optional summary that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let summary = Some("The first episode".to_owned());
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .summary(summary.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(summary, entry.summary());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .summary(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(entry.summary().is_none());
+    /// ```
+    fn summary(&self) -> Option<String>
+    {
+        self.summary.clone()
+    }
+
+
+    /// Get the optional content that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let content = Some("<p>The first episode</p>".to_owned());
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .content(content.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(content, entry.content());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .content(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(entry.content().is_none());
+    /// ```
+    fn content(&self) -> Option<String>
+    {
+        self.content.clone()
+    }
+
+
+    /// Get the optional published date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let published = Some("2017-01-26T10:36:42-05:00".to_owned());
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .published(published.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(published, entry.published());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .published(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(entry.published().is_none());
+    /// ```
+    fn published(&self) -> Option<String>
+    {
+        self.published.clone()
+    }
+
+
+    /// Get the optional updated date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let updated = Some("2017-01-27T10:36:42-05:00".to_owned());
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .updated(updated.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(updated, entry.updated());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .updated(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(entry.updated().is_none());
+    /// ```
+    fn updated(&self) -> Option<String>
+    {
+        self.updated.clone()
+    }
+
+
+    /// Get the authors that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let authors = vec![person];
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .authors(authors.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, entry.authors().len());
+    /// ```
+    fn authors(&self) -> Vec<Person>
+    {
+        self.authors.clone()
+    }
+
+
+    /// Get the categories that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, EntryGetters};
+    ///
+    /// let categories = vec!["Technology".to_owned()];
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .categories(categories.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, entry.categories().len());
+    /// ```
+    fn categories(&self) -> Vec<String>
+    {
+        self.categories.clone()
+    }
+
+
+    /// Get the attachments that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{AttachmentBuilder, EntryBuilder, EntryGetters};
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://example.com/podcast.ogg")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let attachments = vec![attachment];
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .attachments(attachments.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, entry.attachments().len());
+    /// ```
+    fn attachments(&self) -> Vec<Attachment>
+    {
+        self.attachments.clone()
+    }
+}
@@ -0,0 +1,315 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for feed by using the methods under `FeedBuilder`.
+
+
+use model::{Entry, Feed, FeedBuilder, Person};
+use utils::string_utils;
+
+
+impl FeedBuilder
+{
+    /// Construct a new `FeedBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let feed_builder = FeedBuilder::new();
+    /// ```
+    pub fn new() -> FeedBuilder
+    {
+        FeedBuilder::default()
+    }
+
+
+    /// Set the optional id that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.id(Some("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6".to_owned()));
+    /// ```
+    pub fn id(&mut self, id: Option<String>) -> &mut FeedBuilder
+    {
+        self.id = id;
+        self
+    }
+
+
+    /// Set the title that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.title("The Linux Action Show! OGG");
+    /// ```
+    pub fn title(&mut self, title: &str) -> &mut FeedBuilder
+    {
+        self.title = title.to_owned();
+        self
+    }
+
+
+    /// Set the optional link that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.link(Some("http://www.jupiterbroadcasting.com".to_owned()));
+    /// ```
+    pub fn link(&mut self, link: Option<String>) -> &mut FeedBuilder
+    {
+        self.link = link;
+        self
+    }
+
+
+    /// Set the optional description that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.description(Some("A show about Linux".to_owned()));
+    /// ```
+    pub fn description(&mut self, description: Option<String>) -> &mut FeedBuilder
+    {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the optional language that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.language(Some("en".to_owned()));
+    /// ```
+    pub fn language(&mut self, language: Option<String>) -> &mut FeedBuilder
+    {
+        self.language = language;
+        self
+    }
+
+
+    /// Set the optional rights that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.rights(Some("Copyright 2017".to_owned()));
+    /// ```
+    pub fn rights(&mut self, rights: Option<String>) -> &mut FeedBuilder
+    {
+        self.rights = rights;
+        self
+    }
+
+
+    /// Set the optional updated date that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.updated(Some("2017-01-26T10:36:42-05:00".to_owned()));
+    /// ```
+    pub fn updated(&mut self, updated: Option<String>) -> &mut FeedBuilder
+    {
+        self.updated = updated;
+        self
+    }
+
+
+    /// Set the optional generator that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.generator(Some("Feeder 2.5.12".to_owned()));
+    /// ```
+    pub fn generator(&mut self, generator: Option<String>) -> &mut FeedBuilder
+    {
+        self.generator = generator;
+        self
+    }
+
+
+    /// Set the optional image url that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.image(Some("http://www.jupiterbroadcasting.com/images/LAS-300-Beta.jpg".to_owned()));
+    /// ```
+    pub fn image(&mut self, image: Option<String>) -> &mut FeedBuilder
+    {
+        self.image = image;
+        self
+    }
+
+
+    /// Set the authors that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.authors(vec![person]);
+    /// ```
+    pub fn authors(&mut self, authors: Vec<Person>) -> &mut FeedBuilder
+    {
+        self.authors = authors;
+        self
+    }
+
+
+    /// Set the categories that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.categories(vec!["Technology".to_owned()]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<String>) -> &mut FeedBuilder
+    {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the entries that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, FeedBuilder};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .title(Some("Episode 1".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.entries(vec![entry]);
+    /// ```
+    pub fn entries(&mut self, entries: Vec<Entry>) -> &mut FeedBuilder
+    {
+        self.entries = entries;
+        self
+    }
+
+
+    /// Validate the contents of `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link(Some("http://www.jupiterbroadcasting.com".to_owned()))
+    ///     .updated(Some("2017-01-26T10:36:42-05:00".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut FeedBuilder, String>
+    {
+        if self.title.is_empty()
+        {
+            return Err("Feed Title cannot be empty.".to_owned());
+        }
+
+        if let Some(ref link) = self.link
+        {
+            string_utils::str_to_url(link.as_str())?;
+        }
+
+        if let Some(ref image) = self.image
+        {
+            string_utils::str_to_url(image.as_str())?;
+        }
+
+        string_utils::option_string_to_option_rfc3339_date(self.updated.clone())?;
+
+        Ok(self)
+    }
+
+
+    /// Construct the `Feed` from the `FeedBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::FeedBuilder;
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<Feed, String>
+    {
+        Ok(Feed {
+               id: self.id.clone(),
+               title: self.title.clone(),
+               link: self.link.clone(),
+               description: self.description.clone(),
+               language: self.language.clone(),
+               rights: self.rights.clone(),
+               updated: self.updated.clone(),
+               generator: self.generator.clone(),
+               image: self.image.clone(),
+               authors: self.authors.clone(),
+               categories: self.categories.clone(),
+               entries: self.entries.clone(),
+           })
+    }
+}
@@ -0,0 +1,324 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Converting `Feed` into and out of every supported format.
+
+
+use FromLegacy;
+use enums::FeedFormat;
+use model::{Feed, Loss};
+use model::{atom_adapter, json_feed_adapter, rdf_adapter, rss_adapter};
+use rss::Channel;
+use utils::reader_utils;
+
+
+impl Loss
+{
+    /// Construct a new `Loss` for the field at the given path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::Loss;
+    ///
+    /// let loss = Loss::new("ttl", "not part of the feed model");
+    /// assert_eq!("ttl", loss.field);
+    /// ```
+    pub fn new(field: &str, reason: &str) -> Loss
+    {
+        Loss { field: field.to_owned(), reason: reason.to_owned() }
+    }
+}
+
+
+impl Feed
+{
+    /// Construct a `Feed` from a `Channel`, along with every field of the
+    /// `Channel` that has no place in the model.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate feed;
+    ///
+    /// use feed::ChannelBuilder;
+    /// use feed::model::{Feed, FeedGetters};
+    ///
+    /// fn main()
+    /// {
+    ///     let channel = ChannelBuilder::new()
+    ///         .title("The Linux Action Show! OGG")
+    ///         .link("http://www.jupiterbroadcasting.com")
+    ///         .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///         .ttl(Some(60))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let (feed, losses) = Feed::from_channel(&channel);
+    ///     assert_eq!("The Linux Action Show! OGG", feed.title());
+    ///     assert_eq!("ttl", losses[0].field);
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// extern crate rss;
+    /// extern crate feed;
+    ///
+    /// use feed::model::Feed;
+    /// use rss::Channel;
+    ///
+    /// fn main()
+    /// {
+    ///     let xml = r#"<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+    ///     <channel><title>The Linux Action Show! OGG</title>
+    ///     <link>http://www.jupiterbroadcasting.com</link>
+    ///     <description>Ogg Vorbis audio versions of The Linux Action Show!</description>
+    ///     <category domain="http://www.jupiterbroadcasting.com/shows">Technology</category>
+    ///     <image><url>http://www.jupiterbroadcasting.com/images/LAS-300-Badge.jpg</url>
+    ///     <title>The Linux Action Show! OGG</title><link>http://www.jupiterbroadcasting.com</link>
+    ///     <width>144</width></image>
+    ///     <dc:publisher>Jupiter Broadcasting</dc:publisher>
+    ///     <item><title>Making Music with Linux | LAS 408</title>
+    ///     <link>http://www.jupiterbroadcasting.com/97561/making-music-with-linux-las-408/</link>
+    ///     <guid isPermaLink="false">http://www.jupiterbroadcasting.com/?p=97561</guid>
+    ///     <dc:identifier>las-408</dc:identifier></item>
+    ///     </channel></rss>"#;
+    ///     let channel = xml.parse::<Channel>().unwrap();
+    ///
+    ///     let (_, losses) = Feed::from_channel(&channel);
+    ///     let fields: Vec<&str> = losses.iter().map(|loss| loss.field.as_str()).collect();
+    ///     assert_eq!(vec!["entries[0].dc:identifier",
+    ///                     "entries[0].guid.isPermaLink",
+    ///                     "dc:publisher",
+    ///                     "image.width",
+    ///                     "categories[0].domain"],
+    ///                fields);
+    /// }
+    /// ```
+    pub fn from_channel(channel: &Channel) -> (Feed, Vec<Loss>)
+    {
+        rss_adapter::channel_to_feed(channel)
+    }
+
+
+    /// Construct a `Channel` from the `Feed`, along with every field of the
+    /// `Feed` RSS 2.0 cannot hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate feed;
+    ///
+    /// use feed::{ChannelGetters, ItemGetters};
+    /// use feed::model::{AttachmentBuilder, EntryBuilder, FeedBuilder, PersonBuilder};
+    ///
+    /// fn main()
+    /// {
+    ///     let first = AttachmentBuilder::new()
+    ///         .url("http://example.com/podcast.ogg")
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let second = AttachmentBuilder::new()
+    ///         .url("http://example.com/podcast.mp3")
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let entry = EntryBuilder::new()
+    ///         .title(Some("Episode 1".to_owned()))
+    ///         .published(Some("2017-01-26T10:36:42-05:00".to_owned()))
+    ///         .attachments(vec![first, second])
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let feed = FeedBuilder::new()
+    ///         .title("The Linux Action Show! OGG")
+    ///         .entries(vec![entry])
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let (channel, losses) = feed.to_channel();
+    ///     let item = channel.items().remove(0);
+    ///     assert_eq!(Some("Thu, 26 Jan 2017 10:36:42 -0500".to_owned()), item.pub_date());
+    ///     assert_eq!("entries[0].attachments[1..]", losses[0].field);
+    ///
+    ///     let person = PersonBuilder::new()
+    ///         .name("Chris Fisher")
+    ///         .uri(Some("http://www.jupiterbroadcasting.com".to_owned()))
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let feed = FeedBuilder::new()
+    ///         .title("The Linux Action Show! OGG")
+    ///         .authors(vec![person])
+    ///         .finalize()
+    ///         .unwrap();
+    ///
+    ///     let (_, losses) = feed.to_channel();
+    ///     assert_eq!("authors[0].uri", losses[0].field);
+    /// }
+    /// ```
+    pub fn to_channel(&self) -> (Channel, Vec<Loss>)
+    {
+        rss_adapter::feed_to_channel(self)
+    }
+
+
+    /// Construct a `Feed` from an RSS, Atom, JSON Feed or RDF document,
+    /// detecting the format from the document itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::FeedFormat;
+    /// use feed::model::{EntryGetters, Feed, FeedGetters};
+    ///
+    /// let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+    /// <feed xmlns="http://www.w3.org/2005/Atom">
+    ///   <title>Example Feed</title>
+    ///   <link href="http://example.org/"/>
+    ///   <updated>2003-12-13T18:30:02Z</updated>
+    ///   <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+    ///   <entry>
+    ///     <title>Atom-Powered Robots Run Amok</title>
+    ///     <link href="http://example.org/2003/12/13/atom03"/>
+    ///     <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+    ///     <updated>2003-12-13T18:30:02Z</updated>
+    ///     <summary>Some text.</summary>
+    ///   </entry>
+    /// </feed>"#;
+    ///
+    /// let (feed, format, losses) = Feed::read(xml).unwrap();
+    /// assert_eq!(FeedFormat::Atom, format);
+    /// assert_eq!("Example Feed", feed.title());
+    /// assert_eq!(Some("Some text.".to_owned()), feed.entries()[0].summary());
+    /// assert!(losses.is_empty());
+    /// ```
+    ///
+    /// ```
+    /// use feed::enums::FeedFormat;
+    /// use feed::model::{Feed, FeedGetters};
+    ///
+    /// let json = r#"{
+    ///     "version": "https://jsonfeed.org/version/1.1",
+    ///     "title": "My Example Feed",
+    ///     "home_page_url": "https://example.org/",
+    ///     "items": [
+    ///         { "id": "2", "content_text": "This is a second item.", "url": "https://example.org/second-item" }
+    ///     ]
+    /// }"#;
+    ///
+    /// let (feed, format, _) = Feed::read(json).unwrap();
+    /// assert_eq!(FeedFormat::JsonFeed, format);
+    /// assert_eq!(1, feed.entries().len());
+    /// ```
+    pub fn read(s: &str) -> Result<(Feed, FeedFormat, Vec<Loss>), String>
+    {
+        if s.trim_start().starts_with('{')
+        {
+            let (feed, losses) = json_feed_adapter::read_json_feed(s)?;
+            return Ok((feed, FeedFormat::JsonFeed, losses));
+        }
+
+        let root = reader_utils::read_document(s)?;
+        match root.name.as_str()
+        {
+            "rss" | "rdf:RDF" =>
+            {
+                let format = if root.name == "rss" { FeedFormat::Rss } else { FeedFormat::Rdf };
+                let (channel, _) = Channel::from_legacy(s)?;
+                let (feed, losses) = rss_adapter::channel_to_feed(&channel);
+                Ok((feed, format, losses))
+            }
+            _ if root.local_name() == "feed" =>
+            {
+                let (feed, losses) = atom_adapter::read_atom(&root)?;
+                Ok((feed, FeedFormat::Atom, losses))
+            }
+            _ => Err(format!("Invalid value: {}", root.name)),
+        }
+    }
+
+
+    /// Write the `Feed` in the given format, along with every field of the
+    /// `Feed` the format cannot hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::enums::FeedFormat;
+    /// use feed::model::{Feed, FeedBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .email(Some("chris@jupiterbroadcasting.com".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .id(Some("http://www.jupiterbroadcasting.com".to_owned()))
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link(Some("http://www.jupiterbroadcasting.com".to_owned()))
+    ///     .updated(Some("2017-01-26T10:36:42-05:00".to_owned()))
+    ///     .authors(vec![person])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let (atom, losses) = feed.write(FeedFormat::Atom).unwrap();
+    /// assert!(losses.is_empty());
+    /// assert_eq!(feed, Feed::read(atom.as_str()).unwrap().0);
+    ///
+    /// let (_, losses) = feed.write(FeedFormat::JsonFeed).unwrap();
+    /// assert!(losses.iter().any(|loss| loss.field == "authors[0].email"));
+    /// ```
+    ///
+    /// ```
+    /// use feed::enums::FeedFormat;
+    /// use feed::model::{AttachmentBuilder, EntryBuilder, Feed, FeedBuilder, FeedGetters};
+    ///
+    /// let attachment = AttachmentBuilder::new()
+    ///     .url("http://www.podtrac.com/pts/redirect.ogg?show=las&episode=408")
+    ///     .mime_type(Some("audio/ogg".to_owned()))
+    ///     .length(Some(65036940))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .title(Some("Making Music with Linux | LAS 408".to_owned()))
+    ///     .attachments(vec![attachment])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .entries(vec![entry])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let (rss, _) = feed.write(FeedFormat::Rss).unwrap();
+    /// let read = Feed::read(rss.as_str()).unwrap().0;
+    /// assert_eq!("http://www.podtrac.com/pts/redirect.ogg?show=las&episode=408",
+    ///            read.entries()[0].attachments[0].url);
+    /// ```
+    pub fn write(&self, format: FeedFormat) -> Result<(String, Vec<Loss>), String>
+    {
+        match format
+        {
+            FeedFormat::Rss =>
+            {
+                let (channel, losses) = rss_adapter::feed_to_channel(self);
+                Ok((rss_adapter::write_channel(&channel), losses))
+            }
+            FeedFormat::Atom => atom_adapter::write_atom(self),
+            FeedFormat::JsonFeed => json_feed_adapter::write_json_feed(self),
+            FeedFormat::Rdf => rdf_adapter::write_rdf(self),
+        }
+    }
+}
@@ -0,0 +1,396 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under feed can be retrieved by using the methods under `Feed`.
+
+
+use model::{Entry, Feed, FeedGetters, Person};
+
+
+impl FeedGetters for Feed
+{
+    /// Get the optional id that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let id = Some("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .id(id.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(id, feed.id());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .id(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.id().is_none());
+    /// ```
+    fn id(&self) -> Option<String>
+    {
+        self.id.clone()
+    }
+
+
+    /// Get the title that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let title = "The Linux Action Show! OGG";
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title(title)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(title.to_owned(), feed.title());
+    /// ```
+    fn title(&self) -> String
+    {
+        self.title.clone()
+    }
+
+
+    /// Get the optional link that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let link = Some("http://www.jupiterbroadcasting.com".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link(link.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(link, feed.link());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.link().is_none());
+    /// ```
+    fn link(&self) -> Option<String>
+    {
+        self.link.clone()
+    }
+
+
+    /// Get the optional description that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let description = Some("A show about Linux".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .description(description.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(description, feed.description());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .description(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.description().is_none());
+    /// ```
+    fn description(&self) -> Option<String>
+    {
+        self.description.clone()
+    }
+
+
+    /// Get the optional language that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let language = Some("en".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .language(language.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(language, feed.language());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .language(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.language().is_none());
+    /// ```
+    fn language(&self) -> Option<String>
+    {
+        self.language.clone()
+    }
+
+
+    /// Get the optional rights that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let rights = Some("Copyright 2017".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .rights(rights.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(rights, feed.rights());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .rights(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.rights().is_none());
+    /// ```
+    fn rights(&self) -> Option<String>
+    {
+        self.rights.clone()
+    }
+
+
+    /// Get the optional updated date that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let updated = Some("2017-01-26T10:36:42-05:00".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .updated(updated.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(updated, feed.updated());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .updated(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.updated().is_none());
+    /// ```
+    fn updated(&self) -> Option<String>
+    {
+        self.updated.clone()
+    }
+
+
+    /// Get the optional generator that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let generator = Some("Feeder 2.5.12".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .generator(generator.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(generator, feed.generator());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .generator(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.generator().is_none());
+    /// ```
+    fn generator(&self) -> Option<String>
+    {
+        self.generator.clone()
+    }
+
+
+    /// Get the optional image url that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let image = Some("http://www.jupiterbroadcasting.com/images/LAS-300-Beta.jpg".to_owned());
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .image(image.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(image, feed.image());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .image(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(feed.image().is_none());
+    /// ```
+    fn image(&self) -> Option<String>
+    {
+        self.image.clone()
+    }
+
+
+    /// Get the authors that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let authors = vec![person];
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .authors(authors.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, feed.authors().len());
+    /// ```
+    fn authors(&self) -> Vec<Person>
+    {
+        self.authors.clone()
+    }
+
+
+    /// Get the categories that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{FeedBuilder, FeedGetters};
+    ///
+    /// let categories = vec!["Technology".to_owned()];
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .categories(categories.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, feed.categories().len());
+    /// ```
+    fn categories(&self) -> Vec<String>
+    {
+        self.categories.clone()
+    }
+
+
+    /// Get the entries that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, FeedBuilder, FeedGetters};
+    ///
+    /// let entry = EntryBuilder::new()
+    ///     .title(Some("Episode 1".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// let entries = vec![entry];
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .entries(entries.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, feed.entries().len());
+    /// ```
+    fn entries(&self) -> Vec<Entry>
+    {
+        self.entries.clone()
+    }
+}
@@ -0,0 +1,287 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing JSON Feed 1.1 documents for `Feed`.
+
+
+use model::{Attachment, Entry, Feed, Loss, Person};
+use serde_json::{self, Map, Value};
+
+
+/// The version URL of a JSON Feed 1.1 document.
+pub const VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+
+// Read a `Feed` from a JSON Feed 1.0 or 1.1 document.
+pub fn read_json_feed(json: &str) -> Result<(Feed, Vec<Loss>), String>
+{
+    let root: Value = serde_json::from_str(json).map_err(|err| format!("Error: {}", err))?;
+    let root = root.as_object().ok_or_else(|| "Error: JSON Feed must be an object".to_owned())?;
+
+    match root.get("version").and_then(|val| val.as_str())
+    {
+        Some(version) if version.starts_with("https://jsonfeed.org/version/") => (),
+        Some(version) => return Err(format!("Invalid value: {}", version)),
+        None => return Err("Error: JSON Feed has no version".to_owned()),
+    }
+
+    let mut losses = Vec::new();
+    let mut feed = Feed {
+        title: string(root, "title").unwrap_or_default(),
+        link: string(root, "home_page_url"),
+        description: string(root, "description"),
+        language: string(root, "language"),
+        image: string(root, "icon").or_else(|| string(root, "favicon")),
+        authors: read_authors(root),
+        ..Default::default()
+    };
+
+    for (key, _) in root.iter()
+    {
+        match key.as_str()
+        {
+            "version" | "title" | "home_page_url" | "description" | "language" | "icon" | "authors" |
+            "author" | "items" => (),
+            "favicon" if string(root, "icon").is_none() => (),
+            _ => losses.push(Loss::new(key.as_str(), "not part of the feed model")),
+        }
+    }
+
+    if let Some(items) = root.get("items").and_then(|val| val.as_array())
+    {
+        for (index, item) in items.iter().enumerate()
+        {
+            if let Some(item) = item.as_object()
+            {
+                feed.entries.push(read_item(item, index, &mut losses));
+            }
+        }
+    }
+
+    Ok((feed, losses))
+}
+
+
+// Write a `Feed` as a JSON Feed 1.1 document.
+pub fn write_json_feed(feed: &Feed) -> Result<(String, Vec<Loss>), String>
+{
+    let mut losses = Vec::new();
+    let dropped = vec![("id", feed.id.is_some()),
+                       ("rights", feed.rights.is_some()),
+                       ("updated", feed.updated.is_some()),
+                       ("generator", feed.generator.is_some()),
+                       ("categories", !feed.categories.is_empty())];
+
+    for (field, present) in dropped
+    {
+        if present
+        {
+            losses.push(Loss::new(field, "JSON Feed has no such field"));
+        }
+    }
+
+    let mut root = Map::new();
+    root.insert("version".to_owned(), Value::String(VERSION.to_owned()));
+    root.insert("title".to_owned(), Value::String(feed.title.clone()));
+    insert(&mut root, "home_page_url", feed.link.clone());
+    insert(&mut root, "description", feed.description.clone());
+    insert(&mut root, "icon", feed.image.clone());
+    insert(&mut root, "language", feed.language.clone());
+    if !feed.authors.is_empty()
+    {
+        root.insert("authors".to_owned(), write_authors(&feed.authors, "authors", &mut losses));
+    }
+
+    let mut items = Vec::new();
+    for (index, entry) in feed.entries.iter().enumerate()
+    {
+        items.push(write_item(entry, index, &mut losses)?);
+    }
+    root.insert("items".to_owned(), Value::Array(items));
+
+    let json = serde_json::to_string_pretty(&Value::Object(root)).map_err(|err| format!("Error: {}", err))?;
+    Ok((json, losses))
+}
+
+
+fn read_item(item: &Map<String, Value>, index: usize, losses: &mut Vec<Loss>) -> Entry
+{
+    let path = format!("entries[{}]", index);
+    let content_html = string(item, "content_html");
+    let content_text = string(item, "content_text");
+    let summary = string(item, "summary");
+
+    if content_html.is_some() && content_text.is_some()
+    {
+        losses.push(Loss::new(format!("{}.content_text", path).as_str(), "only content_html is kept as the content"));
+    }
+
+    for (key, _) in item.iter()
+    {
+        match key.as_str()
+        {
+            "id" | "url" | "title" | "content_html" | "content_text" | "summary" | "date_published" |
+            "date_modified" | "authors" | "author" | "tags" | "attachments" => (),
+            _ => losses.push(Loss::new(format!("{}.{}", path, key).as_str(), "not part of the feed model")),
+        }
+    }
+
+    let attachments = item.get("attachments")
+        .and_then(|val| val.as_array())
+        .map(|vals| {
+                 vals.iter()
+                     .filter_map(|val| val.as_object())
+                     .map(|val| {
+                              Attachment {
+                                  url: string(val, "url").unwrap_or_default(),
+                                  mime_type: string(val, "mime_type"),
+                                  length: val.get("size_in_bytes").and_then(|size| size.as_i64()),
+                              }
+                          })
+                     .collect()
+             })
+        .unwrap_or_default();
+
+    Entry {
+        id: match item.get("id")
+        {
+            Some(Value::String(id)) => Some(id.clone()),
+            Some(Value::Number(id)) => Some(id.to_string()),
+            _ => None,
+        },
+        title: string(item, "title"),
+        link: string(item, "url"),
+        summary: summary.or_else(|| if content_html.is_some() { None } else { content_text.clone() }),
+        content: content_html,
+        published: string(item, "date_published"),
+        updated: string(item, "date_modified"),
+        authors: read_authors(item),
+        categories: item.get("tags")
+            .and_then(|val| val.as_array())
+            .map(|tags| tags.iter().filter_map(|tag| tag.as_str()).map(|tag| tag.to_owned()).collect())
+            .unwrap_or_default(),
+        attachments,
+    }
+}
+
+
+fn write_item(entry: &Entry, index: usize, losses: &mut Vec<Loss>) -> Result<Value, String>
+{
+    let id = entry.id
+        .clone()
+        .or_else(|| entry.link.clone())
+        .ok_or_else(|| format!("Entry {} must have an id or a link to be written as JSON Feed.", index))?;
+
+    let mut item = Map::new();
+    item.insert("id".to_owned(), Value::String(id));
+    insert(&mut item, "url", entry.link.clone());
+    insert(&mut item, "title", entry.title.clone());
+
+    match entry.content
+    {
+        Some(ref content) =>
+        {
+            insert(&mut item, "content_html", Some(content.clone()));
+            insert(&mut item, "summary", entry.summary.clone());
+        }
+        None => insert(&mut item, "content_text", Some(entry.summary.clone().unwrap_or_default())),
+    }
+
+    insert(&mut item, "date_published", entry.published.clone());
+    insert(&mut item, "date_modified", entry.updated.clone());
+
+    if !entry.authors.is_empty()
+    {
+        let path = format!("entries[{}].authors", index);
+        item.insert("authors".to_owned(), write_authors(&entry.authors, path.as_str(), losses));
+    }
+
+    if !entry.categories.is_empty()
+    {
+        item.insert("tags".to_owned(),
+                    Value::Array(entry.categories.iter().map(|cat| Value::String(cat.clone())).collect()));
+    }
+
+    if !entry.attachments.is_empty()
+    {
+        let attachments = entry.attachments
+            .iter()
+            .map(|attachment| {
+                let mut val = Map::new();
+                val.insert("url".to_owned(), Value::String(attachment.url.clone()));
+                val.insert("mime_type".to_owned(),
+                           Value::String(attachment.mime_type
+                                             .clone()
+                                             .unwrap_or_else(|| "application/octet-stream".to_owned())));
+                if let Some(length) = attachment.length
+                {
+                    val.insert("size_in_bytes".to_owned(), Value::from(length));
+                }
+                Value::Object(val)
+            })
+            .collect();
+        item.insert("attachments".to_owned(), Value::Array(attachments));
+    }
+
+    Ok(Value::Object(item))
+}
+
+
+fn read_authors(object: &Map<String, Value>) -> Vec<Person>
+{
+    let authors = match object.get("authors").and_then(|val| val.as_array())
+    {
+        Some(authors) => authors.iter().collect(),
+        None => object.get("author").into_iter().collect::<Vec<_>>(),
+    };
+
+    authors
+        .into_iter()
+        .filter_map(|val| val.as_object())
+        .map(|val| Person { name: string(val, "name").unwrap_or_default(), email: None, uri: string(val, "url") })
+        .collect()
+}
+
+
+fn write_authors(people: &[Person], path: &str, losses: &mut Vec<Loss>) -> Value
+{
+    let authors = people
+        .iter()
+        .enumerate()
+        .map(|(index, person)| {
+            if person.email.is_some()
+            {
+                losses.push(Loss::new(format!("{}[{}].email", path, index).as_str(), "JSON Feed authors have no email"));
+            }
+
+            let mut val = Map::new();
+            val.insert("name".to_owned(), Value::String(person.name.clone()));
+            insert(&mut val, "url", person.uri.clone());
+            Value::Object(val)
+        })
+        .collect();
+
+    Value::Array(authors)
+}
+
+
+fn string(object: &Map<String, Value>, key: &str) -> Option<String>
+{
+    object.get(key).and_then(|val| val.as_str()).map(|val| val.to_owned())
+}
+
+
+fn insert(object: &mut Map<String, Value>, key: &str, value: Option<String>)
+{
+    if let Some(val) = value
+    {
+        object.insert(key.to_owned(), Value::String(val));
+    }
+}
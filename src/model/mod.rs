@@ -0,0 +1,287 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for the format-agnostic feed model.
+//!
+//! A `Feed` can be read from and written to RSS 2.0, Atom 1.0, JSON Feed 1.1
+//! and RSS 1.0 (RDF). Anything a conversion cannot carry across is reported
+//! as a `Loss` instead of being silently dropped.
+
+
+pub mod attachment_builder;
+pub mod attachment_getters;
+pub mod entry_builder;
+pub mod entry_getters;
+pub mod feed_builder;
+pub mod feed_convert;
+pub mod feed_getters;
+pub mod person_builder;
+pub mod person_getters;
+mod atom_adapter;
+mod json_feed_adapter;
+mod rdf_adapter;
+mod rss_adapter;
+
+
+/// A feed, independent of the format it was read from.
+///
+/// Dates are RFC 3339 strings.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Feed
+{
+    /// The permanent, unique identifier of the feed.
+    pub id: Option<String>,
+    /// The title of the feed.
+    pub title: String,
+    /// The URL of the website of the feed.
+    pub link: Option<String>,
+    /// The description of the feed.
+    pub description: Option<String>,
+    /// The language the feed is written in.
+    pub language: Option<String>,
+    /// The copyright notice of the feed.
+    pub rights: Option<String>,
+    /// The date the feed was last updated.
+    pub updated: Option<String>,
+    /// The program used to generate the feed.
+    pub generator: Option<String>,
+    /// The URL of the image of the feed.
+    pub image: Option<String>,
+    /// The authors of the feed.
+    pub authors: Vec<Person>,
+    /// The categories of the feed.
+    pub categories: Vec<String>,
+    /// The entries of the feed.
+    pub entries: Vec<Entry>,
+}
+
+
+/// An entry of a `Feed`, independent of the format it was read from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Entry
+{
+    /// The permanent, unique identifier of the entry.
+    pub id: Option<String>,
+    /// The title of the entry.
+    pub title: Option<String>,
+    /// The URL of the entry.
+    pub link: Option<String>,
+    /// The plain summary of the entry.
+    pub summary: Option<String>,
+    /// The full HTML content of the entry.
+    pub content: Option<String>,
+    /// The date the entry was published.
+    pub published: Option<String>,
+    /// The date the entry was last updated.
+    pub updated: Option<String>,
+    /// The authors of the entry.
+    pub authors: Vec<Person>,
+    /// The categories of the entry.
+    pub categories: Vec<String>,
+    /// The media files attached to the entry.
+    pub attachments: Vec<Attachment>,
+}
+
+
+/// An author of a `Feed` or an `Entry`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Person
+{
+    /// The name of the person.
+    pub name: String,
+    /// The email address of the person.
+    pub email: Option<String>,
+    /// The URL of the website of the person.
+    pub uri: Option<String>,
+}
+
+
+/// A media file attached to an `Entry`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Attachment
+{
+    /// The URL of the media file.
+    pub url: String,
+    /// The MIME type of the media file.
+    pub mime_type: Option<String>,
+    /// The size of the media file in bytes.
+    pub length: Option<i64>,
+}
+
+
+/// Something a conversion could not carry across to the target format.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loss
+{
+    /// The path of the field that was lost, such as `entries[2].attachments[1]`.
+    pub field: String,
+    /// Why the field was lost.
+    pub reason: String,
+}
+
+
+/// The Getter functions for `Feed`
+pub trait FeedGetters
+{
+    /// Get the optional id that exists under `Feed`.
+    fn id(&self) -> Option<String>;
+
+    /// Get the title that exists under `Feed`.
+    fn title(&self) -> String;
+
+    /// Get the optional link that exists under `Feed`.
+    fn link(&self) -> Option<String>;
+
+    /// Get the optional description that exists under `Feed`.
+    fn description(&self) -> Option<String>;
+
+    /// Get the optional language that exists under `Feed`.
+    fn language(&self) -> Option<String>;
+
+    /// Get the optional rights that exists under `Feed`.
+    fn rights(&self) -> Option<String>;
+
+    /// Get the optional updated date that exists under `Feed`.
+    fn updated(&self) -> Option<String>;
+
+    /// Get the optional generator that exists under `Feed`.
+    fn generator(&self) -> Option<String>;
+
+    /// Get the optional image url that exists under `Feed`.
+    fn image(&self) -> Option<String>;
+
+    /// Get the authors that exists under `Feed`.
+    fn authors(&self) -> Vec<Person>;
+
+    /// Get the categories that exists under `Feed`.
+    fn categories(&self) -> Vec<String>;
+
+    /// Get the entries that exists under `Feed`.
+    fn entries(&self) -> Vec<Entry>;
+}
+
+
+/// This `FeedBuilder` struct creates the `Feed`.
+#[derive(Clone, Default)]
+pub struct FeedBuilder
+{
+    id: Option<String>,
+    title: String,
+    link: Option<String>,
+    description: Option<String>,
+    language: Option<String>,
+    rights: Option<String>,
+    updated: Option<String>,
+    generator: Option<String>,
+    image: Option<String>,
+    authors: Vec<Person>,
+    categories: Vec<String>,
+    entries: Vec<Entry>,
+}
+
+
+/// The Getter functions for `Entry`
+pub trait EntryGetters
+{
+    /// Get the optional id that exists under `Entry`.
+    fn id(&self) -> Option<String>;
+
+    /// Get the optional title that exists under `Entry`.
+    fn title(&self) -> Option<String>;
+
+    /// Get the optional link that exists under `Entry`.
+    fn link(&self) -> Option<String>;
+
+    /// Get the optional summary that exists under `Entry`.
+    fn summary(&self) -> Option<String>;
+
+    /// Get the optional content that exists under `Entry`.
+    fn content(&self) -> Option<String>;
+
+    /// Get the optional published date that exists under `Entry`.
+    fn published(&self) -> Option<String>;
+
+    /// Get the optional updated date that exists under `Entry`.
+    fn updated(&self) -> Option<String>;
+
+    /// Get the authors that exists under `Entry`.
+    fn authors(&self) -> Vec<Person>;
+
+    /// Get the categories that exists under `Entry`.
+    fn categories(&self) -> Vec<String>;
+
+    /// Get the attachments that exists under `Entry`.
+    fn attachments(&self) -> Vec<Attachment>;
+}
+
+
+/// This `EntryBuilder` struct creates the `Entry`.
+#[derive(Clone, Default)]
+pub struct EntryBuilder
+{
+    id: Option<String>,
+    title: Option<String>,
+    link: Option<String>,
+    summary: Option<String>,
+    content: Option<String>,
+    published: Option<String>,
+    updated: Option<String>,
+    authors: Vec<Person>,
+    categories: Vec<String>,
+    attachments: Vec<Attachment>,
+}
+
+
+/// The Getter functions for `Person`
+pub trait PersonGetters
+{
+    /// Get the name that exists under `Person`.
+    fn name(&self) -> String;
+
+    /// Get the optional email that exists under `Person`.
+    fn email(&self) -> Option<String>;
+
+    /// Get the optional uri that exists under `Person`.
+    fn uri(&self) -> Option<String>;
+}
+
+
+/// This `PersonBuilder` struct creates the `Person`.
+#[derive(Clone, Default)]
+pub struct PersonBuilder
+{
+    name: String,
+    email: Option<String>,
+    uri: Option<String>,
+}
+
+
+/// The Getter functions for `Attachment`
+pub trait AttachmentGetters
+{
+    /// Get the url that exists under `Attachment`.
+    fn url(&self) -> String;
+
+    /// Get the optional mime type that exists under `Attachment`.
+    fn mime_type(&self) -> Option<String>;
+
+    /// Get the optional length in bytes that exists under `Attachment`.
+    fn length(&self) -> Option<i64>;
+}
+
+
+/// This `AttachmentBuilder` struct creates the `Attachment`.
+#[derive(Clone, Default)]
+pub struct AttachmentBuilder
+{
+    url: String,
+    mime_type: Option<String>,
+    length: Option<i64>,
+}
@@ -0,0 +1,135 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for person by using the methods under `PersonBuilder`.
+
+
+use model::{Person, PersonBuilder};
+use utils::string_utils;
+
+
+impl PersonBuilder
+{
+    /// Construct a new `PersonBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let person_builder = PersonBuilder::new();
+    /// ```
+    pub fn new() -> PersonBuilder
+    {
+        PersonBuilder::default()
+    }
+
+
+    /// Set the name that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let mut person_builder = PersonBuilder::new();
+    /// person_builder.name("Chris Fisher");
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut PersonBuilder
+    {
+        self.name = name.to_owned();
+        self
+    }
+
+
+    /// Set the optional email that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let mut person_builder = PersonBuilder::new();
+    /// person_builder.email(Some("chris@jupiterbroadcasting.com".to_owned()));
+    /// ```
+    pub fn email(&mut self, email: Option<String>) -> &mut PersonBuilder
+    {
+        self.email = email;
+        self
+    }
+
+
+    /// Set the optional uri that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let mut person_builder = PersonBuilder::new();
+    /// person_builder.uri(Some("http://www.jupiterbroadcasting.com".to_owned()));
+    /// ```
+    pub fn uri(&mut self, uri: Option<String>) -> &mut PersonBuilder
+    {
+        self.uri = uri;
+        self
+    }
+
+
+    /// Validate the contents of `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .uri(Some("http://www.jupiterbroadcasting.com".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut PersonBuilder, String>
+    {
+        if self.name.is_empty()
+        {
+            return Err("Person Name cannot be empty.".to_owned());
+        }
+
+        if let Some(ref uri) = self.uri
+        {
+            string_utils::str_to_url(uri.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `Person` from the `PersonBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<Person, String>
+    {
+        Ok(Person {
+               name: self.name.clone(),
+               email: self.email.clone(),
+               uri: self.uri.clone(),
+           })
+    }
+}
@@ -0,0 +1,109 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under person can be retrieved by using the methods under `Person`.
+
+
+use model::{Person, PersonGetters};
+
+
+impl PersonGetters for Person
+{
+    /// Get the name that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{PersonBuilder, PersonGetters};
+    ///
+    /// let name = "Chris Fisher";
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name(name)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(name.to_owned(), person.name());
+    /// ```
+    fn name(&self) -> String
+    {
+        self.name.clone()
+    }
+
+
+    /// Get the optional email that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{PersonBuilder, PersonGetters};
+    ///
+    /// let email = Some("chris@jupiterbroadcasting.com".to_owned());
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .email(email.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(email, person.email());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{PersonBuilder, PersonGetters};
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .email(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(person.email().is_none());
+    /// ```
+    fn email(&self) -> Option<String>
+    {
+        self.email.clone()
+    }
+
+
+    /// Get the optional uri that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{PersonBuilder, PersonGetters};
+    ///
+    /// let uri = Some("http://www.jupiterbroadcasting.com".to_owned());
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .uri(uri.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(uri, person.uri());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::{PersonBuilder, PersonGetters};
+    ///
+    /// let person = PersonBuilder::new()
+    ///     .name("Chris Fisher")
+    ///     .uri(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(person.uri().is_none());
+    /// ```
+    fn uri(&self) -> Option<String>
+    {
+        self.uri.clone()
+    }
+}
@@ -0,0 +1,162 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Writing RSS 1.0 (RDF) documents for `Feed`.
+//!
+//! Reading goes through `FromLegacy`, which already understands RSS 1.0.
+
+
+use model::{Entry, Feed, Loss};
+use utils::writer_utils;
+
+
+// Write a `Feed` as an RSS 1.0 document, with Dublin Core for the fields
+// RSS 1.0 has no element of its own for.
+pub fn write_rdf(feed: &Feed) -> Result<(String, Vec<Loss>), String>
+{
+    let link = feed.link
+        .clone()
+        .ok_or_else(|| "Feed must have a link to be written as RSS 1.0.".to_owned())?;
+
+    let mut losses = Vec::new();
+    let dropped = vec![("id", feed.id.is_some()), ("generator", feed.generator.is_some())];
+
+    for (field, present) in dropped
+    {
+        if present
+        {
+            losses.push(Loss::new(field, "RSS 1.0 has no such element"));
+        }
+    }
+
+    let mut links = Vec::new();
+    for (index, entry) in feed.entries.iter().enumerate()
+    {
+        links.push(entry.link
+                       .clone()
+                       .or_else(|| entry.id.clone())
+                       .ok_or_else(|| format!("Entry {} must have a link to be written as RSS 1.0.", index))?);
+    }
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\"\n");
+    xml.push_str("         xmlns:dc=\"http://purl.org/dc/elements/1.1/\"\n");
+    xml.push_str("         xmlns:content=\"http://purl.org/rss/1.0/modules/content/\"\n");
+    xml.push_str("         xmlns=\"http://purl.org/rss/1.0/\">\n");
+
+    xml.push_str(format!("  <channel rdf:about=\"{}\">\n", writer_utils::escape(link.as_str())).as_str());
+    push_element(&mut xml, "title", Some(&feed.title));
+    push_element(&mut xml, "link", Some(&link));
+    push_element(&mut xml, "description", Some(&feed.description.clone().unwrap_or_default()));
+    push_element(&mut xml, "dc:language", feed.language.as_ref());
+    push_element(&mut xml, "dc:rights", feed.rights.as_ref());
+    push_element(&mut xml, "dc:date", feed.updated.as_ref());
+
+    for author in &feed.authors
+    {
+        push_element(&mut xml, "dc:creator", Some(&author.name));
+    }
+
+    for category in &feed.categories
+    {
+        push_element(&mut xml, "dc:subject", Some(category));
+    }
+
+    if let Some(ref image) = feed.image
+    {
+        xml.push_str(format!("    <image rdf:resource=\"{}\"/>\n", writer_utils::escape(image.as_str())).as_str());
+    }
+
+    xml.push_str("    <items>\n      <rdf:Seq>\n");
+    for link in &links
+    {
+        xml.push_str(format!("        <rdf:li rdf:resource=\"{}\"/>\n", writer_utils::escape(link.as_str())).as_str());
+    }
+    xml.push_str("      </rdf:Seq>\n    </items>\n  </channel>\n");
+
+    if let Some(ref image) = feed.image
+    {
+        xml.push_str(format!("  <image rdf:about=\"{}\">\n", writer_utils::escape(image.as_str())).as_str());
+        push_element(&mut xml, "title", Some(&feed.title));
+        push_element(&mut xml, "url", Some(image));
+        push_element(&mut xml, "link", Some(&link));
+        xml.push_str("  </image>\n");
+    }
+
+    for (index, (entry, link)) in feed.entries.iter().zip(links.iter()).enumerate()
+    {
+        push_item(&mut xml, entry, link, index, &mut losses);
+    }
+
+    xml.push_str("</rdf:RDF>\n");
+    Ok((xml, losses))
+}
+
+
+fn push_item(xml: &mut String, entry: &Entry, link: &str, index: usize, losses: &mut Vec<Loss>)
+{
+    let path = format!("entries[{}]", index);
+
+    if entry.id.is_some() && entry.link.is_some() && entry.id != entry.link
+    {
+        losses.push(Loss::new(format!("{}.id", path).as_str(), "RSS 1.0 items are identified by their link"));
+    }
+
+    if entry.published.is_some() && entry.updated.is_some()
+    {
+        losses.push(Loss::new(format!("{}.updated", path).as_str(), "RSS 1.0 items have a single dc:date"));
+    }
+
+    if !entry.attachments.is_empty()
+    {
+        losses.push(Loss::new(format!("{}.attachments", path).as_str(), "RSS 1.0 has no enclosures"));
+    }
+
+    for (person, author) in entry.authors.iter().enumerate()
+    {
+        if author.email.is_some() || author.uri.is_some()
+        {
+            losses.push(Loss::new(format!("{}.authors[{}]", path, person).as_str(),
+                                  "dc:creator only holds the name"));
+        }
+    }
+
+    let link = link.to_owned();
+    xml.push_str(format!("  <item rdf:about=\"{}\">\n", writer_utils::escape(link.as_str())).as_str());
+    push_element(xml, "title", Some(&entry.title.clone().unwrap_or_default()));
+    push_element(xml, "link", Some(&link));
+    push_element(xml, "description", entry.summary.as_ref());
+    push_element(xml, "dc:date", entry.published.as_ref().or(entry.updated.as_ref()));
+
+    for author in &entry.authors
+    {
+        push_element(xml, "dc:creator", Some(&author.name));
+    }
+
+    for category in &entry.categories
+    {
+        push_element(xml, "dc:subject", Some(category));
+    }
+
+    push_element(xml, "content:encoded", entry.content.as_ref());
+    xml.push_str("  </item>\n");
+}
+
+
+fn push_element(xml: &mut String, name: &str, value: Option<&String>)
+{
+    if let Some(val) = value
+    {
+        xml.push_str("    ");
+        xml.push_str(writer_utils::text_element(name, val.as_str()).as_str());
+        xml.push('\n');
+    }
+}
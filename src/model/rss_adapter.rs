@@ -0,0 +1,518 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Conversion between `Feed` and the RSS 2.0 `Channel`.
+
+
+use model::{Attachment, Entry, Feed, Loss, Person};
+use rss::{Category, Channel, Enclosure, Guid, Image, Item};
+use rss::extension::dublincore::DublinCoreExtension;
+use utils::string_utils;
+use utils::writer_utils;
+
+
+// Convert a `Channel` to a `Feed`, reporting every field that has no place in
+// the model.
+pub fn channel_to_feed(channel: &Channel) -> (Feed, Vec<Loss>)
+{
+    let mut losses = Vec::new();
+    let dc = channel.dublin_core_ext.clone().unwrap_or_default();
+
+    if channel.last_build_date.is_some() && channel.pub_date.is_some()
+    {
+        losses.push(Loss::new("pubDate", "only lastBuildDate is kept as the updated date"));
+    }
+
+    let updated = channel.last_build_date.clone().or_else(|| channel.pub_date.clone());
+    let mut authors: Vec<Person> = channel.managing_editor.iter().map(|val| parse_person(val)).collect();
+    authors.extend(dc.creator.iter().map(|val| Person { name: val.clone(), ..Default::default() }));
+
+    let mut categories: Vec<String> = channel.categories.iter().map(|cat| cat.name.clone()).collect();
+    categories.extend(dc.subject.iter().cloned());
+
+    let entries = channel
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| item_to_entry(item, index, &mut losses))
+        .collect();
+
+    let updated = rss_date("updated", updated, &mut losses);
+    dublin_core_losses("",
+                       &dc,
+                       kept(&channel.language),
+                       kept(&channel.copyright),
+                       kept(&updated),
+                       &mut losses);
+
+    if let Some(ref image) = channel.image
+    {
+        let dropped = vec![("image.title", image.title != channel.title),
+                           ("image.link", image.link != channel.link),
+                           ("image.width", image.width.is_some()),
+                           ("image.height", image.height.is_some()),
+                           ("image.description", image.description.is_some())];
+
+        for (field, present) in dropped
+        {
+            if present
+            {
+                losses.push(Loss::new(field, "only the image url is part of the feed model"));
+            }
+        }
+    }
+
+    category_losses("", &channel.categories, &mut losses);
+
+    let feed = Feed {
+        id: None,
+        title: channel.title.clone(),
+        link: non_empty(&channel.link),
+        description: non_empty(&channel.description),
+        language: channel.language.clone().or_else(|| dc.language.first().cloned()),
+        rights: channel.copyright.clone().or_else(|| dc.rights.first().cloned()),
+        updated: updated.or_else(|| dc.date.first().cloned()),
+        generator: channel.generator.clone(),
+        image: channel.image.as_ref().map(|image| image.url.clone()),
+        authors,
+        categories,
+        entries,
+    };
+
+    let dropped = vec![("webMaster", channel.webmaster.is_some()),
+                       ("docs", channel.docs.is_some()),
+                       ("cloud", channel.cloud.is_some()),
+                       ("ttl", channel.ttl.is_some()),
+                       ("textInput", channel.text_input.is_some()),
+                       ("skipHours", !channel.skip_hours.is_empty()),
+                       ("skipDays", !channel.skip_days.is_empty()),
+                       ("itunes", channel.itunes_ext.is_some())];
+
+    for (field, present) in dropped
+    {
+        if present
+        {
+            losses.push(Loss::new(field, "not part of the feed model"));
+        }
+    }
+
+    extension_losses("", &channel.extensions.keys().cloned().collect::<Vec<_>>(), &mut losses);
+
+    (feed, losses)
+}
+
+
+// Convert a `Feed` to a `Channel`, reporting every field RSS 2.0 cannot hold.
+pub fn feed_to_channel(feed: &Feed) -> (Channel, Vec<Loss>)
+{
+    let mut losses = Vec::new();
+
+    if feed.id.is_some()
+    {
+        losses.push(Loss::new("id", "RSS 2.0 channels have no identifier"));
+    }
+
+    if feed.authors.len() > 1
+    {
+        losses.push(Loss::new("authors[1..]", "RSS 2.0 channels have a single managingEditor"));
+    }
+
+    uri_loss("", &feed.authors, &mut losses);
+
+    let channel = Channel {
+        title: feed.title.clone(),
+        link: feed.link.clone().unwrap_or_default(),
+        description: feed.description.clone().unwrap_or_default(),
+        language: feed.language.clone(),
+        copyright: feed.rights.clone(),
+        managing_editor: feed.authors.first().map(format_person),
+        last_build_date: model_date("updated", feed.updated.clone(), &mut losses),
+        categories: feed.categories
+            .iter()
+            .map(|cat| Category { name: cat.clone(), domain: None })
+            .collect(),
+        generator: feed.generator.clone(),
+        image: feed.image.as_ref().map(|url| {
+                                           Image {
+                                               url: url.clone(),
+                                               title: feed.title.clone(),
+                                               link: feed.link.clone().unwrap_or_default(),
+                                               ..Default::default()
+                                           }
+                                       }),
+        items: feed.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| entry_to_item(entry, index, &mut losses))
+            .collect(),
+        ..Default::default()
+    };
+
+    (channel, losses)
+}
+
+
+// Write a `Channel` as an RSS 2.0 document with its text escaped.
+pub fn write_channel(channel: &Channel) -> String
+{
+    let mut channel = channel.clone();
+    escape_all(&mut channel.title);
+    escape_all(&mut channel.link);
+    escape_all(&mut channel.description);
+
+    for val in [&mut channel.language,
+                &mut channel.copyright,
+                &mut channel.managing_editor,
+                &mut channel.generator]
+    {
+        escape_option(val);
+    }
+
+    for cat in &mut channel.categories
+    {
+        escape_all(&mut cat.name);
+        escape_option(&mut cat.domain);
+    }
+
+    if let Some(ref mut image) = channel.image
+    {
+        escape_all(&mut image.url);
+        escape_all(&mut image.title);
+        escape_all(&mut image.link);
+    }
+
+    for item in &mut channel.items
+    {
+        for val in [&mut item.title, &mut item.link, &mut item.description, &mut item.author]
+        {
+            escape_option(val);
+        }
+
+        for cat in &mut item.categories
+        {
+            escape_all(&mut cat.name);
+            escape_option(&mut cat.domain);
+        }
+
+        // The `rss` crate does not escape attribute values either.
+        if let Some(ref mut enclosure) = item.enclosure
+        {
+            escape_all(&mut enclosure.url);
+            escape_all(&mut enclosure.length);
+            escape_all(&mut enclosure.mime_type);
+        }
+
+        if let Some(ref mut guid) = item.guid
+        {
+            escape_all(&mut guid.value);
+        }
+    }
+
+    channel.to_string()
+}
+
+
+// Common code to build the `Person` from an RSS `author` or `managingEditor`,
+// written as `email (name)`, `name <email>` or either on its own.
+pub fn parse_person(s: &str) -> Person
+{
+    let s = s.trim();
+
+    if let (Some(open), true) = (s.find('('), s.ends_with(')'))
+    {
+        return Person {
+                   name: s[open + 1..s.len() - 1].trim().to_owned(),
+                   email: Some(s[..open].trim().to_owned()),
+                   uri: None,
+               };
+    }
+
+    if let (Some(open), true) = (s.find('<'), s.ends_with('>'))
+    {
+        return Person {
+                   name: s[..open].trim().to_owned(),
+                   email: Some(s[open + 1..s.len() - 1].trim().to_owned()),
+                   uri: None,
+               };
+    }
+
+    if s.contains('@')
+    {
+        return Person { name: s.to_owned(), email: Some(s.to_owned()), uri: None };
+    }
+
+    Person { name: s.to_owned(), email: None, uri: None }
+}
+
+
+// Common code to write the `Person` as an RSS `author`.
+pub fn format_person(person: &Person) -> String
+{
+    match person.email
+    {
+        Some(ref email) if *email == person.name => email.clone(),
+        Some(ref email) => format!("{} ({})", email, person.name),
+        None => person.name.clone(),
+    }
+}
+
+
+fn item_to_entry(item: &Item, index: usize, losses: &mut Vec<Loss>) -> Entry
+{
+    let path = format!("entries[{}]", index);
+    let dc = item.dublin_core_ext.clone().unwrap_or_default();
+
+    let mut authors: Vec<Person> = item.author.iter().map(|val| parse_person(val)).collect();
+    authors.extend(dc.creator.iter().map(|val| Person { name: val.clone(), ..Default::default() }));
+
+    let mut categories: Vec<String> = item.categories.iter().map(|cat| cat.name.clone()).collect();
+    categories.extend(dc.subject.iter().cloned());
+
+    let attachments = item.enclosure
+        .iter()
+        .map(|enclosure| {
+                 Attachment {
+                     url: enclosure.url.clone(),
+                     mime_type: non_empty(&enclosure.mime_type),
+                     length: string_utils::string_to_i64(enclosure.length.trim()).ok(),
+                 }
+             })
+        .collect();
+
+    let published = rss_date(format!("{}.pubDate", path).as_str(), item.pub_date.clone(), losses);
+    dublin_core_losses(path.as_str(), &dc, 0, 0, kept(&published), losses);
+    category_losses(path.as_str(), &item.categories, losses);
+
+    // The permalink flag is not kept, and is written back as whether the
+    // guid is the link.
+    let guid_lost = item.guid
+        .as_ref()
+        .is_some_and(|guid| !guid.is_permalink || item.link.as_ref() != Some(&guid.value));
+    let length_lost = item.enclosure
+        .as_ref()
+        .is_some_and(|enclosure| string_utils::string_to_i64(enclosure.length.trim()).is_err());
+
+    let dropped = vec![("comments", item.comments.is_some()),
+                       ("source", item.source.is_some()),
+                       ("itunes", item.itunes_ext.is_some()),
+                       ("guid.isPermaLink", guid_lost),
+                       ("enclosure.length", length_lost)];
+
+    for (field, present) in dropped
+    {
+        if present
+        {
+            losses.push(Loss::new(format!("{}.{}", path, field).as_str(), "not part of the feed model"));
+        }
+    }
+
+    extension_losses(path.as_str(), &item.extensions.keys().cloned().collect::<Vec<_>>(), losses);
+
+    Entry {
+        id: item.guid.as_ref().map(|guid| guid.value.clone()),
+        title: item.title.clone(),
+        link: item.link.clone(),
+        summary: item.description.clone(),
+        content: item.content.clone(),
+        published: published.or_else(|| dc.date.first().cloned()),
+        updated: None,
+        authors,
+        categories,
+        attachments,
+    }
+}
+
+
+fn entry_to_item(entry: &Entry, index: usize, losses: &mut Vec<Loss>) -> Item
+{
+    let path = format!("entries[{}]", index);
+
+    if entry.published.is_some() && entry.updated.is_some()
+    {
+        losses.push(Loss::new(format!("{}.updated", path).as_str(), "RSS 2.0 items only have a pubDate"));
+    }
+
+    if entry.authors.len() > 1
+    {
+        losses.push(Loss::new(format!("{}.authors[1..]", path).as_str(), "RSS 2.0 items have a single author"));
+    }
+
+    uri_loss(path.as_str(), &entry.authors, losses);
+
+    if entry.attachments.len() > 1
+    {
+        losses.push(Loss::new(format!("{}.attachments[1..]", path).as_str(),
+                              "RSS 2.0 items have a single enclosure"));
+    }
+
+    let date = entry.published.clone().or_else(|| entry.updated.clone());
+
+    Item {
+        title: entry.title.clone(),
+        link: entry.link.clone(),
+        description: entry.summary.clone(),
+        author: entry.authors.first().map(format_person),
+        categories: entry.categories
+            .iter()
+            .map(|cat| Category { name: cat.clone(), domain: None })
+            .collect(),
+        enclosure: entry.attachments.first().map(|attachment| {
+            Enclosure {
+                url: attachment.url.clone(),
+                length: attachment.length.unwrap_or(0).to_string(),
+                mime_type: attachment.mime_type.clone().unwrap_or_else(|| "application/octet-stream".to_owned()),
+            }
+        }),
+        guid: entry.id.as_ref().map(|id| Guid { value: id.clone(), is_permalink: entry.link.as_ref() == Some(id) }),
+        pub_date: model_date(format!("{}.published", path).as_str(), date, losses),
+        content: entry.content.clone(),
+        ..Default::default()
+    }
+}
+
+
+// Report the Dublin Core values with no place in the model. All creators and
+// subjects are kept, and the first `languages`, `rights` and `dates` of those
+// values.
+fn dublin_core_losses(path: &str,
+                      dc: &DublinCoreExtension,
+                      languages: usize,
+                      rights: usize,
+                      dates: usize,
+                      losses: &mut Vec<Loss>)
+{
+    let fields = vec![("contributor", dc.contributor.len()),
+                      ("coverage", dc.coverage.len()),
+                      ("date", dc.date.len().saturating_sub(dates)),
+                      ("description", dc.description.len()),
+                      ("format", dc.format.len()),
+                      ("identifier", dc.identifier.len()),
+                      ("language", dc.language.len().saturating_sub(languages)),
+                      ("publisher", dc.publisher.len()),
+                      ("relation", dc.relation.len()),
+                      ("rights", dc.rights.len().saturating_sub(rights)),
+                      ("source", dc.source.len()),
+                      ("title", dc.title.len()),
+                      ("type", dc.resource_type.len())];
+
+    for (name, lost) in fields
+    {
+        if lost > 0
+        {
+            losses.push(Loss::new(field(path, format!("dc:{}", name).as_str()).as_str(),
+                                  "not part of the feed model"));
+        }
+    }
+}
+
+
+fn category_losses(path: &str, categories: &[Category], losses: &mut Vec<Loss>)
+{
+    for (index, cat) in categories.iter().enumerate()
+    {
+        if cat.domain.is_some()
+        {
+            losses.push(Loss::new(field(path, format!("categories[{}].domain", index).as_str()).as_str(),
+                                  "categories of the feed model have no domain"));
+        }
+    }
+}
+
+
+// Report the URI of the one author RSS 2.0 keeps, which it has no place for.
+fn uri_loss(path: &str, authors: &[Person], losses: &mut Vec<Loss>)
+{
+    if authors.first().is_some_and(|person| person.uri.is_some())
+    {
+        losses.push(Loss::new(field(path, "authors[0].uri").as_str(), "RSS 2.0 people have no URI"));
+    }
+}
+
+
+fn extension_losses(path: &str, prefixes: &[String], losses: &mut Vec<Loss>)
+{
+    let mut prefixes = prefixes.to_vec();
+    prefixes.sort();
+
+    for prefix in prefixes
+    {
+        let field = match (path.is_empty(), prefix.is_empty())
+        {
+            (true, true) => "extensions".to_owned(),
+            (true, false) => format!("{}:*", prefix),
+            (false, true) => format!("{}.extensions", path),
+            (false, false) => format!("{}.{}:*", path, prefix),
+        };
+        losses.push(Loss::new(field.as_str(), "extension elements are not part of the feed model"));
+    }
+}
+
+
+fn rss_date(field: &str, date: Option<String>, losses: &mut Vec<Loss>) -> Option<String>
+{
+    date.and_then(|val| match string_utils::rfc2822_to_rfc3339(val.as_str())
+    {
+        Ok(val) => Some(val),
+        Err(err) =>
+        {
+            losses.push(Loss::new(field, format!("{} is not an RFC 2822 date: {}", val, err).as_str()));
+            None
+        }
+    })
+}
+
+
+fn model_date(field: &str, date: Option<String>, losses: &mut Vec<Loss>) -> Option<String>
+{
+    date.and_then(|val| match string_utils::rfc3339_to_rfc2822(val.as_str())
+    {
+        Ok(val) => Some(val),
+        Err(err) =>
+        {
+            losses.push(Loss::new(field, format!("{} is not an RFC 3339 date: {}", val, err).as_str()));
+            None
+        }
+    })
+}
+
+
+// How many values of a Dublin Core field are kept when the RSS field is not
+// there to take their place.
+fn kept(rss_field: &Option<String>) -> usize
+{
+    if rss_field.is_none() { 1 } else { 0 }
+}
+
+
+fn field(path: &str, name: &str) -> String
+{
+    if path.is_empty() { name.to_owned() } else { format!("{}.{}", path, name) }
+}
+
+
+fn non_empty(s: &str) -> Option<String>
+{
+    if s.trim().is_empty() { None } else { Some(s.to_owned()) }
+}
+
+
+fn escape_all(s: &mut String)
+{
+    *s = writer_utils::escape(s.as_str());
+}
+
+
+fn escape_option(s: &mut Option<String>)
+{
+    if let Some(ref mut val) = *s
+    {
+        escape_all(val);
+    }
+}
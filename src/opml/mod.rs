@@ -0,0 +1,143 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! All the structs for OPML subscription lists.
+
+
+pub mod opml_getters;
+pub mod opml_builder;
+pub mod opml_read_write;
+pub mod outline_getters;
+pub mod outline_builder;
+
+
+/// A representation of the `<opml>` element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Opml
+{
+    /// The title of the document.
+    pub title: Option<String>,
+    /// The date the document was created.
+    pub date_created: Option<String>,
+    /// The date the document was last modified.
+    pub date_modified: Option<String>,
+    /// The name of the owner of the document.
+    pub owner_name: Option<String>,
+    /// The email address of the owner of the document.
+    pub owner_email: Option<String>,
+    /// The top level outlines in the body of the document.
+    pub outlines: Vec<Outline>,
+}
+
+
+/// A representation of the `<outline>` element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Outline
+{
+    /// The text that is displayed for the outline.
+    pub text: String,
+    /// The title of the feed.
+    pub title: Option<String>,
+    /// The type of the outline, `rss` for a subscription.
+    pub outline_type: Option<String>,
+    /// The URL of the feed.
+    pub xml_url: Option<String>,
+    /// The URL of the website of the feed.
+    pub html_url: Option<String>,
+    /// The description of the feed.
+    pub description: Option<String>,
+    /// The slash delimited categories of the outline.
+    pub categories: Vec<String>,
+    /// The outlines nested under the outline.
+    pub outlines: Vec<Outline>,
+}
+
+
+/// The Getter functions for `Opml`
+pub trait OpmlGetters
+{
+    /// Get the optional title that exists under `Opml`.
+    fn title(&self) -> Option<String>;
+
+    /// Get the optional date created that exists under `Opml`.
+    fn date_created(&self) -> Option<String>;
+
+    /// Get the optional date modified that exists under `Opml`.
+    fn date_modified(&self) -> Option<String>;
+
+    /// Get the optional owner name that exists under `Opml`.
+    fn owner_name(&self) -> Option<String>;
+
+    /// Get the optional owner email that exists under `Opml`.
+    fn owner_email(&self) -> Option<String>;
+
+    /// Get the outlines that exists under `Opml`.
+    fn outlines(&self) -> Vec<Outline>;
+
+    /// Get every subscription in the outline tree under `Opml`.
+    fn subscriptions(&self) -> Vec<Outline>;
+}
+
+
+/// This `OpmlBuilder` struct creates the `Opml`.
+#[derive(Clone, Default)]
+pub struct OpmlBuilder
+{
+    title: Option<String>,
+    date_created: Option<String>,
+    date_modified: Option<String>,
+    owner_name: Option<String>,
+    owner_email: Option<String>,
+    outlines: Vec<Outline>,
+}
+
+
+/// The Getter functions for `Outline`
+pub trait OutlineGetters
+{
+    /// Get the text that exists under `Outline`.
+    fn text(&self) -> String;
+
+    /// Get the optional title that exists under `Outline`.
+    fn title(&self) -> Option<String>;
+
+    /// Get the optional outline type that exists under `Outline`.
+    fn outline_type(&self) -> Option<String>;
+
+    /// Get the optional xml url that exists under `Outline`.
+    fn xml_url(&self) -> Option<String>;
+
+    /// Get the optional html url that exists under `Outline`.
+    fn html_url(&self) -> Option<String>;
+
+    /// Get the optional description that exists under `Outline`.
+    fn description(&self) -> Option<String>;
+
+    /// Get the categories that exists under `Outline`.
+    fn categories(&self) -> Vec<String>;
+
+    /// Get the outlines that exists under `Outline`.
+    fn outlines(&self) -> Vec<Outline>;
+}
+
+
+/// This `OutlineBuilder` struct creates the `Outline`.
+#[derive(Clone, Default)]
+pub struct OutlineBuilder
+{
+    text: String,
+    title: Option<String>,
+    outline_type: Option<String>,
+    xml_url: Option<String>,
+    html_url: Option<String>,
+    description: Option<String>,
+    categories: Vec<String>,
+    outlines: Vec<Outline>,
+}
@@ -0,0 +1,188 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for opml by using the methods under `OpmlBuilder`.
+
+
+use opml::{Opml, OpmlBuilder, Outline};
+use utils::string_utils;
+
+
+impl OpmlBuilder
+{
+    /// Construct a new `OpmlBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let opml_builder = OpmlBuilder::new();
+    /// ```
+    pub fn new() -> OpmlBuilder
+    {
+        OpmlBuilder::default()
+    }
+
+
+    /// Set the optional title that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let mut opml_builder = OpmlBuilder::new();
+    /// opml_builder.title(Some("My Subscriptions".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut OpmlBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional date created that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let mut opml_builder = OpmlBuilder::new();
+    /// opml_builder.date_created(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()));
+    /// ```
+    pub fn date_created(&mut self, date_created: Option<String>) -> &mut OpmlBuilder
+    {
+        self.date_created = date_created;
+        self
+    }
+
+
+    /// Set the optional date modified that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let mut opml_builder = OpmlBuilder::new();
+    /// opml_builder.date_modified(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()));
+    /// ```
+    pub fn date_modified(&mut self, date_modified: Option<String>) -> &mut OpmlBuilder
+    {
+        self.date_modified = date_modified;
+        self
+    }
+
+
+    /// Set the optional owner name that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let mut opml_builder = OpmlBuilder::new();
+    /// opml_builder.owner_name(Some("Chris Fisher".to_owned()));
+    /// ```
+    pub fn owner_name(&mut self, owner_name: Option<String>) -> &mut OpmlBuilder
+    {
+        self.owner_name = owner_name;
+        self
+    }
+
+
+    /// Set the optional owner email that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let mut opml_builder = OpmlBuilder::new();
+    /// opml_builder.owner_email(Some("chris@linuxactionshow.com".to_owned()));
+    /// ```
+    pub fn owner_email(&mut self, owner_email: Option<String>) -> &mut OpmlBuilder
+    {
+        self.owner_email = owner_email;
+        self
+    }
+
+
+    /// Set the outlines that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OutlineBuilder};
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut opml_builder = OpmlBuilder::new();
+    /// opml_builder.outlines(vec![outline]);
+    /// ```
+    pub fn outlines(&mut self, outlines: Vec<Outline>) -> &mut OpmlBuilder
+    {
+        self.outlines = outlines;
+        self
+    }
+
+
+    /// Validate the contents of `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .title(Some("My Subscriptions".to_owned()))
+    ///     .date_created(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut OpmlBuilder, String>
+    {
+        string_utils::option_string_to_option_date(self.date_created.clone())?;
+        string_utils::option_string_to_option_date(self.date_modified.clone())?;
+
+        Ok(self)
+    }
+
+
+    /// Construct the `Opml` from the `OpmlBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OpmlBuilder;
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .title(Some("My Subscriptions".to_owned()))
+    ///     .outlines(Vec::new())
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<Opml, String>
+    {
+        Ok(Opml {
+               title: self.title.clone(),
+               date_created: self.date_created.clone(),
+               date_modified: self.date_modified.clone(),
+               owner_name: self.owner_name.clone(),
+               owner_email: self.owner_email.clone(),
+               outlines: self.outlines.clone(),
+           })
+    }
+}
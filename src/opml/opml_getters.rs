@@ -0,0 +1,201 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under opml can be retrieved by using the methods under `Opml`.
+
+
+use opml::{Opml, OpmlGetters, Outline};
+
+
+impl OpmlGetters for Opml
+{
+    /// Get the optional title that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OpmlGetters};
+    ///
+    /// let title = "My Subscriptions".to_owned();
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .title(Some(title.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(title), opml.title());
+    /// ```
+    fn title(&self) -> Option<String>
+    {
+        self.title.clone()
+    }
+
+
+    /// Get the optional date created that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OpmlGetters};
+    ///
+    /// let date = "Sun, 13 Mar 2016 20:02:02 -0700".to_owned();
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .date_created(Some(date.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(date), opml.date_created());
+    /// ```
+    fn date_created(&self) -> Option<String>
+    {
+        self.date_created.clone()
+    }
+
+
+    /// Get the optional date modified that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OpmlGetters};
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .date_modified(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(opml.date_modified().is_none());
+    /// ```
+    fn date_modified(&self) -> Option<String>
+    {
+        self.date_modified.clone()
+    }
+
+
+    /// Get the optional owner name that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OpmlGetters};
+    ///
+    /// let name = "Chris Fisher".to_owned();
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .owner_name(Some(name.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(name), opml.owner_name());
+    /// ```
+    fn owner_name(&self) -> Option<String>
+    {
+        self.owner_name.clone()
+    }
+
+
+    /// Get the optional owner email that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OpmlGetters};
+    ///
+    /// let email = "chris@linuxactionshow.com".to_owned();
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .owner_email(Some(email.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(email), opml.owner_email());
+    /// ```
+    fn owner_email(&self) -> Option<String>
+    {
+        self.owner_email.clone()
+    }
+
+
+    /// Get the outlines that exists under `Opml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OpmlGetters, OutlineBuilder};
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("Podcasts")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .outlines(vec![outline])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, opml.outlines().len());
+    /// ```
+    fn outlines(&self) -> Vec<Outline>
+    {
+        self.outlines.clone()
+    }
+
+
+    /// Get every subscription in the outline tree under `Opml`.
+    ///
+    /// Subscriptions are the outlines with an xml url, in document order,
+    /// wherever they are nested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OpmlBuilder, OpmlGetters, OutlineBuilder};
+    ///
+    /// let subscription = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .outline_type(Some("rss".to_owned()))
+    ///     .xml_url(Some("http://feeds.feedburner.com/TheLinuxActionShowOGG".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let folder = OutlineBuilder::new()
+    ///     .text("Podcasts")
+    ///     .outlines(vec![subscription])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .outlines(vec![folder])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, opml.subscriptions().len());
+    /// ```
+    fn subscriptions(&self) -> Vec<Outline>
+    {
+        let mut subscriptions = Vec::new();
+        collect_subscriptions(&self.outlines, &mut subscriptions);
+        subscriptions
+    }
+}
+
+
+fn collect_subscriptions(outlines: &[Outline], subscriptions: &mut Vec<Outline>)
+{
+    for outline in outlines
+    {
+        if outline.xml_url.is_some()
+        {
+            subscriptions.push(outline.clone());
+        }
+        collect_subscriptions(&outline.outlines, subscriptions);
+    }
+}
@@ -0,0 +1,204 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! Reading and writing OPML 2.0 documents for `Opml`.
+
+
+use opml::{Opml, Outline};
+use std::fmt;
+use std::str::FromStr;
+use utils::reader_utils::{self, XmlElement};
+use utils::writer_utils;
+
+
+impl FromStr for Opml
+{
+    type Err = String;
+
+
+    /// Construct an `Opml` from an OPML document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{Opml, OpmlGetters, OutlineGetters};
+    /// use std::str::FromStr;
+    ///
+    /// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <opml version="2.0">
+    ///   <head><title>My Subscriptions</title></head>
+    ///   <body>
+    ///     <outline text="Podcasts">
+    ///       <outline text="The Linux Action Show! OGG" type="rss"
+    ///                xmlUrl="http://feeds.feedburner.com/TheLinuxActionShowOGG"
+    ///                htmlUrl="http://www.jupiterbroadcasting.com"
+    ///                category="/Technology,/Technology/Tech News"/>
+    ///     </outline>
+    ///   </body>
+    /// </opml>"#;
+    ///
+    /// let opml = Opml::from_str(xml).unwrap();
+    /// assert_eq!(Some("My Subscriptions".to_owned()), opml.title());
+    ///
+    /// let subscriptions = opml.subscriptions();
+    /// assert_eq!(1, subscriptions.len());
+    /// assert_eq!(2, subscriptions[0].categories().len());
+    /// ```
+    fn from_str(s: &str) -> Result<Opml, String>
+    {
+        let root = reader_utils::read_document(s)?;
+        if root.name != "opml"
+        {
+            return Err(format!("Invalid value: {}", root.name));
+        }
+
+        let mut opml = Opml::default();
+
+        if let Some(head) = root.child("head")
+        {
+            opml.title = head.child_text("title");
+            opml.date_created = head.child_text("dateCreated");
+            opml.date_modified = head.child_text("dateModified");
+            opml.owner_name = head.child_text("ownerName");
+            opml.owner_email = head.child_text("ownerEmail");
+        }
+
+        if let Some(body) = root.child("body")
+        {
+            opml.outlines = read_outlines(body);
+        }
+
+        Ok(opml)
+    }
+}
+
+
+impl fmt::Display for Opml
+{
+    /// Write `Opml` as an OPML 2.0 document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{Opml, OpmlBuilder, OutlineBuilder};
+    /// use std::str::FromStr;
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .outline_type(Some("rss".to_owned()))
+    ///     .xml_url(Some("http://feeds.feedburner.com/TheLinuxActionShowOGG".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let opml = OpmlBuilder::new()
+    ///     .title(Some("My Subscriptions".to_owned()))
+    ///     .outlines(vec![outline])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let xml = opml.to_string();
+    /// assert_eq!(opml, Opml::from_str(xml.as_str()).unwrap());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        writeln!(f, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(f, "<opml version=\"2.0\">")?;
+        writeln!(f, "  <head>")?;
+
+        let head = vec![("title", &self.title),
+                        ("dateCreated", &self.date_created),
+                        ("dateModified", &self.date_modified),
+                        ("ownerName", &self.owner_name),
+                        ("ownerEmail", &self.owner_email)];
+
+        for (name, value) in head
+        {
+            if let Some(ref val) = *value
+            {
+                writeln!(f, "    {}", writer_utils::text_element(name, val.as_str()))?;
+            }
+        }
+
+        writeln!(f, "  </head>")?;
+        writeln!(f, "  <body>")?;
+        for outline in &self.outlines
+        {
+            write_outline(f, outline, 2)?;
+        }
+        writeln!(f, "  </body>")?;
+        writeln!(f, "</opml>")
+    }
+}
+
+
+fn read_outlines(element: &XmlElement) -> Vec<Outline>
+{
+    element
+        .children
+        .iter()
+        .filter(|child| child.name == "outline")
+        .map(|child| {
+            let title = child.attr("title");
+            Outline {
+                text: child.attr("text").or_else(|| title.clone()).unwrap_or_default(),
+                title,
+                outline_type: child.attr("type"),
+                xml_url: child.attr("xmlUrl"),
+                html_url: child.attr("htmlUrl"),
+                description: child.attr("description"),
+                categories: child
+                    .attr("category")
+                    .map(|val| {
+                             val.split(',')
+                                 .map(|cat| cat.trim().to_owned())
+                                 .filter(|cat| !cat.is_empty())
+                                 .collect()
+                         })
+                    .unwrap_or_default(),
+                outlines: read_outlines(child),
+            }
+        })
+        .collect()
+}
+
+
+fn write_outline(f: &mut fmt::Formatter, outline: &Outline, depth: usize) -> fmt::Result
+{
+    let indent = "  ".repeat(depth);
+    write!(f, "{}<outline text=\"{}\"", indent, writer_utils::escape(outline.text.as_str()))?;
+
+    let attrs = vec![("title", outline.title.clone()),
+                     ("type", outline.outline_type.clone()),
+                     ("xmlUrl", outline.xml_url.clone()),
+                     ("htmlUrl", outline.html_url.clone()),
+                     ("description", outline.description.clone()),
+                     ("category",
+                      if outline.categories.is_empty() { None } else { Some(outline.categories.join(",")) })];
+
+    for (name, value) in attrs
+    {
+        if let Some(val) = value
+        {
+            write!(f, " {}=\"{}\"", name, writer_utils::escape(val.as_str()))?;
+        }
+    }
+
+    if outline.outlines.is_empty()
+    {
+        return writeln!(f, "/>");
+    }
+
+    writeln!(f, ">")?;
+    for child in &outline.outlines
+    {
+        write_outline(f, child, depth + 1)?;
+    }
+    writeln!(f, "{}</outline>", indent)
+}
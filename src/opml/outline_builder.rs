@@ -0,0 +1,298 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields can be set for outline by using the methods under
+//! `OutlineBuilder`.
+
+
+use ChannelGetters;
+use opml::{Outline, OutlineBuilder};
+use rss::Channel;
+use utils::string_utils;
+
+
+impl OutlineBuilder
+{
+    /// Construct a new `OutlineBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let outline_builder = OutlineBuilder::new();
+    /// ```
+    pub fn new() -> OutlineBuilder
+    {
+        OutlineBuilder::default()
+    }
+
+
+    /// Construct an `OutlineBuilder` for a subscription to a fetched
+    /// `Channel`, using its title, link and description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::ChannelBuilder;
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("The Linux Action Show! OGG")
+    ///     .link("http://www.jupiterbroadcasting.com")
+    ///     .description("Ogg Vorbis audio versions of The Linux Action Show!")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let outline = OutlineBuilder::from_channel(&channel,
+    ///                                            "http://feeds.feedburner.com/TheLinuxActionShowOGG")
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    ///
+    /// assert_eq!("The Linux Action Show! OGG", outline.text());
+    /// assert_eq!(Some("rss".to_owned()), outline.outline_type());
+    /// assert_eq!(Some("http://www.jupiterbroadcasting.com".to_owned()), outline.html_url());
+    /// ```
+    pub fn from_channel(channel: &Channel, xml_url: &str) -> OutlineBuilder
+    {
+        let description = channel.description();
+
+        OutlineBuilder {
+            text: channel.title(),
+            title: Some(channel.title()),
+            outline_type: Some("rss".to_owned()),
+            xml_url: Some(xml_url.to_owned()),
+            html_url: Some(channel.link()),
+            description: if description.is_empty() { None } else { Some(description) },
+            ..Default::default()
+        }
+    }
+
+
+    /// Set the text that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.text("The Linux Action Show! OGG");
+    /// ```
+    pub fn text(&mut self, text: &str) -> &mut OutlineBuilder
+    {
+        self.text = text.to_owned();
+        self
+    }
+
+
+    /// Set the optional title that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.title(Some("The Linux Action Show! OGG".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut OutlineBuilder
+    {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional outline type that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.outline_type(Some("rss".to_owned()));
+    /// ```
+    pub fn outline_type(&mut self, outline_type: Option<String>) -> &mut OutlineBuilder
+    {
+        self.outline_type = outline_type;
+        self
+    }
+
+
+    /// Set the optional xml url that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.xml_url(Some("http://feeds.feedburner.com/TheLinuxActionShowOGG".to_owned()));
+    /// ```
+    pub fn xml_url(&mut self, xml_url: Option<String>) -> &mut OutlineBuilder
+    {
+        self.xml_url = xml_url;
+        self
+    }
+
+
+    /// Set the optional html url that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.html_url(Some("http://www.jupiterbroadcasting.com".to_owned()));
+    /// ```
+    pub fn html_url(&mut self, html_url: Option<String>) -> &mut OutlineBuilder
+    {
+        self.html_url = html_url;
+        self
+    }
+
+
+    /// Set the optional description that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.description(Some("A show about Linux".to_owned()));
+    /// ```
+    pub fn description(&mut self, description: Option<String>) -> &mut OutlineBuilder
+    {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the categories that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.categories(vec!["/Technology/Tech News".to_owned()]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<String>) -> &mut OutlineBuilder
+    {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the outlines that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let child = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder.outlines(vec![child]);
+    /// ```
+    pub fn outlines(&mut self, outlines: Vec<Outline>) -> &mut OutlineBuilder
+    {
+        self.outlines = outlines;
+        self
+    }
+
+
+    /// Validate the contents of `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .outline_type(Some("rss".to_owned()))
+    ///     .xml_url(Some("http://feeds.feedburner.com/TheLinuxActionShowOGG".to_owned()))
+    ///     .validate().unwrap()
+    ///     .finalize().unwrap();
+    /// ```
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let mut outline_builder = OutlineBuilder::new();
+    /// outline_builder
+    ///     .text("The Linux Action Show! OGG")
+    ///     .outline_type(Some("rss".to_owned()));
+    ///
+    /// assert!(outline_builder.validate().is_err());
+    /// ```
+    pub fn validate(&mut self) -> Result<&mut OutlineBuilder, String>
+    {
+        if self.text.is_empty()
+        {
+            return Err("Outline Text cannot be empty.".to_owned());
+        }
+
+        if self.outline_type.as_deref() == Some("rss") && self.xml_url.is_none()
+        {
+            return Err("Outline of type rss must have an xml url.".to_owned());
+        }
+
+        if let Some(ref xml_url) = self.xml_url
+        {
+            string_utils::str_to_url(xml_url.as_str())?;
+        }
+
+        if let Some(ref html_url) = self.html_url
+        {
+            string_utils::str_to_url(html_url.as_str())?;
+        }
+
+        Ok(self)
+    }
+
+
+    /// Construct the `Outline` from the `OutlineBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::OutlineBuilder;
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .outline_type(Some("rss".to_owned()))
+    ///     .xml_url(Some("http://feeds.feedburner.com/TheLinuxActionShowOGG".to_owned()))
+    ///     .finalize()
+    ///     .unwrap();
+    /// ```
+    pub fn finalize(&self) -> Result<Outline, String>
+    {
+        Ok(Outline {
+               text: self.text.clone(),
+               title: self.title.clone(),
+               outline_type: self.outline_type.clone(),
+               xml_url: self.xml_url.clone(),
+               html_url: self.html_url.clone(),
+               description: self.description.clone(),
+               categories: self.categories.clone(),
+               outlines: self.outlines.clone(),
+           })
+    }
+}
@@ -0,0 +1,264 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+
+//! The fields under outline can be retrieved by using the methods under
+//! `Outline`.
+
+
+use opml::{Outline, OutlineGetters};
+
+
+impl OutlineGetters for Outline
+{
+    /// Get the text that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let text = "The Linux Action Show! OGG";
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text(text)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(text.to_owned(), outline.text());
+    /// ```
+    fn text(&self) -> String
+    {
+        self.text.clone()
+    }
+
+
+    /// Get the optional title that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let title = "The Linux Action Show! OGG".to_owned();
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .title(Some(title.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(title), outline.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .title(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(outline.title().is_none());
+    /// ```
+    fn title(&self) -> Option<String>
+    {
+        self.title.clone()
+    }
+
+
+    /// Get the optional outline type that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let outline_type = "rss".to_owned();
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .outline_type(Some(outline_type.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(outline_type), outline.outline_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .outline_type(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(outline.outline_type().is_none());
+    /// ```
+    fn outline_type(&self) -> Option<String>
+    {
+        self.outline_type.clone()
+    }
+
+
+    /// Get the optional xml url that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let xml_url = "http://feeds.feedburner.com/TheLinuxActionShowOGG".to_owned();
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .xml_url(Some(xml_url.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(xml_url), outline.xml_url());
+    /// ```
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .xml_url(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(outline.xml_url().is_none());
+    /// ```
+    fn xml_url(&self) -> Option<String>
+    {
+        self.xml_url.clone()
+    }
+
+
+    /// Get the optional html url that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let html_url = "http://www.jupiterbroadcasting.com".to_owned();
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .html_url(Some(html_url.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(html_url), outline.html_url());
+    /// ```
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .html_url(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(outline.html_url().is_none());
+    /// ```
+    fn html_url(&self) -> Option<String>
+    {
+        self.html_url.clone()
+    }
+
+
+    /// Get the optional description that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let description = "A show about Linux".to_owned();
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .description(Some(description.clone()))
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(description), outline.description());
+    /// ```
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .description(None)
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert!(outline.description().is_none());
+    /// ```
+    fn description(&self) -> Option<String>
+    {
+        self.description.clone()
+    }
+
+
+    /// Get the categories that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let categories = vec!["/Technology/Tech News".to_owned()];
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .categories(categories.clone())
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(categories, outline.categories());
+    /// ```
+    fn categories(&self) -> Vec<String>
+    {
+        self.categories.clone()
+    }
+
+
+    /// Get the outlines that exists under `Outline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::opml::{OutlineBuilder, OutlineGetters};
+    ///
+    /// let child = OutlineBuilder::new()
+    ///     .text("The Linux Action Show! OGG")
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// let outline = OutlineBuilder::new()
+    ///     .text("Podcasts")
+    ///     .outlines(vec![child])
+    ///     .finalize()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, outline.outlines().len());
+    /// ```
+    fn outlines(&self) -> Vec<Outline>
+    {
+        self.outlines.clone()
+    }
+}
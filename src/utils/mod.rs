@@ -8,6 +8,8 @@
 // (at your option) any later version.
 
 
-// pub mod reader_utils;
+pub mod reader_utils;
+pub mod repair_utils;
 pub mod string_utils;
-// pub mod writer_utils;
+pub mod url_utils;
+pub mod writer_utils;
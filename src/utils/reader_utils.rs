@@ -0,0 +1,403 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+use quick_xml::{Element, Event, XmlReader};
+use rss::{Category, Channel, Cloud, Enclosure, Guid, Image, Item, Source, TextInput};
+use rss::extension::{Extension, ExtensionMap};
+use rss::extension::dublincore::DublinCoreExtension;
+use rss::extension::itunes::{ITunesChannelExtension, ITunesItemExtension};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str;
+
+
+// The `itunes` elements the `rss` crate reads into an `ITunesChannelExtension`,
+// by local name.
+const ITUNES_CHANNEL_ELEMENTS: &[&str] = &["author", "block", "category", "image", "explicit", "complete",
+                                           "new-feed-url", "owner", "subtitle", "summary", "keywords"];
+
+// The `itunes` elements the `rss` crate reads into an `ITunesItemExtension`,
+// by local name.
+const ITUNES_ITEM_ELEMENTS: &[&str] = &["author", "block", "image", "duration", "explicit", "isClosedCaptioned",
+                                        "order", "subtitle", "summary", "keywords"];
+
+
+// A generic element read from an XML document, children in document order.
+#[derive(Clone, Debug, Default)]
+pub struct XmlElement
+{
+    pub name: String,
+    pub attrs: Vec<(String, String)>,
+    pub text: Option<String>,
+    pub children: Vec<XmlElement>,
+}
+
+
+impl XmlElement
+{
+    // Get the name of the element without its namespace prefix.
+    pub fn local_name(&self) -> &str
+    {
+        match self.name.find(':')
+        {
+            Some(pos) => &self.name[pos + 1..],
+            None => self.name.as_str(),
+        }
+    }
+
+
+    // Get the namespace prefix of the element.
+    pub fn prefix(&self) -> Option<&str>
+    {
+        self.name.find(':').map(|pos| &self.name[..pos])
+    }
+
+
+    // Get the value of the attribute with the given qualified name.
+    pub fn attr(&self, name: &str) -> Option<String>
+    {
+        self.attrs.iter().find(|attr| attr.0 == name).map(|attr| attr.1.clone())
+    }
+
+
+    // Get the first child with the given qualified name.
+    pub fn child(&self, name: &str) -> Option<&XmlElement>
+    {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+
+    // Get the text of the first child with the given qualified name.
+    pub fn child_text(&self, name: &str) -> Option<String>
+    {
+        self.child(name).and_then(|child| child.text.clone())
+    }
+
+
+    // Convert the element to an `Extension` as the `rss` crate stores them.
+    pub fn to_extension(&self) -> Extension
+    {
+        let mut children: HashMap<String, Vec<Extension>> = HashMap::new();
+        for child in &self.children
+        {
+            children.entry(child.local_name().to_owned()).or_default().push(child.to_extension());
+        }
+
+        Extension {
+            name: self.name.clone(),
+            value: self.text.clone(),
+            attrs: self.attrs.iter().cloned().collect(),
+            children,
+        }
+    }
+}
+
+
+// Common code to add a namespaced element to an `ExtensionMap`.
+pub fn insert_extension(extensions: &mut ExtensionMap, element: &XmlElement)
+{
+    let prefix = element.prefix().unwrap_or("").to_owned();
+    extensions
+        .entry(prefix)
+        .or_default()
+        .entry(element.local_name().to_owned())
+        .or_default()
+        .push(element.to_extension());
+}
+
+
+// Common code to read the `itunes` extensions into an `ITunesChannelExtension`
+// the way the `rss` crate does, keeping the elements it does not cover.
+pub fn take_itunes_channel_ext(extensions: &mut ExtensionMap) -> Option<ITunesChannelExtension>
+{
+    extensions
+        .remove("itunes")
+        .map(|map| {
+                 keep_uncovered(extensions, &map, ITUNES_CHANNEL_ELEMENTS);
+                 ITunesChannelExtension::from_map(map)
+             })
+}
+
+
+// Common code to read the `itunes` extensions into an `ITunesItemExtension`
+// the way the `rss` crate does, keeping the elements it does not cover.
+pub fn take_itunes_item_ext(extensions: &mut ExtensionMap) -> Option<ITunesItemExtension>
+{
+    extensions
+        .remove("itunes")
+        .map(|map| {
+                 keep_uncovered(extensions, &map, ITUNES_ITEM_ELEMENTS);
+                 ITunesItemExtension::from_map(map)
+             })
+}
+
+
+// Common code to put back the `itunes` elements of a channel and its items
+// that `Channel::from_str` drops, reading them from the `channel` element.
+pub fn restore_itunes_elements(channel: &mut Channel, element: &XmlElement)
+{
+    insert_uncovered(&mut channel.extensions, element, ITUNES_CHANNEL_ELEMENTS);
+
+    let items = element.children.iter().filter(|child| child.name == "item");
+    for (item, item_element) in channel.items.iter_mut().zip(items)
+    {
+        insert_uncovered(&mut item.extensions, item_element, ITUNES_ITEM_ELEMENTS);
+    }
+}
+
+
+// Common code to convert a `quick_xml` error to a `String`.
+pub fn error_to_string(err: (::quick_xml::error::Error, usize)) -> String
+{
+    format!("Error: {} at position {}", err.0, err.1)
+}
+
+
+// Common code to construct an `XmlElement` from its start tag.
+pub fn start_to_element(start: &Element) -> Result<XmlElement, String>
+{
+    let mut attrs = Vec::new();
+    for attr in start.unescaped_attributes()
+    {
+        let (key, value) = attr.map_err(error_to_string)?;
+        let key = str::from_utf8(key).map_err(|err| format!("Error: {}", err))?;
+        let value = str::from_utf8(&value).map_err(|err| format!("Error: {}", err))?;
+        attrs.push((key.to_owned(), value.to_owned()));
+    }
+
+    Ok(XmlElement {
+           name: str::from_utf8(start.name()).map_err(|err| format!("Error: {}", err))?.to_owned(),
+           attrs,
+           ..Default::default()
+       })
+}
+
+
+// Common code to read the rest of an element whose start tag has been read.
+pub fn read_element<B: BufRead>(reader: &mut XmlReader<B>, start: &Element) -> Result<XmlElement, String>
+{
+    let mut element = start_to_element(start)?;
+
+    while let Some(event) = reader.next()
+    {
+        match event.map_err(error_to_string)?
+        {
+            Event::Start(child) => element.children.push(read_element(reader, &child)?),
+            Event::End(_) => return Ok(element),
+            Event::Text(text) =>
+            {
+                let text = text.unescaped_content().map_err(error_to_string)?;
+                push_text(&mut element, str::from_utf8(&text).map_err(|err| format!("Error: {}", err))?);
+            }
+            Event::CData(text) =>
+            {
+                push_text(&mut element, str::from_utf8(text.content()).map_err(|err| format!("Error: {}", err))?);
+            }
+            _ => (),
+        }
+    }
+
+    Err(format!("Error: Missing end tag for {}", element.name))
+}
+
+
+// Common code to read the root element of an XML document.
+pub fn read_document(xml: &str) -> Result<XmlElement, String>
+{
+    let mut reader = XmlReader::from(xml).trim_text(true);
+
+    while let Some(event) = reader.next()
+    {
+        if let Event::Start(start) = event.map_err(error_to_string)?
+        {
+            return read_element(&mut reader, &start);
+        }
+    }
+
+    Err("Error: Document has no root element".to_owned())
+}
+
+
+// Common code to convert an `<item>` element to an `Item` the way the `rss`
+// crate does.
+pub fn element_to_item(element: &XmlElement) -> Item
+{
+    let mut item = Item::default();
+
+    for child in &element.children
+    {
+        match child.name.as_str()
+        {
+            "title" => item.title = child.text.clone(),
+            "link" => item.link = child.text.clone(),
+            "description" => item.description = child.text.clone(),
+            "author" => item.author = child.text.clone(),
+            "comments" => item.comments = child.text.clone(),
+            "pubDate" => item.pub_date = child.text.clone(),
+            "content:encoded" => item.content = child.text.clone(),
+            "category" => item.categories.push(element_to_category(child)),
+            "guid" =>
+            {
+                item.guid = Some(Guid {
+                                     value: child.text.clone().unwrap_or_default(),
+                                     is_permalink: child.attr("isPermaLink").as_deref() != Some("false"),
+                                 })
+            }
+            "enclosure" =>
+            {
+                item.enclosure = Some(Enclosure {
+                                          url: child.attr("url").unwrap_or_default(),
+                                          length: child.attr("length").unwrap_or_default(),
+                                          mime_type: child.attr("type").unwrap_or_default(),
+                                      })
+            }
+            "source" =>
+            {
+                item.source = Some(Source {
+                                       url: child.attr("url").unwrap_or_default(),
+                                       title: child.text.clone(),
+                                   })
+            }
+            _ => insert_namespaced_extension(&mut item.extensions, child),
+        }
+    }
+
+    item.itunes_ext = take_itunes_item_ext(&mut item.extensions);
+    item.dublin_core_ext = item.extensions.remove("dc").map(DublinCoreExtension::from_map);
+    item
+}
+
+
+// Common code to apply a child element of `<channel>` to the `Channel` the
+// way the `rss` crate does, along with the `<rating>` and `<textinput>` of
+// RSS 0.91.
+pub fn apply_channel_element(channel: &mut Channel, element: &XmlElement)
+{
+    let text = element.text.clone();
+
+    match element.name.as_str()
+    {
+        "title" => channel.title = text.unwrap_or_default(),
+        "link" => channel.link = text.unwrap_or_default(),
+        "description" => channel.description = text.unwrap_or_default(),
+        "language" => channel.language = text,
+        "copyright" => channel.copyright = text,
+        "managingEditor" => channel.managing_editor = text,
+        "webMaster" => channel.webmaster = text,
+        "pubDate" => channel.pub_date = text,
+        "lastBuildDate" => channel.last_build_date = text,
+        "generator" => channel.generator = text,
+        "docs" => channel.docs = text,
+        "ttl" => channel.ttl = text,
+        "category" => channel.categories.push(element_to_category(element)),
+        "cloud" =>
+        {
+            channel.cloud = Some(Cloud {
+                                     domain: element.attr("domain").unwrap_or_default(),
+                                     port: element.attr("port").unwrap_or_default(),
+                                     path: element.attr("path").unwrap_or_default(),
+                                     register_procedure: element.attr("registerProcedure").unwrap_or_default(),
+                                     protocol: element.attr("protocol").unwrap_or_default(),
+                                 })
+        }
+        "image" =>
+        {
+            channel.image = Some(Image {
+                                     url: element.child_text("url").unwrap_or_default(),
+                                     title: element.child_text("title").unwrap_or_default(),
+                                     link: element.child_text("link").unwrap_or_default(),
+                                     width: element.child_text("width"),
+                                     height: element.child_text("height"),
+                                     description: element.child_text("description"),
+                                 })
+        }
+        "textInput" | "textinput" =>
+        {
+            channel.text_input = Some(TextInput {
+                                          title: element.child_text("title").unwrap_or_default(),
+                                          description: element.child_text("description").unwrap_or_default(),
+                                          name: element.child_text("name").unwrap_or_default(),
+                                          link: element.child_text("link").unwrap_or_default(),
+                                      })
+        }
+        "skipHours" => channel.skip_hours = child_texts(element, "hour"),
+        "skipDays" => channel.skip_days = child_texts(element, "day"),
+        "rating" => insert_extension(&mut channel.extensions, element),
+        _ => insert_namespaced_extension(&mut channel.extensions, element),
+    }
+}
+
+
+fn element_to_category(element: &XmlElement) -> Category
+{
+    Category {
+        name: element.text.clone().unwrap_or_default(),
+        domain: element.attr("domain"),
+    }
+}
+
+
+fn child_texts(element: &XmlElement, name: &str) -> Vec<String>
+{
+    element
+        .children
+        .iter()
+        .filter(|child| child.name == name)
+        .filter_map(|child| child.text.clone())
+        .collect()
+}
+
+
+// Namespaced elements are kept as extensions, anything else is skipped.
+fn insert_namespaced_extension(extensions: &mut ExtensionMap, element: &XmlElement)
+{
+    match element.prefix()
+    {
+        None | Some("") | Some("rss") | Some("rdf") => (),
+        Some(_) => insert_extension(extensions, element),
+    }
+}
+
+
+fn push_text(element: &mut XmlElement, text: &str)
+{
+    match element.text
+    {
+        Some(ref mut val) => val.push_str(text),
+        None => element.text = Some(text.to_owned()),
+    }
+}
+
+
+// Put the `itunes` elements outside `covered` back under the `itunes` prefix.
+fn keep_uncovered(extensions: &mut ExtensionMap, map: &HashMap<String, Vec<Extension>>, covered: &[&str])
+{
+    let kept: HashMap<String, Vec<Extension>> = map.iter()
+        .filter(|element| !covered.contains(&element.0.as_str()))
+        .map(|element| (element.0.clone(), element.1.clone()))
+        .collect();
+
+    if !kept.is_empty()
+    {
+        extensions.insert("itunes".to_owned(), kept);
+    }
+}
+
+
+// Add the `itunes` children of `element` outside `covered` to the extensions.
+fn insert_uncovered(extensions: &mut ExtensionMap, element: &XmlElement, covered: &[&str])
+{
+    for child in &element.children
+    {
+        if child.prefix() == Some("itunes") && !covered.contains(&child.local_name())
+        {
+            insert_extension(extensions, child);
+        }
+    }
+}
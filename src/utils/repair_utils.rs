@@ -0,0 +1,300 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+use quick_xml::{Event, XmlReader};
+use std::str;
+use utils::reader_utils;
+
+
+// HTML entities that turn up in feeds but are not declared by XML.
+const HTML_ENTITIES: &[(&str, u32)] = &[("nbsp", 160),
+                                        ("copy", 169),
+                                        ("reg", 174),
+                                        ("deg", 176),
+                                        ("middot", 183),
+                                        ("eacute", 233),
+                                        ("ndash", 8211),
+                                        ("mdash", 8212),
+                                        ("lsquo", 8216),
+                                        ("rsquo", 8217),
+                                        ("ldquo", 8220),
+                                        ("rdquo", 8221),
+                                        ("bull", 8226),
+                                        ("hellip", 8230),
+                                        ("trade", 8482)];
+
+
+// A repair made to a document, at a byte offset of the original text.
+#[derive(Clone, Debug)]
+pub struct Repair
+{
+    pub offset: usize,
+    pub message: String,
+}
+
+
+// A span of the original text holding an `<item>`, which is incomplete when
+// the document ends before its end tag.
+#[derive(Clone, Debug)]
+pub struct ItemSpan
+{
+    pub start: usize,
+    pub end: usize,
+    pub complete: bool,
+}
+
+
+// Common code to remove control characters and escape stray ampersands,
+// leaving CDATA sections and comments alone apart from the control
+// characters. `base` is the offset of `text` in the original document.
+pub fn repair_text(text: &str, base: usize, repairs: &mut Vec<Repair>) -> String
+{
+    let mut repaired = String::with_capacity(text.len());
+    let mut pos = 0;
+
+    while pos < text.len()
+    {
+        let rest = &text[pos..];
+
+        if let Some(len) = skipped_section_len(rest)
+        {
+            push_without_controls(&mut repaired, &rest[..len], base + pos, repairs);
+            pos += len;
+            continue;
+        }
+
+        let c = rest.chars().next().unwrap_or_default();
+        if c == '&'
+        {
+            match entity_len(rest)
+            {
+                Some(len) =>
+                {
+                    repaired.push_str(&rest[..len]);
+                    pos += len;
+                }
+                None =>
+                {
+                    match html_entity(rest)
+                    {
+                        Some((name, code)) =>
+                        {
+                            repairs.push(Repair {
+                                             offset: base + pos,
+                                             message: format!("Replaced undeclared entity &{};", name),
+                                         });
+                            repaired.push_str(format!("&#{};", code).as_str());
+                            pos += name.len() + 2;
+                        }
+                        None =>
+                        {
+                            repairs.push(Repair {
+                                             offset: base + pos,
+                                             message: "Escaped stray ampersand".to_owned(),
+                                         });
+                            repaired.push_str("&amp;");
+                            pos += 1;
+                        }
+                    }
+                }
+            }
+            continue;
+        }
+
+        push_without_controls(&mut repaired, &rest[..c.len_utf8()], base + pos, repairs);
+        pos += c.len_utf8();
+    }
+
+    repaired
+}
+
+
+// Common code to find the `<item>` elements of a document without parsing it.
+pub fn item_spans(text: &str) -> Vec<ItemSpan>
+{
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    let mut start = None;
+
+    while let Some(found) = text[pos..].find('<')
+    {
+        pos += found;
+        let rest = &text[pos..];
+
+        if let Some(len) = skipped_section_len(rest)
+        {
+            pos += len;
+            continue;
+        }
+
+        if start.is_none() && is_tag(rest, "<item")
+        {
+            start = Some(pos);
+        }
+        else if let (Some(begin), true) = (start, rest.starts_with("</item>"))
+        {
+            spans.push(ItemSpan {
+                           start: begin,
+                           end: pos + "</item>".len(),
+                           complete: true,
+                       });
+            start = None;
+        }
+        pos += 1;
+    }
+
+    if let Some(begin) = start
+    {
+        spans.push(ItemSpan {
+                       start: begin,
+                       end: text.len(),
+                       complete: false,
+                   });
+    }
+
+    spans
+}
+
+
+// Common code to close the elements left open by a truncated document,
+// returning the completed document and the names of the elements closed.
+pub fn close_open_elements(text: &str) -> Result<(String, Vec<String>), String>
+{
+    let mut text = text.trim_end().to_owned();
+
+    // Drop a tag that was cut off part way through.
+    if let Some(open) = text.rfind('<')
+    {
+        if text[open..].find('>').is_none()
+        {
+            text.truncate(open);
+        }
+    }
+
+    let mut open = Vec::new();
+    {
+        let reader = XmlReader::from(text.as_str()).trim_text(true);
+        for event in reader
+        {
+            match event.map_err(reader_utils::error_to_string)?
+            {
+                Event::Start(element) =>
+                {
+                    open.push(str::from_utf8(element.name()).map_err(|err| format!("Error: {}", err))?.to_owned());
+                }
+                Event::End(_) =>
+                {
+                    open.pop();
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let closed: Vec<String> = open.into_iter().rev().collect();
+    for name in &closed
+    {
+        text.push_str(format!("</{}>", name).as_str());
+    }
+
+    Ok((text, closed))
+}
+
+
+// Common code to convert a byte offset to a line and column, both from 1.
+pub fn line_column(text: &str, offset: usize) -> (usize, usize)
+{
+    let offset = offset.min(text.len());
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = match before.rfind('\n')
+    {
+        Some(pos) => before[pos + 1..].chars().count() + 1,
+        None => before.chars().count() + 1,
+    };
+    (line, column)
+}
+
+
+fn push_without_controls(repaired: &mut String, text: &str, offset: usize, repairs: &mut Vec<Repair>)
+{
+    for (index, c) in text.char_indices()
+    {
+        if c < ' ' && c != '\t' && c != '\n' && c != '\r'
+        {
+            repairs.push(Repair {
+                             offset: offset + index,
+                             message: format!("Removed control character U+{:04X}", c as u32),
+                         });
+        }
+        else
+        {
+            repaired.push(c);
+        }
+    }
+}
+
+
+// The length of the CDATA section or comment `text` starts with.
+fn skipped_section_len(text: &str) -> Option<usize>
+{
+    let sections = [("<![CDATA[", "]]>"), ("<!--", "-->")];
+
+    for &(open, close) in &sections
+    {
+        if let Some(rest) = text.strip_prefix(open)
+        {
+            return Some(match rest.find(close)
+                        {
+                            Some(pos) => open.len() + pos + close.len(),
+                            None => text.len(),
+                        });
+        }
+    }
+
+    None
+}
+
+
+// The length of the well-formed XML entity reference `text` starts with.
+fn entity_len(text: &str) -> Option<usize>
+{
+    let end = text.find(';')?;
+    let name = &text[1..end];
+
+    let valid = match name
+    {
+        "amp" | "lt" | "gt" | "quot" | "apos" => true,
+        _ if name.starts_with("#x") => name.len() > 2 && name[2..].chars().all(|c| c.is_ascii_hexdigit()),
+        _ if name.starts_with('#') => name.len() > 1 && name[1..].chars().all(|c| c.is_ascii_digit()),
+        _ => false,
+    };
+
+    if valid { Some(end + 1) } else { None }
+}
+
+
+fn html_entity(text: &str) -> Option<(&'static str, u32)>
+{
+    HTML_ENTITIES
+        .iter()
+        .find(|entity| text[1..].starts_with(entity.0) && text[1 + entity.0.len()..].starts_with(';'))
+        .cloned()
+}
+
+
+fn is_tag(text: &str, name: &str) -> bool
+{
+    text.starts_with(name) &&
+    match text[name.len()..].chars().next()
+    {
+        Some(c) => c == '>' || c == '/' || c.is_whitespace(),
+        None => true,
+    }
+}
@@ -10,6 +10,7 @@
 use chrono::*;
 use std::i64;
 use std::str::FromStr;
+use std::time::Duration;
 use url::Url;
 
 // Common code to convert String to i64
@@ -93,3 +94,208 @@ pub fn str_to_url(s: &str) -> Result<Url, String>
         Err(err) => Err(format!("Error: {}", err)),
     }
 }
+
+
+// Common code to convert Option<String> to an Option<DateTime<FixedOffset>>
+// from RFC 3339.
+pub fn option_string_to_option_rfc3339_date(date_option: Option<String>)
+    -> Result<Option<DateTime<FixedOffset>>, String>
+{
+    match date_option
+    {
+        Some(val) =>
+        {
+            match DateTime::parse_from_rfc3339(val.as_str())
+            {
+                Ok(val) => Ok(Some(val)),
+                Err(err) => Err(format!("Error: {}", err)),
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+
+// Common code to convert an RFC 2822 date to RFC 3339.
+pub fn rfc2822_to_rfc3339(date: &str) -> Result<String, String>
+{
+    match DateTime::parse_from_rfc2822(date.trim())
+    {
+        Ok(val) => Ok(val.to_rfc3339()),
+        Err(err) => Err(format!("Error: {}", err)),
+    }
+}
+
+
+// Common code to convert an RFC 3339 date to RFC 2822.
+pub fn rfc3339_to_rfc2822(date: &str) -> Result<String, String>
+{
+    match DateTime::parse_from_rfc3339(date.trim())
+    {
+        Ok(val) => Ok(val.to_rfc2822()),
+        Err(err) => Err(format!("Error: {}", err)),
+    }
+}
+
+
+// Common code to convert a W3C date and time to DateTime<FixedOffset>. Dates
+// without a time are taken as midnight UTC, and times without seconds as the
+// start of the minute.
+pub fn w3cdtf_to_date(date: &str) -> Result<DateTime<FixedOffset>, String>
+{
+    let date = date.trim();
+    let full = match date.len()
+    {
+        4 => format!("{}-01-01T00:00:00+00:00", date),
+        7 => format!("{}-01T00:00:00+00:00", date),
+        10 => format!("{}T00:00:00+00:00", date),
+        len if len > 16 && date.is_char_boundary(16) && !date[16..].starts_with(':') =>
+        {
+            format!("{}:00{}", &date[..16], &date[16..])
+        }
+        _ => date.to_owned(),
+    };
+
+    match DateTime::parse_from_rfc3339(full.as_str())
+    {
+        Ok(val) => Ok(val),
+        Err(err) => Err(format!("Error: {}", err)),
+    }
+}
+
+
+// Common code to convert an ISO 8601 date and time, with or without a colon in
+// its offset, to DateTime<FixedOffset>.
+pub fn iso8601_to_date(date: &str) -> Result<DateTime<FixedOffset>, String>
+{
+    let date = date.trim();
+    match DateTime::parse_from_rfc3339(date).or_else(|_| DateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S%.f%z"))
+    {
+        Ok(val) => Ok(val),
+        Err(err) => Err(format!("Error: {}", err)),
+    }
+}
+
+
+// Common code to convert a timestamp such as `01:02:03.500`, `02:03,500` or
+// `123.5` to seconds.
+pub fn timestamp_to_seconds(timestamp: &str) -> Result<f64, String>
+{
+    let timestamp = timestamp.trim().replace(',', ".");
+    let parts: Vec<&str> = timestamp.split(':').collect();
+    if parts.len() > 3
+    {
+        return Err(format!("Invalid value: {}", timestamp));
+    }
+
+    let mut seconds = 0.0;
+    for part in parts
+    {
+        let value = match part.parse::<f64>()
+        {
+            Ok(val) if val >= 0.0 => val,
+            Ok(_) => return Err(format!("Invalid value: {}", timestamp)),
+            Err(err) => return Err(format!("Error: {}", err)),
+        };
+        seconds = seconds * 60.0 + value;
+    }
+
+    Ok(seconds)
+}
+
+
+// Common code to convert an iTunes duration, `HH:MM:SS`, `MM:SS` or a number
+// of seconds, to a Duration. The seconds can have a fraction.
+pub fn itunes_duration_to_duration(duration: &str) -> Result<Duration, String>
+{
+    let mut parts: Vec<&str> = duration.trim().split(':').collect();
+    if parts.len() > 3
+    {
+        return Err(format!("Invalid value: {}", duration));
+    }
+
+    let mut nanos = 0;
+    let last = parts.len() - 1;
+    if let Some(pos) = parts[last].find('.')
+    {
+        let fraction = &parts[last][pos + 1..];
+        if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(format!("Invalid value: {}", duration));
+        }
+
+        let digits: String = fraction.chars().chain("000000000".chars()).take(9).collect();
+        nanos = match digits.parse::<u32>()
+        {
+            Ok(val) => val,
+            Err(err) => return Err(format!("Error: {}", err)),
+        };
+        parts[last] = &parts[last][..pos];
+    }
+
+    let mut seconds: u64 = 0;
+    for (i, part) in parts.iter().enumerate()
+    {
+        if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) || (i > 0 && part.len() > 2)
+        {
+            return Err(format!("Invalid value: {}", duration));
+        }
+
+        let value = match part.parse::<u64>()
+        {
+            Ok(val) => val,
+            Err(err) => return Err(format!("Error: {}", err)),
+        };
+        if i > 0 && value >= 60
+        {
+            return Err(format!("Invalid value: {}", duration));
+        }
+        seconds = match seconds.checked_mul(60).and_then(|val| val.checked_add(value))
+        {
+            Some(val) => val,
+            None => return Err(format!("Invalid value: {}", duration)),
+        };
+    }
+
+    Ok(Duration::new(seconds, nanos))
+}
+
+
+// Common code to convert a Duration to an iTunes duration, `HH:MM:SS`.
+pub fn duration_to_itunes_duration(duration: Duration) -> String
+{
+    let seconds = duration.as_secs();
+    format!("{:02}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+
+// Common code to split comma separated keywords, leaving out empty ones.
+pub fn keywords_to_vec(keywords: &str) -> Vec<String>
+{
+    keywords.split(',')
+        .map(|keyword| keyword.trim().to_owned())
+        .filter(|keyword| !keyword.is_empty())
+        .collect()
+}
+
+
+// Common code to count the characters to insert, delete or replace to turn one
+// string into another.
+pub fn edit_distance(a: &str, b: &str) -> usize
+{
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..b.len() + 1).collect();
+    for (i, ca) in a.chars().enumerate()
+    {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate()
+        {
+            let cost = if ca == *cb { 0 } else { 1 };
+            let distance = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+            current.push(distance);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
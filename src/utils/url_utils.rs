@@ -0,0 +1,65 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+use curl::easy::Easy;
+use utils::string_utils;
+
+
+// Common code to fetch the XML document at a url.
+pub fn fetch_xml(url: &str) -> Result<Vec<u8>, String>
+{
+    let (xml, content_type) = fetch(url)?;
+
+    if !content_type.contains("xml")
+    {
+        return Err("Url must end with .xml".to_owned());
+    }
+
+    Ok(xml)
+}
+
+
+// Common code to fetch the document at a url, along with its content type.
+pub fn fetch(url: &str) -> Result<(Vec<u8>, String), String>
+{
+    let feed_url = string_utils::str_to_url(url)?;
+    let mut body = Vec::new();
+    let mut handle = Easy::new();
+
+    let handle_url = handle.url(feed_url.into_string().as_str());
+    {
+        let mut transfer = handle.transfer();
+        let write_function = transfer.write_function(|data| {
+                                                         body.extend_from_slice(data);
+                                                         Ok(data.len())
+                                                     });
+        if let Err(err) = write_function
+        {
+            return Err(format!("Error: {}", err));
+        }
+
+        if let Err(err) = transfer.perform()
+        {
+            return Err(format!("Error: {}", err));
+        }
+    }
+
+    if let Err(err) = handle_url
+    {
+        return Err(format!("Error: {:?}", err));
+    }
+
+    let content_type = match handle.content_type()
+    {
+        Ok(val) => val.unwrap_or_default().to_owned(),
+        Err(err) => return Err(format!("Error: {}", err)),
+    };
+
+    Ok((body, content_type))
+}
@@ -0,0 +1,581 @@
+// This file is part of feed.
+//
+// Copyright © 2015-2017 Chris Palmer <pennstate5013@gmail.com>
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation; either version 3 of the License, or
+// (at your option) any later version.
+
+use extension::{atom, comments, content, georss, googleplay, media, podcast, registry, syndication};
+use rss::{Category, Channel, Item};
+use rss::extension::{self, Extension, ExtensionMap};
+use rss::extension::dublincore::{self, DublinCoreExtension};
+use rss::extension::itunes::{self, ITunesCategory, ITunesChannelExtension, ITunesItemExtension};
+use utils::reader_utils::XmlElement;
+
+
+// Common code to escape the XML special characters of a `&str`.
+pub fn escape(s: &str) -> String
+{
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars()
+    {
+        match c
+        {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+
+// Common code to write an element containing escaped text.
+pub fn text_element(name: &str, text: &str) -> String
+{
+    format!("<{0}>{1}</{0}>", name, escape(text))
+}
+
+
+// Common code to build an element that only holds text.
+pub fn text_node(name: &str, text: &str) -> XmlElement
+{
+    XmlElement {
+        name: name.to_owned(),
+        text: Some(text.to_owned()),
+        ..Default::default()
+    }
+}
+
+
+// Common code to build the `<rss>` element of a `Channel`, declaring its
+// namespaces in order of prefix and leaving out its children. When `stream`
+// is set the items are not known yet, so the namespaces of every extension
+// this crate handles and of every registered extension are declared.
+pub fn rss_element(channel: &Channel, stream: bool) -> XmlElement
+{
+    let mut namespaces: Vec<(String, String)> = channel.namespaces
+        .iter()
+        .filter(|ns| ns.0 != "itunes" && ns.0 != "dc")
+        .map(|ns| (ns.0.clone(), ns.1.clone()))
+        .collect();
+
+    if stream || channel.itunes_ext.is_some() || channel.extensions.contains_key("itunes") ||
+       channel.items.iter().any(|item| item.itunes_ext.is_some() || item.extensions.contains_key("itunes"))
+    {
+        namespaces.push(("itunes".to_owned(), itunes::NAMESPACE.to_owned()));
+    }
+
+    if stream || channel.dublin_core_ext.is_some() || channel.extensions.contains_key("dc") ||
+       channel.items.iter().any(|item| item.dublin_core_ext.is_some() || item.extensions.contains_key("dc"))
+    {
+        namespaces.push(("dc".to_owned(), dublincore::NAMESPACE.to_owned()));
+    }
+
+    if !channel.namespaces.contains_key("content") &&
+       (stream || channel.items.iter().any(|item| item.content.is_some()))
+    {
+        namespaces.push(("content".to_owned(), content::NAMESPACE.to_owned()));
+    }
+
+    if stream
+    {
+        let mut known = vec![("atom", atom::NAMESPACE),
+                             ("georss", georss::NAMESPACE),
+                             ("gml", georss::GML_NAMESPACE),
+                             ("googleplay", googleplay::NAMESPACE),
+                             ("media", media::NAMESPACE),
+                             ("podcast", podcast::NAMESPACE),
+                             ("slash", comments::SLASH_NAMESPACE),
+                             ("sy", syndication::NAMESPACE),
+                             ("thr", comments::THR_NAMESPACE),
+                             ("wfw", comments::WFW_NAMESPACE)];
+        known.extend(registry::registered());
+
+        for (prefix, namespace) in known
+        {
+            if !namespaces.iter().any(|ns| ns.0 == prefix)
+            {
+                namespaces.push((prefix.to_owned(), namespace.to_owned()));
+            }
+        }
+    }
+
+    namespaces.sort();
+
+    let mut attrs = vec![("version".to_owned(), "2.0".to_owned())];
+    attrs.extend(namespaces.into_iter().map(|ns| (format!("xmlns:{}", ns.0), ns.1)));
+
+    XmlElement {
+        name: "rss".to_owned(),
+        attrs,
+        ..Default::default()
+    }
+}
+
+
+// Common code to convert the fields of a `Channel`, apart from its items, to
+// elements in the order of the RSS 2.0 specification followed by the
+// extensions in order of prefix.
+pub fn channel_elements(channel: &Channel) -> Vec<XmlElement>
+{
+    let mut elements = vec![text_node("title", channel.title.as_str()),
+                            text_node("link", channel.link.as_str()),
+                            text_node("description", channel.description.as_str())];
+
+    push_text_node(&mut elements, "language", &channel.language);
+    push_text_node(&mut elements, "copyright", &channel.copyright);
+    push_text_node(&mut elements, "managingEditor", &channel.managing_editor);
+    push_text_node(&mut elements, "webMaster", &channel.webmaster);
+    push_text_node(&mut elements, "pubDate", &channel.pub_date);
+    push_text_node(&mut elements, "lastBuildDate", &channel.last_build_date);
+    elements.extend(channel.categories.iter().map(category_element));
+    push_text_node(&mut elements, "generator", &channel.generator);
+    push_text_node(&mut elements, "docs", &channel.docs);
+
+    if let Some(ref cloud) = channel.cloud
+    {
+        elements.push(XmlElement {
+                          name: "cloud".to_owned(),
+                          attrs: vec![("domain".to_owned(), cloud.domain.clone()),
+                                      ("port".to_owned(), cloud.port.clone()),
+                                      ("path".to_owned(), cloud.path.clone()),
+                                      ("registerProcedure".to_owned(), cloud.register_procedure.clone()),
+                                      ("protocol".to_owned(), cloud.protocol.clone())],
+                          ..Default::default()
+                      });
+    }
+
+    push_text_node(&mut elements, "ttl", &channel.ttl);
+
+    if let Some(ref image) = channel.image
+    {
+        let mut children = vec![text_node("url", image.url.as_str()),
+                                text_node("title", image.title.as_str()),
+                                text_node("link", image.link.as_str())];
+        push_text_node(&mut children, "width", &image.width);
+        push_text_node(&mut children, "height", &image.height);
+        push_text_node(&mut children, "description", &image.description);
+        elements.push(parent_node("image", children));
+    }
+
+    let rating = channel.extensions.get("").and_then(|map| extension::get_extension_value(map, "rating"));
+    if let Some(rating) = rating
+    {
+        elements.push(text_node("rating", rating));
+    }
+
+    if let Some(ref text_input) = channel.text_input
+    {
+        elements.push(parent_node("textInput",
+                                  vec![text_node("title", text_input.title.as_str()),
+                                       text_node("description", text_input.description.as_str()),
+                                       text_node("name", text_input.name.as_str()),
+                                       text_node("link", text_input.link.as_str())]));
+    }
+
+    if !channel.skip_hours.is_empty()
+    {
+        elements.push(parent_node("skipHours",
+                                  channel.skip_hours.iter().map(|hour| text_node("hour", hour)).collect()));
+    }
+
+    if !channel.skip_days.is_empty()
+    {
+        elements.push(parent_node("skipDays",
+                                  channel.skip_days.iter().map(|day| text_node("day", day)).collect()));
+    }
+
+    let mut extensions = Vec::new();
+    if let Some(ref itunes_ext) = channel.itunes_ext
+    {
+        extensions.extend(itunes_channel_elements(itunes_ext));
+    }
+    if let Some(ref dublin_core_ext) = channel.dublin_core_ext
+    {
+        extensions.extend(dublin_core_elements(dublin_core_ext));
+    }
+    extensions.extend(extension_elements(&channel.extensions, channel.dublin_core_ext.is_some(), &["rating"]));
+    extensions.sort_by(|a, b| a.prefix().cmp(&b.prefix()));
+
+    elements.extend(extensions);
+    elements
+}
+
+
+// Common code to convert an `Item` to an element, its children in the order
+// of the RSS 2.0 specification followed by the extensions in order of prefix.
+pub fn item_element(item: &Item) -> XmlElement
+{
+    let mut elements = Vec::new();
+
+    push_text_node(&mut elements, "title", &item.title);
+    push_text_node(&mut elements, "link", &item.link);
+    push_text_node(&mut elements, "description", &item.description);
+    push_text_node(&mut elements, "author", &item.author);
+    elements.extend(item.categories.iter().map(category_element));
+    push_text_node(&mut elements, "comments", &item.comments);
+
+    if let Some(ref enclosure) = item.enclosure
+    {
+        elements.push(XmlElement {
+                          name: "enclosure".to_owned(),
+                          attrs: vec![("url".to_owned(), enclosure.url.clone()),
+                                      ("length".to_owned(), enclosure.length.clone()),
+                                      ("type".to_owned(), enclosure.mime_type.clone())],
+                          ..Default::default()
+                      });
+    }
+
+    if let Some(ref guid) = item.guid
+    {
+        let mut element = text_node("guid", guid.value.as_str());
+        if !guid.is_permalink
+        {
+            element.attrs.push(("isPermaLink".to_owned(), "false".to_owned()));
+        }
+        elements.push(element);
+    }
+
+    push_text_node(&mut elements, "pubDate", &item.pub_date);
+
+    if let Some(ref source) = item.source
+    {
+        elements.push(XmlElement {
+                          name: "source".to_owned(),
+                          attrs: vec![("url".to_owned(), source.url.clone())],
+                          text: source.title.clone(),
+                          ..Default::default()
+                      });
+    }
+
+    let mut extensions = Vec::new();
+    push_text_node(&mut extensions, "content:encoded", &item.content);
+    if let Some(ref itunes_ext) = item.itunes_ext
+    {
+        extensions.extend(itunes_item_elements(itunes_ext));
+    }
+    if let Some(ref dublin_core_ext) = item.dublin_core_ext
+    {
+        extensions.extend(dublin_core_elements(dublin_core_ext));
+    }
+    extensions.extend(extension_elements(&item.extensions, item.dublin_core_ext.is_some(), &[]));
+    extensions.sort_by(|a, b| a.prefix().cmp(&b.prefix()));
+
+    elements.extend(extensions);
+    parent_node("item", elements)
+}
+
+
+// Common code to write the start tag of an element with children, indented
+// to `depth` when `indent` is given.
+pub fn write_start(xml: &mut String, element: &XmlElement, depth: usize, indent: Option<usize>, ascii: bool)
+{
+    push_indent(xml, depth, indent);
+    xml.push_str(start_tag(element, ascii).as_str());
+    push_newline(xml, indent);
+}
+
+
+// Common code to write the end tag of an element with children.
+pub fn write_end(xml: &mut String, name: &str, depth: usize, indent: Option<usize>)
+{
+    push_indent(xml, depth, indent);
+    xml.push_str(format!("</{}>", name).as_str());
+    push_newline(xml, indent);
+}
+
+
+// Common code to write an element and its children, indented to `depth` when
+// `indent` is given. Text holding markup is written as CDATA when `cdata` is
+// set or the element is `content:encoded`, and characters outside ASCII are
+// written as character references when `ascii` is set.
+pub fn write_element(xml: &mut String,
+                     element: &XmlElement,
+                     depth: usize,
+                     indent: Option<usize>,
+                     cdata: bool,
+                     ascii: bool)
+{
+    let cdata = cdata || element.name == "content:encoded";
+
+    if element.children.is_empty()
+    {
+        push_indent(xml, depth, indent);
+        match element.text
+        {
+            Some(ref text) if !text.is_empty() =>
+            {
+                xml.push_str(start_tag(element, ascii).as_str());
+                xml.push_str(text_content(text, cdata, ascii).as_str());
+                xml.push_str(format!("</{}>", element.name).as_str());
+            }
+            _ =>
+            {
+                let tag = start_tag(element, ascii);
+                xml.push_str(format!("{}/>", &tag[..tag.len() - 1]).as_str());
+            }
+        }
+        push_newline(xml, indent);
+        return;
+    }
+
+    write_start(xml, element, depth, indent, ascii);
+    if let Some(ref text) = element.text
+    {
+        if !text.trim().is_empty()
+        {
+            push_indent(xml, depth + 1, indent);
+            xml.push_str(text_content(text, cdata, ascii).as_str());
+            push_newline(xml, indent);
+        }
+    }
+    for child in &element.children
+    {
+        write_element(xml, child, depth + 1, indent, cdata, ascii);
+    }
+    write_end(xml, element.name.as_str(), depth, indent);
+}
+
+
+fn start_tag(element: &XmlElement, ascii: bool) -> String
+{
+    let mut tag = format!("<{}", element.name);
+    for attr in &element.attrs
+    {
+        tag.push_str(format!(" {}=\"{}\"", attr.0, escape_text(attr.1.as_str(), ascii)).as_str());
+    }
+    tag.push('>');
+    tag
+}
+
+
+fn text_content(text: &str, cdata: bool, ascii: bool) -> String
+{
+    // Readers trim the whitespace around escaped text, but not CDATA.
+    let markup = text.contains('<') || text.contains('>') || text.contains('&') || text.trim() != text;
+    if cdata && markup && !text.contains("]]>") && (!ascii || text.is_ascii())
+    {
+        format!("<![CDATA[{}]]>", text)
+    }
+    else
+    {
+        escape_text(text, ascii)
+    }
+}
+
+
+fn escape_text(text: &str, ascii: bool) -> String
+{
+    let escaped = escape(text);
+    if !ascii || escaped.is_ascii()
+    {
+        return escaped;
+    }
+
+    let mut referenced = String::with_capacity(escaped.len());
+    for c in escaped.chars()
+    {
+        if c.is_ascii()
+        {
+            referenced.push(c);
+        }
+        else
+        {
+            referenced.push_str(format!("&#{};", c as u32).as_str());
+        }
+    }
+    referenced
+}
+
+
+fn push_indent(xml: &mut String, depth: usize, indent: Option<usize>)
+{
+    if let Some(width) = indent
+    {
+        xml.push_str(" ".repeat(depth * width).as_str());
+    }
+}
+
+
+fn push_newline(xml: &mut String, indent: Option<usize>)
+{
+    if indent.is_some()
+    {
+        xml.push('\n');
+    }
+}
+
+
+fn push_text_node(elements: &mut Vec<XmlElement>, name: &str, text: &Option<String>)
+{
+    if let Some(ref text) = *text
+    {
+        elements.push(text_node(name, text));
+    }
+}
+
+
+fn parent_node(name: &str, children: Vec<XmlElement>) -> XmlElement
+{
+    XmlElement {
+        name: name.to_owned(),
+        children,
+        ..Default::default()
+    }
+}
+
+
+fn category_element(category: &Category) -> XmlElement
+{
+    let mut element = text_node("category", category.name.as_str());
+    if let Some(ref domain) = category.domain
+    {
+        element.attrs.push(("domain".to_owned(), domain.clone()));
+    }
+    element
+}
+
+
+// The extensions in order of prefix and then name, apart from the un-prefixed
+// elements in `skipped` that have their own place. The `dc` elements are left
+// out when `skip_dc` is set, as the `DublinCoreExtension` is written instead.
+fn extension_elements(extensions: &ExtensionMap, skip_dc: bool, skipped: &[&str]) -> Vec<XmlElement>
+{
+    let mut prefixes: Vec<&String> = extensions.keys().filter(|prefix| !skip_dc || *prefix != "dc").collect();
+    prefixes.sort();
+
+    let mut elements = Vec::new();
+    for prefix in prefixes
+    {
+        let map = &extensions[prefix];
+        let mut names: Vec<&String> = map.keys()
+            .filter(|name| !prefix.is_empty() || !skipped.contains(&name.as_str()))
+            .collect();
+        names.sort();
+
+        for name in names
+        {
+            elements.extend(map[name].iter().map(extension_element));
+        }
+    }
+    elements
+}
+
+
+// Common code to convert an `Extension` to an element, its attributes and
+// children in order of name.
+pub fn extension_element(extension: &Extension) -> XmlElement
+{
+    let mut attrs: Vec<(String, String)> = extension.attrs.iter().map(|attr| (attr.0.clone(), attr.1.clone())).collect();
+    attrs.sort();
+
+    let mut names: Vec<&String> = extension.children.keys().collect();
+    names.sort();
+
+    XmlElement {
+        name: extension.name.clone(),
+        attrs,
+        text: extension.value.clone(),
+        children: names.into_iter().flat_map(|name| extension.children[name].iter().map(extension_element)).collect(),
+    }
+}
+
+
+fn itunes_channel_elements(itunes_ext: &ITunesChannelExtension) -> Vec<XmlElement>
+{
+    let mut elements = Vec::new();
+
+    push_text_node(&mut elements, "itunes:author", &itunes_ext.author);
+    push_text_node(&mut elements, "itunes:block", &itunes_ext.block);
+    elements.extend(itunes_ext.categories.iter().map(itunes_category_element));
+    push_itunes_image(&mut elements, &itunes_ext.image);
+    push_text_node(&mut elements, "itunes:explicit", &itunes_ext.explicit);
+    push_text_node(&mut elements, "itunes:complete", &itunes_ext.complete);
+    push_text_node(&mut elements, "itunes:new-feed-url", &itunes_ext.new_feed_url);
+
+    if let Some(ref owner) = itunes_ext.owner
+    {
+        let mut children = Vec::new();
+        push_text_node(&mut children, "itunes:name", &owner.name);
+        push_text_node(&mut children, "itunes:email", &owner.email);
+        elements.push(parent_node("itunes:owner", children));
+    }
+
+    push_text_node(&mut elements, "itunes:subtitle", &itunes_ext.subtitle);
+    push_text_node(&mut elements, "itunes:summary", &itunes_ext.summary);
+    push_text_node(&mut elements, "itunes:keywords", &itunes_ext.keywords);
+    elements
+}
+
+
+fn itunes_item_elements(itunes_ext: &ITunesItemExtension) -> Vec<XmlElement>
+{
+    let mut elements = Vec::new();
+
+    push_text_node(&mut elements, "itunes:author", &itunes_ext.author);
+    push_text_node(&mut elements, "itunes:block", &itunes_ext.block);
+    push_itunes_image(&mut elements, &itunes_ext.image);
+    push_text_node(&mut elements, "itunes:duration", &itunes_ext.duration);
+    push_text_node(&mut elements, "itunes:explicit", &itunes_ext.explicit);
+    push_text_node(&mut elements, "itunes:isClosedCaptioned", &itunes_ext.closed_captioned);
+    push_text_node(&mut elements, "itunes:order", &itunes_ext.order);
+    push_text_node(&mut elements, "itunes:subtitle", &itunes_ext.subtitle);
+    push_text_node(&mut elements, "itunes:summary", &itunes_ext.summary);
+    push_text_node(&mut elements, "itunes:keywords", &itunes_ext.keywords);
+    elements
+}
+
+
+fn push_itunes_image(elements: &mut Vec<XmlElement>, image: &Option<String>)
+{
+    if let Some(ref href) = *image
+    {
+        elements.push(XmlElement {
+                          name: "itunes:image".to_owned(),
+                          attrs: vec![("href".to_owned(), href.clone())],
+                          ..Default::default()
+                      });
+    }
+}
+
+
+fn itunes_category_element(category: &ITunesCategory) -> XmlElement
+{
+    XmlElement {
+        name: "itunes:category".to_owned(),
+        attrs: vec![("text".to_owned(), category.text.clone())],
+        children: category.subcategory.iter().map(|sub| itunes_category_element(sub)).collect(),
+        ..Default::default()
+    }
+}
+
+
+fn dublin_core_elements(dublin_core_ext: &DublinCoreExtension) -> Vec<XmlElement>
+{
+    let fields = [("dc:contributor", &dublin_core_ext.contributor),
+                  ("dc:coverage", &dublin_core_ext.coverage),
+                  ("dc:creator", &dublin_core_ext.creator),
+                  ("dc:date", &dublin_core_ext.date),
+                  ("dc:description", &dublin_core_ext.description),
+                  ("dc:format", &dublin_core_ext.format),
+                  ("dc:identifier", &dublin_core_ext.identifier),
+                  ("dc:language", &dublin_core_ext.language),
+                  ("dc:publisher", &dublin_core_ext.publisher),
+                  ("dc:relation", &dublin_core_ext.relation),
+                  ("dc:rights", &dublin_core_ext.rights),
+                  ("dc:source", &dublin_core_ext.source),
+                  ("dc:subject", &dublin_core_ext.subject),
+                  ("dc:title", &dublin_core_ext.title),
+                  ("dc:type", &dublin_core_ext.resource_type)];
+
+    fields
+        .iter()
+        .flat_map(|field| field.1.iter().map(move |val| text_node(field.0, val)))
+        .collect()
+}